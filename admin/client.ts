@@ -29,6 +29,7 @@ import {
 } from "./market_types";
 import { createPriceLadder } from "./price_ladders";
 import { printMarket } from "./print_market";
+import { migrateAccount } from "./migrate_account";
 
 if (process.argv.length < 3) {
   printUsageAndExit();
@@ -121,6 +122,9 @@ switch (script) {
   case "createProduct":
     create_product();
     break;
+  case "migrateAccount":
+    migrateAccount();
+    break;
   default:
     printUsageAndExit();
 }
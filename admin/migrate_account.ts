@@ -0,0 +1,31 @@
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  findAuthorisedOperatorsAccountPda,
+  Operator,
+} from "../npm-admin-client/src";
+import { getAnchorProvider, getProtocolProgram } from "./util";
+
+export async function migrateAccount() {
+  if (process.argv.length != 4) {
+    console.log("Usage: yarn run migrateAccount <ACCOUNT_ID>");
+    process.exit(1);
+  }
+
+  const accountPk = new PublicKey(process.argv[3]);
+  const protocolProgram = await getProtocolProgram();
+  const adminOperators = await findAuthorisedOperatorsAccountPda(
+    protocolProgram,
+    Operator.ADMIN,
+  );
+
+  const tx = await protocolProgram.methods
+    .migrateAccount()
+    .accounts({
+      account: accountPk,
+      adminOperator: getAnchorProvider().wallet.publicKey,
+      adminOperators: adminOperators.data.pda,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+  console.log(tx);
+}
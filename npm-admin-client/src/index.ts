@@ -6,6 +6,10 @@ export * from "./operators";
 export * from "./market_outcome";
 export * from "./market_outcome_instruction";
 export * from "./market_outcome_prices";
+export * from "./market_order_book";
+export * from "./market_access_control";
+export * from "./market_settlement_proposal";
+export * from "./market_limits";
 export * from "./market_type_create";
 export * from "./market_validate";
 export * from "./market_helpers";
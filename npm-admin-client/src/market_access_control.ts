@@ -0,0 +1,235 @@
+import { Program, AnchorProvider } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ClientResponse,
+  CreateMarketAccessControlResponse,
+  FindPdaResponse,
+  Operator,
+  PurchaserListMode,
+  ResponseFactory,
+  TransactionResponse,
+} from "../types";
+import { findAuthorisedOperatorsAccountPda } from "./operators";
+
+/**
+ * For the given market, create the access control account checked when order requests are created - the market must be initializing or open, and program must be initialized by the `MARKET` operator that initialised the market
+ *
+ * Once created, the access control account must be provided when order requests are created or orders amended
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey for the market to create the access control for
+ * @param purchaserListMode {PurchaserListMode} whether the purchasers list is ignored, the purchasers allowed, or the purchasers denied
+ * @param purchasersCapacity {number} max number of purchasers the list can hold, space for them is allocated up front
+ * @param tokenGateMint {PublicKey} Optional: mint of a token purchasers must hold to create order requests
+ * @returns {CreateMarketAccessControlResponse} the market access control PDA and the transaction ID for the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const accessControl = await createMarketAccessControl(program, marketPk, PurchaserListModeValue.allow, 100)
+ */
+export async function createMarketAccessControl(
+  program: Program,
+  marketPk: PublicKey,
+  purchaserListMode: PurchaserListMode,
+  purchasersCapacity: number,
+  tokenGateMint?: PublicKey,
+): Promise<ClientResponse<CreateMarketAccessControlResponse>> {
+  const response = new ResponseFactory(
+    {} as CreateMarketAccessControlResponse,
+  );
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  const marketAccessControlPda = findMarketAccessControlPda(program, marketPk);
+  if (!marketAccessControlPda.success) {
+    response.addErrors(marketAccessControlPda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await program.methods
+      .createMarketAccessControl(
+        purchaserListMode,
+        tokenGateMint ?? null,
+        purchasersCapacity,
+      )
+      .accounts({
+        marketAccessControl: marketAccessControlPda.data.pda,
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    response.addResponseData({
+      marketAccessControlPda: marketAccessControlPda.data.pda,
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the given market, update the purchaser list mode and token gate mint of its access control; the purchasers list is kept as is
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey for the market to update the access control for
+ * @param purchaserListMode {PurchaserListMode} whether the purchasers list is ignored, the purchasers allowed, or the purchasers denied
+ * @param tokenGateMint {PublicKey} Optional: mint of a token purchasers must hold to create order requests, if omitted the token gate is removed
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const update = await updateMarketAccessControl(program, marketPk, PurchaserListModeValue.deny)
+ */
+export async function updateMarketAccessControl(
+  program: Program,
+  marketPk: PublicKey,
+  purchaserListMode: PurchaserListMode,
+  tokenGateMint?: PublicKey,
+): Promise<ClientResponse<TransactionResponse>> {
+  return await sendMarketAccessControlUpdate(program, marketPk, (methods) =>
+    methods.updateMarketAccessControl(purchaserListMode, tokenGateMint ?? null),
+  );
+}
+
+/**
+ * For the given market, add purchasers to its access control list; purchasers already on the list are ignored
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey for the market to update the access control for
+ * @param purchasers {PublicKey[]} purchasers to add to the list
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const purchaserPk = new PublicKey('5BZWY6XWPxuWFxs2jagkmUkCoBWmJ6c4YEArr83hYBWk')
+ * const update = await addMarketAccessPurchasers(program, marketPk, [purchaserPk])
+ */
+export async function addMarketAccessPurchasers(
+  program: Program,
+  marketPk: PublicKey,
+  purchasers: PublicKey[],
+): Promise<ClientResponse<TransactionResponse>> {
+  return await sendMarketAccessControlUpdate(program, marketPk, (methods) =>
+    methods.addMarketAccessPurchasers(purchasers),
+  );
+}
+
+/**
+ * For the given market, remove purchasers from its access control list
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey for the market to update the access control for
+ * @param purchasers {PublicKey[]} purchasers to remove from the list
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const purchaserPk = new PublicKey('5BZWY6XWPxuWFxs2jagkmUkCoBWmJ6c4YEArr83hYBWk')
+ * const update = await removeMarketAccessPurchasers(program, marketPk, [purchaserPk])
+ */
+export async function removeMarketAccessPurchasers(
+  program: Program,
+  marketPk: PublicKey,
+  purchasers: PublicKey[],
+): Promise<ClientResponse<TransactionResponse>> {
+  return await sendMarketAccessControlUpdate(program, marketPk, (methods) =>
+    methods.removeMarketAccessPurchasers(purchasers),
+  );
+}
+
+async function sendMarketAccessControlUpdate(
+  program: Program,
+  marketPk: PublicKey,
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  buildMethod: (methods: Program["methods"]) => any,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  const marketAccessControlPda = findMarketAccessControlPda(program, marketPk);
+  if (!marketAccessControlPda.success) {
+    response.addErrors(marketAccessControlPda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await buildMethod(program.methods)
+      .accounts({
+        marketAccessControl: marketAccessControlPda.data.pda,
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+      })
+      .rpc();
+
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the provided market publicKey, return the PDA (publicKey) of the market access control account
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @returns {FindPdaResponse} PDA of the market access control account
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketAccessControlPda = findMarketAccessControlPda(program, marketPk)
+ */
+export function findMarketAccessControlPda(
+  program: Program,
+  marketPk: PublicKey,
+): ClientResponse<FindPdaResponse> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [Buffer.from("access_control"), marketPk.toBuffer()],
+      program.programId,
+    );
+
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
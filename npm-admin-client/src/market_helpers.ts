@@ -232,6 +232,45 @@ export async function findMarketLiquiditiesPda(
   return response.body;
 }
 
+/**
+ * For the provided market publicKey and outcome index, return the PDA (publicKey) of the market outcome liquidities account.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @param marketOutcomeIndex {number} index of the market outcome
+ * @returns {FindPdaResponse} PDA of the market outcome liquidities account
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketOutcomeLiquiditiesPk = await findMarketOutcomeLiquiditiesPda(program, marketPK, 0)
+ */
+export async function findMarketOutcomeLiquiditiesPda(
+  program: Program,
+  marketPk: PublicKey,
+  marketOutcomeIndex: number,
+): Promise<ClientResponse<FindPdaResponse>> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("liquidities"),
+        marketPk.toBuffer(),
+        Buffer.from(marketOutcomeIndex.toString()),
+      ],
+      program.programId,
+    );
+
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
 /**
  * For the provided market publicKey, return the PDA (publicKey) of the market matching-queue account.
  *
@@ -0,0 +1,183 @@
+import { Program, AnchorProvider, BN } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ClientResponse,
+  CreateMarketLimitsResponse,
+  FindPdaResponse,
+  MarketLimitsValues,
+  Operator,
+  ResponseFactory,
+  TransactionResponse,
+} from "../types";
+import { findAuthorisedOperatorsAccountPda } from "./operators";
+
+/**
+ * For the given market, create the limits account enforced on order requests - the market must still be initializing, with all of its outcomes added, and program must be initialized by the `MARKET` operator that initialised the market
+ *
+ * Once created, the limits account must be provided when order requests are created or processed; a limit of 0 is not enforced
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey for the market to create the limits for
+ * @param limits {MarketLimitsValues} max stake per order, max exposure per market position and max matched stake per outcome
+ * @returns {CreateMarketLimitsResponse} the market limits PDA and the transaction ID for the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const limits = {
+ *   maxOrderStake: new BN(100_000_000),
+ *   maxPositionExposure: new BN(500_000_000),
+ *   maxOutcomeStakeMatched: new BN(0),
+ * }
+ * const marketLimits = await createMarketLimits(program, marketPk, limits)
+ */
+export async function createMarketLimits(
+  program: Program,
+  marketPk: PublicKey,
+  limits: MarketLimitsValues,
+): Promise<ClientResponse<CreateMarketLimitsResponse>> {
+  const response = new ResponseFactory({} as CreateMarketLimitsResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  const marketLimitsPda = findMarketLimitsPda(program, marketPk);
+  if (!marketLimitsPda.success) {
+    response.addErrors(marketLimitsPda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await program.methods
+      .createMarketLimits(
+        new BN(limits.maxOrderStake),
+        new BN(limits.maxPositionExposure),
+        new BN(limits.maxOutcomeStakeMatched),
+      )
+      .accounts({
+        marketLimits: marketLimitsPda.data.pda,
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    response.addResponseData({
+      marketLimitsPda: marketLimitsPda.data.pda,
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the given market, update the limits enforced on order requests - the market must be initializing or open; lowered limits only apply to order requests created or processed from then on
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey for the market to update the limits for
+ * @param limits {MarketLimitsValues} max stake per order, max exposure per market position and max matched stake per outcome
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const limits = {
+ *   maxOrderStake: new BN(0),
+ *   maxPositionExposure: new BN(500_000_000),
+ *   maxOutcomeStakeMatched: new BN(2_000_000_000),
+ * }
+ * const update = await updateMarketLimits(program, marketPk, limits)
+ */
+export async function updateMarketLimits(
+  program: Program,
+  marketPk: PublicKey,
+  limits: MarketLimitsValues,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  const marketLimitsPda = findMarketLimitsPda(program, marketPk);
+  if (!marketLimitsPda.success) {
+    response.addErrors(marketLimitsPda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await program.methods
+      .updateMarketLimits(
+        new BN(limits.maxOrderStake),
+        new BN(limits.maxPositionExposure),
+        new BN(limits.maxOutcomeStakeMatched),
+      )
+      .accounts({
+        marketLimits: marketLimitsPda.data.pda,
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+      })
+      .rpc();
+
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the provided market publicKey, return the PDA (publicKey) of the market limits account
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @returns {FindPdaResponse} PDA of the market limits account
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketLimitsPda = findMarketLimitsPda(program, marketPk)
+ */
+export function findMarketLimitsPda(
+  program: Program,
+  marketPk: PublicKey,
+): ClientResponse<FindPdaResponse> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [Buffer.from("limits"), marketPk.toBuffer()],
+      program.programId,
+    );
+
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
@@ -4,10 +4,13 @@ import {
   TransactionResponse,
   ClientResponse,
   EpochTimeStamp,
+  MarketOrderBehaviour,
+  OutcomeResult,
   ResponseFactory,
   TransactionOptions,
 } from "../types";
 import {
+  getAssociatedTokenAddressSync,
   getOrCreateAssociatedTokenAccount,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -96,6 +99,45 @@ export async function settleMarket(
   );
 }
 
+/**
+ * Settle a handicap or total market with a result for each outcome, the first outcome being the side the line applies to
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to settle
+ * @param outcomeResults {OutcomeResult[]} result of each outcome, e.g., half-win for the first outcome and half-loss for the second
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const outcomeResults = [OutcomeResultValue.halfWin, OutcomeResultValue.halfLoss]
+ * const settledMarket = await settleMarketWithResults(program, marketPk, outcomeResults)
+ */
+export async function settleMarketWithResults(
+  program: Program,
+  marketPk: PublicKey,
+  outcomeResults: OutcomeResult[],
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const instruction = await buildMarketManagementInstruction(
+    program,
+    marketPk,
+    MarketManagementInstructionType.SETTLE_WITH_RESULTS,
+    { outcomeResults },
+  );
+  return await sendManagementTransaction(
+    program,
+    [instruction.data.instruction],
+    instruction.errors,
+    options,
+  );
+}
+
 /**
  * Set the published flag on a market to `true`
  *
@@ -347,6 +389,241 @@ export async function setMarketEventStartToNow(
   );
 }
 
+/**
+ * For the given market, update the window in seconds after matching within which trades can be voided, 0 disables voiding trades
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to update
+ * @param tradeVoidWindow {number} number of seconds after a trade is matched that it can be voided
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const tradeVoidWindow = 300
+ * const update = await updateMarketTradeVoidWindow(program, marketPk, tradeVoidWindow)
+ */
+export async function updateMarketTradeVoidWindow(
+  program: Program,
+  marketPk: PublicKey,
+  tradeVoidWindow: number,
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const instruction = await buildMarketManagementInstruction(
+    program,
+    marketPk,
+    MarketManagementInstructionType.UPDATE_TRADE_VOID_WINDOW,
+    { tradeVoidWindow },
+  );
+  return await sendManagementTransaction(
+    program,
+    [instruction.data.instruction],
+    instruction.errors,
+    options,
+  );
+}
+
+/**
+ * For the given market, enable or disable price improvement; when enabled taker orders are matched best price first, including cross liquidity rounded onto the price ladder
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to update
+ * @param priceImprovementEnabled {boolean} whether taker orders are matched best price first
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const update = await updateMarketPriceImprovement(program, marketPk, true)
+ */
+export async function updateMarketPriceImprovement(
+  program: Program,
+  marketPk: PublicKey,
+  priceImprovementEnabled: boolean,
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const instruction = await buildMarketManagementInstruction(
+    program,
+    marketPk,
+    MarketManagementInstructionType.UPDATE_PRICE_IMPROVEMENT,
+    { priceImprovementEnabled },
+  );
+  return await sendManagementTransaction(
+    program,
+    [instruction.data.instruction],
+    instruction.errors,
+    options,
+  );
+}
+
+/**
+ * For the given market, enable or disable atomic matching; when enabled maker matches are completed in the same transaction as the taker order, so the matching queue never needs cranking
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to update
+ * @param atomicMatchingEnabled {boolean} whether matches are completed when the taker order is processed
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const update = await updateMarketAtomicMatching(program, marketPk, true)
+ */
+export async function updateMarketAtomicMatching(
+  program: Program,
+  marketPk: PublicKey,
+  atomicMatchingEnabled: boolean,
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const instruction = await buildMarketManagementInstruction(
+    program,
+    marketPk,
+    MarketManagementInstructionType.UPDATE_ATOMIC_MATCHING,
+    { atomicMatchingEnabled },
+  );
+  return await sendManagementTransaction(
+    program,
+    [instruction.data.instruction],
+    instruction.errors,
+    options,
+  );
+}
+
+/**
+ * For the given market, set the crank fee charged on each order request and the reward paid from collected fees to cranks for each order request and maker match they process
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to update
+ * @param crankFee {number} fee charged on each order request, in the market's token
+ * @param crankReward {number} reward paid to the crank for each step, in the market's token, no more than crankFee
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const update = await updateMarketCrankFee(program, marketPk, 1000, 250)
+ */
+export async function updateMarketCrankFee(
+  program: Program,
+  marketPk: PublicKey,
+  crankFee: number,
+  crankReward: number,
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const instruction = await buildMarketManagementInstruction(
+    program,
+    marketPk,
+    MarketManagementInstructionType.UPDATE_CRANK_FEE,
+    { crankFee, crankReward },
+  );
+  return await sendManagementTransaction(
+    program,
+    [instruction.data.instruction],
+    instruction.errors,
+    options,
+  );
+}
+
+/**
+ * For the given market, set the behaviour on suspension; with MarketOrderBehaviourValue.cancelUnmatched, suspending the market clears its liquidities and unmatched orders resting at the time can then be cancelled by anyone
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to update
+ * @param suspensionOrderBehaviour {MarketOrderBehaviour} protocol behaviour to perform when the market is suspended
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const update = await updateMarketSuspensionOrderBehaviour(program, marketPk, MarketOrderBehaviourValue.cancelUnmatched)
+ */
+export async function updateMarketSuspensionOrderBehaviour(
+  program: Program,
+  marketPk: PublicKey,
+  suspensionOrderBehaviour: MarketOrderBehaviour,
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const instruction = await buildMarketManagementInstruction(
+    program,
+    marketPk,
+    MarketManagementInstructionType.UPDATE_SUSPENSION_ORDER_BEHAVIOUR,
+    { suspensionOrderBehaviour },
+  );
+  return await sendManagementTransaction(
+    program,
+    [instruction.data.instruction],
+    instruction.errors,
+    options,
+  );
+}
+
+/**
+ * For the given market, withdraw an outcome; orders on the outcome are voided on settlement and winnings on trades matched on the remaining outcomes before the withdrawal are reduced by the reduction factor
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to update
+ * @param outcomeIndex {number} index of the outcome to withdraw
+ * @param reductionFactor {number} reduction factor in basis points, the total for all withdrawn outcomes cannot exceed 7500
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const outcomeIndex = 3
+ * const reductionFactor = 1500
+ * const withdrawal = await withdrawMarketOutcome(program, marketPk, outcomeIndex, reductionFactor)
+ */
+export async function withdrawMarketOutcome(
+  program: Program,
+  marketPk: PublicKey,
+  outcomeIndex: number,
+  reductionFactor: number,
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const instruction = await buildMarketManagementInstruction(
+    program,
+    marketPk,
+    MarketManagementInstructionType.WITHDRAW_OUTCOME,
+    { withdrawnOutcomeIndex: outcomeIndex, reductionFactor },
+  );
+  return await sendManagementTransaction(
+    program,
+    [instruction.data.instruction],
+    instruction.errors,
+    options,
+  );
+}
+
 /**
  * For the given market, update the lock time
  *
@@ -626,3 +903,93 @@ export async function transferMarketTokenSurplus(
   }
   return response.body;
 }
+
+/**
+ * Void the stake common to a for and against trade of the same outcome and price, refunding both purchasers from the market escrow.
+ *
+ * Trades can only be voided within the market trade void window after they were matched, and only if voiding does not increase either purchaser's exposure.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market the trades belong to
+ * @param forTradePk {PublicKey} publicKey of the trade backing the outcome
+ * @param againstTradePk {PublicKey} publicKey of the trade laying the outcome
+ * @param options {TransactionOptions} optional parameters:
+ *   <ul>
+ *     <li> computeUnitLimit - number of compute units to limit the transaction to</li>
+ *     <li> computeUnitPrice - price in micro lamports per compute unit for the transaction</li>
+ *   </ul>
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const forTradePk = new PublicKey('5BZWY6XWPxuWFxs2jagkmUkCoBWmJ6c4YEArr83hYBWk')
+ * const againstTradePk = new PublicKey('E4YEQpkedH8SbcRkN1iByoRnH8HZeBcTnqrrWkjpqLXA')
+ * const voidTxn = await voidTrades(program, marketPk, forTradePk, againstTradePk)
+ */
+export async function voidTrades(
+  program: Program,
+  marketPk: PublicKey,
+  forTradePk: PublicKey,
+  againstTradePk: PublicKey,
+  options?: TransactionOptions,
+): Promise<ClientResponse<TransactionResponse>> {
+  const { response, provider, authorisedOperators } =
+    await setupManagementRequest(program);
+
+  if (!authorisedOperators.success) {
+    response.addErrors(authorisedOperators.errors);
+    return response.body;
+  }
+
+  const marketEscrow = await findEscrowPda(program, marketPk);
+  if (!marketEscrow.success) {
+    response.addErrors(marketEscrow.errors);
+    return response.body;
+  }
+
+  try {
+    const market = await program.account.market.fetch(marketPk);
+    const forTrade = await program.account.trade.fetch(forTradePk);
+    const againstTrade = await program.account.trade.fetch(againstTradePk);
+    const tradeAccounts = (trade: {
+      order: PublicKey;
+      purchaser: PublicKey;
+    }) => ({
+      order: trade.order,
+      marketPosition: PublicKey.findProgramAddressSync(
+        [trade.purchaser.toBuffer(), marketPk.toBuffer()],
+        program.programId,
+      )[0],
+      purchaserToken: getAssociatedTokenAddressSync(
+        market.mintAccount as PublicKey,
+        trade.purchaser,
+      ),
+    });
+    const forAccounts = tradeAccounts(forTrade);
+    const againstAccounts = tradeAccounts(againstTrade);
+
+    const instruction = await program.methods
+      .voidTrades()
+      .accounts({
+        market: marketPk,
+        marketEscrow: marketEscrow.data.pda,
+        forTrade: forTradePk,
+        forOrder: forAccounts.order,
+        forMarketPosition: forAccounts.marketPosition,
+        forPurchaserToken: forAccounts.purchaserToken,
+        againstTrade: againstTradePk,
+        againstOrder: againstAccounts.order,
+        againstMarketPosition: againstAccounts.marketPosition,
+        againstPurchaserToken: againstAccounts.purchaserToken,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperators.data.pda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+    return await sendManagementTransaction(program, [instruction], [], options);
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+}
@@ -7,6 +7,9 @@ import {
   ClientResponse,
   MarketInstructionResponse,
   MarketAccount,
+  MarketOrderBehaviour,
+  MarketWinningOutcome,
+  OutcomeResult,
 } from "../types";
 import { findAuthorisedOperatorsAccountPda } from "./operators";
 import {
@@ -16,6 +19,7 @@ import {
   findMarketLiquiditiesPda,
   findMarketMatchingQueuePda,
   findMarketOrderRequestQueuePda,
+  findMarketOutcomeLiquiditiesPda,
 } from "./market_helpers";
 
 export enum MarketManagementInstructionType {
@@ -32,15 +36,32 @@ export enum MarketManagementInstructionType {
   UPDATE_MARKET_EVENT_START_TIME = 10,
   UPDATE_MARKET_EVENT_START_TIME_TO_NOW = 11,
   UPDATE_MARKET_LOCK_TIME_TO_NOW = 12,
+  SETTLE_WITH_RESULTS = 13,
+  UPDATE_TRADE_VOID_WINDOW = 14,
+  WITHDRAW_OUTCOME = 15,
+  UPDATE_PRICE_IMPROVEMENT = 16,
+  UPDATE_ATOMIC_MATCHING = 17,
+  UPDATE_CRANK_FEE = 18,
+  UPDATE_SUSPENSION_ORDER_BEHAVIOUR = 19,
 }
 
 export type MarketUpdateInstructionData = {
   winningOutcomeIndex?: number;
+  winningOutcomes?: MarketWinningOutcome[];
+  outcomeResults?: OutcomeResult[];
   title?: string;
   marketLockTimestamp?: number;
   marketMatchingQueuePk?: PublicKey;
   eventStartTimeTimestamp?: number;
   enableCrossMatching?: boolean;
+  tradeVoidWindow?: number;
+  withdrawnOutcomeIndex?: number;
+  reductionFactor?: number;
+  priceImprovementEnabled?: boolean;
+  atomicMatchingEnabled?: boolean;
+  crankFee?: number;
+  crankReward?: number;
+  suspensionOrderBehaviour?: MarketOrderBehaviour;
 };
 
 export async function buildMarketManagementInstruction(
@@ -59,9 +80,21 @@ export async function buildMarketManagementInstruction(
 
   switch (instructionType) {
     case MarketManagementInstructionType.SETTLE: {
-      if (instructionData?.winningOutcomeIndex === undefined) {
+      if (
+        instructionData?.winningOutcomeIndex === undefined &&
+        instructionData?.winningOutcomes === undefined
+      ) {
         throw new Error(
-          "winningOutcomeIndex is required in instructionData. Received: " +
+          "winningOutcomeIndex or winningOutcomes is required in instructionData. Received: " +
+            JSON.stringify(instructionData),
+        );
+      }
+      break;
+    }
+    case MarketManagementInstructionType.SETTLE_WITH_RESULTS: {
+      if (instructionData?.outcomeResults === undefined) {
+        throw new Error(
+          "outcomeResults is required in instructionData. Received: " +
             JSON.stringify(instructionData),
         );
       }
@@ -85,6 +118,66 @@ export async function buildMarketManagementInstruction(
       }
       break;
     }
+    case MarketManagementInstructionType.UPDATE_TRADE_VOID_WINDOW: {
+      if (instructionData?.tradeVoidWindow === undefined) {
+        throw new Error(
+          "tradeVoidWindow is required in instructionData. Received: " +
+            JSON.stringify(instructionData),
+        );
+      }
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_PRICE_IMPROVEMENT: {
+      if (instructionData?.priceImprovementEnabled === undefined) {
+        throw new Error(
+          "priceImprovementEnabled is required in instructionData. Received: " +
+            JSON.stringify(instructionData),
+        );
+      }
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_ATOMIC_MATCHING: {
+      if (instructionData?.atomicMatchingEnabled === undefined) {
+        throw new Error(
+          "atomicMatchingEnabled is required in instructionData. Received: " +
+            JSON.stringify(instructionData),
+        );
+      }
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_CRANK_FEE: {
+      if (
+        instructionData?.crankFee === undefined ||
+        instructionData?.crankReward === undefined
+      ) {
+        throw new Error(
+          "crankFee and crankReward are required in instructionData. Received: " +
+            JSON.stringify(instructionData),
+        );
+      }
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_SUSPENSION_ORDER_BEHAVIOUR: {
+      if (instructionData?.suspensionOrderBehaviour === undefined) {
+        throw new Error(
+          "suspensionOrderBehaviour is required in instructionData. Received: " +
+            JSON.stringify(instructionData),
+        );
+      }
+      break;
+    }
+    case MarketManagementInstructionType.WITHDRAW_OUTCOME: {
+      if (
+        instructionData?.withdrawnOutcomeIndex === undefined ||
+        instructionData?.reductionFactor === undefined
+      ) {
+        throw new Error(
+          "withdrawnOutcomeIndex and reductionFactor are required in instructionData. Received: " +
+            JSON.stringify(instructionData),
+        );
+      }
+      break;
+    }
     case MarketManagementInstructionType.UPDATE_MARKET_EVENT_START_TIME: {
       if (!instructionData?.eventStartTimeTimestamp) {
         throw new Error(
@@ -122,13 +215,52 @@ export async function buildMarketManagementInstruction(
       break;
     }
     case MarketManagementInstructionType.SUSPEND: {
+      const market = (await program.account.market.fetch(
+        marketPk,
+      )) as MarketAccount;
+      // cancelling unmatched orders clears liquidities for every outcome
+      const cancelUnmatched =
+        market.suspensionOrderBehaviour.cancelUnmatched !== undefined;
+      const marketLiquiditiesPk = cancelUnmatched
+        ? (await findMarketLiquiditiesPda(program, marketPk)).data.pda
+        : null;
+      const marketMatchingQueuePk = cancelUnmatched
+        ? (await findMarketMatchingQueuePda(program, marketPk)).data.pda
+        : null;
+      const marketOutcomeLiquiditiesPks = cancelUnmatched
+        ? await Promise.all(
+            [...Array(market.marketOutcomesCount).keys()].map(
+              async (index) =>
+                (
+                  await findMarketOutcomeLiquiditiesPda(
+                    program,
+                    marketPk,
+                    index,
+                  )
+                ).data.pda,
+            ),
+          )
+        : [];
       const instruction = await program.methods
         .suspendMarket()
         .accounts({
           market: new PublicKey(marketPk),
           authorisedOperators: authorisedOperators.data.pda,
           marketOperator: provider.wallet.publicKey,
+          // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+          // @ts-ignore
+          marketLiquidities: marketLiquiditiesPk,
+          // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+          // @ts-ignore
+          marketMatchingQueue: marketMatchingQueuePk,
         })
+        .remainingAccounts(
+          marketOutcomeLiquiditiesPks.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          })),
+        )
         .instruction();
       response.addResponseData({ instruction: instruction });
       break;
@@ -258,7 +390,15 @@ export async function buildMarketManagementInstruction(
         marketPk,
       );
       const instruction = await program.methods
-        .settleMarket(instructionData?.winningOutcomeIndex)
+        .settleMarket(
+          instructionData?.winningOutcomes ?? [
+            {
+              outcomeIndex: instructionData?.winningOutcomeIndex,
+              deadHeatNumerator: 1,
+              deadHeatDenominator: 1,
+            },
+          ],
+        )
         .accounts({
           market: marketPk,
           marketMatchingQueue: marketMatchingQueuePk.data.pda,
@@ -272,6 +412,28 @@ export async function buildMarketManagementInstruction(
       response.addResponseData({ instruction: instruction });
       break;
     }
+    case MarketManagementInstructionType.SETTLE_WITH_RESULTS: {
+      const market = (await program.account.market.fetch(
+        marketPk,
+      )) as MarketAccount;
+      const instruction = await program.methods
+        .settleMarketWithResults(instructionData?.outcomeResults)
+        .accounts({
+          market: marketPk,
+          marketType: market.marketType,
+          marketMatchingQueue: (
+            await findMarketMatchingQueuePda(program, marketPk)
+          ).data.pda,
+          orderRequestQueue: (
+            await findMarketOrderRequestQueuePda(program, marketPk)
+          ).data.pda,
+          authorisedOperators: authorisedOperators.data.pda,
+          marketOperator: provider.wallet.publicKey,
+        })
+        .instruction();
+      response.addResponseData({ instruction: instruction });
+      break;
+    }
     case MarketManagementInstructionType.UPDATE_TITLE: {
       const instruction = await program.methods
         .updateMarketTitle(instructionData?.title)
@@ -284,6 +446,112 @@ export async function buildMarketManagementInstruction(
       response.addResponseData({ instruction: instruction });
       break;
     }
+    case MarketManagementInstructionType.UPDATE_TRADE_VOID_WINDOW: {
+      const instruction = await program.methods
+        .updateMarketTradeVoidWindow(new BN(instructionData?.tradeVoidWindow))
+        .accounts({
+          market: marketPk,
+          authorisedOperators: authorisedOperators.data.pda,
+          marketOperator: provider.wallet.publicKey,
+        })
+        .instruction();
+      response.addResponseData({ instruction: instruction });
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_PRICE_IMPROVEMENT: {
+      const instruction = await program.methods
+        .updateMarketPriceImprovement(instructionData?.priceImprovementEnabled)
+        .accounts({
+          market: marketPk,
+          authorisedOperators: authorisedOperators.data.pda,
+          marketOperator: provider.wallet.publicKey,
+        })
+        .instruction();
+      response.addResponseData({ instruction: instruction });
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_ATOMIC_MATCHING: {
+      const instruction = await program.methods
+        .updateMarketAtomicMatching(instructionData?.atomicMatchingEnabled)
+        .accounts({
+          market: marketPk,
+          authorisedOperators: authorisedOperators.data.pda,
+          marketOperator: provider.wallet.publicKey,
+        })
+        .instruction();
+      response.addResponseData({ instruction: instruction });
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_SUSPENSION_ORDER_BEHAVIOUR: {
+      const instruction = await program.methods
+        .updateMarketSuspensionOrderBehaviour(
+          instructionData?.suspensionOrderBehaviour,
+        )
+        .accounts({
+          market: marketPk,
+          authorisedOperators: authorisedOperators.data.pda,
+          marketOperator: provider.wallet.publicKey,
+        })
+        .instruction();
+      response.addResponseData({ instruction: instruction });
+      break;
+    }
+    case MarketManagementInstructionType.UPDATE_CRANK_FEE: {
+      const instruction = await program.methods
+        .updateMarketCrankFee(
+          new BN(instructionData?.crankFee),
+          new BN(instructionData?.crankReward),
+        )
+        .accounts({
+          market: marketPk,
+          authorisedOperators: authorisedOperators.data.pda,
+          marketOperator: provider.wallet.publicKey,
+        })
+        .instruction();
+      response.addResponseData({ instruction: instruction });
+      break;
+    }
+    case MarketManagementInstructionType.WITHDRAW_OUTCOME: {
+      const [
+        liquiditiesPk,
+        matchingQueuePk,
+        orderRequestQueuePk,
+        outcomeLiquiditiesPk,
+      ] = await Promise.all([
+        findMarketLiquiditiesPda(program, marketPk),
+        findMarketMatchingQueuePda(program, marketPk),
+        findMarketOrderRequestQueuePda(program, marketPk),
+        findMarketOutcomeLiquiditiesPda(
+          program,
+          marketPk,
+          instructionData?.withdrawnOutcomeIndex as number,
+        ),
+      ]);
+      const instruction = await program.methods
+        .withdrawMarketOutcome(
+          instructionData?.withdrawnOutcomeIndex,
+          instructionData?.reductionFactor,
+        )
+        .accounts({
+          market: marketPk,
+          marketLiquidities: liquiditiesPk.data.pda,
+          marketMatchingQueue: matchingQueuePk.data.pda,
+          orderRequestQueue: orderRequestQueuePk.data.pda,
+          authorisedOperators: authorisedOperators.data.pda,
+          marketOperator: provider.wallet.publicKey,
+        })
+        // liquidity of the withdrawn outcome is cleared
+        .remainingAccounts([
+          {
+            pubkey: outcomeLiquiditiesPk.data.pda,
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
+        .instruction();
+      response.addResponseData({ instruction: instruction });
+      break;
+    }
     case MarketManagementInstructionType.UPDATE_LOCK_TIME: {
       const instruction = await program.methods
         .updateMarketLocktime(new BN(instructionData?.marketLockTimestamp))
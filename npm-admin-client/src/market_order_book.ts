@@ -0,0 +1,133 @@
+import { Program, AnchorProvider } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ClientResponse,
+  CreateOrderBookPageResponse,
+  FindPdaResponse,
+  Operator,
+  ResponseFactory,
+} from "../types";
+import { findAuthorisedOperatorsAccountPda } from "./operators";
+import { findMarketOutcomePda } from "./market_outcome_instruction";
+
+/**
+ * For the given market, outcome index and page index, create the order book page tracking the liquidity at each price of that page of the outcome's price ladder - the market must still be initializing and program must be initialized by the `MARKET` operator that initialised the market
+ *
+ * Once a page is created, order books are enabled for the market and the page covering an order's price must be provided when the order is processed, cancelled or matched
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey for the market the outcome is associated with
+ * @param outcomeIndex {number} index representing the outcome on the market
+ * @param pageIndex {number} index of the page of the outcome's price ladder, each page covers up to 100 prices
+ * @param priceLadderPk {PublicKey} Optional: publicKey of the price ladder the outcome was initialised with
+ * @returns {CreateOrderBookPageResponse} the order book page PDA and the transaction ID for the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const outcomeIndex = 1
+ * const pageIndex = 0
+ * const orderBookPage = await createMarketOrderBookPage(program, marketPk, outcomeIndex, pageIndex)
+ */
+export async function createMarketOrderBookPage(
+  program: Program,
+  marketPk: PublicKey,
+  outcomeIndex: number,
+  pageIndex: number,
+  priceLadderPk?: PublicKey,
+): Promise<ClientResponse<CreateOrderBookPageResponse>> {
+  const response = new ResponseFactory({} as CreateOrderBookPageResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const [authorisedOperatorsPda, marketOutcomePda, orderBookPagePda] =
+    await Promise.all([
+      findAuthorisedOperatorsAccountPda(program, Operator.MARKET),
+      findMarketOutcomePda(program, marketPk, outcomeIndex),
+      findMarketOrderBookPda(program, marketPk, outcomeIndex, pageIndex),
+    ]);
+
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  if (!marketOutcomePda.success) {
+    response.addErrors(marketOutcomePda.errors);
+    return response.body;
+  }
+
+  if (!orderBookPagePda.success) {
+    response.addErrors(orderBookPagePda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await program.methods
+      .createMarketOrderBookPage(outcomeIndex, pageIndex)
+      .accounts({
+        marketOutcome: marketOutcomePda.data.pda,
+        // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+        // @ts-ignore
+        priceLadder: priceLadderPk == undefined ? null : priceLadderPk,
+        marketOrderBook: orderBookPagePda.data.pda,
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    response.addResponseData({
+      orderBookPagePda: orderBookPagePda.data.pda,
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the provided market publicKey, outcome index and page index, return the PDA (publicKey) of the order book page
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @param marketOutcomeIndex {number} index representing the outcome on the market
+ * @param pageIndex {number} index of the page of the outcome's price ladder
+ * @returns {FindPdaResponse} PDA of the order book page
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const orderBookPagePda = findMarketOrderBookPda(program, marketPk, 1, 0)
+ */
+export function findMarketOrderBookPda(
+  program: Program,
+  marketPk: PublicKey,
+  marketOutcomeIndex: number,
+  pageIndex: number,
+): ClientResponse<FindPdaResponse> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("order_book"),
+        marketPk.toBuffer(),
+        Buffer.from(marketOutcomeIndex.toString()),
+        Buffer.from("-"),
+        Buffer.from(pageIndex.toString()),
+      ],
+      program.programId,
+    );
+
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
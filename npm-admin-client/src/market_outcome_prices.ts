@@ -12,6 +12,7 @@ import {
 } from "../types";
 import { findAuthorisedOperatorsAccountPda } from "./operators";
 import { findMarketOutcomePda } from "./market_outcome_instruction";
+import { toPrice } from "./utils";
 
 /**
  * For the given market and outcome index, add the provided prices to the price ladder for that outcome - program must be initialized by the `MARKET` operator that initialised the market
@@ -57,7 +58,7 @@ export async function addPricesToOutcome(
 
   try {
     const tnxId = await program.methods
-      .addPricesToMarketOutcome(outcomeIndex, priceLadder)
+      .addPricesToMarketOutcome(outcomeIndex, priceLadder.map(toPrice))
       .accounts({
         systemProgram: SystemProgram.programId,
         outcome: marketOutcomePda.data.pda,
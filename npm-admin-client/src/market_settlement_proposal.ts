@@ -0,0 +1,357 @@
+import { Program, AnchorProvider, BN } from "@coral-xyz/anchor";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  ClientResponse,
+  CreateMarketSettlementProposalResponse,
+  FindPdaResponse,
+  MarketAccount,
+  MarketSettlementProposalAccount,
+  MarketWinningOutcome,
+  Operator,
+  ResponseFactory,
+  SettlementProposalAction,
+  TransactionResponse,
+} from "../types";
+import { findAuthorisedOperatorsAccountPda } from "./operators";
+import {
+  findMarketMatchingQueuePda,
+  findMarketOrderRequestQueuePda,
+} from "./market_helpers";
+
+/**
+ * For the given market, set the number of `MARKET` operator approvals required to settle or force void it, and the challenge period in seconds after the approvals are reached before it can be executed - program must be initialized by the market authority or a `MARKET` operator delegated the settle permission
+ *
+ * Once approvals are required the market can no longer be settled or force voided directly, and the settings can only be raised
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to update
+ * @param approvalsRequired {number} number of approvals required, at most 16 and at most the number of `MARKET` operators permitted to both settle and force void
+ * @param challengePeriod {number} seconds after the approvals are reached during which the proposal can still be cancelled, at most one week
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const update = await updateMarketSettlementApprovals(program, marketPk, 2, 3600)
+ */
+export async function updateMarketSettlementApprovals(
+  program: Program,
+  marketPk: PublicKey,
+  approvalsRequired: number,
+  challengePeriod: number,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await program.methods
+      .updateMarketSettlementApprovals(
+        approvalsRequired,
+        new BN(challengePeriod),
+      )
+      .accounts({
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+      })
+      .rpc();
+
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the given market, propose its settlement or force void; the proposal counts as the first approval - program must be initialized by the market authority or an operator delegated the permission for the action
+ *
+ * Only one proposal can exist for a market at a time, it is closed when executed or cancelled
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to propose the settlement of
+ * @param action {SettlementProposalAction} whether the market is to be settled or force voided
+ * @param winningOutcomes {MarketWinningOutcome[]} Optional: winning outcomes when settling, omitted when force voiding
+ * @returns {CreateMarketSettlementProposalResponse} the market settlement proposal PDA and the transaction ID for the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const winningOutcomes = [{ outcomeIndex: 0, deadHeatNumerator: 1, deadHeatDenominator: 1 }]
+ * const proposal = await proposeMarketSettlement(program, marketPk, SettlementProposalActionValue.settle, winningOutcomes)
+ */
+export async function proposeMarketSettlement(
+  program: Program,
+  marketPk: PublicKey,
+  action: SettlementProposalAction,
+  winningOutcomes: MarketWinningOutcome[] = [],
+): Promise<ClientResponse<CreateMarketSettlementProposalResponse>> {
+  const response = new ResponseFactory(
+    {} as CreateMarketSettlementProposalResponse,
+  );
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  const proposalPda = findMarketSettlementProposalPda(program, marketPk);
+  if (!proposalPda.success) {
+    response.addErrors(proposalPda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await program.methods
+      .proposeMarketSettlement(action, winningOutcomes)
+      .accounts({
+        marketSettlementProposal: proposalPda.data.pda,
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    response.addResponseData({
+      marketSettlementProposalPda: proposalPda.data.pda,
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the given market, approve its settlement proposal - program must be initialized by a `MARKET` operator holding the permission for the proposed action
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market the proposal is for
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const approval = await approveMarketSettlementProposal(program, marketPk)
+ */
+export async function approveMarketSettlementProposal(
+  program: Program,
+  marketPk: PublicKey,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  const proposalPda = findMarketSettlementProposalPda(program, marketPk);
+  if (!proposalPda.success) {
+    response.addErrors(proposalPda.errors);
+    return response.body;
+  }
+
+  try {
+    const tnxId = await program.methods
+      .approveMarketSettlementProposal()
+      .accounts({
+        marketSettlementProposal: proposalPda.data.pda,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+      })
+      .rpc();
+
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the given market, execute its approved settlement proposal once the challenge period has passed, settling or force voiding the market; can be sent by any wallet and the proposal rent is returned to the proposer
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market the proposal is for
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const execution = await executeMarketSettlementProposal(program, marketPk)
+ */
+export async function executeMarketSettlementProposal(
+  program: Program,
+  marketPk: PublicKey,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+
+  const proposalPda = findMarketSettlementProposalPda(program, marketPk);
+  if (!proposalPda.success) {
+    response.addErrors(proposalPda.errors);
+    return response.body;
+  }
+
+  try {
+    const proposal = (await program.account.marketSettlementProposal.fetch(
+      proposalPda.data.pda,
+    )) as MarketSettlementProposalAccount;
+    const market = (await program.account.market.fetch(
+      marketPk,
+    )) as MarketAccount;
+
+    const marketMatchingQueuePk = market.marketStatus.initializing
+      ? null
+      : (await findMarketMatchingQueuePda(program, marketPk)).data.pda;
+    const orderRequestQueuePk = market.marketStatus.initializing
+      ? null
+      : (await findMarketOrderRequestQueuePda(program, marketPk)).data.pda;
+
+    const tnxId = await program.methods
+      .executeMarketSettlementProposal()
+      .accounts({
+        marketSettlementProposal: proposalPda.data.pda,
+        market: marketPk,
+        // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+        // @ts-ignore
+        marketMatchingQueue: marketMatchingQueuePk,
+        // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+        // @ts-ignore
+        orderRequestQueue: orderRequestQueuePk,
+        proposer: proposal.proposer,
+      })
+      .rpc();
+
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the given market, cancel its settlement proposal, e.g., to challenge it during the challenge period - program must be initialized by a `MARKET` operator holding the permission for the proposed action
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market the proposal is for
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const cancellation = await cancelMarketSettlementProposal(program, marketPk)
+ */
+export async function cancelMarketSettlementProposal(
+  program: Program,
+  marketPk: PublicKey,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPda = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+  if (!authorisedOperatorsPda.success) {
+    response.addErrors(authorisedOperatorsPda.errors);
+    return response.body;
+  }
+
+  const proposalPda = findMarketSettlementProposalPda(program, marketPk);
+  if (!proposalPda.success) {
+    response.addErrors(proposalPda.errors);
+    return response.body;
+  }
+
+  try {
+    const proposal = (await program.account.marketSettlementProposal.fetch(
+      proposalPda.data.pda,
+    )) as MarketSettlementProposalAccount;
+
+    const tnxId = await program.methods
+      .cancelMarketSettlementProposal()
+      .accounts({
+        marketSettlementProposal: proposalPda.data.pda,
+        proposer: proposal.proposer,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPda.data.pda,
+      })
+      .rpc();
+
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+
+  return response.body;
+}
+
+/**
+ * For the provided market publicKey, return the PDA (publicKey) of its settlement proposal account
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @returns {FindPdaResponse} PDA of the market settlement proposal account
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const proposalPda = findMarketSettlementProposalPda(program, marketPk)
+ */
+export function findMarketSettlementProposalPda(
+  program: Program,
+  marketPk: PublicKey,
+): ClientResponse<FindPdaResponse> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [Buffer.from("settlement_proposal"), marketPk.toBuffer()],
+      program.programId,
+    );
+
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
@@ -6,7 +6,11 @@ import {
   GetOrCreateAccountResponse,
   FindPdaResponse,
 } from "../types";
-import { MarketType } from "../types/market_type";
+import {
+  MarketTemplate,
+  MarketTemplateValue,
+  MarketType,
+} from "../types/market_type";
 
 export function findMarketTypePda(
   program: Program,
@@ -32,6 +36,7 @@ export function findMarketTypePda(
  * @param marketTypeName {string} name of the market type being fetched or created
  * @param requiresDiscriminator {boolean} if creating a new market type, whether the market type requires a discriminator to be set on markets using the type
  * @param requiresValue {boolean} if creating a new market type, whether the market type requires a value (e.g. a number) to be set on markets using the type
+ * @param template {MarketTemplate} if creating a new market type, how markets using the type are settled - handicap and total templates read their line from the market type value and require a value
  * @returns {GetOrCreateAccountResponse} the market type account and public key - if a new account was created, also includes the transaction id
  *
  * @example
@@ -46,6 +51,7 @@ export async function getOrCreateMarketType(
   marketTypeName: string,
   requiresDiscriminator = false,
   requiresValue = false,
+  template: MarketTemplate = MarketTemplateValue.standard,
 ): Promise<ClientResponse<GetOrCreateAccountResponse<MarketType>>> {
   const response = new ResponseFactory({});
 
@@ -59,7 +65,12 @@ export async function getOrCreateMarketType(
   } catch (_) {
     try {
       txId = await program.methods
-        .createMarketType(marketTypeName, requiresDiscriminator, requiresValue)
+        .createMarketType(
+          marketTypeName,
+          requiresDiscriminator,
+          requiresValue,
+          template,
+        )
         .accounts({ marketType: publicKey })
         .rpc();
       account = await program.account.marketType.fetch(publicKey);
@@ -1,6 +1,7 @@
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { getMarketOutcomesByMarket } from "./market_helpers";
+import { fromPrice } from "./utils";
 import {
   ClientResponse,
   ResponseFactory,
@@ -105,7 +106,7 @@ function validateOutcomePriceLadder(
   const missingPrices = [] as number[];
   const additionalPrices = [] as number[];
   const outcomePk = outcome.publicKey;
-  const priceLadder = outcome.account.priceLadder;
+  const priceLadder = outcome.account.priceLadder.map(fromPrice);
   for (const expectedPrice of expectedPriceLadder) {
     if (!priceLadder.includes(+expectedPrice)) {
       priceLadderValid = false;
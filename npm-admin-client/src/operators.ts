@@ -8,6 +8,7 @@ import {
   ClientResponse,
   ResponseFactory,
   FindPdaResponse,
+  TransactionResponse,
 } from "../types";
 import { findPdaWithSeeds } from "./utils";
 
@@ -94,6 +95,151 @@ export async function authoriseAdminOperator(
   return response.body;
 }
 
+/**
+ * Replaces the permissions held by an operator already authorised for the given operator type - program must be initialized an `ADMIN` operator
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param operatorType {Operator} type of operator to update the permissions for
+ * @param operatorPk {PublicKey} publicKey of the authorised operator
+ * @param permissions {number} permission flags to hold, see `OperatorPermission`
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const operatorPk = new PublicKey('5BZWY6XWPxuWFxs2jagkmUkCoBWmJ6c4YEArr83hYBWk')
+ * const permissions = OperatorPermission.SETTLE | OperatorPermission.VOID
+ * const update = await updateOperatorPermissions(program, Operator.MARKET, operatorPk, permissions)
+ */
+export async function updateOperatorPermissions(
+  program: Program,
+  operatorType: Operator,
+  operatorPk: PublicKey,
+  permissions: number,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const [authorisedOperatorsPk, adminOperatorsPk] = await Promise.all([
+    findAuthorisedOperatorsAccountPda(program, operatorType),
+    findAuthorisedOperatorsAccountPda(program, Operator.ADMIN),
+  ]);
+
+  try {
+    const tnxId = await program.methods
+      .updateOperatorPermissions(operatorType, operatorPk, permissions)
+      .accounts({
+        authorisedOperators: authorisedOperatorsPk.data.pda,
+        adminOperators: adminOperatorsPk.data.pda,
+        adminOperator: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+  return response.body;
+}
+
+/**
+ * Delegates permissions on the given market to another `MARKET` operator - program must be initialized by the market authority, which can only delegate permissions it holds itself
+ *
+ * The delegated operator must also hold the permissions as a `MARKET` operator; delegating to an operator already delegated to replaces its permissions
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to delegate permissions on
+ * @param operatorPk {PublicKey} publicKey of the operator to delegate to
+ * @param permissions {number} permission flags to delegate, see `OperatorPermission`
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const operatorPk = new PublicKey('5BZWY6XWPxuWFxs2jagkmUkCoBWmJ6c4YEArr83hYBWk')
+ * const delegation = await delegateMarketOperator(program, marketPk, operatorPk, OperatorPermission.SETTLE)
+ */
+export async function delegateMarketOperator(
+  program: Program,
+  marketPk: PublicKey,
+  operatorPk: PublicKey,
+  permissions: number,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPk = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+
+  try {
+    const tnxId = await program.methods
+      .delegateMarketOperator(operatorPk, permissions)
+      .accounts({
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPk.data.pda,
+      })
+      .rpc();
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+  return response.body;
+}
+
+/**
+ * Revokes all permissions delegated on the given market to an operator - program must be initialized by the market authority
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of the market to revoke the delegation on
+ * @param operatorPk {PublicKey} publicKey of the operator delegated to
+ * @returns {TransactionResponse} transaction ID of the request
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const operatorPk = new PublicKey('5BZWY6XWPxuWFxs2jagkmUkCoBWmJ6c4YEArr83hYBWk')
+ * const revocation = await revokeMarketOperatorDelegation(program, marketPk, operatorPk)
+ */
+export async function revokeMarketOperatorDelegation(
+  program: Program,
+  marketPk: PublicKey,
+  operatorPk: PublicKey,
+): Promise<ClientResponse<TransactionResponse>> {
+  const response = new ResponseFactory({} as TransactionResponse);
+  const provider = program.provider as AnchorProvider;
+
+  const authorisedOperatorsPk = await findAuthorisedOperatorsAccountPda(
+    program,
+    Operator.MARKET,
+  );
+
+  try {
+    const tnxId = await program.methods
+      .revokeMarketOperatorDelegation(operatorPk)
+      .accounts({
+        market: marketPk,
+        marketOperator: provider.wallet.publicKey,
+        authorisedOperators: authorisedOperatorsPk.data.pda,
+      })
+      .rpc();
+    response.addResponseData({
+      tnxId: tnxId,
+    });
+  } catch (e) {
+    response.addError(e);
+    return response.body;
+  }
+  return response.body;
+}
+
 /**
  * For the provided operator type account, fine the pda of the authorised operators account
  *
@@ -7,6 +7,7 @@ import {
   TransactionResponse,
   TransactionBatchResponse,
 } from "../types";
+import { toPrice } from "./utils";
 
 /**
  * Find a PDA for a price ladder.
@@ -141,7 +142,7 @@ export async function addPricesToPriceLadder(
     try {
       tnxIds.push(
         await program.methods
-          .addPricesToPriceLadder(priceBatch)
+          .addPricesToPriceLadder(priceBatch.map(toPrice))
           .accounts({
             priceLadder: priceLadderPk,
             authority: provider.wallet.publicKey,
@@ -183,7 +184,7 @@ export async function removePricesFromPriceLadder(
     try {
       tnxIds.push(
         await program.methods
-          .removePricesFromPriceLadder(priceBatch)
+          .removePricesFromPriceLadder(priceBatch.map(toPrice))
           .accounts({
             priceLadder: priceLadderPk,
             authority: provider.wallet.publicKey,
@@ -1,10 +1,15 @@
-import { Program, AnchorProvider, web3 } from "@coral-xyz/anchor";
+import { Program, AnchorProvider, BN, web3 } from "@coral-xyz/anchor";
 import {
   PublicKey,
   TransactionInstruction,
   ComputeBudgetProgram,
 } from "@solana/web3.js";
-import { ClientResponse, ResponseFactory } from "../types";
+import {
+  ClientResponse,
+  ResponseFactory,
+  Price,
+  PRICE_DECIMALS,
+} from "../types";
 import {
   SignAndSendInstructionsBatchResponse,
   SignAndSendInstructionsResponse,
@@ -196,3 +201,30 @@ export async function signAndSendInstructionsBatch(
 
   return response.body;
 }
+
+/**
+ * Helper function to convert a ui price into its on chain representation
+ *
+ * @param price {number} ui price, e.g. 2.5
+ * @returns {Price} price as stored on chain
+ *
+ * @example
+ * const price = toPrice(2.5)
+ * // returns { value: 2_500 } with the value represented as a BN
+ */
+export function toPrice(price: number): Price {
+  return { value: new BN(Math.round(price * 10 ** PRICE_DECIMALS)) };
+}
+
+/**
+ * Helper function to convert an on chain price into a ui price
+ *
+ * @param price {Price} price as stored on chain
+ * @returns {number} ui price, e.g. 2.5
+ *
+ * @example
+ * const price = fromPrice(marketOutcome.priceLadder[0])
+ */
+export function fromPrice(price: Price): number {
+  return price.value.toNumber() / 10 ** PRICE_DECIMALS;
+}
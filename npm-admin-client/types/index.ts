@@ -5,4 +5,5 @@ export * from "./markets";
 export * from "./market_type";
 export * from "./market_outcomes";
 export * from "./operator";
+export * from "./price";
 export * from "./transactions";
@@ -1,5 +1,6 @@
 import { PublicKey } from "@solana/web3.js";
 import { GetAccount } from "./account";
+import { Price } from "./price";
 
 export type GetPublicKeys = {
   publicKeys: PublicKey[];
@@ -10,7 +11,7 @@ export type MarketOutcomeAccount = {
   title: string;
   market: PublicKey;
   prices: PublicKey | null;
-  priceLadder: number[];
+  priceLadder: Price[];
 };
 
 export type MarketOutcomeAccounts = {
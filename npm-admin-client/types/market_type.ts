@@ -1,3 +1,18 @@
+export class MarketTemplate {
+  standard?: Record<string, never>;
+  handicap?: Record<string, never>;
+  total?: Record<string, never>;
+}
+
+export const MarketTemplateValue = {
+  standard: { standard: {} } as MarketTemplate,
+  handicap: { handicap: {} } as MarketTemplate,
+  total: { total: {} } as MarketTemplate,
+};
+
 export type MarketType = {
   name: string;
+  requiresDiscriminator: boolean;
+  requiresValue: boolean;
+  template: MarketTemplate;
 };
@@ -47,6 +47,66 @@ export type AddPricesToOutcomeResponse = {
   tnxId: string;
 };
 
+export type CreateOrderBookPageResponse = {
+  orderBookPagePda: PublicKey;
+  tnxId: string;
+};
+
+export type MarketLimitsValues = {
+  maxOrderStake: BN | number;
+  maxPositionExposure: BN | number;
+  maxOutcomeStakeMatched: BN | number;
+};
+
+export type CreateMarketLimitsResponse = {
+  marketLimitsPda: PublicKey;
+  tnxId: string;
+};
+
+export class PurchaserListMode {
+  none?: Record<string, never>;
+  allow?: Record<string, never>;
+  deny?: Record<string, never>;
+}
+
+export const PurchaserListModeValue = {
+  none: { none: {} } as PurchaserListMode,
+  allow: { allow: {} } as PurchaserListMode,
+  deny: { deny: {} } as PurchaserListMode,
+};
+
+export type CreateMarketAccessControlResponse = {
+  marketAccessControlPda: PublicKey;
+  tnxId: string;
+};
+
+export class SettlementProposalAction {
+  settle?: Record<string, never>;
+  forceVoid?: Record<string, never>;
+}
+
+export const SettlementProposalActionValue = {
+  settle: { settle: {} } as SettlementProposalAction,
+  forceVoid: { forceVoid: {} } as SettlementProposalAction,
+};
+
+export type CreateMarketSettlementProposalResponse = {
+  marketSettlementProposalPda: PublicKey;
+  tnxId: string;
+};
+
+export type MarketSettlementProposalAccount = {
+  market: PublicKey;
+  proposer: PublicKey;
+  action: SettlementProposalAction;
+  winningOutcomes: MarketWinningOutcome[];
+  approvals: PublicKey[];
+  approvalsRequired: number;
+  challengePeriod: BN;
+  proposedTimestamp: BN;
+  approvedTimestamp?: BN;
+};
+
 export type BatchAddPricesToOutcomeResponse = {
   batches: AddPricesToOutcomeResponse[];
 };
@@ -104,6 +164,39 @@ export const MarketOrderBehaviourValue = {
   cancelUnmatched: { cancelUnmatched: {} } as MarketOrderBehaviour,
 };
 
+export type MarketWinningOutcome = {
+  outcomeIndex: number;
+  deadHeatNumerator: number;
+  deadHeatDenominator: number;
+};
+
+export type WithdrawnOutcome = {
+  outcomeIndex: number;
+  reductionFactor: number;
+  withdrawalTimestamp: BN;
+};
+
+export type MarketOperatorDelegation = {
+  operator: PublicKey;
+  permissions: number;
+};
+
+export class OutcomeResult {
+  win?: Record<string, never>;
+  halfWin?: Record<string, never>;
+  push?: Record<string, never>;
+  halfLoss?: Record<string, never>;
+  loss?: Record<string, never>;
+}
+
+export const OutcomeResultValue = {
+  win: { win: {} } as OutcomeResult,
+  halfWin: { halfWin: {} } as OutcomeResult,
+  push: { push: {} } as OutcomeResult,
+  halfLoss: { halfLoss: {} } as OutcomeResult,
+  loss: { loss: {} } as OutcomeResult,
+};
+
 export type MarketAccount = {
   authority: BN;
   decimalLimit: number;
@@ -118,7 +211,8 @@ export type MarketAccount = {
   marketType: PublicKey;
   marketTypeDiscriminator: string;
   marketTypeValue: string;
-  marketWinningOutcomeIndex?: number;
+  marketWinningPlaces: number;
+  marketWinningOutcomes: MarketWinningOutcome[];
   mintAccount: PublicKey;
   published: boolean;
   suspended: boolean;
@@ -131,6 +225,23 @@ export type MarketAccount = {
   unsettledAccountsCount: number;
   unclosedAccountsCount: number;
   version: number;
+  schemaVersion: number;
+  marketOutcomeResults: OutcomeResult[];
+  tradeVoidWindow: BN;
+  withdrawnOutcomes: WithdrawnOutcome[];
+  orderBookEnabled: boolean;
+  priceImprovementEnabled: boolean;
+  limitsEnabled: boolean;
+  accessControlEnabled: boolean;
+  operatorDelegations: MarketOperatorDelegation[];
+  settlementApprovalsRequired: number;
+  settlementChallengePeriod: BN;
+  atomicMatchingEnabled: boolean;
+  crankFee: BN;
+  crankReward: BN;
+  crankFeeBalance: BN;
+  suspensionOrderBehaviour: MarketOrderBehaviour;
+  suspensionTimestamp: BN;
 };
 
 export type EpochTimeStamp = number;
@@ -6,6 +6,19 @@ export enum Operator {
   ADMIN = "ADMIN",
 }
 
+/**
+ * Permission flags held by operators, combine with bitwise or, e.g., `OperatorPermission.SETTLE | OperatorPermission.VOID`
+ */
+export const OperatorPermission = {
+  SETTLE: 1 << 0,
+  VOID: 1 << 1,
+  SUSPEND: 1 << 2,
+  UPDATE_TIMES: 1 << 3,
+  CREATE_MARKETS: 1 << 4,
+  CRANK: 1 << 5,
+  ALL: (1 << 6) - 1,
+};
+
 export type AuthoriseOperatorResponse = {
   tnxId: string;
   authorisedOperatorsPk: PublicKey;
@@ -15,6 +28,7 @@ export type AuthoriseOperatorResponse = {
 export type AuthorisedOperatorsAccount = {
   authority: PublicKey;
   operatorList: PublicKey[];
+  operatorPermissions: number[];
 };
 
 export type AuthorisedOperatorsAccountResponse = {
@@ -0,0 +1,10 @@
+import { BN } from "@coral-xyz/anchor";
+
+/**
+ * Price as stored on chain, an integer with 3 implied decimals, e.g. a value of 2_500 is a price of 2.5
+ */
+export type Price = {
+  value: BN;
+};
+
+export const PRICE_DECIMALS = 3;
@@ -1,6 +1,6 @@
 import { PublicKey } from "@solana/web3.js";
 import { Program } from "@coral-xyz/anchor";
-import { signAndSendInstructions } from "./utils";
+import { fromPrice, signAndSendInstructions } from "./utils";
 import {
   ClientResponse,
   ResponseFactory,
@@ -157,7 +157,8 @@ export function calculateOrderCancellationRefund(
     }
   } else {
     const orderExposure =
-      order.stakeUnmatched * order.expectedPrice - order.stakeUnmatched;
+      order.stakeUnmatched * fromPrice(order.expectedPrice) -
+      order.stakeUnmatched;
     unmatchedExposures[order.marketOutcomeIndex] -= orderExposure;
   }
 
@@ -1,7 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { AnchorProvider, Program } from "@coral-xyz/anchor";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { findEscrowPda } from "./utils";
+import { findEscrowPda, fromPrice } from "./utils";
 import { getOrder } from "./order";
 import { getMarket } from "./markets";
 import { getWalletTokenAccount } from "./wallet_tokens";
@@ -16,8 +16,12 @@ import { findMarketPositionPda } from "./market_position";
 import { findMarketMatchingPoolPda } from "./market_matching_pools";
 import { findMarketOutcomePda } from "./market_outcomes";
 import { getCancellableOrdersByMarketForProviderWallet } from "./order_query";
-import { findMarketLiquiditiesPda } from "./market_liquidities";
+import {
+  findMarketLiquiditiesPda,
+  findMarketOutcomeLiquiditiesPda,
+} from "./market_liquidities";
 import { findMarketMatchingQueuePda } from "./market_matching_queues";
+import { findMarketOrderBookPdaForPrice } from "./market_order_books";
 
 /**
  * Constructs the instruction required to perform a cancel order transaction.
@@ -67,13 +71,15 @@ export async function buildCancelOrderInstruction(
     liquiditiesPda,
     matchingQueuePda,
     purchaserTokenAccount,
+    marketOrderBookPda,
+    marketOutcomeLiquiditiesPda,
   ] = await Promise.all([
     findMarketPositionPda(program, order.market, provider.wallet.publicKey),
     findMarketMatchingPoolPda(
       program,
       order.market,
       order.marketOutcomeIndex,
-      order.expectedPrice,
+      fromPrice(order.expectedPrice),
       order.forOutcome,
     ),
     findMarketOutcomePda(program, order.market, order.marketOutcomeIndex),
@@ -81,6 +87,17 @@ export async function buildCancelOrderInstruction(
     findMarketLiquiditiesPda(program, order.market),
     findMarketMatchingQueuePda(program, order.market),
     getWalletTokenAccount(program, mintPk),
+    findMarketOrderBookPdaForPrice(
+      program,
+      order.market,
+      order.marketOutcomeIndex,
+      fromPrice(order.expectedPrice),
+    ),
+    findMarketOutcomeLiquiditiesPda(
+      program,
+      order.market,
+      order.marketOutcomeIndex,
+    ),
   ]);
 
   const instruction = await program.methods
@@ -99,7 +116,20 @@ export async function buildCancelOrderInstruction(
       marketMatchingQueue: matchingQueuePda.data.pda,
       mint: mintPk,
       tokenProgram: TOKEN_PROGRAM_ID,
+      // only markets with an order book have pages
+      // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+      // @ts-ignore
+      marketOrderBook: marketOrderBookPda.success
+        ? marketOrderBookPda.data.pda
+        : null,
     })
+    .remainingAccounts([
+      {
+        pubkey: marketOutcomeLiquiditiesPda.data.pda,
+        isSigner: false,
+        isWritable: true,
+      },
+    ])
     .instruction();
 
   response.addResponseData({ orderPk, instruction });
@@ -161,7 +191,12 @@ export async function buildCancelOrdersForMarketInstructions(
   const instructions = await Promise.all(
     orders.map(async (order) => {
       const orderPk = order.publicKey;
-      const [marketOutcomePda, marketMatchingPoolPda] = await Promise.all([
+      const [
+        marketOutcomePda,
+        marketMatchingPoolPda,
+        marketOrderBookPda,
+        marketOutcomeLiquiditiesPda,
+      ] = await Promise.all([
         findMarketOutcomePda(
           program,
           order.account.market,
@@ -171,9 +206,22 @@ export async function buildCancelOrdersForMarketInstructions(
           program,
           order.account.market,
           order.account.marketOutcomeIndex,
-          order.account.expectedPrice,
+          fromPrice(order.account.expectedPrice),
           order.account.forOutcome,
         ),
+        market.orderBookEnabled
+          ? findMarketOrderBookPdaForPrice(
+              program,
+              order.account.market,
+              order.account.marketOutcomeIndex,
+              fromPrice(order.account.expectedPrice),
+            )
+          : undefined,
+        findMarketOutcomeLiquiditiesPda(
+          program,
+          order.account.market,
+          order.account.marketOutcomeIndex,
+        ),
       ]);
       const instruction = await program.methods
         .cancelOrder()
@@ -192,7 +240,19 @@ export async function buildCancelOrdersForMarketInstructions(
           marketMatchingQueue: matchingQueuePda.data.pda,
           mint: market.mintAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
+          // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+          // @ts-ignore
+          marketOrderBook: marketOrderBookPda?.success
+            ? marketOrderBookPda.data.pda
+            : null,
         })
+        .remainingAccounts([
+          {
+            pubkey: marketOutcomeLiquiditiesPda.data.pda,
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
         .instruction();
       return { orderPk, instruction };
     }),
@@ -1,14 +1,18 @@
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { AnchorProvider, BN, Program } from "@coral-xyz/anchor";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { getMarketAccounts, uiStakeToInteger } from "./utils";
+import { getMarketAccounts, toPrice, uiStakeToInteger } from "./utils";
 import { getWalletTokenAccount } from "./wallet_tokens";
 import {
   OrderInstructionResponse,
   ClientResponse,
   ResponseFactory,
+  TimeInForce,
+  TimeInForceValue,
 } from "../types";
 import { findOrderPda } from "./order";
+import { findMarketLimitsPda } from "./market_limits";
+import { findMarketAccessControlPda } from "./market_access_control";
 
 /**
  * Constructs the instruction required to perform a create order transaction using a UI stake value, the client calculates the actual stake value based on mintInfo.data.decimals using uiStakeToInteger().
@@ -75,6 +79,9 @@ export async function buildOrderInstructionUIStake(
  * @param options.priceLadderPk {PublicKey} Optional: publicKey of the price ladder associated with the market outcome - if there is one
  * @param options.productPk {PublicKey} Optional: publicKey of product account this order was created on
  * @param options.expiresOn {BN} Optional: unix timestamp (seconds) defining expiration of request; if omitted or null or undefined order request will never expire
+ * @param options.timeInForce {TimeInForce} Optional: what happens to stake not matched when the request is processed; if omitted the unmatched stake stays on the book
+ * @param options.postOnly {boolean} Optional: if true the order is rejected when processed if any of its stake would be matched; requires the default time in force
+ * @param options.purchaserGateTokenPk {PublicKey} Optional: publicKey of the purchaser token account holding the gate token, required if the market access control is token gated
  * @returns {OrderInstructionResponse}  derived order publicKey and the instruction to perform a create order transaction
  *
  * @example
@@ -100,6 +107,9 @@ export async function buildOrderInstruction(
     priceLadderPk?: PublicKey;
     productPk?: PublicKey;
     expiresOn?: BN;
+    timeInForce?: TimeInForce;
+    postOnly?: boolean;
+    purchaserGateTokenPk?: PublicKey;
   },
 ): Promise<ClientResponse<OrderInstructionResponse>> {
   const response = new ResponseFactory({} as OrderInstructionResponse);
@@ -119,9 +129,16 @@ export async function buildOrderInstruction(
 
   const marketTokenPk = new PublicKey(marketAccounts.data.market.mintAccount);
 
-  const [purchaserTokenAccount, orderPdaResponse] = await Promise.all([
+  const [
+    purchaserTokenAccount,
+    orderPdaResponse,
+    marketLimitsPda,
+    marketAccessControlPda,
+  ] = await Promise.all([
     getWalletTokenAccount(program, marketTokenPk),
     findOrderPda(program, marketPk, provider.wallet.publicKey),
+    findMarketLimitsPda(program, marketPk),
+    findMarketAccessControlPda(program, marketPk),
   ]);
 
   if (!purchaserTokenAccount.success) {
@@ -141,9 +158,11 @@ export async function buildOrderInstruction(
       marketOutcomeIndex: marketOutcomeIndex,
       forOutcome: forOutcome,
       stake: stake,
-      price: price,
+      price: toPrice(price),
       distinctSeed: distinctSeed,
       expiresOn: options.expiresOn ?? null,
+      timeInForce: options.timeInForce ?? TimeInForceValue.goodTillCancelled,
+      postOnly: options.postOnly ?? false,
     })
     .accounts({
       reservedOrder: orderPk,
@@ -163,6 +182,19 @@ export async function buildOrderInstruction(
       // @ts-ignore
       product: options.productPk ?? null,
       orderRequestQueue: marketAccounts.data.marketOrderRequestQueuePda,
+      // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+      // @ts-ignore
+      marketLimits: marketAccounts.data.market.limitsEnabled
+        ? marketLimitsPda.data.pda
+        : null,
+      // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+      // @ts-ignore
+      marketAccessControl: marketAccounts.data.market.accessControlEnabled
+        ? marketAccessControlPda.data.pda
+        : null,
+      // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+      // @ts-ignore
+      purchaserGateToken: options.purchaserGateTokenPk ?? null,
     })
     .instruction();
   response.addResponseData({ orderPk, instruction });
@@ -8,9 +8,12 @@ export * from "./cancel_order_instruction";
 export * from "./cancel_order";
 export * from "./markets";
 export * from "./market_commission_payment_queues";
+export * from "./market_access_control";
+export * from "./market_limits";
 export * from "./market_liquidities";
 export * from "./market_matching_pools";
 export * from "./market_matching_queues";
+export * from "./market_order_books";
 export * from "./market_matching_pool_query";
 export * from "./market_order_request_queues";
 export * from "./market_outcomes";
@@ -0,0 +1,73 @@
+import { PublicKey } from "@solana/web3.js";
+import { Program } from "@coral-xyz/anchor";
+import {
+  ClientResponse,
+  ResponseFactory,
+  FindPdaResponse,
+  GetAccount,
+  MarketAccessControl,
+} from "../types";
+
+/**
+ * For the provided market publicKey, return the PDA (publicKey) of the market access control account.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @returns {FindPdaResponse} PDA of the market access control account
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketAccessControlPk = await findMarketAccessControlPda(program, marketPk)
+ */
+export async function findMarketAccessControlPda(
+  program: Program,
+  marketPk: PublicKey,
+): Promise<ClientResponse<FindPdaResponse>> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [Buffer.from("access_control"), marketPk.toBuffer()],
+      program.programId,
+    );
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
+/**
+ * For the provided market access control publicKey, get the market access control account details, including the purchasers allowed or denied and any token gate mint.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketAccessControlPk {PublicKey} publicKey of the market access control account
+ * @returns {GetAccount<MarketAccessControl>} market access control account details
+ *
+ * @example
+ *
+ * const marketAccessControlPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketAccessControl = await getMarketAccessControl(program, marketAccessControlPk)
+ */
+export async function getMarketAccessControl(
+  program: Program,
+  marketAccessControlPk: PublicKey,
+): Promise<ClientResponse<GetAccount<MarketAccessControl>>> {
+  const response = new ResponseFactory({} as GetAccount<MarketAccessControl>);
+  try {
+    const marketAccessControl =
+      (await program.account.marketAccessControl.fetch(
+        marketAccessControlPk,
+      )) as MarketAccessControl;
+
+    response.addResponseData({
+      publicKey: marketAccessControlPk,
+      account: marketAccessControl,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
@@ -0,0 +1,72 @@
+import { PublicKey } from "@solana/web3.js";
+import { Program } from "@coral-xyz/anchor";
+import {
+  ClientResponse,
+  ResponseFactory,
+  FindPdaResponse,
+  GetAccount,
+  MarketLimits,
+} from "../types";
+
+/**
+ * For the provided market publicKey, return the PDA (publicKey) of the market limits account.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @returns {FindPdaResponse} PDA of the market limits account
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketLimitsPk = await findMarketLimitsPda(program, marketPk)
+ */
+export async function findMarketLimitsPda(
+  program: Program,
+  marketPk: PublicKey,
+): Promise<ClientResponse<FindPdaResponse>> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [Buffer.from("limits"), marketPk.toBuffer()],
+      program.programId,
+    );
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
+/**
+ * For the provided market limits publicKey, get the market limits account details; a limit of 0 is not enforced.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketLimitsPk {PublicKey} publicKey of the market limits account
+ * @returns {GetAccount<MarketLimits>} market limits account details
+ *
+ * @example
+ *
+ * const marketLimitsPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketLimits = await getMarketLimits(program, marketLimitsPk)
+ */
+export async function getMarketLimits(
+  program: Program,
+  marketLimitsPk: PublicKey,
+): Promise<ClientResponse<GetAccount<MarketLimits>>> {
+  const response = new ResponseFactory({} as GetAccount<MarketLimits>);
+  try {
+    const marketLimits = (await program.account.marketLimits.fetch(
+      marketLimitsPk,
+    )) as MarketLimits;
+
+    response.addResponseData({
+      publicKey: marketLimitsPk,
+      account: marketLimits,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
@@ -8,8 +8,14 @@ import {
   GetPublicKeys,
   MarketLiquidities,
   MarketLiquiditiesAccounts,
+  MarketOutcomeLiquidities,
+  MarketOutcomeLiquiditiesAccounts,
 } from "../types";
-import { BooleanCriterion, toFilters } from "./queries/filtering";
+import {
+  BooleanCriterion,
+  PublicKeyCriterion,
+  toFilters,
+} from "./queries/filtering";
 
 /**
  * For the provided market publicKey, return the PDA (publicKey) of the market liquidities account.
@@ -76,6 +82,88 @@ export async function getMarketLiquidities(
   return response.body;
 }
 
+/**
+ * For the provided market publicKey and outcome index, return the PDA (publicKey) of the account holding the liquidities of the outcome.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @param marketOutcomeIndex {number} index of the market outcome
+ * @returns {FindPdaResponse} PDA of the market outcome liquidities account
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketOutcomeLiquiditiesPk = await findMarketOutcomeLiquiditiesPda(program, marketPk, 0)
+ */
+export async function findMarketOutcomeLiquiditiesPda(
+  program: Program,
+  marketPk: PublicKey,
+  marketOutcomeIndex: number,
+): Promise<ClientResponse<FindPdaResponse>> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("liquidities"),
+        marketPk.toBuffer(),
+        Buffer.from(marketOutcomeIndex.toString()),
+      ],
+      program.programId,
+    );
+
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
+/**
+ * For the provided market publicKey, return the accounts holding the liquidities of its outcomes, ordered by outcome index. Outcomes without liquidities may have no account.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @returns {MarketOutcomeLiquiditiesAccounts} market outcome liquidities accounts of the market
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketOutcomeLiquidities = await getMarketOutcomeLiquiditiesForMarket(program, marketPk)
+ */
+export async function getMarketOutcomeLiquiditiesForMarket(
+  program: Program,
+  marketPk: PublicKey,
+): Promise<ClientResponse<MarketOutcomeLiquiditiesAccounts>> {
+  const response = new ResponseFactory({} as MarketOutcomeLiquiditiesAccounts);
+
+  try {
+    const marketFilter = new PublicKeyCriterion(8);
+    marketFilter.setValue(marketPk);
+
+    const accounts = await program.account.marketOutcomeLiquidities.all(
+      toFilters("market_outcome_liquidities", marketFilter),
+    );
+    const result = accounts
+      .map((account) => {
+        return {
+          publicKey: account.publicKey,
+          account: account.account as MarketOutcomeLiquidities,
+        };
+      })
+      .sort(
+        (a, b) => a.account.marketOutcomeIndex - b.account.marketOutcomeIndex,
+      );
+
+    response.addResponseData({ accounts: result });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
 export async function getCrossMatchEnabledMarketLiquiditiesPks(
   program: Program,
 ): Promise<ClientResponse<GetPublicKeys>> {
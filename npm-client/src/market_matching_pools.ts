@@ -15,6 +15,7 @@ import {
 import { FindPdaResponse } from "../types";
 import { getMarketOutcomesByMarket } from "./market_outcome_query";
 import { MarketMatchingPools } from "./market_matching_pool_query";
+import { fromPrice } from "./utils";
 
 /**
  * For the provided market publicKey, outcome, price and forOutcome, return the PDA (publicKey) of the matching account.
@@ -184,7 +185,7 @@ export async function getAllMarketMatchingPools(
               marketMatchingPool: pool.account,
               seeds: {
                 outcomeIndex: pool.account.marketOutcomeIndex.toString(),
-                price: pool.account.price.toFixed(3).toString(),
+                price: fromPrice(pool.account.price).toFixed(3).toString(),
                 forOutcome: pool.account.forOutcome.toString(),
               },
             },
@@ -244,7 +245,7 @@ export async function findAllMarketMatchingPoolPks(
         for (const forOutcome of [true, false]) {
           const seeds = [
             outcome.toString(),
-            price.toFixed(3).toString(),
+            fromPrice(price).toFixed(3).toString(),
             forOutcome.toString(),
           ];
           seedsMatrix.push(seeds);
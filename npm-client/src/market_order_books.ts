@@ -0,0 +1,184 @@
+import { PublicKey } from "@solana/web3.js";
+import { Program } from "@coral-xyz/anchor";
+import {
+  ClientResponse,
+  ResponseFactory,
+  FindPdaResponse,
+  GetAccount,
+  MarketOrderBook,
+  MarketOrderBookAccounts,
+} from "../types";
+import {
+  PublicKeyCriterion,
+  U16Criterion,
+  toFilters,
+} from "./queries/filtering";
+import { fromPrice } from "./utils";
+
+/**
+ * For the provided market publicKey, outcome index and page index, return the PDA (publicKey) of the order book page.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @param marketOutcomeIndex {number} index of the market outcome
+ * @param pageIndex {number} index of the page of the outcome price ladder
+ * @returns {FindPdaResponse} PDA of the order book page
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketOrderBookPk = await findMarketOrderBookPda(program, marketPk, 0, 0)
+ */
+export async function findMarketOrderBookPda(
+  program: Program,
+  marketPk: PublicKey,
+  marketOutcomeIndex: number,
+  pageIndex: number,
+): Promise<ClientResponse<FindPdaResponse>> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  try {
+    const [pda, _] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("order_book"),
+        marketPk.toBuffer(),
+        Buffer.from(marketOutcomeIndex.toString()),
+        Buffer.from("-"),
+        Buffer.from(pageIndex.toString()),
+      ],
+      program.programId,
+    );
+
+    response.addResponseData({
+      pda: pda,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
+/**
+ * For the provided order book page publicKey, return the order book page account.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketOrderBookPk {PublicKey} publicKey of the order book page
+ * @returns {MarketOrderBook} order book page account info
+ *
+ * @example
+ *
+ * const marketOrderBookPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketOrderBook = await getMarketOrderBook(program, marketOrderBookPk)
+ */
+export async function getMarketOrderBook(
+  program: Program,
+  marketOrderBookPk: PublicKey,
+): Promise<ClientResponse<GetAccount<MarketOrderBook>>> {
+  const response = new ResponseFactory({} as GetAccount<MarketOrderBook>);
+  try {
+    const marketOrderBook = (await program.account.marketOrderBook.fetch(
+      marketOrderBookPk,
+    )) as MarketOrderBook;
+
+    response.addResponseData({
+      publicKey: marketOrderBookPk,
+      account: marketOrderBook,
+    });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
+/**
+ * For the provided market publicKey and outcome index, return all order book pages of the outcome, ordered by page index.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @param marketOutcomeIndex {number} index of the market outcome
+ * @returns {MarketOrderBookAccounts} order book pages of the outcome
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketOrderBooks = await getMarketOrderBooksForOutcome(program, marketPk, 0)
+ */
+export async function getMarketOrderBooksForOutcome(
+  program: Program,
+  marketPk: PublicKey,
+  marketOutcomeIndex: number,
+): Promise<ClientResponse<MarketOrderBookAccounts>> {
+  const response = new ResponseFactory({} as MarketOrderBookAccounts);
+
+  try {
+    const marketFilter = new PublicKeyCriterion(8);
+    marketFilter.setValue(marketPk);
+    const marketOutcomeIndexFilter = new U16Criterion(8 + 32);
+    marketOutcomeIndexFilter.setValue(marketOutcomeIndex);
+
+    const accounts = await program.account.marketOrderBook.all(
+      toFilters("market_order_book", marketFilter, marketOutcomeIndexFilter),
+    );
+    const result = accounts
+      .map((account) => {
+        return {
+          publicKey: account.publicKey,
+          account: account.account as MarketOrderBook,
+        };
+      })
+      .sort((a, b) => a.account.pageIndex - b.account.pageIndex);
+
+    response.addResponseData({ accounts: result });
+  } catch (e) {
+    response.addError(e);
+  }
+  return response.body;
+}
+
+/**
+ * For the provided market publicKey, outcome index and price, return the PDA (publicKey) of the order book page containing the price.
+ *
+ * @param program {program} anchor program initialized by the consuming client
+ * @param marketPk {PublicKey} publicKey of a market
+ * @param marketOutcomeIndex {number} index of the market outcome
+ * @param price {number} price of the order
+ * @returns {FindPdaResponse} PDA of the order book page containing the price
+ *
+ * @example
+ *
+ * const marketPk = new PublicKey('7o1PXyYZtBBDFZf9cEhHopn2C9R4G6GaPwFAxaNWM33D')
+ * const marketOrderBookPk = await findMarketOrderBookPdaForPrice(program, marketPk, 0, 2.5)
+ */
+export async function findMarketOrderBookPdaForPrice(
+  program: Program,
+  marketPk: PublicKey,
+  marketOutcomeIndex: number,
+  price: number,
+): Promise<ClientResponse<FindPdaResponse>> {
+  const response = new ResponseFactory({} as FindPdaResponse);
+
+  const pages = await getMarketOrderBooksForOutcome(
+    program,
+    marketPk,
+    marketOutcomeIndex,
+  );
+  if (!pages.success) {
+    response.addErrors(pages.errors);
+    return response.body;
+  }
+
+  const page = pages.data.accounts.find((page) =>
+    page.account.levels.some((level) => fromPrice(level.price) == price),
+  );
+  if (!page) {
+    response.addError(
+      `No order book page for outcome ${marketOutcomeIndex} contains price ${price}`,
+    );
+    return response.body;
+  }
+
+  response.addResponseData({
+    pda: page.publicKey,
+  });
+  return response.body;
+}
@@ -16,6 +16,7 @@ import {
   ResponseFactory,
 } from "../types";
 import { getPendingOrdersForMarket } from "./order";
+import { fromPrice } from "./utils";
 
 /**
  * For the provided market publicKey return:
@@ -181,7 +182,7 @@ function marketPricesFromOrders(
     const price = {
       marketOutcome: marketOutcomeTitles[account.marketOutcomeIndex],
       marketOutcomeIndex: account.marketOutcomeIndex,
-      price: account.expectedPrice,
+      price: fromPrice(account.expectedPrice),
       forOutcome: account.forOutcome,
     };
     marketPricesSet.add(JSON.stringify(price));
@@ -19,6 +19,8 @@ import {
   SignAndSendInstructionsResponse,
   SignAndSendInstructionsBatchResponse,
   MarketAccountsForCreateOrder,
+  Price,
+  PRICE_DECIMALS,
 } from "../types";
 import { v4 as uuid } from "uuid";
 
@@ -140,6 +142,38 @@ export async function uiStakeToInteger(
   return response.body;
 }
 
+/**
+ * For the provided ui price, get the on chain representation of that price, an integer with 3 implied decimals.
+ *
+ * @param price {number} ui price, e.g. 2.5
+ * @returns {Price} price as stored on chain
+ *
+ * @example
+ *
+ * const price = toPrice(2.5)
+ * // returns { value: 2_500 } with the value represented as a BN
+ */
+export function toPrice(price: number): Price {
+  return {
+    value: new BN(new Big(price).times(10 ** PRICE_DECIMALS).toFixed(0)),
+  };
+}
+
+/**
+ * For the provided on chain price, get the ui price.
+ *
+ * @param price {Price} price as stored on chain
+ * @returns {number} ui price, e.g. 2.5
+ *
+ * @example
+ *
+ * const order = await getOrder(program, orderPk)
+ * const price = fromPrice(order.data.account.expectedPrice)
+ */
+export function fromPrice(price: Price): number {
+  return new Big(price.value.toString()).div(10 ** PRICE_DECIMALS).toNumber();
+}
+
 /**
  * For the provided market publicKey, return the escrow account PDA (publicKey) for that market.
  *
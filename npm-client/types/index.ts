@@ -2,8 +2,12 @@ export * from "./client";
 export * from "./default_price_ladder";
 export * from "./errors";
 export * from "./order";
+export * from "./price";
 export * from "./market";
+export * from "./market_access_control";
+export * from "./market_limits";
 export * from "./market_liquidities";
+export * from "./market_order_book";
 export * from "./get_account";
 export * from "./market_matching_queue";
 export * from "./market_order_request_queue";
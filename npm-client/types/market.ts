@@ -2,6 +2,7 @@ import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { Order, PendingOrders } from "./order";
 import { GetAccount } from "./get_account";
+import { Price } from "./price";
 
 export interface MarketStatus {
   readonly initializing?: Record<string, never>;
@@ -19,6 +20,31 @@ export interface MarketOrderBehaviour {
   cancelUnmatched?: Record<string, never>;
 }
 
+export type MarketWinningOutcome = {
+  outcomeIndex: number;
+  deadHeatNumerator: number;
+  deadHeatDenominator: number;
+};
+
+export type WithdrawnOutcome = {
+  outcomeIndex: number;
+  reductionFactor: number;
+  withdrawalTimestamp: BN;
+};
+
+export type MarketOperatorDelegation = {
+  operator: PublicKey;
+  permissions: number;
+};
+
+export interface OutcomeResult {
+  win?: Record<string, never>;
+  halfWin?: Record<string, never>;
+  push?: Record<string, never>;
+  halfLoss?: Record<string, never>;
+  loss?: Record<string, never>;
+}
+
 export type MarketAccount = {
   authority: PublicKey;
   decimalLimit: number;
@@ -32,7 +58,8 @@ export type MarketAccount = {
   marketType: PublicKey;
   marketTypeDiscriminator: string;
   marketTypeValue: string;
-  marketWinningOutcomeIndex?: number;
+  marketWinningPlaces: number;
+  marketWinningOutcomes: MarketWinningOutcome[];
   mintAccount: PublicKey;
   published: boolean;
   suspended: boolean;
@@ -46,6 +73,23 @@ export type MarketAccount = {
   unsettledAccountsCount: number;
   unclosedAccountsCount: number;
   version: number;
+  schemaVersion: number;
+  marketOutcomeResults: OutcomeResult[];
+  tradeVoidWindow: BN;
+  withdrawnOutcomes: WithdrawnOutcome[];
+  orderBookEnabled: boolean;
+  priceImprovementEnabled: boolean;
+  limitsEnabled: boolean;
+  accessControlEnabled: boolean;
+  operatorDelegations: MarketOperatorDelegation[];
+  settlementApprovalsRequired: number;
+  settlementChallengePeriod: BN;
+  atomicMatchingEnabled: boolean;
+  crankFee: BN;
+  crankReward: BN;
+  crankFeeBalance: BN;
+  suspensionOrderBehaviour: MarketOrderBehaviour;
+  suspensionTimestamp: BN;
 };
 
 export type MarketAccounts = {
@@ -57,7 +101,7 @@ export type MarketMatchingPoolAccount = {
   inplay: boolean;
   forOutcome: boolean;
   marketOutcomeIndex: number;
-  price: number;
+  price: Price;
   orders: {
     front: number;
     len: number;
@@ -101,7 +145,7 @@ export type MarketOutcomeAccount = {
   title: string;
   market: PublicKey;
   prices: PublicKey | null;
-  priceLadder: number[];
+  priceLadder: Price[];
 };
 
 export type MarketOutcomeAccounts = {
@@ -125,7 +169,7 @@ export type MarketAccountsForCreateOrder = {
 export type MarketPrice = {
   marketOutcome: string;
   marketOutcomeIndex: number;
-  price: number;
+  price: Price;
   forOutcome: boolean;
   matchingPoolPda: PublicKey;
   matchingPool: MarketMatchingPoolAccount;
@@ -0,0 +1,21 @@
+import { PublicKey } from "@solana/web3.js";
+
+export type MarketAccessControl = {
+  market: PublicKey;
+  purchaserListMode: PurchaserListMode;
+  tokenGateMint: PublicKey | null;
+  purchasersCapacity: number;
+  purchasers: PublicKey[];
+};
+
+export class PurchaserListMode {
+  none?: Record<string, never>;
+  allow?: Record<string, never>;
+  deny?: Record<string, never>;
+}
+
+export const PurchaserListModeValue = {
+  none: { none: {} } as PurchaserListMode,
+  allow: { allow: {} } as PurchaserListMode,
+  deny: { deny: {} } as PurchaserListMode,
+};
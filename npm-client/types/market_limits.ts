@@ -0,0 +1,10 @@
+import { PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+
+export type MarketLimits = {
+  market: PublicKey;
+  maxOrderStake: BN;
+  maxPositionExposure: BN;
+  maxOutcomeStakeMatched: BN;
+  outcomeStakesMatched: BN[];
+};
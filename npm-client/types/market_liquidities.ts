@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { GetAccount } from "./get_account";
+import { Price } from "./price";
 
 export type MarketLiquiditiesAccounts = {
   accounts: GetAccount<MarketLiquidities>[];
@@ -14,14 +15,27 @@ export type MarketLiquidities = {
   liquiditiesAgainst: MarketLiquidity[];
 };
 
+export type MarketOutcomeLiquiditiesAccounts = {
+  accounts: GetAccount<MarketOutcomeLiquidities>[];
+};
+
+export type MarketOutcomeLiquidities = {
+  market: PublicKey;
+  marketOutcomeIndex: number;
+  payer: PublicKey;
+  inplay: boolean;
+  liquiditiesFor: MarketLiquidity[];
+  liquiditiesAgainst: MarketLiquidity[];
+};
+
 export type MarketLiquidity = {
   outcome: number;
-  price: number;
+  price: Price;
   sources: LiquiditySource[];
   liquidity: BN;
 };
 
 export type LiquiditySource = {
   outcome: number;
-  price: number;
+  price: Price;
 };
@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { GetAccount } from "./get_account";
+import { Price } from "./price";
 
 export type MarketMatchingQueues = {
   marketMatchingQueues: GetAccount<MarketMatchingQueue>[];
@@ -24,6 +25,6 @@ export type OrderMatch = {
 
   forOutcome: boolean;
   outcomeIndex: number;
-  price: number;
+  price: Price;
   stake: BN;
 };
@@ -0,0 +1,23 @@
+import { PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { GetAccount } from "./get_account";
+import { Price } from "./price";
+
+export type MarketOrderBookAccounts = {
+  accounts: GetAccount<MarketOrderBook>[];
+};
+
+export type MarketOrderBook = {
+  market: PublicKey;
+  marketOutcomeIndex: number;
+  pageIndex: number;
+  levels: OrderBookLevel[];
+  inplay: boolean;
+  suspensionTimestamp: BN;
+};
+
+export type OrderBookLevel = {
+  price: Price;
+  liquidityFor: BN;
+  liquidityAgainst: BN;
+};
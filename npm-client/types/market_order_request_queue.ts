@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { GetAccount } from "./get_account";
+import { Price } from "./price";
 
 export type MarketOrderRequestQueues = {
   marketOrderRequestQueues: GetAccount<MarketOrderRequestQueue>[];
@@ -25,10 +26,24 @@ export type OrderRequest = {
   forOutcome: boolean;
   product: PublicKey | null;
   stake: BN;
-  expectedPrice: number;
+  expectedPrice: Price;
   delayExpirationTimestamp: BN;
   productCommissionRate: number;
   distinctSeed: number[];
   creationTimestamp: BN;
   expiresOn: BN;
+  timeInForce: TimeInForce;
+  postOnly: boolean;
+};
+
+export class TimeInForce {
+  goodTillCancelled?: Record<string, never>;
+  immediateOrCancel?: Record<string, never>;
+  fillOrKill?: Record<string, never>;
+}
+
+export const TimeInForceValue = {
+  goodTillCancelled: { goodTillCancelled: {} } as TimeInForce,
+  immediateOrCancel: { immediateOrCancel: {} } as TimeInForce,
+  fillOrKill: { fillOrKill: {} } as TimeInForce,
 };
@@ -12,6 +12,10 @@ export type MarketPosition = {
   payer: PublicKey;
   matchedRisk: BN;
   matchedRiskPerProduct: ProductMatchedRiskAndRate[];
+  schemaVersion: number;
+  marketOutcomeStakes: BN[];
+  marketOutcomeDeductions: BN[];
+  outcomeWithdrawalsApplied: number;
 };
 
 export type MarketPositionAccounts = {
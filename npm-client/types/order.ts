@@ -1,6 +1,7 @@
 import { PublicKey, TransactionInstruction } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { GetAccount } from "./get_account";
+import { Price } from "./price";
 
 export interface OrderStatus {
   readonly open?: Record<string, never>;
@@ -25,12 +26,13 @@ export type Order = {
   product: PublicKey | null;
   stake: BN;
   voidedStake: BN;
-  expectedPrice: number;
+  expectedPrice: Price;
   creationTimestamp: BN;
   stakeUnmatched: BN;
   payout: BN;
   payer: PublicKey;
   productCommissionRate: number;
+  schemaVersion: number;
 };
 
 export type OrderInstructionResponse = {
@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { GetAccount } from "./get_account";
+import { Price } from "./price";
 
 export type Trade = {
   purchaser: PublicKey;
@@ -9,9 +10,10 @@ export type Trade = {
   marketOutcomeIndex: number;
   forOutcome: boolean;
   stake: BN;
-  price: number;
+  price: Price;
   creationTimestamp: BN;
   payer: PublicKey;
+  matchId: PublicKey;
 };
 
 export type TradeAccounts = {
@@ -6,12 +6,20 @@ use solana_program::rent::Rent;
 
 use crate::error::CoreError;
 use crate::monaco_protocol::SEED_SEPARATOR;
+use crate::state::market_access_control::{MarketAccessControl, PurchaserListMode};
+use crate::state::market_limits::MarketLimits;
 use crate::state::market_liquidities::MarketLiquidities;
 use crate::state::market_matching_pool_account::MarketMatchingPool;
 use crate::state::market_matching_queue_account::MarketMatchingQueue;
+use crate::state::market_order_book::MarketOrderBook;
 use crate::state::market_order_request_queue::MarketOrderRequestQueue;
 use crate::state::market_outcome_account::MarketOutcome;
+use crate::state::market_outcome_liquidities::MarketOutcomeLiquidities;
+use crate::state::market_settlement_proposal::{
+    MarketSettlementProposal, SettlementProposalAction,
+};
 use crate::state::market_type::MarketType;
+use crate::state::operator_account::OperatorPermission;
 use crate::state::order_account::ReservedOrder;
 use crate::state::payments_queue::MarketPaymentsQueue;
 use crate::state::price_ladder::PriceLadder;
@@ -91,6 +99,68 @@ pub struct CreateOrderRequest<'info> {
     pub system_program: Program<'info, System>,
     #[account(address = anchor_spl::token::ID)]
     pub token_program: Program<'info, Token>,
+
+    #[account(has_one = market @ CoreError::MarketLimitsMismatch)]
+    pub market_limits: Option<Account<'info, MarketLimits>>,
+    #[account(has_one = market @ CoreError::MarketAccessControlMismatch)]
+    pub market_access_control: Option<Account<'info, MarketAccessControl>>,
+    pub purchaser_gate_token: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct CreateOrderRequests<'info> {
+    #[account(
+        mut,
+        seeds = [b"order_request".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub order_request_queue: Account<'info, MarketOrderRequestQueue>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            purchaser.key().as_ref(),
+            market.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = MarketPosition::size_for(usize::from(market.market_outcomes_count))
+    )]
+    pub market_position: Box<Account<'info, MarketPosition>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub purchaser: Signer<'info>,
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+    )]
+    pub purchaser_token: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    pub price_ladder: Option<Account<'info, PriceLadder>>,
+
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+        token::authority = market_escrow,
+        seeds = [b"escrow".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub market_escrow: Account<'info, TokenAccount>,
+
+    pub product: Option<Account<'info, Product>>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(has_one = market @ CoreError::MarketLimitsMismatch)]
+    pub market_limits: Option<Account<'info, MarketLimits>>,
+    #[account(has_one = market @ CoreError::MarketAccessControlMismatch)]
+    pub market_access_control: Option<Account<'info, MarketAccessControl>>,
+    pub purchaser_gate_token: Option<Account<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
@@ -166,12 +236,10 @@ pub struct ProcessOrderRequest<'info> {
                 .ok_or(CoreError::OrderRequestQueueIsEmpty)?
                 .market_outcome_index.to_string().as_ref(),
             b"-".as_ref(),
-            format!("{:.3}",
-                order_request_queue.order_requests
-                    .peek_front()
-                    .ok_or(CoreError::OrderRequestQueueIsEmpty)?
-                    .expected_price
-            ).as_ref(),
+            order_request_queue.order_requests
+                .peek_front()
+                .ok_or(CoreError::OrderRequestQueueIsEmpty)?
+                .expected_price.to_string().as_ref(),
             order_request_queue.order_requests
                 .peek_front()
                 .ok_or(CoreError::OrderRequestQueueIsEmpty)?
@@ -218,6 +286,35 @@ pub struct ProcessOrderRequest<'info> {
     pub token_program: Program<'info, Token>,
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
+    #[account(mut, has_one = market @ CoreError::MarketLimitsMismatch)]
+    pub market_limits: Option<Account<'info, MarketLimits>>,
+    // crank rewards are only paid when a token account to receive them is provided
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+        token::authority = crank_operator,
+    )]
+    pub crank_operator_token: Option<Account<'info, TokenAccount>>,
+    // the outcome of the order and its price ladder, cross prices are rounded onto its prices when
+    // the market has price improvement enabled
+    #[account(
+        seeds = [
+            market.key().as_ref(),
+            order_request_queue.order_requests
+                .peek_front()
+                .ok_or(CoreError::OrderRequestQueueIsEmpty)?
+                .market_outcome_index.to_string().as_ref(),
+        ],
+        bump,
+        constraint = market_outcome.prices.is_none() ||
+        (market_outcome.prices.is_some() && price_ladder.is_some() && market_outcome.prices.unwrap() == price_ladder.as_ref().unwrap().key())
+        @ CoreError::CreationInvalidPriceLadder
+    )]
+    pub market_outcome: Option<Account<'info, MarketOutcome>>,
+    pub price_ladder: Option<Account<'info, PriceLadder>>,
 }
 
 #[derive(Accounts)]
@@ -254,7 +351,14 @@ pub struct DequeueOrderRequest<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CRANK)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CRANK)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 
     #[account(address = anchor_spl::token::ID)]
@@ -308,7 +412,7 @@ pub struct CancelOrder<'info> {
             market.key().as_ref(),
             order.market_outcome_index.to_string().as_ref(),
             b"-".as_ref(),
-            format!("{:.3}", order.expected_price).as_ref(),
+            order.expected_price.to_string().as_ref(),
             order.for_outcome.to_string().as_ref(),
         ],
         bump,
@@ -329,6 +433,144 @@ pub struct CancelOrder<'info> {
 
     #[account(address = anchor_spl::token::ID)]
     pub token_program: Program<'info, Token>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
+}
+
+#[derive(Accounts)]
+#[instruction(data: OrderRequestData)]
+pub struct AmendOrder<'info> {
+    #[account(mut)]
+    pub order: Account<'info, Order>,
+    #[account(
+        init,
+        seeds = [
+            market.key().as_ref(),
+            purchaser.key().as_ref(),
+            &data.distinct_seed,
+        ],
+        bump,
+        payer = purchaser,
+        space = ReservedOrder::SIZE,
+    )]
+    pub reserved_order: Account<'info, ReservedOrder>,
+
+    #[account(mut, address = order.purchaser @ CoreError::CancelationPurchaserMismatch)]
+    pub purchaser: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = market.mint_account,
+        associated_token::authority = purchaser,
+    )]
+    pub purchaser_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, address = order.payer @ CoreError::CancelationPayerMismatch)]
+    pub payer: SystemAccount<'info>,
+
+    #[account(mut, address = order.market @ CoreError::CancelationMarketMismatch)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        has_one = market @ CoreError::CancelationMarketLiquiditiesMismatch,
+    )]
+    pub market_liquidities: Account<'info, MarketLiquidities>,
+    #[account(
+        has_one = market @ CoreError::CancelationMarketOutcomeMismatch,
+        constraint = market_outcome.index == order.market_outcome_index @ CoreError::CancelationMarketOutcomeMismatch,
+        constraint = market_outcome.prices.is_none() ||
+        (market_outcome.prices.is_some() && price_ladder.is_some() && market_outcome.prices.unwrap() == price_ladder.as_ref().unwrap().key())
+        @ CoreError::CreationInvalidPriceLadder
+    )]
+    pub market_outcome: Account<'info, MarketOutcome>,
+    pub price_ladder: Option<Account<'info, PriceLadder>>,
+    #[account(has_one = market)]
+    pub market_matching_queue: Account<'info, MarketMatchingQueue>,
+    #[account(
+        mut,
+        seeds = [
+            market.key().as_ref(),
+            order.market_outcome_index.to_string().as_ref(),
+            b"-".as_ref(),
+            order.expected_price.to_string().as_ref(),
+            order.for_outcome.to_string().as_ref(),
+        ],
+        bump,
+    )]
+    pub market_matching_pool: Box<Account<'info, MarketMatchingPool>>,
+    #[account(
+        mut,
+        seeds = [b"order_request".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub order_request_queue: Box<Account<'info, MarketOrderRequestQueue>>,
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+        token::authority = market_escrow,
+        seeds = [b"escrow".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub market_escrow: Box<Account<'info, TokenAccount>>,
+
+    // market_position needs to be here so market validation happens first
+    #[account(mut, seeds = [purchaser.key().as_ref(), market.key().as_ref()], bump)]
+    pub market_position: Box<Account<'info, MarketPosition>>,
+
+    pub product: Option<Account<'info, Product>>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(has_one = market @ CoreError::MarketLimitsMismatch)]
+    pub market_limits: Option<Account<'info, MarketLimits>>,
+    #[account(has_one = market @ CoreError::MarketAccessControlMismatch)]
+    pub market_access_control: Option<Account<'info, MarketAccessControl>>,
+    pub purchaser_gate_token: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAllOrders<'info> {
+    pub purchaser: Signer<'info>,
+    #[account(
+        mut,
+        associated_token::mint = market.mint_account,
+        associated_token::authority = purchaser,
+    )]
+    pub purchaser_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        has_one = market @ CoreError::CancelationMarketLiquiditiesMismatch,
+    )]
+    pub market_liquidities: Account<'info, MarketLiquidities>,
+    #[account(has_one = market)]
+    pub market_matching_queue: Account<'info, MarketMatchingQueue>,
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+        token::authority = market_escrow,
+        seeds = [b"escrow".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub market_escrow: Box<Account<'info, TokenAccount>>,
+
+    // market_position needs to be here so market validation happens first
+    #[account(mut, seeds = [purchaser.key().as_ref(), market.key().as_ref()], bump)]
+    pub market_position: Box<Account<'info, MarketPosition>>,
+
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
 }
 
 #[derive(Accounts)]
@@ -376,7 +618,7 @@ pub struct CancelOrderPostMarketLock<'info> {
             market.key().as_ref(),
             order.market_outcome_index.to_string().as_ref(),
             b"-".as_ref(),
-            format!("{:.3}", order.expected_price).as_ref(),
+            order.expected_price.to_string().as_ref(),
             order.for_outcome.to_string().as_ref(),
         ],
         bump,
@@ -389,6 +631,9 @@ pub struct CancelOrderPostMarketLock<'info> {
 
     #[account(address = anchor_spl::token::ID)]
     pub token_program: Program<'info, Token>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
 }
 
 #[derive(Accounts)]
@@ -418,7 +663,7 @@ pub struct CancelPreplayOrderPostEventStart<'info> {
             market.key().as_ref(),
             order.market_outcome_index.to_string().as_ref(),
             b"-".as_ref(),
-            format!("{:.3}", order.expected_price).as_ref(),
+            order.expected_price.to_string().as_ref(),
             order.for_outcome.to_string().as_ref(),
         ],
         bump,
@@ -450,6 +695,70 @@ pub struct CancelPreplayOrderPostEventStart<'info> {
 
     #[account(address = anchor_spl::token::ID)]
     pub token_program: Program<'info, Token>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOrderPostSuspension<'info> {
+    #[account(mut)]
+    pub order: Account<'info, Order>,
+
+    #[account(mut, address = order.purchaser @ CoreError::CancelationPurchaserMismatch)]
+    pub purchaser: SystemAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = market.mint_account,
+        associated_token::authority = purchaser,
+    )]
+    pub purchaser_token: Account<'info, TokenAccount>,
+
+    #[account(mut, address = order.payer @ CoreError::CancelationPayerMismatch)]
+    pub payer: SystemAccount<'info>,
+
+    #[account(mut, address = order.market @ CoreError::CancelationMarketMismatch)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        has_one = market @ CoreError::CreationMarketMismatch,
+    )]
+    pub market_liquidities: Account<'info, MarketLiquidities>,
+    #[account(
+        mut,
+        seeds = [
+            market.key().as_ref(),
+            order.market_outcome_index.to_string().as_ref(),
+            b"-".as_ref(),
+            order.expected_price.to_string().as_ref(),
+            order.for_outcome.to_string().as_ref(),
+        ],
+        bump,
+    )]
+    pub market_matching_pool: Account<'info, MarketMatchingPool>,
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+        token::authority = market_escrow,
+        seeds = [b"escrow".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub market_escrow: Box<Account<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"matching".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub matching_queue: Account<'info, MarketMatchingQueue>,
+
+    // market_position needs to be here so market validation happens first
+    #[account(mut, seeds = [purchaser.key().as_ref(), market.key().as_ref()], bump)]
+    pub market_position: Box<Account<'info, MarketPosition>>,
+
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
 }
 
 #[derive(Accounts)]
@@ -459,7 +768,10 @@ pub struct AuthoriseAdminOperator<'info> {
         seeds = [b"authorised_operators".as_ref(), b"ADMIN".as_ref()],
         payer = admin_operator,
         bump,
-        space = AuthorisedOperators::SIZE
+        space = AuthorisedOperators::SIZE,
+        // the first admin operator is authorised by whoever initializes the list
+        constraint = authorised_operators.operator_list.is_empty()
+            || authorised_operators.contains(admin_operator.key) @ CoreError::UnauthorisedOperator
     )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
     #[account(mut)]
@@ -482,7 +794,29 @@ pub struct AuthoriseOperator<'info> {
     pub authorised_operators: Account<'info, AuthorisedOperators>,
     #[account(mut)]
     pub admin_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"ADMIN".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"ADMIN".as_ref()],
+        bump,
+        constraint = admin_operators.contains(admin_operator.key) @ CoreError::UnauthorisedOperator,
+    )]
+    pub admin_operators: Account<'info, AuthorisedOperators>,
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: owner and account type are validated by the migration, which rewrites the data in
+    /// the current layout
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub admin_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"ADMIN".as_ref()],
+        bump,
+        constraint = admin_operators.contains(admin_operator.key) @ CoreError::UnauthorisedOperator,
+    )]
     pub admin_operators: Account<'info, AuthorisedOperators>,
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
@@ -666,17 +1000,32 @@ pub struct ProcessOrderMatchMaker<'info> {
     pub system_program: Program<'info, System>,
     #[account(address = anchor_spl::token::ID)]
     pub token_program: Program<'info, Token>,
+
+    #[account(mut, has_one = market @ CoreError::OrderBookPageMismatch)]
+    pub market_order_book: Option<Account<'info, MarketOrderBook>>,
+    // crank rewards are only paid when a token account to receive them is provided
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+        token::authority = crank_operator,
+    )]
+    pub crank_operator_token: Option<Account<'info, TokenAccount>>,
 }
 
 #[derive(Accounts)]
 pub struct UpdateMarketLiquidities<'info> {
-    #[account()]
+    #[account(mut)]
     pub market: Account<'info, Market>,
     #[account(
         mut,
         has_one = market @ CoreError::MarketMismatch,
     )]
     pub market_liquidities: Account<'info, MarketLiquidities>,
+
+    #[account(mut)]
+    pub crank_operator: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
@@ -716,7 +1065,7 @@ pub struct MatchOrders<'info> {
             market.key().as_ref(),
             order_against.market_outcome_index.to_string().as_ref(),
             b"-".as_ref(),
-            format!("{:.3}", order_against.expected_price).as_ref(),
+            order_against.expected_price.to_string().as_ref(),
             false.to_string().as_ref(),
         ],
         bump,
@@ -755,7 +1104,7 @@ pub struct MatchOrders<'info> {
             market.key().as_ref(),
             order_for.market_outcome_index.to_string().as_ref(),
             b"-".as_ref(),
-            format!("{:.3}", order_for.expected_price).as_ref(),
+            order_for.expected_price.to_string().as_ref(),
             true.to_string().as_ref(),
         ],
         bump,
@@ -777,7 +1126,12 @@ pub struct MatchOrders<'info> {
     // crank operator --------------------------------------------
     #[account(mut)]
     pub crank_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"CRANK".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"CRANK".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(crank_operator.key, OperatorPermission::CRANK)
+            @ CoreError::UnauthorisedOperator,
+    )]
     pub authorised_operators: Box<Account<'info, AuthorisedOperators>>,
 
     // token account --------------------------------------------
@@ -882,6 +1236,61 @@ pub struct VoidOrder<'info> {
     pub market: Account<'info, Market>,
 }
 
+#[derive(Accounts)]
+pub struct VoidTrades<'info> {
+    #[account(mut)]
+    pub market: Box<Account<'info, Market>>,
+    #[account(
+        mut,
+        token::mint = market.mint_account,
+        token::authority = market_escrow,
+        seeds = [b"escrow".as_ref(), market.key().as_ref()],
+        bump,
+    )]
+    pub market_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = market @ CoreError::VoidMarketMismatch)]
+    pub for_trade: Box<Account<'info, Trade>>,
+    #[account(mut, address = for_trade.order @ CoreError::VoidTradeMismatch)]
+    pub for_order: Box<Account<'info, Order>>,
+    #[account(mut, seeds = [for_trade.purchaser.as_ref(), market.key().as_ref()], bump)]
+    pub for_market_position: Box<Account<'info, MarketPosition>>,
+    #[account(
+        mut,
+        associated_token::mint = market.mint_account,
+        associated_token::authority = for_trade.purchaser,
+    )]
+    pub for_purchaser_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, has_one = market @ CoreError::VoidMarketMismatch)]
+    pub against_trade: Box<Account<'info, Trade>>,
+    #[account(mut, address = against_trade.order @ CoreError::VoidTradeMismatch)]
+    pub against_order: Box<Account<'info, Order>>,
+    #[account(mut, seeds = [against_trade.purchaser.as_ref(), market.key().as_ref()], bump)]
+    pub against_market_position: Box<Account<'info, MarketPosition>>,
+    #[account(
+        mut,
+        associated_token::mint = market.mint_account,
+        associated_token::authority = against_trade.purchaser,
+    )]
+    pub against_purchaser_token: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::VOID)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::VOID)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+
+    #[account(address = anchor_spl::token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
 #[derive(Accounts)]
 #[instruction(name: String)]
 pub struct CreateMarketType<'info> {
@@ -975,122 +1384,397 @@ pub struct CreateMarket<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
+
+#[derive(Accounts)]
+#[instruction(_distinct_seed: String, max_number_of_prices: u16)]
+pub struct CreatePriceLadder<'info> {
+    #[account(
+        init,
+        seeds = [
+            b"price_ladder".as_ref(),
+            authority.key().as_ref(),
+            _distinct_seed.as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = PriceLadder::size_for(max_number_of_prices)
+    )]
+    pub price_ladder: Account<'info, PriceLadder>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePriceLadder<'info> {
+    #[account(mut, has_one = authority)]
+    pub price_ladder: Account<'info, PriceLadder>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(max_number_of_prices: u16)]
+pub struct UpdatePriceLadderSize<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        realloc = PriceLadder::size_for(max_number_of_prices),
+        realloc::zero = false,
+        realloc::payer = authority
+    )]
+    pub price_ladder: Account<'info, PriceLadder>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePriceLadder<'info> {
+    #[account(mut, has_one = authority, close = authority)]
+    pub price_ladder: Account<'info, PriceLadder>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMarketOutcome<'info> {
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        init,
+        seeds = [
+            market.key().as_ref(),
+            market.market_outcomes_count.to_string().as_ref(),
+        ],
+        bump,
+        payer = market_operator,
+        space =  MarketOutcome::SIZE
+    )]
+    pub outcome: Account<'info, MarketOutcome>,
+
+    pub price_ladder: Option<Account<'info, PriceLadder>>,
+
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
+
+#[derive(Accounts)]
+#[instruction(outcome_index: u16, page_index: u16)]
+pub struct CreateMarketOrderBookPage<'info> {
+    #[account(
+        seeds = [
+            market.key().as_ref(),
+            outcome_index.to_string().as_ref(),
+        ],
+        bump,
+        constraint = market_outcome.prices.is_none() ||
+        (market_outcome.prices.is_some() && price_ladder.is_some() && market_outcome.prices.unwrap() == price_ladder.as_ref().unwrap().key())
+        @ CoreError::CreationInvalidPriceLadder
+    )]
+    pub market_outcome: Account<'info, MarketOutcome>,
+    pub price_ladder: Option<Account<'info, PriceLadder>>,
+
+    #[account(
+        init,
+        seeds = [
+            b"order_book".as_ref(),
+            market.key().as_ref(),
+            outcome_index.to_string().as_ref(),
+            b"-".as_ref(),
+            page_index.to_string().as_ref(),
+        ],
+        bump,
+        payer = market_operator,
+        space = MarketOrderBook::size_for(
+            MarketOrderBook::outcome_page_prices(&market_outcome, price_ladder.as_deref(), page_index).len()
+        )
+    )]
+    pub market_order_book: Account<'info, MarketOrderBook>,
+
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMarketLimits<'info> {
+    #[account(
+        init,
+        seeds = [b"limits".as_ref(), market.key().as_ref()],
+        bump,
+        payer = market_operator,
+        space = MarketLimits::size_for(usize::from(market.market_outcomes_count))
+    )]
+    pub market_limits: Account<'info, MarketLimits>,
+
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMarketLimits<'info> {
+    #[account(mut, has_one = market @ CoreError::MarketLimitsMismatch)]
+    pub market_limits: Account<'info, MarketLimits>,
+
+    pub market: Account<'info, Market>,
+
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
 #[derive(Accounts)]
-#[instruction(_distinct_seed: String, max_number_of_prices: u16)]
-pub struct CreatePriceLadder<'info> {
+#[instruction(_purchaser_list_mode: PurchaserListMode, _token_gate_mint: Option<Pubkey>, purchasers_capacity: u16)]
+pub struct CreateMarketAccessControl<'info> {
     #[account(
         init,
-        seeds = [
-            b"price_ladder".as_ref(),
-            authority.key().as_ref(),
-            _distinct_seed.as_ref()
-        ],
+        seeds = [b"access_control".as_ref(), market.key().as_ref()],
         bump,
-        payer = authority,
-        space = PriceLadder::size_for(max_number_of_prices)
+        payer = market_operator,
+        space = MarketAccessControl::size_for(purchasers_capacity)
     )]
-    pub price_ladder: Account<'info, PriceLadder>,
+    pub market_access_control: Account<'info, MarketAccessControl>,
+
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct UpdatePriceLadder<'info> {
-    #[account(mut, has_one = authority)]
-    pub price_ladder: Account<'info, PriceLadder>,
-    pub authority: Signer<'info>,
-}
+pub struct UpdateMarketAccessControl<'info> {
+    #[account(mut, has_one = market @ CoreError::MarketAccessControlMismatch)]
+    pub market_access_control: Account<'info, MarketAccessControl>,
 
-#[derive(Accounts)]
-#[instruction(max_number_of_prices: u16)]
-pub struct UpdatePriceLadderSize<'info> {
+    pub market: Account<'info, Market>,
+
+    pub market_operator: Signer<'info>,
     #[account(
-        mut,
-        has_one = authority,
-        realloc = PriceLadder::size_for(max_number_of_prices),
-        realloc::zero = false,
-        realloc::payer = authority
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
     )]
-    pub price_ladder: Account<'info, PriceLadder>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct ClosePriceLadder<'info> {
-    #[account(mut, has_one = authority, close = authority)]
-    pub price_ladder: Account<'info, PriceLadder>,
-    pub authority: Signer<'info>,
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeMarketOutcome<'info> {
+#[instruction(_outcome_index: u16)]
+pub struct UpdateMarketOutcome<'info> {
     #[account(address = system_program::ID)]
     pub system_program: Program<'info, System>,
 
     #[account(
-        init,
+        mut,
         seeds = [
             market.key().as_ref(),
-            market.market_outcomes_count.to_string().as_ref(),
+            _outcome_index.to_string().as_ref(),
         ],
         bump,
-        payer = market_operator,
-        space =  MarketOutcome::SIZE
     )]
     pub outcome: Account<'info, MarketOutcome>,
 
-    pub price_ladder: Option<Account<'info, PriceLadder>>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
 
+#[derive(Accounts)]
+pub struct UpdateMarket<'info> {
     #[account(mut)]
     pub market: Account<'info, Market>,
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
 #[derive(Accounts)]
-#[instruction(_outcome_index: u16)]
-pub struct UpdateMarketOutcome<'info> {
-    #[account(address = system_program::ID)]
-    pub system_program: Program<'info, System>,
+pub struct UpdateMarketSettlementApprovals<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
 
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
     #[account(
-        mut,
-        seeds = [
-            market.key().as_ref(),
-            _outcome_index.to_string().as_ref(),
-        ],
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
         bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::MarketAuthorityMismatch,
     )]
-    pub outcome: Account<'info, MarketOutcome>,
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMarketTimes<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::UPDATE_TIMES)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::UPDATE_TIMES)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
 
+#[derive(Accounts)]
+pub struct SuspendMarket<'info> {
     #[account(mut)]
     pub market: Account<'info, Market>,
+    // required to suspend markets cancelling unmatched orders on suspension
+    #[account(mut, has_one = market @ CoreError::MarketMismatch)]
+    pub market_liquidities: Option<Account<'info, MarketLiquidities>>,
+    #[account(has_one = market @ CoreError::MarketMismatch)]
+    pub market_matching_queue: Option<Account<'info, MarketMatchingQueue>>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::SUSPEND)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::SUSPEND)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
 
+#[derive(Accounts)]
+pub struct UpdateMarketOperatorDelegation<'info> {
     #[account(mut)]
+    pub market: Account<'info, Market>,
+
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.contains(market_operator.key) @ CoreError::UnauthorisedOperator,
+        constraint = market.authority == market_operator.key() @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateMarket<'info> {
+pub struct WithdrawMarketOutcome<'info> {
     #[account(mut)]
     pub market: Account<'info, Market>,
+    #[account(mut, has_one = market @ CoreError::MarketMismatch)]
+    pub market_liquidities: Account<'info, MarketLiquidities>,
+    #[account(has_one = market @ CoreError::MarketMismatch)]
+    pub market_matching_queue: Account<'info, MarketMatchingQueue>,
+    #[account(has_one = market @ CoreError::MarketMismatch)]
+    pub order_request_queue: Account<'info, MarketOrderRequestQueue>,
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
@@ -1109,13 +1793,23 @@ pub struct VoidMarket<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::VOID)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::VOID)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
 #[derive(Accounts)]
 pub struct ForceVoidMarket<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = market.settlement_approvals_required == 0 @ CoreError::SettlementProposalRequired,
+    )]
     pub market: Account<'info, Market>,
     #[account(
         mut,
@@ -1129,7 +1823,14 @@ pub struct ForceVoidMarket<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::VOID)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::VOID)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
@@ -1147,7 +1848,14 @@ pub struct ForceUnsettledCount<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
@@ -1203,7 +1911,14 @@ pub struct OpenMarket<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 
     pub system_program: Program<'info, System>,
@@ -1211,8 +1926,42 @@ pub struct OpenMarket<'info> {
 
 #[derive(Accounts)]
 pub struct SettleMarket<'info> {
+    #[account(
+        mut,
+        constraint = market.settlement_approvals_required == 0 @ CoreError::SettlementProposalRequired,
+    )]
+    pub market: Account<'info, Market>,
+    #[account(
+        has_one = market @ CoreError::SettlementMarketMismatch,
+    )]
+    pub market_matching_queue: Account<'info, MarketMatchingQueue>,
+    #[account(
+        has_one = market @ CoreError::SettlementMarketMismatch,
+    )]
+    pub order_request_queue: Account<'info, MarketOrderRequestQueue>,
+
     #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
+
+#[derive(Accounts)]
+pub struct SettleMarketWithResults<'info> {
+    #[account(
+        mut,
+        constraint = market.settlement_approvals_required == 0 @ CoreError::SettlementProposalRequired,
+    )]
     pub market: Account<'info, Market>,
+    #[account(address = market.market_type @ CoreError::MarketTypeMismatch)]
+    pub market_type: Account<'info, MarketType>,
     #[account(
         has_one = market @ CoreError::SettlementMarketMismatch,
     )]
@@ -1224,7 +1973,109 @@ pub struct SettleMarket<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::SETTLE)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
+
+#[derive(Accounts)]
+#[instruction(action: SettlementProposalAction)]
+pub struct ProposeMarketSettlement<'info> {
+    #[account(
+        init,
+        seeds = [b"settlement_proposal".as_ref(), market.key().as_ref()],
+        bump,
+        payer = market_operator,
+        space = MarketSettlementProposal::SIZE
+    )]
+    pub market_settlement_proposal: Account<'info, MarketSettlementProposal>,
+
+    pub market: Account<'info, Market>,
+
+    #[account(mut)]
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, action.permission())
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, action.permission())
+            @ CoreError::MarketAuthorityMismatch,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMarketSettlementProposal<'info> {
+    #[account(mut)]
+    pub market_settlement_proposal: Account<'info, MarketSettlementProposal>,
+
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(
+            market_operator.key,
+            market_settlement_proposal.action.permission(),
+        ) @ CoreError::UnauthorisedOperator,
+    )]
+    pub authorised_operators: Account<'info, AuthorisedOperators>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMarketSettlementProposal<'info> {
+    #[account(
+        mut,
+        has_one = market @ CoreError::SettlementProposalMarketMismatch,
+        has_one = proposer @ CoreError::SettlementProposalProposerMismatch,
+        close = proposer,
+    )]
+    pub market_settlement_proposal: Account<'info, MarketSettlementProposal>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        has_one = market @ CoreError::SettlementMarketMismatch,
+    )]
+    pub market_matching_queue: Option<Account<'info, MarketMatchingQueue>>,
+    #[account(
+        has_one = market @ CoreError::SettlementMarketMismatch,
+    )]
+    pub order_request_queue: Option<Account<'info, MarketOrderRequestQueue>>,
+
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelMarketSettlementProposal<'info> {
+    #[account(
+        mut,
+        has_one = proposer @ CoreError::SettlementProposalProposerMismatch,
+        close = proposer,
+    )]
+    pub market_settlement_proposal: Account<'info, MarketSettlementProposal>,
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    pub market_operator: Signer<'info>,
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(
+            market_operator.key,
+            market_settlement_proposal.action.permission(),
+        ) @ CoreError::UnauthorisedOperator,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
@@ -1257,7 +2108,14 @@ pub struct SetMarketReadyToClose<'info> {
 
     #[account(mut)]
     pub market_operator: Signer<'info>,
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 }
 
@@ -1277,7 +2135,7 @@ pub struct CompleteMarketVoid<'info> {
 
 #[derive(Accounts)]
 pub struct TransferMarketTokenSurplus<'info> {
-    #[account()]
+    #[account(mut)]
     pub market: Account<'info, Market>,
 
     #[account(
@@ -1306,7 +2164,14 @@ pub struct TransferMarketTokenSurplus<'info> {
 
     pub market_operator: Signer<'info>,
 
-    #[account(seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()], bump)]
+    #[account(
+        seeds = [b"authorised_operators".as_ref(), b"MARKET".as_ref()],
+        bump,
+        constraint = authorised_operators.has_permission(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::UnauthorisedOperator,
+        constraint = market.is_operator_permitted(market_operator.key, OperatorPermission::CREATE_MARKETS)
+            @ CoreError::MarketAuthorityMismatch,
+    )]
     pub authorised_operators: Account<'info, AuthorisedOperators>,
 
     #[account(address = anchor_spl::token::ID)]
@@ -1411,6 +2276,21 @@ pub struct CloseMarketMatchingPool<'info> {
     pub payer: SystemAccount<'info>,
 }
 
+#[derive(Accounts)]
+pub struct CloseMarketOutcomeLiquidities<'info> {
+    #[account(
+        mut,
+        has_one = payer @ CoreError::CloseAccountPayerMismatch,
+        has_one = market @ CoreError::CloseAccountMarketMismatch,
+        close = payer,
+    )]
+    pub market_outcome_liquidities: Account<'info, MarketOutcomeLiquidities>,
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+}
+
 #[derive(Accounts)]
 pub struct CloseMarketOutcome<'info> {
     #[account(
@@ -13,6 +13,10 @@ pub enum CoreError {
     MarketLiquiditiesCrossMatchingDisabled,
     #[msg("MarketLiquidities: source_liquidities invalid")]
     MarketLiquiditiesSourceLiquiditiesInvalid,
+    #[msg("MarketLiquidities: outcome liquidities account required")]
+    MarketLiquiditiesOutcomeLiquiditiesRequired,
+    #[msg("MarketLiquidities: outcome liquidities account mismatch")]
+    MarketLiquiditiesOutcomeLiquiditiesMismatch,
 
     #[msg("Market: mismatch")]
     MarketMismatch,
@@ -48,6 +52,22 @@ pub enum CoreError {
     CreationPurchaserMismatch,
     #[msg("Order Creation: expired")]
     CreationExpired,
+    #[msg("Order Creation: post-only orders must be good-till-cancelled")]
+    CreationPostOnlyInvalidTimeInForce,
+    #[msg("Order Creation: market outcome has been withdrawn")]
+    CreationMarketOutcomeWithdrawn,
+    #[msg("Order Creation: stake above market limit")]
+    CreationStakeAboveMarketLimit,
+    #[msg("Order Creation: market position exposure above market limit")]
+    CreationExposureAboveMarketLimit,
+    #[msg("Order Creation: matched stake for outcome at market limit")]
+    CreationOutcomeStakeMatchedAtMarketLimit,
+    #[msg("Order Creation: purchaser not on market allow list")]
+    CreationPurchaserNotAllowed,
+    #[msg("Order Creation: purchaser on market deny list")]
+    CreationPurchaserDenied,
+    #[msg("Order Creation: purchaser does not hold a token of the market gate mint")]
+    CreationPurchaserTokenGateNotHeld,
 
     #[msg("Order Request Creation: request queue is full")]
     OrderRequestCreationQueueFull,
@@ -55,6 +75,12 @@ pub enum CoreError {
     OrderRequestCreationDuplicateRequest,
     #[msg("Order Request Creation: invalid payer token account")]
     OrderRequestCreationInvalidPayerTokenAccount,
+    #[msg("Order Request Creation: no order requests provided")]
+    OrderRequestCreationNoRequests,
+    #[msg("Order Request Creation: order account invalid or already in use")]
+    OrderRequestCreationInvalidOrderAccount,
+    #[msg("Order Request Creation: market outcome account missing or invalid")]
+    OrderRequestCreationInvalidMarketOutcome,
     #[msg("Order Request Processing: request queue is empty")]
     OrderRequestQueueIsEmpty,
     #[msg("Order Request Processing: request queue is not empty")]
@@ -87,16 +113,48 @@ pub enum CoreError {
     CancelationOrderStatusInvalid,
     #[msg("Order Cancelation: order created after market event started")]
     CancelationOrderCreatedAfterMarketEventStarted,
+    #[msg("Order Cancelation: order created after market suspension")]
+    CancelationOrderCreatedAfterMarketSuspension,
+    #[msg("Order Cancelation: unmatched stake was cancelled by market suspension")]
+    CancelationOrderCancelledBySuspension,
     #[msg("Order Cancelation: liquidity too low")]
     CancelationLowLiquidity,
     #[msg("Order Cancelation: cannot cancel preplay orders until all preplay order requests are processed")]
     CancelationPreplayOrderRequestsExist,
+    #[msg("Order Cancelation: expected order and market matching pool account pairs")]
+    CancelationInvalidOrderAccounts,
+    #[msg("Order Cancelation: duplicate order")]
+    CancelationDuplicateOrder,
+    #[msg("Core Cancelation: market matching pool mismatch")]
+    CancelationMarketMatchingPoolMismatch,
+    #[msg("Order Amendment: replacement must be for the same market outcome and side")]
+    AmendmentMarketOutcomeMismatch,
 
     /*
     Settlement
      */
     #[msg("Core Settlement: market outcome index is not valid for market")]
     SettlementInvalidMarketOutcomeIndex,
+    #[msg("Core Settlement: market outcome index provided more than once")]
+    SettlementDuplicateMarketOutcomeIndex,
+    #[msg(format!("Core Settlement: too many winning outcomes, max length: {}", Market::WINNING_OUTCOMES_MAX_LENGTH))]
+    SettlementTooManyWinningOutcomes,
+    #[msg("Core Settlement: dead-heat factor must be greater than 0 and at most 1")]
+    SettlementInvalidDeadHeatFactor,
+    #[msg("Core Settlement: dead-heat factors do not sum to market winning places")]
+    SettlementDeadHeatFactorsWinningPlacesMismatch,
+    #[msg(
+        "Core Settlement: outcome results are only supported for handicap and total market types"
+    )]
+    SettlementOutcomeResultsNotSupported,
+    #[msg("Core Settlement: a result is required for each of the two outcomes of the market")]
+    SettlementInvalidOutcomeResults,
+    #[msg("Core Settlement: outcome results must be opposites of each other")]
+    SettlementOutcomeResultsNotComplementary,
+    #[msg("Core Settlement: outcome result is not possible for the market line")]
+    SettlementOutcomeResultInvalidForLine,
+    #[msg("Settlement: winning outcome has been withdrawn")]
+    SettlementWinningOutcomeWithdrawn,
     #[msg("Core Settlement: payer mismatch")]
     SettlementPayerMismatch,
     #[msg("Core Settlement: market mismatch")]
@@ -111,6 +169,8 @@ pub enum CoreError {
     SettlementMarketEscrowNonZero,
     #[msg("Core Settlement: market funding is non zero")]
     SettlementMarketFundingNonZero,
+    #[msg("Core Settlement: market crank fee balance is non zero")]
+    SettlementMarketCrankFeeBalanceNonZero,
     #[msg("Core Settlement: market matching queue not empty")]
     SettlementMarketMatchingQueueNotEmpty,
     #[msg("Core Settlement: market payment queue not empty")]
@@ -143,6 +203,20 @@ pub enum CoreError {
     VoidMarketMatchingQueueNotProvided,
     #[msg("Void: request queue must be provided for non Initializing markets")]
     VoidMarketRequestQueueNotProvided,
+    #[msg("Void: trade void window must not be negative")]
+    VoidTradeWindowInvalid,
+    #[msg("Void: trade can only be voided within the market trade void window")]
+    VoidTradeWindowExpired,
+    #[msg("Void: market not open or locked for voiding trades")]
+    VoidTradeMarketNotOpenOrLocked,
+    #[msg("Void: trades are not the for and against sides of the same match")]
+    VoidTradeMismatch,
+    #[msg("Void: trade has no stake left to void")]
+    VoidTradeNoStake,
+    #[msg("Void: voiding trade would increase purchaser exposure")]
+    VoidTradeExposureIncrease,
+    #[msg("Void: trade matched before an outcome withdrawal")]
+    VoidTradeMatchedBeforeOutcomeWithdrawal,
 
     /*
     Account counts
@@ -161,6 +235,10 @@ pub enum CoreError {
     InvalidOperatorType,
     #[msg("Unauthorised operator")]
     UnauthorisedOperator,
+    #[msg("Authorised operator not found")]
+    AuthorisedOperatorNotFound,
+    #[msg("Operator permissions invalid")]
+    InvalidOperatorPermissions,
     #[msg("Unsupported operation")]
     UnsupportedOperation,
 
@@ -173,6 +251,8 @@ pub enum CoreError {
     MatchingMarketMismatch,
     #[msg("Core Matching: market-outcome mismatch")]
     MatchingMarketOutcomeMismatch,
+    #[msg("Core Matching: market-outcome has been withdrawn")]
+    MatchingMarketOutcomeWithdrawn,
     #[msg("Core Matching: expected for order")]
     MatchingExpectedAForOrder,
     #[msg("Core Matching: expected against order")]
@@ -240,6 +320,16 @@ pub enum CoreError {
     MatchingPoolIsEmpty,
     #[msg("Matching: matching pool head mismatch")]
     MatchingPoolHeadMismatch,
+    #[msg("Matching: matching pool head was cancelled by market suspension")]
+    MatchingPoolHeadCancelledBySuspension,
+    // atomic matching related errors
+    #[msg("Matching: atomic matching requires the matching queue to be empty")]
+    MatchingAtomicQueueNotEmpty,
+    #[msg("Matching: account required for atomic matching not provided")]
+    MatchingAtomicAccountNotProvided,
+    // price improvement related errors
+    #[msg("Matching: market outcome must be provided for price improvement")]
+    MatchingPriceImprovementMarketOutcomeRequired,
 
     /*
     Inplay
@@ -260,6 +350,10 @@ pub enum CoreError {
     MarketTypeValueUsageIncorrect,
     #[msg("Market type discriminator contains seed separator character")]
     MarketTypeDiscriminatorContainsSeedSeparator,
+    #[msg("Market type template requires a market type value to read the line from")]
+    MarketTypeTemplateRequiresValue,
+    #[msg("Market type value is not a valid line for the market type template")]
+    MarketTypeValueInvalidLine,
 
     /*
     PriceLadder
@@ -314,12 +408,18 @@ pub enum CoreError {
     OpenMarketNotEnoughOutcomes,
     #[msg("Market: too many outcomes for this operation")]
     MarketTooManyOutcomes,
+    #[msg("Market: winning places must be at least 1")]
+    MarketWinningPlacesInvalid,
+    #[msg("Market: cross matching not supported for more than 1 winning place")]
+    MarketWinningPlacesCrossMatchingUnsupported,
     #[msg("Market: market is not settled or voided")]
     MarketNotSettledOrVoided,
     #[msg("Market: market is not ready to close")]
     MarketNotReadyToClose,
     #[msg("Market: market authority does not match operator")]
     MarketAuthorityMismatch,
+    #[msg("Market: operator delegations full")]
+    MarketOperatorDelegationsFull,
     #[msg("Market: market inplay not enabled")]
     MarketInplayNotEnabled,
     #[msg("Market: market is already inplay")]
@@ -328,6 +428,14 @@ pub enum CoreError {
     MarketEventNotStarted,
     #[msg("Market: market not open to allow transition to inplay")]
     MarketNotOpenForInplay,
+    #[msg("Market: market liquidities and matching queue required to cancel unmatched orders on suspension")]
+    MarketSuspensionAccountsRequired,
+    #[msg("Market: market matching queue must be empty to cancel unmatched orders on suspension")]
+    MarketSuspensionMatchingQueueNotEmpty,
+    #[msg("Market: market cannot be unsuspended in the same second it was suspended")]
+    MarketUnsuspensionTooEarly,
+    #[msg("Market: crank reward cannot be more than the crank fee")]
+    MarketCrankRewardExceedsFee,
 
     #[msg("Market: cannot recreate market, provided event account does not match existing market")]
     MarketEventAccountMismatch,
@@ -345,6 +453,91 @@ pub enum CoreError {
     #[msg("Market: attempted to decrease market unsettled count with non-zero escrow")]
     MarketUnsettledCountDecreaseWithNonZeroEscrow,
 
+    #[msg("Market: outcome withdrawal only supported for markets with 1 winning place")]
+    MarketOutcomeWithdrawalWinningPlacesUnsupported,
+    #[msg("Market: outcome withdrawal not supported with cross matching enabled")]
+    MarketOutcomeWithdrawalCrossMatchingUnsupported,
+    #[msg("Market: invalid market outcome index")]
+    MarketOutcomeIndexInvalid,
+    #[msg("Market: outcome already withdrawn")]
+    MarketOutcomeAlreadyWithdrawn,
+    #[msg("Market: too few outcomes remaining to withdraw outcome")]
+    MarketOutcomeWithdrawalTooFewOutcomes,
+    #[msg(format!("Market: too many outcomes withdrawn, max: {}", Market::WITHDRAWN_OUTCOMES_MAX_LENGTH))]
+    MarketOutcomeWithdrawalLimitReached,
+    #[msg("Market: total reduction factor for withdrawn outcomes too large")]
+    MarketOutcomeWithdrawalReductionFactorInvalid,
+
+    /*
+    Order Book
+     */
+    #[msg("Order Book: pages can only be created while the market is initializing")]
+    OrderBookMarketNotInitializing,
+    #[msg("Order Book: page index is beyond the end of the outcome price ladder")]
+    OrderBookPageEmpty,
+    #[msg("Order Book: page for the order outcome and price must be provided")]
+    OrderBookPageRequired,
+    #[msg("Order Book: page does not match the order market, outcome or price")]
+    OrderBookPageMismatch,
+    #[msg("Order Book: price is not on the page")]
+    OrderBookPriceNotOnPage,
+
+    /*
+    Market Limits
+     */
+    #[msg("Market Limits: limits can only be created while the market is initializing")]
+    MarketLimitsMarketNotInitializing,
+    #[msg("Market Limits: limits account must be provided")]
+    MarketLimitsRequired,
+    #[msg("Market Limits: limits account does not match the market")]
+    MarketLimitsMismatch,
+    #[msg("Market Limits: outcomes cannot be added once limits are created")]
+    MarketLimitsOutcomesFixed,
+    #[msg("Market Limits: matched stake for outcome above limit")]
+    MarketLimitsOutcomeStakeMatchedExceeded,
+
+    /*
+    Market Access Control
+     */
+    #[msg("Market Access Control: access control account must be provided")]
+    MarketAccessControlRequired,
+    #[msg("Market Access Control: access control account does not match the market")]
+    MarketAccessControlMismatch,
+    #[msg("Market Access Control: purchaser list is full")]
+    MarketAccessControlPurchasersFull,
+    #[msg(format!("Market Access Control: purchaser list capacity too large, max: {}", MarketAccessControl::PURCHASERS_MAX_CAPACITY))]
+    MarketAccessControlCapacityTooLarge,
+
+    /*
+    Settlement Proposal
+     */
+    #[msg("Settlement Proposal: market requires settlement by an approved proposal")]
+    SettlementProposalRequired,
+    #[msg("Settlement Proposal: market does not require settlement approvals")]
+    SettlementProposalsNotEnabled,
+    #[msg("Settlement Proposal: market mismatch")]
+    SettlementProposalMarketMismatch,
+    #[msg("Settlement Proposal: proposer mismatch")]
+    SettlementProposalProposerMismatch,
+    #[msg("Settlement Proposal: winning outcomes are only proposed for settlement")]
+    SettlementProposalWinningOutcomesInvalid,
+    #[msg("Settlement Proposal: operator has already approved the proposal")]
+    SettlementProposalAlreadyApproved,
+    #[msg("Settlement Proposal: approvals full")]
+    SettlementProposalApprovalsFull,
+    #[msg("Settlement Proposal: proposal does not have the approvals required")]
+    SettlementProposalNotApproved,
+    #[msg("Settlement Proposal: market queues required for settlement not provided")]
+    SettlementProposalQueuesNotProvided,
+    #[msg("Settlement Proposal: challenge period has not ended")]
+    SettlementProposalChallengePeriodActive,
+    #[msg("Settlement Proposal: approvals required or challenge period invalid")]
+    SettlementProposalApprovalSettingsInvalid,
+    #[msg("Settlement Proposal: approvals required and challenge period cannot be lowered")]
+    SettlementProposalApprovalSettingsLowered,
+    #[msg("Settlement Proposal: approvals required exceed the operators permitted to approve")]
+    SettlementProposalApprovalsExceedOperators,
+
     /*
     Close Account
      */
@@ -364,4 +557,14 @@ pub enum CoreError {
     CloseAccountMarketMatchingQueueNotEmpty,
     #[msg("CloseAccount: Market order request queue is not empty")]
     CloseAccountOrderRequestQueueNotEmpty,
+
+    /*
+    Migration
+     */
+    #[msg("Migration: account is not a migratable program account")]
+    MigrationInvalidAccount,
+    #[msg("Migration: account is already at the current schema version")]
+    MigrationAccountUpToDate,
+    #[msg("Migration: account layout does not match a known schema version")]
+    MigrationUnknownSchemaVersion,
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::state::market_account::MarketStatus;
+
+#[event]
+pub struct MarketStatusChangedEvent {
+    pub market: Pubkey,
+    pub market_status: MarketStatus,
+}
+
+#[event]
+pub struct MarketInplayEvent {
+    pub market: Pubkey,
+}
+
+#[event]
+pub struct MarketOutcomeWithdrawnEvent {
+    pub market: Pubkey,
+    pub outcome_index: u16,
+    pub reduction_factor: u16,
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PositionSettledEvent {
+    pub market: Pubkey,
+    pub market_position: Pubkey,
+    pub purchaser: Pubkey,
+    pub profit: i128,
+    pub protocol_commission: u64,
+    pub product_commission: u64,
+    pub payout: u64,
+}
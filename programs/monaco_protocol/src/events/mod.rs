@@ -1 +1,5 @@
-pub(crate) mod trade;
+pub mod market;
+pub mod market_position;
+pub mod order;
+pub mod payment;
+pub mod trade;
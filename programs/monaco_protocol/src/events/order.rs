@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::order_account::OrderStatus;
+use crate::state::price::Price;
+
+#[event]
+pub struct OrderRequestCreatedEvent {
+    pub market: Pubkey,
+    pub purchaser: Pubkey,
+    pub market_outcome_index: u16,
+    pub for_outcome: bool,
+    pub stake: u64,
+    pub price: Price,
+    pub distinct_seed: [u8; 16],
+}
+
+#[event]
+pub struct OrderCreatedEvent {
+    pub market: Pubkey,
+    pub order: Pubkey,
+    pub purchaser: Pubkey,
+    pub market_outcome_index: u16,
+    pub for_outcome: bool,
+    pub stake: u64,
+    pub price: Price,
+}
+
+// outcome and side are the maker's, which differ from the taker's for cross matches
+#[event]
+pub struct OrderMatchedEvent {
+    pub market: Pubkey,
+    pub maker_order: Pubkey,
+    pub taker_order: Option<Pubkey>,
+    pub market_outcome_index: u16,
+    pub for_outcome: bool,
+    pub price: Price,
+    pub stake: u64,
+}
+
+#[event]
+pub struct OrderCancelledEvent {
+    pub market: Pubkey,
+    pub order: Pubkey,
+    pub purchaser: Pubkey,
+    pub stake_voided: u64,
+    pub stake_unmatched: u64,
+}
+
+#[event]
+pub struct OrderSettledEvent {
+    pub market: Pubkey,
+    pub order: Pubkey,
+    pub purchaser: Pubkey,
+    pub order_status: OrderStatus,
+    pub payout: u64,
+}
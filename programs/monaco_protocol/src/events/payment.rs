@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CommissionPaidEvent {
+    pub market: Pubkey,
+    pub product: Pubkey,
+    pub amount: u64,
+}
@@ -1,8 +1,18 @@
+use crate::state::price::Price;
 use anchor_lang::prelude::*;
 
 #[event]
 pub struct TradeEvent {
     pub amount: u64,
-    pub price: f64,
+    pub price: Price,
     pub market: Pubkey,
 }
+
+#[event]
+pub struct TradesVoidedEvent {
+    pub market: Pubkey,
+    pub for_trade: Pubkey,
+    pub against_trade: Pubkey,
+    pub stake: u64,
+    pub price: Price,
+}
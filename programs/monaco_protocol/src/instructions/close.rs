@@ -86,6 +86,7 @@ mod tests {
     use crate::state::market_matching_queue_account::OrderMatch;
     use crate::state::order_account::{mock_order_default, OrderStatus};
     use crate::state::payments_queue::PaymentInfo;
+    use crate::state::price::Price;
 
     // generic close account validation
 
@@ -170,7 +171,7 @@ mod tests {
         });
 
         let matching_queue = &mut MatchingQueue::new(1);
-        matching_queue.enqueue(OrderMatch::maker(false, 0, 0.0, 0));
+        matching_queue.enqueue(OrderMatch::maker(false, 0, Price::new(0), 0));
         let request_queue = OrderRequestQueue::new(1);
 
         let result = close_market_queues(
@@ -316,7 +317,8 @@ mod tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -328,6 +330,23 @@ mod tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         }
     }
 
@@ -341,6 +360,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 0,
             matched_risk_per_product: vec![],
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         }
     }
 }
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 
 use crate::context::{CreateMarket, InitializeMarketOutcome};
-use crate::instructions::{current_timestamp, price_precision_is_within_range};
+use crate::instructions::current_timestamp;
+use crate::instructions::market::set_market_status;
 use crate::monaco_protocol::{PRICE_SCALE, SEED_SEPARATOR_CHAR};
 use crate::state::market_account::{Market, MarketOrderBehaviour, MarketStatus};
 use crate::state::market_matching_pool_account::{Cirque, MarketMatchingPool};
 use crate::state::market_outcome_account::MarketOutcome;
+use crate::state::market_type::{MarketLine, MarketTemplate};
 use crate::state::order_account::Order;
+use crate::state::price::Price;
 use crate::CoreError;
 
 const STATUSES_THAT_SUPPORT_MARKET_RECREATION: [MarketStatus; 2] =
@@ -55,6 +58,12 @@ pub fn create(
         ctx.accounts.market_type.requires_value == market_type_value.is_some(),
         CoreError::MarketTypeValueUsageIncorrect
     );
+    if let Some(value) = &market_type_value {
+        let template = ctx.accounts.market_type.template;
+        if template != MarketTemplate::Standard {
+            MarketLine::parse(template, value)?;
+        }
+    }
 
     require!(
         market_type_discriminator.is_none()
@@ -115,8 +124,25 @@ pub fn create(
     ctx.accounts.market.market_type_discriminator = market_type_discriminator;
     ctx.accounts.market.market_type_value = market_type_value;
     ctx.accounts.market.version = version;
+    ctx.accounts.market.schema_version = Market::SCHEMA_VERSION;
     ctx.accounts.market.market_outcomes_count = 0_u16;
-    ctx.accounts.market.market_winning_outcome_index = None;
+    ctx.accounts.market.market_winning_places = 1_u16;
+    ctx.accounts.market.market_winning_outcomes = vec![];
+    ctx.accounts.market.market_outcome_results = vec![];
+    ctx.accounts.market.trade_void_window = 0;
+    ctx.accounts.market.withdrawn_outcomes = vec![];
+    ctx.accounts.market.order_book_enabled = false;
+    ctx.accounts.market.price_improvement_enabled = false;
+    ctx.accounts.market.limits_enabled = false;
+    ctx.accounts.market.access_control_enabled = false;
+    ctx.accounts.market.settlement_approvals_required = 0;
+    ctx.accounts.market.settlement_challenge_period = 0;
+    ctx.accounts.market.atomic_matching_enabled = false;
+    ctx.accounts.market.crank_fee = 0;
+    ctx.accounts.market.crank_reward = 0;
+    ctx.accounts.market.crank_fee_balance = 0;
+    ctx.accounts.market.suspension_order_behaviour = MarketOrderBehaviour::None;
+    ctx.accounts.market.suspension_timestamp = 0;
     ctx.accounts.market.market_lock_timestamp = market_lock_timestamp;
     ctx.accounts.market.market_settle_timestamp = None;
     ctx.accounts.market.title = title;
@@ -124,7 +150,11 @@ pub fn create(
     ctx.accounts.market.decimal_limit = decimal_limit;
     ctx.accounts.market.escrow_account_bump = ctx.bumps.escrow;
     ctx.accounts.market.funding_account_bump = ctx.bumps.funding;
-    ctx.accounts.market.market_status = MarketStatus::Initializing;
+    set_market_status(
+        &ctx.accounts.market.key(),
+        &mut ctx.accounts.market,
+        MarketStatus::Initializing,
+    );
     ctx.accounts.market.published = false;
     ctx.accounts.market.suspended = false;
     ctx.accounts.market.event_start_timestamp = event_start_timestamp;
@@ -150,6 +180,11 @@ pub fn initialize_outcome(ctx: Context<InitializeMarketOutcome>, title: String)
         ctx.accounts.market.market_status == MarketStatus::Initializing,
         CoreError::MarketOutcomeMarketInvalidStatus
     );
+    // limits track matched stake for the outcomes present when they were created
+    require!(
+        !ctx.accounts.market.limits_enabled,
+        CoreError::MarketLimitsOutcomesFixed
+    );
     require!(
         title.len() <= MarketOutcome::TITLE_MAX_LENGTH,
         CoreError::MarketOutcomeTitleTooLong
@@ -178,21 +213,21 @@ pub fn initialize_outcome(ctx: Context<InitializeMarketOutcome>, title: String)
     Ok(())
 }
 
-fn validate_prices(prices: &[f64]) -> Result<()> {
+fn validate_prices(prices: &[Price]) -> Result<()> {
     let prices_iter = prices.iter();
     for price in prices_iter {
-        price_precision_is_within_range(*price)?;
-        require!(*price > 1_f64, CoreError::MarketPriceOneOrLess);
+        require!(*price > Price::ONE, CoreError::MarketPriceOneOrLess);
     }
     Ok(())
 }
 
 pub fn initialize_market_matching_pool(
-    matching_pool: &mut Account<MarketMatchingPool>,
-    market: &Account<Market>,
+    matching_pool: &mut MarketMatchingPool,
+    market_pk: Pubkey,
+    market: &Market,
     order: &Order,
 ) -> Result<()> {
-    matching_pool.market = market.key();
+    matching_pool.market = market_pk;
     matching_pool.market_outcome_index = order.market_outcome_index;
     matching_pool.price = order.expected_price;
     matching_pool.for_outcome = order.for_outcome;
@@ -206,14 +241,14 @@ pub fn initialize_market_matching_pool(
 
 pub fn add_prices_to_market_outcome(
     market_outcome: &mut MarketOutcome,
-    new_prices: Vec<f64>,
+    new_prices: Vec<Price>,
 ) -> Result<()> {
     validate_prices(&new_prices)?;
 
     let mut ladder = market_outcome.price_ladder.clone();
 
     ladder.extend(new_prices);
-    ladder.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ladder.sort();
     ladder.dedup();
 
     market_outcome.price_ladder = ladder;
@@ -232,11 +267,22 @@ mod tests {
         add_prices_to_market_outcome, validate_prices,
     };
     use crate::state::market_outcome_account::MarketOutcome;
+    use crate::state::price::Price;
 
     #[test]
     fn test_add_prices_to_market_outcome() {
-        let new_prices = vec![1.11, 1.12, 1.13, 1.4];
-        let existing_prices = vec![1.2, 1.3, 1.4, 1.4];
+        let new_prices = vec![
+            Price::new(1_110),
+            Price::new(1_120),
+            Price::new(1_130),
+            Price::new(1_400),
+        ];
+        let existing_prices = vec![
+            Price::new(1_200),
+            Price::new(1_300),
+            Price::new(1_400),
+            Price::new(1_400),
+        ];
 
         let mut outcome = MarketOutcome {
             market: Default::default(),
@@ -249,42 +295,31 @@ mod tests {
         let result = add_prices_to_market_outcome(&mut outcome, new_prices);
         assert!(result.is_ok());
         assert_eq!(outcome.price_ladder.len(), 6);
-        assert_eq!(outcome.price_ladder, vec![1.11, 1.12, 1.13, 1.2, 1.3, 1.4]);
+        assert_eq!(
+            outcome.price_ladder,
+            vec![
+                Price::new(1_110),
+                Price::new(1_120),
+                Price::new(1_130),
+                Price::new(1_200),
+                Price::new(1_300),
+                Price::new(1_400)
+            ]
+        );
     }
 
     #[test]
     fn test_validate_prices() {
-        let precision_ok = validate_prices(&vec![1.111, 1.11, 1.1]);
-        assert!(precision_ok.is_ok());
-
-        let precision_not_ok_0 = validate_prices(&vec![1.1111, 1.111, 1.11, 1.1]);
-        assert!(precision_not_ok_0.is_err());
+        let ok = validate_prices(&[Price::new(1_111), Price::new(1_110), Price::new(1_100)]);
+        assert!(ok.is_ok());
 
-        let precision_not_ok_1 = validate_prices(&vec![1.111, 1.1111, 1.11, 1.1]);
-        assert!(precision_not_ok_1.is_err());
-
-        let precision_not_ok_2 = validate_prices(&vec![1.111, 1.11, 1.1111, 1.1]);
-        assert!(precision_not_ok_2.is_err());
-
-        let precision_not_ok_3 = validate_prices(&vec![1.111, 1.11, 1.1, 1_f64, 1.1111]);
-        assert!(precision_not_ok_3.is_err());
-
-        let attempting_to_round_not_ok = validate_prices(&vec![1.1118]);
-        assert!(attempting_to_round_not_ok.is_err());
-
-        let attempting_to_round_2_not_ok = validate_prices(&vec![9.9999]);
-        assert!(attempting_to_round_2_not_ok.is_err());
-
-        let one_not_ok = validate_prices(&vec![1_f64]);
+        let one_not_ok = validate_prices(&[Price::new(1_111), Price::ONE]);
         assert!(one_not_ok.is_err());
 
-        let fraction_not_ok = validate_prices(&vec![0.5_f64]);
+        let fraction_not_ok = validate_prices(&[Price::new(500)]);
         assert!(fraction_not_ok.is_err());
 
-        let zero_not_ok = validate_prices(&vec![0_f64]);
+        let zero_not_ok = validate_prices(&[Price::new(0)]);
         assert!(zero_not_ok.is_err());
-
-        let neg_not_ok = validate_prices(&vec![-1_f64]);
-        assert!(neg_not_ok.is_err());
     }
 }
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_access_control::{MarketAccessControl, PurchaserListMode};
+use crate::state::market_account::{Market, MarketStatus};
+
+pub fn create_market_access_control(
+    market_pk: Pubkey,
+    market: &mut Market,
+    market_access_control: &mut MarketAccessControl,
+    purchaser_list_mode: PurchaserListMode,
+    token_gate_mint: Option<Pubkey>,
+    purchasers_capacity: u16,
+) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open
+        ),
+        CoreError::MarketInvalidStatus
+    );
+    require!(
+        purchasers_capacity <= MarketAccessControl::PURCHASERS_MAX_CAPACITY,
+        CoreError::MarketAccessControlCapacityTooLarge
+    );
+
+    market_access_control.market = market_pk;
+    market_access_control.purchaser_list_mode = purchaser_list_mode;
+    market_access_control.token_gate_mint = token_gate_mint;
+    market_access_control.purchasers_capacity = purchasers_capacity;
+    market_access_control.purchasers = vec![];
+
+    market.access_control_enabled = true;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_access_control::mock_market_access_control;
+    use crate::state::market_account::mock_market;
+
+    #[test]
+    fn test_create_market_access_control() {
+        let market_pk = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        let mut market_access_control =
+            mock_market_access_control(Pubkey::default(), PurchaserListMode::None, vec![]);
+
+        let result = create_market_access_control(
+            market_pk,
+            &mut market,
+            &mut market_access_control,
+            PurchaserListMode::Allow,
+            Some(mint),
+            100,
+        );
+
+        assert!(result.is_ok());
+        assert!(market.access_control_enabled);
+        assert_eq!(market_pk, market_access_control.market);
+        assert_eq!(
+            PurchaserListMode::Allow,
+            market_access_control.purchaser_list_mode
+        );
+        assert_eq!(Some(mint), market_access_control.token_gate_mint);
+        assert_eq!(100, market_access_control.purchasers_capacity);
+    }
+
+    #[test]
+    fn test_create_market_access_control_invalid() {
+        let mut market = mock_market(MarketStatus::Settled);
+        let mut market_access_control =
+            mock_market_access_control(Pubkey::default(), PurchaserListMode::None, vec![]);
+
+        let result = create_market_access_control(
+            Pubkey::new_unique(),
+            &mut market,
+            &mut market_access_control,
+            PurchaserListMode::Allow,
+            None,
+            100,
+        );
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+
+        market.market_status = MarketStatus::Open;
+        let result = create_market_access_control(
+            Pubkey::new_unique(),
+            &mut market,
+            &mut market_access_control,
+            PurchaserListMode::Allow,
+            None,
+            MarketAccessControl::PURCHASERS_MAX_CAPACITY + 1,
+        );
+        assert_eq!(
+            Err(error!(CoreError::MarketAccessControlCapacityTooLarge)),
+            result
+        );
+        assert!(!market.access_control_enabled);
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketStatus};
+use crate::state::market_limits::MarketLimits;
+
+pub fn create_market_limits(
+    market_pk: Pubkey,
+    market: &mut Market,
+    market_limits: &mut MarketLimits,
+    max_order_stake: u64,
+    max_position_exposure: u64,
+    max_outcome_stake_matched: u64,
+) -> Result<()> {
+    // limits have to exist before any orders are placed to track all matched stake
+    require!(
+        market.market_status == MarketStatus::Initializing,
+        CoreError::MarketLimitsMarketNotInitializing
+    );
+
+    market_limits.market = market_pk;
+    market_limits.max_order_stake = max_order_stake;
+    market_limits.max_position_exposure = max_position_exposure;
+    market_limits.max_outcome_stake_matched = max_outcome_stake_matched;
+    market_limits.outcome_stakes_matched = vec![0; usize::from(market.market_outcomes_count)];
+
+    market.limits_enabled = true;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+    use crate::state::market_limits::mock_market_limits;
+
+    #[test]
+    fn test_create_market_limits() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Initializing);
+        market.market_outcomes_count = 3;
+        let mut market_limits = mock_market_limits(Pubkey::default(), 0, 0, 0, 0);
+
+        let result =
+            create_market_limits(market_pk, &mut market, &mut market_limits, 100, 200, 300);
+
+        assert!(result.is_ok());
+        assert!(market.limits_enabled);
+        assert_eq!(market_pk, market_limits.market);
+        assert_eq!(100, market_limits.max_order_stake);
+        assert_eq!(200, market_limits.max_position_exposure);
+        assert_eq!(300, market_limits.max_outcome_stake_matched);
+        assert_eq!(vec![0, 0, 0], market_limits.outcome_stakes_matched);
+    }
+
+    #[test]
+    fn test_create_market_limits_market_open() {
+        let mut market = mock_market(MarketStatus::Open);
+        let mut market_limits = mock_market_limits(Pubkey::default(), 0, 0, 0, 0);
+
+        let result = create_market_limits(
+            Pubkey::new_unique(),
+            &mut market,
+            &mut market_limits,
+            100,
+            200,
+            300,
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::MarketLimitsMarketNotInitializing)),
+            result
+        );
+        assert!(!market.limits_enabled);
+    }
+}
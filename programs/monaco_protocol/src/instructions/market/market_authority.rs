@@ -1,11 +1,119 @@
 use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketOperatorDelegation};
+use crate::state::operator_account::OperatorPermission;
 use anchor_lang::prelude::*;
 use solana_program::pubkey::Pubkey;
 
-pub fn verify_market_authority(operator: &Pubkey, market_authority: &Pubkey) -> Result<()> {
+// delegating to an operator already delegated to replaces its permissions; the market authority
+// can only delegate permissions it holds itself
+pub fn delegate_market_operator(
+    market: &mut Market,
+    authority_permissions: u16,
+    operator: Pubkey,
+    permissions: u16,
+) -> Result<()> {
     require!(
-        market_authority.eq(operator),
-        CoreError::MarketAuthorityMismatch
+        OperatorPermission::is_valid(permissions) && permissions & !authority_permissions == 0,
+        CoreError::InvalidOperatorPermissions
     );
+
+    match market
+        .operator_delegations
+        .iter_mut()
+        .find(|delegation| delegation.operator == operator)
+    {
+        Some(delegation) => delegation.permissions = permissions,
+        None => {
+            require!(
+                market.operator_delegations.len() < Market::OPERATOR_DELEGATIONS_MAX_LENGTH,
+                CoreError::MarketOperatorDelegationsFull
+            );
+            market.operator_delegations.push(MarketOperatorDelegation {
+                operator,
+                permissions,
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn revoke_market_operator_delegation(market: &mut Market, operator: Pubkey) -> Result<()> {
+    market
+        .operator_delegations
+        .retain(|delegation| delegation.operator != operator);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::{mock_market, MarketStatus};
+
+    #[test]
+    fn test_delegate_market_operator() {
+        let operator = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        let authority = market.authority;
+
+        let result = delegate_market_operator(
+            &mut market,
+            OperatorPermission::ALL,
+            operator,
+            OperatorPermission::SETTLE,
+        );
+        assert!(result.is_ok());
+        assert!(market.is_operator_permitted(&operator, OperatorPermission::SETTLE));
+        assert!(!market.is_operator_permitted(&operator, OperatorPermission::VOID));
+        assert!(market.is_operator_permitted(&authority, OperatorPermission::VOID));
+
+        // delegating again replaces the permissions
+        let result = delegate_market_operator(
+            &mut market,
+            OperatorPermission::ALL,
+            operator,
+            OperatorPermission::VOID,
+        );
+        assert!(result.is_ok());
+        assert_eq!(1, market.operator_delegations.len());
+        assert!(!market.is_operator_permitted(&operator, OperatorPermission::SETTLE));
+        assert!(market.is_operator_permitted(&operator, OperatorPermission::VOID));
+
+        let result = revoke_market_operator_delegation(&mut market, operator);
+        assert!(result.is_ok());
+        assert!(!market.is_operator_permitted(&operator, OperatorPermission::VOID));
+    }
+
+    #[test]
+    fn test_delegate_market_operator_invalid() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        // permissions not held by the market authority
+        let result = delegate_market_operator(
+            &mut market,
+            OperatorPermission::SETTLE,
+            Pubkey::new_unique(),
+            OperatorPermission::SETTLE | OperatorPermission::VOID,
+        );
+        assert_eq!(Err(error!(CoreError::InvalidOperatorPermissions)), result);
+
+        for _ in 0..Market::OPERATOR_DELEGATIONS_MAX_LENGTH {
+            delegate_market_operator(
+                &mut market,
+                OperatorPermission::ALL,
+                Pubkey::new_unique(),
+                OperatorPermission::SETTLE,
+            )
+            .unwrap();
+        }
+        let result = delegate_market_operator(
+            &mut market,
+            OperatorPermission::ALL,
+            Pubkey::new_unique(),
+            OperatorPermission::SETTLE,
+        );
+        assert_eq!(
+            Err(error!(CoreError::MarketOperatorDelegationsFull)),
+            result
+        );
+    }
+}
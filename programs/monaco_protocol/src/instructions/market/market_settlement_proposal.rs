@@ -0,0 +1,354 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+use crate::error::CoreError;
+use crate::instructions::market::validate_winning_outcomes;
+use crate::state::market_account::{Market, MarketStatus, MarketWinningOutcome};
+use crate::state::market_settlement_proposal::{
+    MarketSettlementProposal, SettlementProposalAction,
+};
+use crate::state::operator_account::{AuthorisedOperators, OperatorPermission};
+
+pub fn update_settlement_approval_settings(
+    market: &mut Market,
+    authorised_operators: &AuthorisedOperators,
+    approvals_required: u8,
+    challenge_period: i64,
+) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open
+        ),
+        CoreError::MarketInvalidStatus
+    );
+    require!(
+        usize::from(approvals_required) <= MarketSettlementProposal::APPROVALS_MAX_LENGTH
+            && (0..=MarketSettlementProposal::CHALLENGE_PERIOD_MAX).contains(&challenge_period),
+        CoreError::SettlementProposalApprovalSettingsInvalid
+    );
+    // the threshold has to be reachable for both settling and force voiding the market
+    let approvers = |permission: u16| {
+        authorised_operators
+            .operator_list
+            .iter()
+            .filter(|operator| authorised_operators.has_permission(operator, permission))
+            .count()
+    };
+    require!(
+        usize::from(approvals_required)
+            <= approvers(OperatorPermission::SETTLE).min(approvers(OperatorPermission::VOID)),
+        CoreError::SettlementProposalApprovalsExceedOperators
+    );
+    // settings can only be tightened so a single key cannot fall back to direct settlement
+    require!(
+        approvals_required >= market.settlement_approvals_required
+            && challenge_period >= market.settlement_challenge_period,
+        CoreError::SettlementProposalApprovalSettingsLowered
+    );
+
+    market.settlement_approvals_required = approvals_required;
+    market.settlement_challenge_period = challenge_period;
+    Ok(())
+}
+
+pub fn propose_settlement(
+    proposal: &mut MarketSettlementProposal,
+    market_pk: Pubkey,
+    market: &Market,
+    proposer: Pubkey,
+    action: SettlementProposalAction,
+    winning_outcomes: Vec<MarketWinningOutcome>,
+    now: UnixTimestamp,
+) -> Result<()> {
+    require!(
+        market.settlement_approvals_required > 0,
+        CoreError::SettlementProposalsNotEnabled
+    );
+    match action {
+        SettlementProposalAction::Settle => {
+            require!(
+                MarketStatus::Open.eq(&market.market_status),
+                CoreError::SettlementMarketNotOpen
+            );
+            validate_winning_outcomes(market, &winning_outcomes)?;
+        }
+        SettlementProposalAction::ForceVoid => {
+            require!(
+                winning_outcomes.is_empty(),
+                CoreError::SettlementProposalWinningOutcomesInvalid
+            );
+        }
+    }
+
+    proposal.market = market_pk;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    proposal.winning_outcomes = winning_outcomes;
+    proposal.approvals = vec![];
+    proposal.approvals_required = market.settlement_approvals_required;
+    proposal.challenge_period = market.settlement_challenge_period;
+    proposal.proposed_timestamp = now;
+    proposal.approved_timestamp = None;
+
+    // the proposer's approval counts towards the threshold
+    proposal.approve(proposer, now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+    use crate::state::market_settlement_proposal::mock_market_settlement_proposal;
+
+    fn settlement_operators(count: usize) -> AuthorisedOperators {
+        let mut operators = AuthorisedOperators::default();
+        for _ in 0..count {
+            operators.insert(Pubkey::new_unique(), OperatorPermission::ALL);
+        }
+        operators
+    }
+
+    fn winning_outcome(outcome_index: u16) -> MarketWinningOutcome {
+        MarketWinningOutcome {
+            outcome_index,
+            dead_heat_numerator: 1,
+            dead_heat_denominator: 1,
+        }
+    }
+
+    #[test]
+    fn test_update_settlement_approval_settings() {
+        let operators = settlement_operators(3);
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_settlement_approval_settings(&mut market, &operators, 2, 3600);
+
+        assert!(result.is_ok());
+        assert_eq!(2, market.settlement_approvals_required);
+        assert_eq!(3600, market.settlement_challenge_period);
+
+        let result = update_settlement_approval_settings(&mut market, &operators, 3, 3600);
+
+        assert!(result.is_ok());
+        assert_eq!(3, market.settlement_approvals_required);
+    }
+
+    #[test]
+    fn test_update_settlement_approval_settings_lowered() {
+        let operators = settlement_operators(3);
+        let mut market = mock_market(MarketStatus::Open);
+        update_settlement_approval_settings(&mut market, &operators, 2, 3600).unwrap();
+
+        let result = update_settlement_approval_settings(&mut market, &operators, 1, 3600);
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalApprovalSettingsLowered)),
+            result
+        );
+
+        let result = update_settlement_approval_settings(&mut market, &operators, 2, 0);
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalApprovalSettingsLowered)),
+            result
+        );
+        assert_eq!(2, market.settlement_approvals_required);
+        assert_eq!(3600, market.settlement_challenge_period);
+    }
+
+    #[test]
+    fn test_update_settlement_approval_settings_invalid() {
+        let operators = settlement_operators(3);
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_settlement_approval_settings(&mut market, &operators, 17, 0);
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalApprovalSettingsInvalid)),
+            result
+        );
+
+        let result = update_settlement_approval_settings(&mut market, &operators, 1, -1);
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalApprovalSettingsInvalid)),
+            result
+        );
+
+        let mut market = mock_market(MarketStatus::Locked);
+        let result = update_settlement_approval_settings(&mut market, &operators, 1, 0);
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+    }
+
+    #[test]
+    fn test_update_settlement_approval_settings_challenge_period_too_long() {
+        let operators = settlement_operators(3);
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_settlement_approval_settings(
+            &mut market,
+            &operators,
+            1,
+            MarketSettlementProposal::CHALLENGE_PERIOD_MAX + 1,
+        );
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalApprovalSettingsInvalid)),
+            result
+        );
+
+        let result = update_settlement_approval_settings(
+            &mut market,
+            &operators,
+            1,
+            MarketSettlementProposal::CHALLENGE_PERIOD_MAX,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_update_settlement_approval_settings_exceed_operators() {
+        let mut operators = settlement_operators(2);
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_settlement_approval_settings(&mut market, &operators, 3, 0);
+        assert_eq!(
+            Err(error!(
+                CoreError::SettlementProposalApprovalsExceedOperators
+            )),
+            result
+        );
+
+        // operators that can settle but not force void do not count towards the threshold
+        operators.insert(Pubkey::new_unique(), OperatorPermission::SETTLE);
+        let result = update_settlement_approval_settings(&mut market, &operators, 3, 0);
+        assert_eq!(
+            Err(error!(
+                CoreError::SettlementProposalApprovalsExceedOperators
+            )),
+            result
+        );
+
+        operators.insert(Pubkey::new_unique(), OperatorPermission::VOID);
+        let result = update_settlement_approval_settings(&mut market, &operators, 2, 0);
+        assert!(result.is_ok());
+        assert_eq!(2, market.settlement_approvals_required);
+    }
+
+    #[test]
+    fn test_propose_settlement() {
+        let operators = settlement_operators(3);
+        let market_pk = Pubkey::new_unique();
+        let proposer = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        update_settlement_approval_settings(&mut market, &operators, 2, 60).unwrap();
+        let mut proposal = mock_market_settlement_proposal(
+            Pubkey::default(),
+            SettlementProposalAction::Settle,
+            0,
+            0,
+        );
+
+        let result = propose_settlement(
+            &mut proposal,
+            market_pk,
+            &market,
+            proposer,
+            SettlementProposalAction::Settle,
+            vec![winning_outcome(1)],
+            100,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(market_pk, proposal.market);
+        assert_eq!(proposer, proposal.proposer);
+        assert_eq!(vec![winning_outcome(1)], proposal.winning_outcomes);
+        assert_eq!(vec![proposer], proposal.approvals);
+        assert_eq!(2, proposal.approvals_required);
+        assert_eq!(60, proposal.challenge_period);
+        assert_eq!(100, proposal.proposed_timestamp);
+        assert_eq!(None, proposal.approved_timestamp);
+    }
+
+    #[test]
+    fn test_propose_settlement_single_approval_required() {
+        let operators = settlement_operators(3);
+        let proposer = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Initializing);
+        update_settlement_approval_settings(&mut market, &operators, 1, 0).unwrap();
+        let mut proposal = mock_market_settlement_proposal(
+            Pubkey::default(),
+            SettlementProposalAction::Settle,
+            0,
+            0,
+        );
+
+        let result = propose_settlement(
+            &mut proposal,
+            Pubkey::new_unique(),
+            &market,
+            proposer,
+            SettlementProposalAction::ForceVoid,
+            vec![],
+            100,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(SettlementProposalAction::ForceVoid, proposal.action);
+        assert_eq!(Some(100), proposal.approved_timestamp);
+        assert!(proposal.validate_executable(100).is_ok());
+    }
+
+    #[test]
+    fn test_propose_settlement_invalid() {
+        let operators = settlement_operators(3);
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        let mut proposal = mock_market_settlement_proposal(
+            Pubkey::default(),
+            SettlementProposalAction::Settle,
+            0,
+            0,
+        );
+
+        let result = propose_settlement(
+            &mut proposal,
+            Pubkey::new_unique(),
+            &market,
+            Pubkey::new_unique(),
+            SettlementProposalAction::Settle,
+            vec![winning_outcome(1)],
+            100,
+        );
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalsNotEnabled)),
+            result
+        );
+
+        update_settlement_approval_settings(&mut market, &operators, 2, 0).unwrap();
+        let result = propose_settlement(
+            &mut proposal,
+            Pubkey::new_unique(),
+            &market,
+            Pubkey::new_unique(),
+            SettlementProposalAction::Settle,
+            vec![winning_outcome(3)],
+            100,
+        );
+        assert_eq!(
+            Err(error!(CoreError::SettlementInvalidMarketOutcomeIndex)),
+            result
+        );
+
+        let result = propose_settlement(
+            &mut proposal,
+            Pubkey::new_unique(),
+            &market,
+            Pubkey::new_unique(),
+            SettlementProposalAction::ForceVoid,
+            vec![winning_outcome(1)],
+            100,
+        );
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalWinningOutcomesInvalid)),
+            result
+        );
+    }
+}
@@ -13,7 +13,7 @@ const TRANSFER_SURPLUS_ALLOWED_STATUSES: [MarketStatus; 2] =
     [MarketStatus::Settled, MarketStatus::Voided];
 
 pub fn transfer_market_token_surplus<'info>(
-    market: &Account<'info, Market>,
+    market: &mut Account<'info, Market>,
     market_escrow: &Account<'info, TokenAccount>,
     market_funding: &Account<'info, TokenAccount>,
     destination_token_account: &Account<'info, TokenAccount>,
@@ -29,6 +29,8 @@ pub fn transfer_market_token_surplus<'info>(
         token_program,
         market,
     )?;
+    // crank fees not paid out as rewards leave with the escrow surplus
+    market.crank_fee_balance = 0;
     transfer::transfer_market_funding_surplus(
         market_funding,
         destination_token_account,
@@ -1,17 +1,41 @@
 mod create_market;
+mod create_market_access_control;
+mod create_market_limits;
 mod market_authority;
+mod market_settlement_proposal;
 mod market_token_accounts;
 mod move_to_inplay;
+mod update_market_access_control;
+mod update_market_atomic_matching;
+mod update_market_crank_fee;
 mod update_market_event_start_time;
+mod update_market_limits;
 mod update_market_locktime;
+mod update_market_price_improvement;
 mod update_market_status;
+mod update_market_suspension_order_behaviour;
 mod update_market_title;
+mod update_market_trade_void_window;
+mod update_market_winning_places;
+mod withdraw_market_outcome;
 
 pub use create_market::*;
+pub use create_market_access_control::*;
+pub use create_market_limits::*;
 pub use market_authority::*;
+pub use market_settlement_proposal::*;
 pub use market_token_accounts::*;
 pub use move_to_inplay::*;
+pub use update_market_access_control::*;
+pub use update_market_atomic_matching::*;
+pub use update_market_crank_fee::*;
 pub use update_market_event_start_time::*;
+pub use update_market_limits::*;
 pub use update_market_locktime::*;
+pub use update_market_price_improvement::*;
 pub use update_market_status::*;
+pub use update_market_suspension_order_behaviour::*;
 pub use update_market_title::*;
+pub use update_market_trade_void_window::*;
+pub use update_market_winning_places::*;
+pub use withdraw_market_outcome::*;
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::error::CoreError;
+use crate::events::market::MarketInplayEvent;
 use crate::instructions::current_timestamp;
 use crate::state::market_account::Market;
 use crate::state::market_account::MarketStatus::Open;
@@ -31,6 +32,9 @@ pub fn move_market_to_inplay(
 
     market.move_to_inplay();
     market_liquidities.move_to_inplay(&market.event_start_order_behaviour);
+    emit!(MarketInplayEvent {
+        market: market_liquidities.market,
+    });
 
     Ok(())
 }
@@ -49,6 +53,9 @@ pub fn move_market_to_inplay_if_needed(
     if market.inplay_enabled && market.event_start_timestamp <= now && !market.inplay {
         market.move_to_inplay();
         market_liquidities.move_to_inplay(&market.event_start_order_behaviour);
+        emit!(MarketInplayEvent {
+            market: market_liquidities.market,
+        });
     }
 
     Ok(())
@@ -60,6 +67,7 @@ mod tests {
     use crate::instructions::market::move_market_to_inplay;
     use crate::state::market_account::{mock_market, Market, MarketOrderBehaviour, MarketStatus};
     use crate::state::market_liquidities::{mock_market_liquidities, MarketLiquidities};
+    use crate::state::price::Price;
     use solana_program::pubkey::Pubkey;
 
     fn market_setup() -> Market {
@@ -74,17 +82,23 @@ mod tests {
 
     fn liquidities_setup() -> MarketLiquidities {
         let mut market_liquidities = mock_market_liquidities(Pubkey::new_unique());
-        market_liquidities.add_liquidity_for(0, 2.0, 1).expect("");
-        market_liquidities.add_liquidity_for(0, 3.0, 1).expect("");
-        market_liquidities.add_liquidity_for(0, 4.0, 1).expect("");
         market_liquidities
-            .add_liquidity_against(0, 2.0, 1)
+            .add_liquidity_for(0, Price::new(2_000), 1)
             .expect("");
         market_liquidities
-            .add_liquidity_against(0, 3.0, 1)
+            .add_liquidity_for(0, Price::new(3_000), 1)
             .expect("");
         market_liquidities
-            .add_liquidity_against(0, 4.0, 1)
+            .add_liquidity_for(0, Price::new(4_000), 1)
+            .expect("");
+        market_liquidities
+            .add_liquidity_against(0, Price::new(2_000), 1)
+            .expect("");
+        market_liquidities
+            .add_liquidity_against(0, Price::new(3_000), 1)
+            .expect("");
+        market_liquidities
+            .add_liquidity_against(0, Price::new(4_000), 1)
             .expect("");
         return market_liquidities;
     }
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::state::market_access_control::{MarketAccessControl, PurchaserListMode};
+
+pub fn update_market_access_control(
+    market_access_control: &mut MarketAccessControl,
+    purchaser_list_mode: PurchaserListMode,
+    token_gate_mint: Option<Pubkey>,
+) -> Result<()> {
+    market_access_control.purchaser_list_mode = purchaser_list_mode;
+    market_access_control.token_gate_mint = token_gate_mint;
+    Ok(())
+}
+
+pub fn add_market_access_purchasers(
+    market_access_control: &mut MarketAccessControl,
+    purchasers: Vec<Pubkey>,
+) -> Result<()> {
+    market_access_control.add_purchasers(&purchasers)
+}
+
+pub fn remove_market_access_purchasers(
+    market_access_control: &mut MarketAccessControl,
+    purchasers: Vec<Pubkey>,
+) -> Result<()> {
+    market_access_control.remove_purchasers(&purchasers);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_access_control::mock_market_access_control;
+
+    #[test]
+    fn test_update_market_access_control() {
+        let mint = Pubkey::new_unique();
+        let purchaser = Pubkey::new_unique();
+        let mut market_access_control = mock_market_access_control(
+            Pubkey::new_unique(),
+            PurchaserListMode::Allow,
+            vec![purchaser],
+        );
+
+        let result = update_market_access_control(
+            &mut market_access_control,
+            PurchaserListMode::Deny,
+            Some(mint),
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(
+            PurchaserListMode::Deny,
+            market_access_control.purchaser_list_mode
+        );
+        assert_eq!(Some(mint), market_access_control.token_gate_mint);
+        // the list is kept, so switching modes flips who can request orders
+        assert_eq!(vec![purchaser], market_access_control.purchasers);
+    }
+
+    #[test]
+    fn test_add_and_remove_market_access_purchasers() {
+        let purchaser_1 = Pubkey::new_unique();
+        let purchaser_2 = Pubkey::new_unique();
+        let mut market_access_control =
+            mock_market_access_control(Pubkey::new_unique(), PurchaserListMode::Allow, vec![]);
+
+        let result = add_market_access_purchasers(
+            &mut market_access_control,
+            vec![purchaser_1, purchaser_2],
+        );
+        assert!(result.is_ok());
+        assert_eq!(
+            vec![purchaser_1, purchaser_2],
+            market_access_control.purchasers
+        );
+
+        let result = remove_market_access_purchasers(&mut market_access_control, vec![purchaser_1]);
+        assert!(result.is_ok());
+        assert_eq!(vec![purchaser_2], market_access_control.purchasers);
+    }
+}
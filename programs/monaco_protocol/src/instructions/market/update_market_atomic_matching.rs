@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketStatus};
+
+pub fn update_atomic_matching(market: &mut Market, atomic_matching_enabled: bool) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open
+        ),
+        CoreError::MarketInvalidStatus
+    );
+
+    market.atomic_matching_enabled = atomic_matching_enabled;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+
+    #[test]
+    fn test_update_atomic_matching() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_atomic_matching(&mut market, true);
+
+        assert!(result.is_ok());
+        assert!(market.atomic_matching_enabled);
+
+        let result = update_atomic_matching(&mut market, false);
+
+        assert!(result.is_ok());
+        assert!(!market.atomic_matching_enabled);
+    }
+
+    #[test]
+    fn test_update_atomic_matching_market_locked() {
+        let mut market = mock_market(MarketStatus::Locked);
+
+        let result = update_atomic_matching(&mut market, true);
+
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+        assert!(!market.atomic_matching_enabled);
+    }
+}
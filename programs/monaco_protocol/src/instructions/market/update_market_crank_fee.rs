@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketStatus};
+
+pub fn update_crank_fee(market: &mut Market, crank_fee: u64, crank_reward: u64) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open
+        ),
+        CoreError::MarketInvalidStatus
+    );
+    // rewards are paid per step and each order request pays for at least one
+    require!(
+        crank_reward <= crank_fee,
+        CoreError::MarketCrankRewardExceedsFee
+    );
+
+    // fees already collected stay in the balance and keep paying rewards
+    market.crank_fee = crank_fee;
+    market.crank_reward = crank_reward;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+
+    #[test]
+    fn test_update_crank_fee() {
+        let mut market = mock_market(MarketStatus::Open);
+        market.crank_fee_balance = 30;
+
+        let result = update_crank_fee(&mut market, 10, 2);
+
+        assert!(result.is_ok());
+        assert_eq!(10, market.crank_fee);
+        assert_eq!(2, market.crank_reward);
+        assert_eq!(30, market.crank_fee_balance);
+    }
+
+    #[test]
+    fn test_update_crank_fee_market_locked() {
+        let mut market = mock_market(MarketStatus::Locked);
+
+        let result = update_crank_fee(&mut market, 10, 2);
+
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+        assert_eq!(0, market.crank_fee);
+        assert_eq!(0, market.crank_reward);
+    }
+
+    #[test]
+    fn test_update_crank_fee_reward_exceeds_fee() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_crank_fee(&mut market, 2, 3);
+
+        assert_eq!(Err(error!(CoreError::MarketCrankRewardExceedsFee)), result);
+        assert_eq!(0, market.crank_fee);
+        assert_eq!(0, market.crank_reward);
+
+        let result = update_crank_fee(&mut market, 3, 3);
+
+        assert!(result.is_ok());
+    }
+}
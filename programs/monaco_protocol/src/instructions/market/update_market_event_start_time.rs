@@ -38,7 +38,9 @@ fn update_market_event_start_time_internal(
 #[cfg(test)]
 mod tests {
     use crate::instructions::market::update_market_event_start_time::update_market_event_start_time_internal;
-    use crate::state::market_account::{Market, MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_account::{
+        Market, MarketOrderBehaviour, MarketStatus, MarketWinningOutcome,
+    };
 
     #[test]
     fn test_market_update_success() {
@@ -48,7 +50,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -69,6 +72,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let time_in_future = 100;
         let now = 99;
@@ -85,7 +105,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -106,6 +127,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let time_in_future = 100;
         let now = 101;
@@ -122,7 +160,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -143,6 +182,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let time_in_future = 100;
         let now = 99;
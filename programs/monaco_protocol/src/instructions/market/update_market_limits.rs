@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketStatus};
+use crate::state::market_limits::MarketLimits;
+
+pub fn update_market_limits(
+    market: &Market,
+    market_limits: &mut MarketLimits,
+    max_order_stake: u64,
+    max_position_exposure: u64,
+    max_outcome_stake_matched: u64,
+) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open
+        ),
+        CoreError::MarketInvalidStatus
+    );
+
+    // lowered limits only apply to orders requested from now on
+    market_limits.max_order_stake = max_order_stake;
+    market_limits.max_position_exposure = max_position_exposure;
+    market_limits.max_outcome_stake_matched = max_outcome_stake_matched;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+    use crate::state::market_limits::mock_market_limits;
+
+    #[test]
+    fn test_update_market_limits() {
+        let market = mock_market(MarketStatus::Open);
+        let mut market_limits = mock_market_limits(Pubkey::new_unique(), 2, 100, 200, 300);
+        market_limits.outcome_stakes_matched = vec![10, 20];
+
+        let result = update_market_limits(&market, &mut market_limits, 0, 400, 500);
+
+        assert!(result.is_ok());
+        assert_eq!(0, market_limits.max_order_stake);
+        assert_eq!(400, market_limits.max_position_exposure);
+        assert_eq!(500, market_limits.max_outcome_stake_matched);
+        assert_eq!(vec![10, 20], market_limits.outcome_stakes_matched);
+    }
+
+    #[test]
+    fn test_update_market_limits_market_settled() {
+        let market = mock_market(MarketStatus::Settled);
+        let mut market_limits = mock_market_limits(Pubkey::new_unique(), 2, 100, 200, 300);
+
+        let result = update_market_limits(&market, &mut market_limits, 0, 0, 0);
+
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+        assert_eq!(100, market_limits.max_order_stake);
+    }
+}
@@ -36,7 +36,9 @@ fn update_market_locktime(market: &mut Market, lock_time: i64, now: i64) -> Resu
 #[cfg(test)]
 mod tests {
     use crate::instructions::market::update_market_locktime::update_market_locktime;
-    use crate::state::market_account::{Market, MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_account::{
+        Market, MarketOrderBehaviour, MarketStatus, MarketWinningOutcome,
+    };
 
     #[test]
     fn test_market_update_success() {
@@ -46,7 +48,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -67,6 +70,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let now = 1575975177;
         let time_in_future = 43041841910;
@@ -83,7 +103,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -104,6 +125,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let now = 1575975177;
         let time_in_future = 43041841910;
@@ -120,7 +158,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -141,6 +180,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let now = 1575975177;
         let time_in_future = 43041841910;
@@ -157,7 +213,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -178,6 +235,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let now = 1575975177;
         let time_in_past = 1418209910;
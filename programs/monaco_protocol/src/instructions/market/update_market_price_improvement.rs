@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketStatus};
+
+pub fn update_price_improvement(
+    market: &mut Market,
+    price_improvement_enabled: bool,
+) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open
+        ),
+        CoreError::MarketInvalidStatus
+    );
+
+    market.price_improvement_enabled = price_improvement_enabled;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+
+    #[test]
+    fn test_update_price_improvement() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_price_improvement(&mut market, true);
+
+        assert!(result.is_ok());
+        assert!(market.price_improvement_enabled);
+
+        let result = update_price_improvement(&mut market, false);
+
+        assert!(result.is_ok());
+        assert!(!market.price_improvement_enabled);
+    }
+
+    #[test]
+    fn test_update_price_improvement_market_locked() {
+        let mut market = mock_market(MarketStatus::Locked);
+
+        let result = update_price_improvement(&mut market, true);
+
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+        assert!(!market.price_improvement_enabled);
+    }
+}
@@ -4,11 +4,16 @@ use solana_program::clock::UnixTimestamp;
 
 use crate::context::UpdateMarket;
 use crate::error::CoreError;
-use crate::state::market_account::Market;
+use crate::events::market::MarketStatusChangedEvent;
+use crate::instructions::{calculate_dead_heat_common_denominator, current_timestamp};
 use crate::state::market_account::MarketStatus::*;
+use crate::state::market_account::{
+    Market, MarketOrderBehaviour, MarketStatus, MarketWinningOutcome, OutcomeResult,
+};
 use crate::state::market_liquidities::MarketLiquidities;
 use crate::state::market_matching_queue_account::{MarketMatchingQueue, MatchingQueue};
 use crate::state::market_order_request_queue::{MarketOrderRequestQueue, OrderRequestQueue};
+use crate::state::market_type::{MarketLine, MarketTemplate, MarketType};
 use crate::state::payments_queue::{MarketPaymentsQueue, PaymentQueue};
 
 pub fn open(
@@ -30,9 +35,13 @@ pub fn open(
     );
     if enable_cross_matching {
         require!(
-            market.market_outcomes_count < 6,
+            market.market_outcomes_count <= MarketLiquidities::CROSS_MATCHING_OUTCOMES_MAX,
             CoreError::MarketTooManyOutcomes
         );
+        require!(
+            market.market_winning_places == 1,
+            CoreError::MarketWinningPlacesCrossMatchingUnsupported
+        );
     }
 
     intialize_liquidities(liquidities, market_pk, enable_cross_matching)?;
@@ -47,7 +56,7 @@ pub fn open(
     intialize_order_request_queue(order_request_queue, market_pk)?;
     market.increment_unclosed_accounts_count()?;
 
-    market.market_status = Open;
+    set_market_status(market_pk, market, Open);
     Ok(())
 }
 
@@ -92,6 +101,7 @@ fn intialize_order_request_queue(
 }
 
 pub fn void(
+    market_pk: &Pubkey,
     market: &mut Market,
     void_time: UnixTimestamp,
     market_matching_queue: &Option<MarketMatchingQueue>,
@@ -126,11 +136,12 @@ pub fn void(
     }
 
     market.market_settle_timestamp = Option::from(void_time);
-    market.market_status = ReadyToVoid;
+    set_market_status(market_pk, market, ReadyToVoid);
     Ok(())
 }
 
 pub fn force_void(
+    market_pk: &Pubkey,
     market: &mut Market,
     void_time: UnixTimestamp,
     order_request_queue: &Option<MarketOrderRequestQueue>,
@@ -156,7 +167,7 @@ pub fn force_void(
     }
 
     market.market_settle_timestamp = Option::from(void_time);
-    market.market_status = ReadyToVoid;
+    set_market_status(market_pk, market, ReadyToVoid);
     Ok(())
 }
 
@@ -187,7 +198,7 @@ pub fn force_unsettled_count(
     Ok(())
 }
 
-pub fn complete_void(market: &mut Market) -> Result<()> {
+pub fn complete_void(market_pk: &Pubkey, market: &mut Market) -> Result<()> {
     require!(
         ReadyToVoid.eq(&market.market_status),
         CoreError::VoidMarketNotReadyForVoid
@@ -196,25 +207,110 @@ pub fn complete_void(market: &mut Market) -> Result<()> {
         market.unsettled_accounts_count == 0_u32,
         CoreError::MarketUnsettledAccountsCountNonZero,
     );
-    market.market_status = Voided;
+    set_market_status(market_pk, market, Voided);
     Ok(())
 }
 
 pub fn settle(
+    market_pk: &Pubkey,
     market: &mut Market,
     market_matching_queue: &MarketMatchingQueue,
     order_request_queue: &MarketOrderRequestQueue,
-    winning_outcome_index: u16,
+    winning_outcomes: Vec<MarketWinningOutcome>,
     settle_time: UnixTimestamp,
 ) -> Result<()> {
     require!(
         Open.eq(&market.market_status),
         CoreError::SettlementMarketNotOpen
     );
+    validate_winning_outcomes(market, &winning_outcomes)?;
+    require!(
+        market_matching_queue.matches.is_empty(),
+        CoreError::SettlementMarketMatchingQueueNotEmpty
+    );
     require!(
-        winning_outcome_index < market.market_outcomes_count,
-        CoreError::SettlementInvalidMarketOutcomeIndex
+        order_request_queue.order_requests.is_empty(),
+        CoreError::OrderRequestQueueIsNotEmpty
     );
+
+    market.market_winning_outcomes = winning_outcomes;
+    market.market_settle_timestamp = Option::from(settle_time);
+    set_market_status(market_pk, market, ReadyForSettlement);
+    Ok(())
+}
+
+pub(crate) fn validate_winning_outcomes(
+    market: &Market,
+    winning_outcomes: &[MarketWinningOutcome],
+) -> Result<()> {
+    require!(
+        winning_outcomes.len() <= Market::WINNING_OUTCOMES_MAX_LENGTH,
+        CoreError::SettlementTooManyWinningOutcomes
+    );
+
+    for (index, winning_outcome) in winning_outcomes.iter().enumerate() {
+        require!(
+            winning_outcome.outcome_index < market.market_outcomes_count,
+            CoreError::SettlementInvalidMarketOutcomeIndex
+        );
+        require!(
+            !winning_outcomes[..index]
+                .iter()
+                .any(|other| other.outcome_index == winning_outcome.outcome_index),
+            CoreError::SettlementDuplicateMarketOutcomeIndex
+        );
+        require!(
+            !market.is_outcome_withdrawn(winning_outcome.outcome_index),
+            CoreError::SettlementWinningOutcomeWithdrawn
+        );
+        require!(
+            0 < winning_outcome.dead_heat_numerator
+                && winning_outcome.dead_heat_numerator <= winning_outcome.dead_heat_denominator,
+            CoreError::SettlementInvalidDeadHeatFactor
+        );
+    }
+
+    // dead-heat factors are summed over their common denominator so the check is exact
+    let denominators: Vec<u16> = winning_outcomes
+        .iter()
+        .map(|winning_outcome| winning_outcome.dead_heat_denominator)
+        .collect();
+    let common_denominator =
+        calculate_dead_heat_common_denominator(&denominators).ok_or(CoreError::ArithmeticError)?;
+    let dead_heat_factors_sum = winning_outcomes
+        .iter()
+        .try_fold(0_u128, |sum, winning_outcome| {
+            let factor = u128::from(winning_outcome.dead_heat_numerator)
+                .checked_mul(u128::from(common_denominator))?
+                .checked_div(u128::from(winning_outcome.dead_heat_denominator))?;
+            sum.checked_add(factor)
+        })
+        .ok_or(CoreError::ArithmeticError)?;
+    let winning_places_sum = u128::from(market.market_winning_places)
+        .checked_mul(u128::from(common_denominator))
+        .ok_or(CoreError::ArithmeticError)?;
+    require!(
+        dead_heat_factors_sum == winning_places_sum,
+        CoreError::SettlementDeadHeatFactorsWinningPlacesMismatch
+    );
+
+    Ok(())
+}
+
+pub fn settle_with_results(
+    market_pk: &Pubkey,
+    market: &mut Market,
+    market_type: &MarketType,
+    market_matching_queue: &MarketMatchingQueue,
+    order_request_queue: &MarketOrderRequestQueue,
+    outcome_results: Vec<OutcomeResult>,
+    settle_time: UnixTimestamp,
+) -> Result<()> {
+    require!(
+        Open.eq(&market.market_status),
+        CoreError::SettlementMarketNotOpen
+    );
+    validate_outcome_results(market, market_type, &outcome_results)?;
     require!(
         market_matching_queue.matches.is_empty(),
         CoreError::SettlementMarketMatchingQueueNotEmpty
@@ -224,13 +320,48 @@ pub fn settle(
         CoreError::OrderRequestQueueIsNotEmpty
     );
 
-    market.market_winning_outcome_index = Some(winning_outcome_index);
+    market.market_winning_outcomes = vec![];
+    market.market_outcome_results = outcome_results;
     market.market_settle_timestamp = Option::from(settle_time);
-    market.market_status = ReadyForSettlement;
+    set_market_status(market_pk, market, ReadyForSettlement);
+    Ok(())
+}
+
+// the line applies to the first outcome, the second outcome is the other side of it
+fn validate_outcome_results(
+    market: &Market,
+    market_type: &MarketType,
+    outcome_results: &[OutcomeResult],
+) -> Result<()> {
+    require!(
+        market_type.template != MarketTemplate::Standard,
+        CoreError::SettlementOutcomeResultsNotSupported
+    );
+    require!(
+        market.market_outcomes_count == MarketLine::OUTCOMES_COUNT
+            && market.market_winning_places == 1
+            && outcome_results.len() == usize::from(MarketLine::OUTCOMES_COUNT),
+        CoreError::SettlementInvalidOutcomeResults
+    );
+    require!(
+        outcome_results[1] == outcome_results[0].opposite(),
+        CoreError::SettlementOutcomeResultsNotComplementary
+    );
+
+    let line = MarketLine::parse(
+        market_type.template,
+        market.market_type_value.as_deref().unwrap_or_default(),
+    )?;
+    require!(
+        line.allows_result(outcome_results[0]),
+        CoreError::SettlementOutcomeResultInvalidForLine
+    );
+
     Ok(())
 }
 
 pub fn complete_settlement(
+    market_pk: &Pubkey,
     market: &mut Market,
     commission_payments_queue: &MarketPaymentsQueue,
 ) -> Result<()> {
@@ -246,7 +377,7 @@ pub fn complete_settlement(
         commission_payments_queue.payment_queue.is_empty(),
         CoreError::SettlementMarketPaymentsQueueNotEmpty
     );
-    market.market_status = Settled;
+    set_market_status(market_pk, market, Settled);
     Ok(())
 }
 
@@ -262,19 +393,48 @@ pub fn unpublish(ctx: Context<UpdateMarket>) -> Result<()> {
     Ok(())
 }
 
-pub fn suspend(ctx: Context<UpdateMarket>) -> Result<()> {
-    let market = &mut ctx.accounts.market;
+pub fn suspend(
+    market: &mut Market,
+    market_liquidities: Option<&mut MarketLiquidities>,
+    market_matching_queue: Option<&MarketMatchingQueue>,
+) -> Result<()> {
+    if MarketOrderBehaviour::CancelUnmatched.eq(&market.suspension_order_behaviour) {
+        let (market_liquidities, market_matching_queue) =
+            match (market_liquidities, market_matching_queue) {
+                (Some(market_liquidities), Some(market_matching_queue)) => {
+                    (market_liquidities, market_matching_queue)
+                }
+                _ => return Err(error!(CoreError::MarketSuspensionAccountsRequired)),
+            };
+        // matches already made must be processed against the orders they were made with
+        require!(
+            market_matching_queue.matches.is_empty(),
+            CoreError::MarketSuspensionMatchingQueueNotEmpty
+        );
+
+        // unmatched stake left in matching pools is cancelled order by order, order book pages
+        // are cleared when next updated
+        market_liquidities.clear_liquidities();
+        market.suspension_timestamp = current_timestamp();
+    }
+
     market.suspended = true;
     Ok(())
 }
 
-pub fn unsuspend(ctx: Context<UpdateMarket>) -> Result<()> {
-    let market = &mut ctx.accounts.market;
+pub fn unsuspend(market: &mut Market) -> Result<()> {
+    // orders created in the second of the suspension would be taken as cancelled by it
+    require!(
+        market.suspension_timestamp < current_timestamp(),
+        CoreError::MarketUnsuspensionTooEarly
+    );
+
     market.suspended = false;
     Ok(())
 }
 
 pub fn ready_to_close(
+    market_pk: &Pubkey,
     market: &mut Market,
     market_escrow: &TokenAccount,
     market_funding: &TokenAccount,
@@ -292,16 +452,34 @@ pub fn ready_to_close(
         market_funding.amount == 0_u64,
         CoreError::SettlementMarketFundingNonZero
     );
+    require!(
+        market.crank_fee_balance == 0_u64,
+        CoreError::SettlementMarketCrankFeeBalanceNonZero
+    );
 
-    market.market_status = ReadyToClose;
+    set_market_status(market_pk, market, ReadyToClose);
     Ok(())
 }
 
+pub(crate) fn set_market_status(
+    market_pk: &Pubkey,
+    market: &mut Market,
+    market_status: MarketStatus,
+) {
+    emit!(MarketStatusChangedEvent {
+        market: *market_pk,
+        market_status: market_status.clone(),
+    });
+    market.market_status = market_status;
+}
+
 #[cfg(test)]
 mod settle_market_tests {
     use crate::error::CoreError;
     use crate::instructions::market::settle;
-    use crate::state::market_account::{mock_market, MarketStatus};
+    use crate::state::market_account::{
+        mock_market, MarketStatus, MarketWinningOutcome, WithdrawnOutcome,
+    };
     use crate::state::market_matching_queue_account::{mock_market_matching_queue, OrderMatch};
     use crate::state::market_order_request_queue::{mock_order_request_queue, OrderRequest};
     use anchor_lang::error;
@@ -318,10 +496,11 @@ mod settle_market_tests {
         let settle_time = 1665483869;
 
         let result = settle(
+            &Pubkey::new_unique(),
             &mut market,
             &market_matching_queue,
             &order_request_queue,
-            0,
+            vec![MarketWinningOutcome::new(0, 1, 1)],
             settle_time,
         );
         assert!(result.is_ok());
@@ -339,10 +518,11 @@ mod settle_market_tests {
         let settle_time = 1665483869;
 
         let result = settle(
+            &Pubkey::new_unique(),
             &mut market,
             &market_matching_queue,
             &order_request_queue,
-            0,
+            vec![MarketWinningOutcome::new(0, 1, 1)],
             settle_time,
         );
 
@@ -350,6 +530,34 @@ mod settle_market_tests {
         assert_eq!(Err(error!(CoreError::SettlementMarketNotOpen)), result);
     }
 
+    #[test]
+    fn winning_outcome_withdrawn() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        market.withdrawn_outcomes.push(WithdrawnOutcome {
+            outcome_index: 0,
+            reduction_factor: 1_000,
+            withdrawal_timestamp: 0,
+        });
+        let order_request_queue = mock_order_request_queue(Pubkey::new_unique());
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = settle(
+            &Pubkey::new_unique(),
+            &mut market,
+            &market_matching_queue,
+            &order_request_queue,
+            vec![MarketWinningOutcome::new(0, 1, 1)],
+            1665483869,
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::SettlementWinningOutcomeWithdrawn)),
+            result
+        );
+    }
+
     #[test]
     fn invalid_outcome_index() {
         let market_pk = Pubkey::new_unique();
@@ -361,10 +569,11 @@ mod settle_market_tests {
         let settle_time = 1665483869;
 
         let result = settle(
+            &Pubkey::new_unique(),
             &mut market,
             &market_matching_queue,
             &order_request_queue,
-            4,
+            vec![MarketWinningOutcome::new(4, 1, 1)],
             settle_time,
         );
 
@@ -387,10 +596,11 @@ mod settle_market_tests {
         let settle_time = 1665483869;
 
         let result = settle(
+            &Pubkey::new_unique(),
             &mut market,
             &market_matching_queue,
             &order_request_queue,
-            0,
+            vec![MarketWinningOutcome::new(0, 1, 1)],
             settle_time,
         );
 
@@ -414,16 +624,326 @@ mod settle_market_tests {
         let market_matching_queue = mock_market_matching_queue(market_pk);
 
         let result = settle(
+            &Pubkey::new_unique(),
             &mut market,
             &market_matching_queue,
             &order_request_queue,
-            0,
+            vec![MarketWinningOutcome::new(0, 1, 1)],
             1665483869,
         );
 
         assert!(result.is_err());
         assert_eq!(Err(error!(CoreError::OrderRequestQueueIsNotEmpty)), result);
     }
+
+    #[test]
+    fn success_dead_heat() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        let order_request_queue = mock_order_request_queue(Pubkey::new_unique());
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 2),
+            MarketWinningOutcome::new(2, 1, 2),
+        ];
+        let result = settle(
+            &Pubkey::new_unique(),
+            &mut market,
+            &market_matching_queue,
+            &order_request_queue,
+            winning_outcomes.clone(),
+            1665483869,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(market.market_status, MarketStatus::ReadyForSettlement);
+        assert_eq!(market.market_winning_outcomes, winning_outcomes);
+    }
+
+    #[test]
+    fn success_multiple_winning_places_with_dead_heat() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 6;
+        market.market_winning_places = 3;
+        let order_request_queue = mock_order_request_queue(Pubkey::new_unique());
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        // outcome 0 first, outcomes 1, 2 and 3 tied for the remaining 2 places
+        let result = settle(
+            &Pubkey::new_unique(),
+            &mut market,
+            &market_matching_queue,
+            &order_request_queue,
+            vec![
+                MarketWinningOutcome::new(0, 1, 1),
+                MarketWinningOutcome::new(1, 2, 3),
+                MarketWinningOutcome::new(2, 2, 3),
+                MarketWinningOutcome::new(3, 2, 3),
+            ],
+            1665483869,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(market.market_status, MarketStatus::ReadyForSettlement);
+    }
+
+    #[test]
+    fn dead_heat_factors_winning_places_mismatch() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        let order_request_queue = mock_order_request_queue(Pubkey::new_unique());
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = settle(
+            &Pubkey::new_unique(),
+            &mut market,
+            &market_matching_queue,
+            &order_request_queue,
+            vec![
+                MarketWinningOutcome::new(0, 1, 1),
+                MarketWinningOutcome::new(1, 1, 1),
+            ],
+            1665483869,
+        );
+        assert_eq!(
+            Err(error!(
+                CoreError::SettlementDeadHeatFactorsWinningPlacesMismatch
+            )),
+            result
+        );
+
+        let result = settle(
+            &Pubkey::new_unique(),
+            &mut market,
+            &market_matching_queue,
+            &order_request_queue,
+            vec![],
+            1665483869,
+        );
+        assert_eq!(
+            Err(error!(
+                CoreError::SettlementDeadHeatFactorsWinningPlacesMismatch
+            )),
+            result
+        );
+        assert_eq!(market.market_status, MarketStatus::Open);
+    }
+
+    #[test]
+    fn duplicate_outcome_index() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        let order_request_queue = mock_order_request_queue(Pubkey::new_unique());
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = settle(
+            &Pubkey::new_unique(),
+            &mut market,
+            &market_matching_queue,
+            &order_request_queue,
+            vec![
+                MarketWinningOutcome::new(1, 1, 2),
+                MarketWinningOutcome::new(1, 1, 2),
+            ],
+            1665483869,
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::SettlementDuplicateMarketOutcomeIndex)),
+            result
+        );
+    }
+
+    #[test]
+    fn invalid_dead_heat_factor() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        let order_request_queue = mock_order_request_queue(Pubkey::new_unique());
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        for dead_heat_factor in [(0, 1), (1, 0), (3, 2)] {
+            let result = settle(
+                &Pubkey::new_unique(),
+                &mut market,
+                &market_matching_queue,
+                &order_request_queue,
+                vec![MarketWinningOutcome::new(
+                    0,
+                    dead_heat_factor.0,
+                    dead_heat_factor.1,
+                )],
+                1665483869,
+            );
+
+            assert_eq!(
+                Err(error!(CoreError::SettlementInvalidDeadHeatFactor)),
+                result
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod settle_market_with_results_tests {
+    use crate::error::CoreError;
+    use crate::instructions::market::settle_with_results;
+    use crate::state::market_account::{mock_market, Market, MarketStatus, OutcomeResult};
+    use crate::state::market_matching_queue_account::mock_market_matching_queue;
+    use crate::state::market_order_request_queue::mock_order_request_queue;
+    use crate::state::market_type::{MarketTemplate, MarketType};
+    use anchor_lang::error;
+    use anchor_lang::prelude::*;
+
+    fn line_market(line: &str) -> Market {
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 2;
+        market.market_type_value = Some(line.to_string());
+        market
+    }
+
+    fn market_type(template: MarketTemplate) -> MarketType {
+        MarketType {
+            name: "AsianHandicap".to_string(),
+            requires_discriminator: false,
+            requires_value: true,
+            template,
+            schema_version: MarketType::SCHEMA_VERSION,
+        }
+    }
+
+    fn settle_line_market(
+        market: &mut Market,
+        template: MarketTemplate,
+        outcome_results: Vec<OutcomeResult>,
+    ) -> Result<()> {
+        let market_pk = Pubkey::new_unique();
+        settle_with_results(
+            &market_pk,
+            market,
+            &market_type(template),
+            &mock_market_matching_queue(market_pk),
+            &mock_order_request_queue(market_pk),
+            outcome_results,
+            1665483869,
+        )
+    }
+
+    #[test]
+    fn success_quarter_line() {
+        let mut market = line_market("-0.75");
+
+        let result = settle_line_market(
+            &mut market,
+            MarketTemplate::Handicap,
+            vec![OutcomeResult::HalfWin, OutcomeResult::HalfLoss],
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(MarketStatus::ReadyForSettlement, market.market_status);
+        assert_eq!(
+            vec![OutcomeResult::HalfWin, OutcomeResult::HalfLoss],
+            market.market_outcome_results
+        );
+        assert!(market.market_winning_outcomes.is_empty());
+        assert_eq!(Some(1665483869), market.market_settle_timestamp);
+    }
+
+    #[test]
+    fn success_whole_line_push() {
+        let mut market = line_market("2");
+
+        let result = settle_line_market(
+            &mut market,
+            MarketTemplate::Total,
+            vec![OutcomeResult::Push, OutcomeResult::Push],
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(Some(OutcomeResult::Push), market.outcome_result(1));
+    }
+
+    #[test]
+    fn standard_market_type() {
+        let mut market = line_market("-0.75");
+
+        let result = settle_line_market(
+            &mut market,
+            MarketTemplate::Standard,
+            vec![OutcomeResult::Win, OutcomeResult::Loss],
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::SettlementOutcomeResultsNotSupported)),
+            result
+        );
+    }
+
+    #[test]
+    fn invalid_outcome_results() {
+        let mut market = line_market("-0.75");
+        let result = settle_line_market(
+            &mut market,
+            MarketTemplate::Handicap,
+            vec![OutcomeResult::Win],
+        );
+        assert_eq!(
+            Err(error!(CoreError::SettlementInvalidOutcomeResults)),
+            result
+        );
+
+        let mut market = line_market("-0.75");
+        market.market_outcomes_count = 3;
+        let result = settle_line_market(
+            &mut market,
+            MarketTemplate::Handicap,
+            vec![OutcomeResult::Win, OutcomeResult::Loss, OutcomeResult::Loss],
+        );
+        assert_eq!(
+            Err(error!(CoreError::SettlementInvalidOutcomeResults)),
+            result
+        );
+    }
+
+    #[test]
+    fn outcome_results_not_complementary() {
+        let mut market = line_market("-0.75");
+
+        let result = settle_line_market(
+            &mut market,
+            MarketTemplate::Handicap,
+            vec![OutcomeResult::HalfWin, OutcomeResult::Loss],
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::SettlementOutcomeResultsNotComplementary)),
+            result
+        );
+        assert_eq!(MarketStatus::Open, market.market_status);
+    }
+
+    #[test]
+    fn outcome_result_invalid_for_line() {
+        for (line, outcome_results) in [
+            ("2.5", vec![OutcomeResult::Push, OutcomeResult::Push]),
+            ("1", vec![OutcomeResult::HalfWin, OutcomeResult::HalfLoss]),
+            ("0.25", vec![OutcomeResult::Push, OutcomeResult::Push]),
+        ] {
+            let mut market = line_market(line);
+
+            let result = settle_line_market(&mut market, MarketTemplate::Total, outcome_results);
+
+            assert_eq!(
+                Err(error!(CoreError::SettlementOutcomeResultInvalidForLine)),
+                result
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -441,7 +961,7 @@ mod complete_settlement_tests {
         let mut market = mock_market(MarketStatus::ReadyForSettlement);
         let market_payment_queue = mock_market_payments_queue(market_pk);
 
-        let result = complete_settlement(&mut market, &market_payment_queue);
+        let result = complete_settlement(&Pubkey::new_unique(), &mut market, &market_payment_queue);
         assert!(result.is_ok());
     }
 
@@ -451,7 +971,7 @@ mod complete_settlement_tests {
         let mut market = mock_market(MarketStatus::Settled);
         let market_payment_queue = mock_market_payments_queue(market_pk);
 
-        let result = complete_settlement(&mut market, &market_payment_queue);
+        let result = complete_settlement(&Pubkey::new_unique(), &mut market, &market_payment_queue);
         assert!(result.is_err());
         assert_eq!(
             error!(CoreError::SettlementMarketNotReadyForSettlement),
@@ -466,7 +986,7 @@ mod complete_settlement_tests {
         market.unsettled_accounts_count = 1;
         let market_payment_queue = mock_market_payments_queue(market_pk);
 
-        let result = complete_settlement(&mut market, &market_payment_queue);
+        let result = complete_settlement(&Pubkey::new_unique(), &mut market, &market_payment_queue);
         assert!(result.is_err());
         assert_eq!(
             error!(CoreError::MarketUnsettledAccountsCountNonZero),
@@ -485,7 +1005,7 @@ mod complete_settlement_tests {
             amount: 1,
         });
 
-        let result = complete_settlement(&mut market, &market_payment_queue);
+        let result = complete_settlement(&Pubkey::new_unique(), &mut market, &market_payment_queue);
         assert!(result.is_err());
         assert_eq!(
             error!(CoreError::SettlementMarketPaymentsQueueNotEmpty),
@@ -498,8 +1018,8 @@ mod complete_settlement_tests {
 mod open_market_tests {
     use crate::error::CoreError;
     use crate::instructions::market::open;
-    use crate::state::market_account::{MarketOrderBehaviour, MarketStatus};
-    use crate::state::market_liquidities::mock_market_liquidities;
+    use crate::state::market_account::{mock_market, MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_liquidities::{mock_market_liquidities, MarketLiquidities};
     use crate::state::market_matching_queue_account::{MarketMatchingQueue, MatchingQueue};
     use crate::state::market_order_request_queue::{
         mock_order_request_queue, MarketOrderRequestQueue, OrderRequestQueue,
@@ -525,7 +1045,8 @@ mod open_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 2,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             title: "".to_string(),
@@ -539,6 +1060,23 @@ mod open_market_tests {
             inplay_order_delay: 0,
             event_start_order_behaviour: MarketOrderBehaviour::None,
             market_lock_order_behaviour: MarketOrderBehaviour::None,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let liquidities = &mut mock_market_liquidities(market_pk);
         let matching_queue = &mut MarketMatchingQueue {
@@ -601,7 +1139,8 @@ mod open_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 2,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             title: "".to_string(),
@@ -615,6 +1154,23 @@ mod open_market_tests {
             inplay_order_delay: 0,
             event_start_order_behaviour: MarketOrderBehaviour::None,
             market_lock_order_behaviour: MarketOrderBehaviour::None,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let liquidities = &mut mock_market_liquidities(market_pk);
         let matching_queue = &mut MarketMatchingQueue {
@@ -661,7 +1217,8 @@ mod open_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 1,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             title: "".to_string(),
@@ -675,6 +1232,23 @@ mod open_market_tests {
             inplay_order_delay: 0,
             event_start_order_behaviour: MarketOrderBehaviour::None,
             market_lock_order_behaviour: MarketOrderBehaviour::None,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let liquidities = &mut mock_market_liquidities(market_pk);
         let matching_queue = &mut MarketMatchingQueue {
@@ -701,6 +1275,77 @@ mod open_market_tests {
         let expected_error = Err(error!(CoreError::OpenMarketNotEnoughOutcomes));
         assert_eq!(expected_error, result)
     }
+
+    #[test]
+    fn cross_matching_with_multiple_winning_places() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Initializing);
+        market.market_outcomes_count = 3;
+        market.market_winning_places = 2;
+        let liquidities = &mut mock_market_liquidities(market_pk);
+        let matching_queue = &mut MarketMatchingQueue {
+            market: market_pk,
+            matches: MatchingQueue::new(1),
+        };
+        let payments_queue = &mut MarketPaymentsQueue {
+            market: market_pk,
+            payment_queue: PaymentQueue::new(1),
+        };
+        let order_request_queue = &mut mock_order_request_queue(Pubkey::new_unique());
+
+        let result = open(
+            &market_pk,
+            &mut market,
+            true,
+            liquidities,
+            matching_queue,
+            payments_queue,
+            order_request_queue,
+        );
+
+        let expected_error = Err(error!(
+            CoreError::MarketWinningPlacesCrossMatchingUnsupported
+        ));
+        assert_eq!(expected_error, result)
+    }
+
+    #[test]
+    fn cross_matching_outcomes_count() {
+        for (market_outcomes_count, expected_open) in [
+            (6, true),
+            (MarketLiquidities::CROSS_MATCHING_OUTCOMES_MAX, true),
+            (MarketLiquidities::CROSS_MATCHING_OUTCOMES_MAX + 1, false),
+        ] {
+            let market_pk = Pubkey::new_unique();
+            let mut market = mock_market(MarketStatus::Initializing);
+            market.market_outcomes_count = market_outcomes_count;
+            let liquidities = &mut mock_market_liquidities(market_pk);
+            let matching_queue = &mut MarketMatchingQueue {
+                market: market_pk,
+                matches: MatchingQueue::new(1),
+            };
+            let payments_queue = &mut MarketPaymentsQueue {
+                market: market_pk,
+                payment_queue: PaymentQueue::new(1),
+            };
+            let order_request_queue = &mut mock_order_request_queue(Pubkey::new_unique());
+
+            let result = open(
+                &market_pk,
+                &mut market,
+                true,
+                liquidities,
+                matching_queue,
+                payments_queue,
+                order_request_queue,
+            );
+
+            match expected_open {
+                true => assert!(result.is_ok()),
+                false => assert_eq!(Err(error!(CoreError::MarketTooManyOutcomes)), result),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -710,6 +1355,7 @@ mod void_market_tests {
     use crate::state::market_account::{MarketOrderBehaviour, MarketStatus};
     use crate::state::market_matching_queue_account::{mock_market_matching_queue, OrderMatch};
     use crate::state::market_order_request_queue::{mock_order_request_queue, OrderRequest};
+    use crate::state::price::Price;
     use crate::Market;
     use anchor_lang::error;
     use solana_program::pubkey::Pubkey;
@@ -731,7 +1377,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -744,6 +1391,23 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let market_matching_queue = mock_market_matching_queue(market_pk);
         let order_request_queue = mock_order_request_queue(market_pk);
@@ -751,6 +1415,7 @@ mod void_market_tests {
         let settle_time = 1665483869;
 
         let result = void(
+            &Pubkey::new_unique(),
             &mut market,
             settle_time,
             &Option::from(market_matching_queue),
@@ -777,7 +1442,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -790,11 +1456,34 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         let settle_time = 1665483869;
 
-        let result = void(&mut market, settle_time, &None, &None);
+        let result = void(
+            &Pubkey::new_unique(),
+            &mut market,
+            settle_time,
+            &None,
+            &None,
+        );
 
         assert!(result.is_ok());
         assert_eq!(MarketStatus::ReadyToVoid, market.market_status)
@@ -817,7 +1506,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -830,6 +1520,23 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let market_matching_queue = mock_market_matching_queue(market_pk);
         let order_request_queue = mock_order_request_queue(market_pk);
@@ -837,6 +1544,7 @@ mod void_market_tests {
         let settle_time = 1665483869;
 
         let result = void(
+            &Pubkey::new_unique(),
             &mut market,
             settle_time,
             &Option::from(market_matching_queue),
@@ -864,7 +1572,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -877,6 +1586,23 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let market_matching_queue = mock_market_matching_queue(market_pk);
         let order_request_queue = mock_order_request_queue(market_pk);
@@ -884,6 +1610,7 @@ mod void_market_tests {
         let settle_time = 1665483869;
 
         let result = void(
+            &Pubkey::new_unique(),
             &mut market,
             settle_time,
             &Option::from(market_matching_queue),
@@ -912,7 +1639,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -925,6 +1653,23 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let market_matching_queue = mock_market_matching_queue(market_pk);
         let order_request_queue = &mut mock_order_request_queue(market_pk);
@@ -935,6 +1680,7 @@ mod void_market_tests {
         let settle_time = 1665483869;
 
         let result = void(
+            &Pubkey::new_unique(),
             &mut market,
             settle_time,
             &Option::from(market_matching_queue.clone()),
@@ -963,7 +1709,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -976,16 +1723,34 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let market_matching_queue = &mut mock_market_matching_queue(market_pk);
         let order_request_queue = mock_order_request_queue(market_pk);
         market_matching_queue
             .matches
-            .enqueue(OrderMatch::maker(true, 0, 0.0, 0));
+            .enqueue(OrderMatch::maker(true, 0, Price::new(0), 0));
 
         let settle_time = 1665483869;
 
         let result = void(
+            &Pubkey::new_unique(),
             &mut market,
             settle_time,
             &Option::from(market_matching_queue.clone()),
@@ -1014,7 +1779,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -1027,12 +1793,30 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let market_matching_queue = mock_market_matching_queue(market_pk);
 
         let settle_time = 1665483869;
 
         let result = void(
+            &Pubkey::new_unique(),
             &mut market,
             settle_time,
             &Option::from(market_matching_queue),
@@ -1061,7 +1845,8 @@ mod void_market_tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -1074,12 +1859,30 @@ mod void_market_tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
         let order_request_queue = mock_order_request_queue(market_pk);
 
         let settle_time = 1665483869;
 
         let result = void(
+            &Pubkey::new_unique(),
             &mut market,
             settle_time,
             &None,
@@ -1108,7 +1911,12 @@ mod force_void_tests {
             let mut market = mock_market(status);
             let order_request_queue = mock_order_request_queue(market_pk);
 
-            let result = force_void(&mut market, 1665483869, &Some(order_request_queue));
+            let result = force_void(
+                &Pubkey::new_unique(),
+                &mut market,
+                1665483869,
+                &Some(order_request_queue),
+            );
 
             assert!(result.is_ok());
             assert_eq!(MarketStatus::ReadyToVoid, market.market_status);
@@ -1121,7 +1929,12 @@ mod force_void_tests {
         let mut market = mock_market(MarketStatus::Settled);
         let order_request_queue = mock_order_request_queue(market_pk);
 
-        let result = force_void(&mut market, 1665483869, &Some(order_request_queue));
+        let result = force_void(
+            &Pubkey::new_unique(),
+            &mut market,
+            1665483869,
+            &Some(order_request_queue),
+        );
 
         assert!(result.is_err());
         let expected_error = Err(error!(CoreError::VoidMarketInvalidStatus));
@@ -1138,7 +1951,12 @@ mod force_void_tests {
             .order_requests
             .enqueue(OrderRequest::new_unique());
 
-        let result = force_void(&mut market, 1665483869, &Some(order_request_queue));
+        let result = force_void(
+            &Pubkey::new_unique(),
+            &mut market,
+            1665483869,
+            &Some(order_request_queue),
+        );
 
         assert!(result.is_err());
         let expected_error = Err(error!(CoreError::OrderRequestQueueIsNotEmpty));
@@ -1190,3 +2008,112 @@ mod force_unsettled_count_tests {
         assert_eq!(expected_error, result)
     }
 }
+
+#[cfg(test)]
+mod suspend_market_tests {
+    use crate::error::CoreError;
+    use crate::instructions::current_timestamp;
+    use crate::instructions::market::{suspend, unsuspend};
+    use crate::state::market_account::{mock_market, MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_liquidities::mock_market_liquidities;
+    use crate::state::market_matching_queue_account::{mock_market_matching_queue, OrderMatch};
+    use crate::state::price::Price;
+    use anchor_lang::error;
+    use solana_program::pubkey::Pubkey;
+
+    #[test]
+    fn suspend_without_cancelling_unmatched() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = suspend(&mut market, None, None);
+
+        assert!(result.is_ok());
+        assert!(market.suspended);
+        assert_eq!(0, market.suspension_timestamp);
+    }
+
+    #[test]
+    fn suspend_cancelling_unmatched() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.suspension_order_behaviour = MarketOrderBehaviour::CancelUnmatched;
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        market_liquidities
+            .add_liquidity_for(0, Price::new(3_000), 10)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_against(1, Price::new(2_000), 10)
+            .unwrap();
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = suspend(
+            &mut market,
+            Some(&mut market_liquidities),
+            Some(&market_matching_queue),
+        );
+
+        assert!(result.is_ok());
+        assert!(market.suspended);
+        assert!(market.suspension_timestamp > 0);
+        assert!(market_liquidities.liquidities_for.is_empty());
+        assert!(market_liquidities.liquidities_against.is_empty());
+    }
+
+    #[test]
+    fn suspend_cancelling_unmatched_accounts_required() {
+        let mut market = mock_market(MarketStatus::Open);
+        market.suspension_order_behaviour = MarketOrderBehaviour::CancelUnmatched;
+
+        let result = suspend(&mut market, None, None);
+
+        assert_eq!(
+            Err(error!(CoreError::MarketSuspensionAccountsRequired)),
+            result
+        );
+        assert!(!market.suspended);
+    }
+
+    #[test]
+    fn suspend_cancelling_unmatched_matching_queue_not_empty() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.suspension_order_behaviour = MarketOrderBehaviour::CancelUnmatched;
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let mut market_matching_queue = mock_market_matching_queue(market_pk);
+        market_matching_queue
+            .matches
+            .enqueue(OrderMatch::maker(true, 0, Price::new(3_000), 10));
+
+        let result = suspend(
+            &mut market,
+            Some(&mut market_liquidities),
+            Some(&market_matching_queue),
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::MarketSuspensionMatchingQueueNotEmpty)),
+            result
+        );
+        assert!(!market.suspended);
+        assert_eq!(0, market.suspension_timestamp);
+    }
+
+    #[test]
+    fn unsuspend_too_early() {
+        let mut market = mock_market(MarketStatus::Open);
+        market.suspended = true;
+        market.suspension_timestamp = current_timestamp();
+
+        let result = unsuspend(&mut market);
+
+        assert_eq!(Err(error!(CoreError::MarketUnsuspensionTooEarly)), result);
+        assert!(market.suspended);
+
+        market.suspension_timestamp -= 1;
+
+        let result = unsuspend(&mut market);
+
+        assert!(result.is_ok());
+        assert!(!market.suspended);
+    }
+}
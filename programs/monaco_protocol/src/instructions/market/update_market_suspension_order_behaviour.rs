@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketOrderBehaviour, MarketStatus};
+
+pub fn update_suspension_order_behaviour(
+    market: &mut Market,
+    suspension_order_behaviour: MarketOrderBehaviour,
+) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open
+        ),
+        CoreError::MarketInvalidStatus
+    );
+
+    market.suspension_order_behaviour = suspension_order_behaviour;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+
+    #[test]
+    fn test_update_suspension_order_behaviour() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result =
+            update_suspension_order_behaviour(&mut market, MarketOrderBehaviour::CancelUnmatched);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            MarketOrderBehaviour::CancelUnmatched,
+            market.suspension_order_behaviour
+        );
+
+        let result = update_suspension_order_behaviour(&mut market, MarketOrderBehaviour::None);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            MarketOrderBehaviour::None,
+            market.suspension_order_behaviour
+        );
+    }
+
+    #[test]
+    fn test_update_suspension_order_behaviour_market_locked() {
+        let mut market = mock_market(MarketStatus::Locked);
+
+        let result =
+            update_suspension_order_behaviour(&mut market, MarketOrderBehaviour::CancelUnmatched);
+
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+        assert_eq!(
+            MarketOrderBehaviour::None,
+            market.suspension_order_behaviour
+        );
+    }
+}
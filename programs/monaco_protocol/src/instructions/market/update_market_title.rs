@@ -24,7 +24,9 @@ fn update_market_title(market: &mut Market, title: String) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use crate::instructions::market::update_market_title::update_market_title;
-    use crate::state::market_account::{Market, MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_account::{
+        Market, MarketOrderBehaviour, MarketStatus, MarketWinningOutcome,
+    };
 
     #[test]
     fn test_market_update_success_100_char_title() {
@@ -34,7 +36,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -55,6 +58,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         let result = update_market_title(&mut market, "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string());
@@ -69,7 +89,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -90,6 +111,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         let result = update_market_title(&mut market, "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string());
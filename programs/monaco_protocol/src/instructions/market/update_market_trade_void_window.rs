@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketStatus};
+
+pub fn update_trade_void_window(market: &mut Market, trade_void_window: i64) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Initializing | MarketStatus::Open | MarketStatus::Locked
+        ),
+        CoreError::MarketInvalidStatus
+    );
+    require!(trade_void_window >= 0, CoreError::VoidTradeWindowInvalid);
+
+    market.trade_void_window = trade_void_window;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+
+    #[test]
+    fn test_update_trade_void_window() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_trade_void_window(&mut market, 300);
+
+        assert!(result.is_ok());
+        assert_eq!(300, market.trade_void_window);
+    }
+
+    #[test]
+    fn test_update_trade_void_window_negative() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_trade_void_window(&mut market, -1);
+
+        assert_eq!(Err(error!(CoreError::VoidTradeWindowInvalid)), result);
+        assert_eq!(0, market.trade_void_window);
+    }
+
+    #[test]
+    fn test_update_trade_void_window_market_settled() {
+        let mut market = mock_market(MarketStatus::ReadyForSettlement);
+
+        let result = update_trade_void_window(&mut market, 300);
+
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+    }
+}
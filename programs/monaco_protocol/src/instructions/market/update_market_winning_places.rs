@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::Market;
+use crate::state::market_account::MarketStatus::Initializing;
+
+pub fn update_market_winning_places(market: &mut Market, winning_places: u16) -> Result<()> {
+    // market positions track exposure for the winning places at the time of their creation
+    require!(
+        Initializing.eq(&market.market_status),
+        CoreError::MarketInvalidStatus
+    );
+    require!(winning_places > 0, CoreError::MarketWinningPlacesInvalid);
+
+    market.market_winning_places = winning_places;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::CoreError;
+    use crate::instructions::market::update_market_winning_places;
+    use crate::state::market_account::{mock_market, MarketStatus};
+    use anchor_lang::error;
+
+    #[test]
+    fn test_update_market_winning_places() {
+        let mut market = mock_market(MarketStatus::Initializing);
+
+        let result = update_market_winning_places(&mut market, 3);
+
+        assert!(result.is_ok());
+        assert_eq!(3, market.market_winning_places);
+    }
+
+    #[test]
+    fn test_update_market_winning_places_zero() {
+        let mut market = mock_market(MarketStatus::Initializing);
+
+        let result = update_market_winning_places(&mut market, 0);
+
+        assert_eq!(Err(error!(CoreError::MarketWinningPlacesInvalid)), result);
+        assert_eq!(1, market.market_winning_places);
+    }
+
+    #[test]
+    fn test_update_market_winning_places_market_open() {
+        let mut market = mock_market(MarketStatus::Open);
+
+        let result = update_market_winning_places(&mut market, 3);
+
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+        assert_eq!(1, market.market_winning_places);
+    }
+}
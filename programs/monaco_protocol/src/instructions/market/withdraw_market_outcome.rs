@@ -0,0 +1,270 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+use crate::error::CoreError;
+use crate::events::market::MarketOutcomeWithdrawnEvent;
+use crate::state::market_account::{Market, MarketStatus, WithdrawnOutcome};
+use crate::state::market_liquidities::MarketLiquidities;
+use crate::state::market_matching_queue_account::MarketMatchingQueue;
+use crate::state::market_order_request_queue::MarketOrderRequestQueue;
+
+// liquidity of the withdrawn outcome is cleared, its unmatched stake can only be cancelled and
+// its orders are voided on settlement; the reduction factor is applied to winnings on the
+// remaining outcomes for trades matched before the withdrawal
+pub fn withdraw_market_outcome(
+    market_pk: &Pubkey,
+    market: &mut Market,
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_queue: &MarketMatchingQueue,
+    order_request_queue: &MarketOrderRequestQueue,
+    outcome_index: u16,
+    reduction_factor: u16,
+    now: UnixTimestamp,
+) -> Result<()> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Open | MarketStatus::Locked
+        ),
+        CoreError::MarketInvalidStatus
+    );
+    require!(
+        market.market_winning_places == 1,
+        CoreError::MarketOutcomeWithdrawalWinningPlacesUnsupported
+    );
+    // cross matched trades are priced across outcomes, so can't be reduced per outcome
+    require!(
+        !market_liquidities.enable_cross_matching,
+        CoreError::MarketOutcomeWithdrawalCrossMatchingUnsupported
+    );
+    require!(
+        outcome_index < market.market_outcomes_count,
+        CoreError::MarketOutcomeIndexInvalid
+    );
+    require!(
+        !market.is_outcome_withdrawn(outcome_index),
+        CoreError::MarketOutcomeAlreadyWithdrawn
+    );
+    require!(
+        market.withdrawn_outcomes.len() < Market::WITHDRAWN_OUTCOMES_MAX_LENGTH,
+        CoreError::MarketOutcomeWithdrawalLimitReached
+    );
+    let remaining_outcomes = usize::from(market.market_outcomes_count)
+        .checked_sub(market.withdrawn_outcomes.len())
+        .and_then(|remaining_outcomes| remaining_outcomes.checked_sub(1))
+        .ok_or(CoreError::ArithmeticError)?;
+    require!(
+        remaining_outcomes > usize::from(market.market_winning_places),
+        CoreError::MarketOutcomeWithdrawalTooFewOutcomes
+    );
+    let total_reduction_factor = market
+        .total_reduction_factor()
+        .and_then(|total| total.checked_add(reduction_factor));
+    require!(
+        matches!(total_reduction_factor, Some(total) if total <= WithdrawnOutcome::MAX_TOTAL_REDUCTION_FACTOR),
+        CoreError::MarketOutcomeWithdrawalReductionFactorInvalid
+    );
+    // positions snapshot their winnings when next updated, so all matches must be processed
+    require!(
+        market_matching_queue.matches.is_empty(),
+        CoreError::MatchingQueueIsNotEmpty
+    );
+    require!(
+        order_request_queue.order_requests.is_empty(),
+        CoreError::OrderRequestQueueIsNotEmpty
+    );
+
+    // matching pools of the outcome are cleared when next used
+    market_liquidities.clear_outcome_liquidities(outcome_index);
+    market.withdrawn_outcomes.push(WithdrawnOutcome {
+        outcome_index,
+        reduction_factor,
+        withdrawal_timestamp: now,
+    });
+
+    emit!(MarketOutcomeWithdrawnEvent {
+        market: *market_pk,
+        outcome_index,
+        reduction_factor,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+    use crate::state::market_liquidities::mock_market_liquidities;
+    use crate::state::market_matching_queue_account::{mock_market_matching_queue, OrderMatch};
+    use crate::state::market_order_request_queue::mock_order_request_queue;
+    use crate::state::price::Price;
+
+    struct Accounts {
+        market: Market,
+        market_liquidities: MarketLiquidities,
+        market_matching_queue: MarketMatchingQueue,
+        order_request_queue: MarketOrderRequestQueue,
+    }
+
+    fn withdrawal_accounts() -> Accounts {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 4;
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        market_liquidities.enable_cross_matching = false;
+        Accounts {
+            market,
+            market_liquidities,
+            market_matching_queue: mock_market_matching_queue(market_pk),
+            order_request_queue: mock_order_request_queue(market_pk),
+        }
+    }
+
+    fn withdraw(accounts: &mut Accounts, outcome_index: u16, reduction_factor: u16) -> Result<()> {
+        withdraw_market_outcome(
+            &Pubkey::new_unique(),
+            &mut accounts.market,
+            &mut accounts.market_liquidities,
+            &accounts.market_matching_queue,
+            &accounts.order_request_queue,
+            outcome_index,
+            reduction_factor,
+            100,
+        )
+    }
+
+    #[test]
+    fn test_withdraw_market_outcome() {
+        let mut accounts = withdrawal_accounts();
+
+        let result = withdraw(&mut accounts, 2, 2_500);
+
+        assert!(result.is_ok());
+        assert!(accounts.market.is_outcome_withdrawn(2));
+        assert_eq!(
+            vec![WithdrawnOutcome {
+                outcome_index: 2,
+                reduction_factor: 2_500,
+                withdrawal_timestamp: 100,
+            }],
+            accounts.market.withdrawn_outcomes
+        );
+
+        let result = withdraw(&mut accounts, 2, 2_500);
+        assert_eq!(
+            Err(error!(CoreError::MarketOutcomeAlreadyWithdrawn)),
+            result
+        );
+    }
+
+    #[test]
+    fn test_withdraw_market_outcome_clears_liquidity() {
+        let mut accounts = withdrawal_accounts();
+        let market_liquidities = &mut accounts.market_liquidities;
+        market_liquidities
+            .add_liquidity_for(2, Price::new(3_000), 10)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_against(2, Price::new(2_000), 10)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_for(1, Price::new(3_000), 10)
+            .unwrap();
+
+        let result = withdraw(&mut accounts, 2, 2_500);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            vec![(1, Price::new(3_000), 10)],
+            accounts
+                .market_liquidities
+                .liquidities_for
+                .iter()
+                .map(|liquidity| (liquidity.outcome, liquidity.price, liquidity.liquidity))
+                .collect::<Vec<(u16, Price, u64)>>()
+        );
+        assert!(accounts.market_liquidities.liquidities_against.is_empty());
+    }
+
+    #[test]
+    fn test_withdraw_market_outcome_too_few_outcomes() {
+        let mut accounts = withdrawal_accounts();
+        assert!(withdraw(&mut accounts, 0, 1_000).is_ok());
+
+        let result = withdraw(&mut accounts, 1, 1_000);
+        assert!(result.is_ok());
+
+        let result = withdraw(&mut accounts, 2, 1_000);
+        assert_eq!(
+            Err(error!(CoreError::MarketOutcomeWithdrawalTooFewOutcomes)),
+            result
+        );
+    }
+
+    #[test]
+    fn test_withdraw_market_outcome_reduction_factor_too_large() {
+        let mut accounts = withdrawal_accounts();
+        assert!(withdraw(&mut accounts, 0, 5_000).is_ok());
+
+        let result = withdraw(&mut accounts, 1, 2_501);
+
+        assert_eq!(
+            Err(error!(
+                CoreError::MarketOutcomeWithdrawalReductionFactorInvalid
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn test_withdraw_market_outcome_invalid_index() {
+        let mut accounts = withdrawal_accounts();
+
+        let result = withdraw(&mut accounts, 4, 1_000);
+
+        assert_eq!(Err(error!(CoreError::MarketOutcomeIndexInvalid)), result);
+    }
+
+    #[test]
+    fn test_withdraw_market_outcome_unsupported_market() {
+        let mut accounts = withdrawal_accounts();
+        accounts.market.market_winning_places = 2;
+        let result = withdraw(&mut accounts, 0, 1_000);
+        assert_eq!(
+            Err(error!(
+                CoreError::MarketOutcomeWithdrawalWinningPlacesUnsupported
+            )),
+            result
+        );
+
+        let mut accounts = withdrawal_accounts();
+        accounts.market_liquidities.enable_cross_matching = true;
+        let result = withdraw(&mut accounts, 0, 1_000);
+        assert_eq!(
+            Err(error!(
+                CoreError::MarketOutcomeWithdrawalCrossMatchingUnsupported
+            )),
+            result
+        );
+
+        let mut accounts = withdrawal_accounts();
+        accounts.market.market_status = MarketStatus::ReadyForSettlement;
+        let result = withdraw(&mut accounts, 0, 1_000);
+        assert_eq!(Err(error!(CoreError::MarketInvalidStatus)), result);
+    }
+
+    #[test]
+    fn test_withdraw_market_outcome_matching_queue_not_empty() {
+        let mut accounts = withdrawal_accounts();
+        accounts
+            .market_matching_queue
+            .matches
+            .enqueue(OrderMatch::maker(true, 0, Price::new(2_000), 10));
+
+        let result = withdraw(&mut accounts, 0, 1_000);
+
+        assert_eq!(Err(error!(CoreError::MatchingQueueIsNotEmpty)), result);
+        assert!(accounts.market.withdrawn_outcomes.is_empty());
+    }
+}
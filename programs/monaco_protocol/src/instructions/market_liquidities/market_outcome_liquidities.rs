@@ -0,0 +1,283 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use solana_program::program::invoke_signed;
+use solana_program::system_instruction;
+
+use crate::error::CoreError;
+use crate::state::market_account::Market;
+use crate::state::market_liquidities::MarketLiquidities;
+use crate::state::market_outcome_liquidities::MarketOutcomeLiquidities;
+use crate::state::type_size::DISCRIMINATOR_SIZE;
+
+pub struct MarketOutcomeLiquiditiesPage<'info> {
+    account: AccountInfo<'info>,
+    market_outcome_index: u16,
+    // only known for accounts expected at their address, which are the only ones ever created
+    bump: Option<u8>,
+    // none until the account is created
+    outcome_liquidities: Option<MarketOutcomeLiquidities>,
+}
+
+pub fn find_market_outcome_liquidities_address(
+    market_pk: &Pubkey,
+    market_outcome_index: u16,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"liquidities".as_ref(),
+            market_pk.as_ref(),
+            market_outcome_index.to_string().as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+// outcomes whose liquidities an order for the given outcome can be matched against
+pub fn matching_outcomes(
+    market: &Market,
+    market_liquidities: &MarketLiquidities,
+    market_outcome_index: u16,
+) -> Vec<u16> {
+    match market_liquidities.enable_cross_matching {
+        true => (0..market.market_outcomes_count).collect(),
+        false => vec![market_outcome_index],
+    }
+}
+
+pub fn is_market_outcome_liquidities(account: &AccountInfo) -> bool {
+    account.owner == &crate::ID
+        && account.try_borrow_data().is_ok_and(|data| {
+            data.len() >= DISCRIMINATOR_SIZE
+                && data[..DISCRIMINATOR_SIZE] == MarketOutcomeLiquidities::discriminator()
+        })
+}
+
+// moves liquidities of any outcome liquidities accounts passed in into the market liquidities;
+// accounts of required outcomes must be passed even if they are yet to be created
+pub fn load_market_outcome_liquidities<'info>(
+    market_pk: &Pubkey,
+    market: &Market,
+    market_liquidities: &mut MarketLiquidities,
+    accounts: &[AccountInfo<'info>],
+    required_outcomes: &[u16],
+) -> Result<Vec<MarketOutcomeLiquiditiesPage<'info>>> {
+    let mut pages: Vec<MarketOutcomeLiquiditiesPage> = Vec::new();
+
+    for account in accounts {
+        if !is_market_outcome_liquidities(account)
+            || pages.iter().any(|page| page.account.key == account.key)
+        {
+            continue;
+        }
+        let outcome_liquidities =
+            MarketOutcomeLiquidities::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        require!(
+            outcome_liquidities.market == *market_pk,
+            CoreError::MarketLiquiditiesOutcomeLiquiditiesMismatch
+        );
+        pages.push(MarketOutcomeLiquiditiesPage {
+            account: account.clone(),
+            market_outcome_index: outcome_liquidities.market_outcome_index,
+            bump: None,
+            outcome_liquidities: Some(outcome_liquidities),
+        });
+    }
+
+    for market_outcome_index in required_outcomes {
+        if pages
+            .iter()
+            .any(|page| page.market_outcome_index == *market_outcome_index)
+        {
+            continue;
+        }
+        let (address, bump) =
+            find_market_outcome_liquidities_address(market_pk, *market_outcome_index);
+        let account = accounts
+            .iter()
+            .find(|account| account.key == &address)
+            .ok_or(CoreError::MarketLiquiditiesOutcomeLiquiditiesRequired)?;
+        require!(
+            solana_program::system_program::check_id(account.owner) && account.data_is_empty(),
+            CoreError::MarketLiquiditiesOutcomeLiquiditiesMismatch
+        );
+        pages.push(MarketOutcomeLiquiditiesPage {
+            account: account.clone(),
+            market_outcome_index: *market_outcome_index,
+            bump: Some(bump),
+            outcome_liquidities: None,
+        });
+    }
+
+    for page in pages.iter_mut() {
+        if let Some(outcome_liquidities) = page.outcome_liquidities.as_mut() {
+            // liquidities are reset lazily for accounts not loaded when the market moved to inplay
+            if market.inplay && !outcome_liquidities.inplay {
+                outcome_liquidities.move_to_inplay(&market.event_start_order_behaviour);
+            }
+            market_liquidities.load_outcome_liquidities(outcome_liquidities)?;
+        }
+    }
+
+    Ok(pages)
+}
+
+// moves liquidities of each loaded outcome back out to its account, creating or growing accounts
+// as needed when a payer is given; anything left over must fit in the market liquidities account
+pub fn store_market_outcome_liquidities<'info>(
+    market_pk: &Pubkey,
+    market: &mut Market,
+    market_liquidities: &mut Account<'info, MarketLiquidities>,
+    pages: Vec<MarketOutcomeLiquiditiesPage<'info>>,
+    payer: Option<(&Signer<'info>, &Program<'info, System>)>,
+) -> Result<()> {
+    for page in pages {
+        match page.outcome_liquidities {
+            Some(mut outcome_liquidities) => {
+                market_liquidities.store_outcome_liquidities(&mut outcome_liquidities);
+                outcome_liquidities.inplay = market.inplay;
+                write_outcome_liquidities(&page.account, &outcome_liquidities, payer)?;
+            }
+            None => {
+                let (bump, (payer_account, system_program)) = match (page.bump, payer) {
+                    (Some(bump), Some(payer)) => (bump, payer),
+                    // liquidities stay in the market liquidities account
+                    _ => continue,
+                };
+                let mut outcome_liquidities = MarketOutcomeLiquidities::new(
+                    *market_pk,
+                    page.market_outcome_index,
+                    payer_account.key(),
+                    market.inplay,
+                );
+                market_liquidities.store_outcome_liquidities(&mut outcome_liquidities);
+                if outcome_liquidities.is_empty() {
+                    continue;
+                }
+
+                create_outcome_liquidities_account(
+                    market_pk,
+                    &page.account,
+                    page.market_outcome_index,
+                    bump,
+                    outcome_liquidities.size() + MarketOutcomeLiquidities::SIZE_INCREMENT,
+                    payer_account,
+                    system_program,
+                )?;
+                market.increment_unclosed_accounts_count()?;
+                write_outcome_liquidities(&page.account, &outcome_liquidities, payer)?;
+            }
+        }
+    }
+
+    require!(
+        market_liquidities.size() <= market_liquidities.to_account_info().data_len(),
+        CoreError::MarketLiquiditiesIsFull
+    );
+    Ok(())
+}
+
+fn create_outcome_liquidities_account<'info>(
+    market_pk: &Pubkey,
+    account: &AccountInfo<'info>,
+    market_outcome_index: u16,
+    bump: u8,
+    size: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let market_outcome_index = market_outcome_index.to_string();
+    let seeds: &[&[u8]] = &[
+        b"liquidities".as_ref(),
+        market_pk.as_ref(),
+        market_outcome_index.as_ref(),
+        &[bump],
+    ];
+    create_program_account(account, seeds, size, payer, system_program)
+}
+
+// creates an account at a program address, signing with its seeds
+pub(crate) fn create_program_account<'info>(
+    account: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    size: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(size);
+
+    // an account already holding lamports cannot be created, so it is funded, allocated and
+    // assigned instead
+    if account.lamports() == 0 {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                account.key,
+                rent_exempt_lamports,
+                size as u64,
+                &crate::ID,
+            ),
+            &[
+                payer.to_account_info(),
+                account.clone(),
+                system_program.to_account_info(),
+            ],
+            &[seeds],
+        )?;
+    } else {
+        top_up_rent(account, size, payer, system_program)?;
+        invoke_signed(
+            &system_instruction::allocate(account.key, size as u64),
+            &[account.clone(), system_program.to_account_info()],
+            &[seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(account.key, &crate::ID),
+            &[account.clone(), system_program.to_account_info()],
+            &[seeds],
+        )?;
+    }
+    Ok(())
+}
+
+fn write_outcome_liquidities<'info>(
+    account: &AccountInfo<'info>,
+    outcome_liquidities: &MarketOutcomeLiquidities,
+    payer: Option<(&Signer<'info>, &Program<'info, System>)>,
+) -> Result<()> {
+    let size = outcome_liquidities.size();
+    if account.data_len() < size {
+        let (payer, system_program) = payer.ok_or(CoreError::MarketLiquiditiesIsFull)?;
+        let new_size = size + MarketOutcomeLiquidities::SIZE_INCREMENT;
+        top_up_rent(account, new_size, payer, system_program)?;
+        account.realloc(new_size, false)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    outcome_liquidities.try_serialize(&mut writer)
+}
+
+fn top_up_rent<'info>(
+    account: &AccountInfo<'info>,
+    size: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let top_up = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    Ok(())
+}
@@ -1,3 +1,5 @@
+pub mod market_outcome_liquidities;
 pub mod update_market_liquidities_with_cross_liquidity;
 
+pub use market_outcome_liquidities::*;
 pub use update_market_liquidities_with_cross_liquidity::update_market_liquidities_with_cross_liquidity;
@@ -46,6 +46,7 @@ mod test {
     use super::*;
     use crate::state::market_account::{mock_market, MarketStatus};
     use crate::state::market_liquidities::{mock_market_liquidities, MarketOutcomePriceLiquidity};
+    use crate::state::price::Price;
 
     #[test]
     fn test_source_liquidities_validation() {
@@ -53,18 +54,21 @@ mod test {
         market.market_outcomes_count = 3;
         let mut market_liquidities = mock_market_liquidities(Pubkey::new_unique());
         market_liquidities
-            .add_liquidity_for(0, 2.0, 100_000)
+            .add_liquidity_for(0, Price::new(2_000), 100_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(1, 3.0, 100_000)
+            .add_liquidity_for(1, Price::new(3_000), 100_000)
             .unwrap();
 
         assert_eq!(
-            vec!((0, 2.0, 100_000), (1, 3.0, 100_000)),
+            vec!(
+                (0, Price::new(2_000), 100_000),
+                (1, Price::new(3_000), 100_000)
+            ),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            Vec::<(u16, f64, u64)>::new(),
+            Vec::<(u16, Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_against)
         );
 
@@ -74,7 +78,7 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(0, 2.0)], // missing source
+            vec![LiquiditySource::new(0, Price::new(2_000))], // missing source
         );
         assert!(result1.is_err());
         assert_eq!(
@@ -86,7 +90,10 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(1, 3.0), LiquiditySource::new(1, 2.0)], // duplicate outcomes
+            vec![
+                LiquiditySource::new(1, Price::new(3_000)),
+                LiquiditySource::new(1, Price::new(2_000)),
+            ], // duplicate outcomes
         );
         assert!(result2.is_err());
         assert_eq!(
@@ -98,7 +105,10 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(1, 3.0), LiquiditySource::new(3, 2.0)], // incorrect outcomes
+            vec![
+                LiquiditySource::new(1, Price::new(3_000)),
+                LiquiditySource::new(3, Price::new(2_000)),
+            ], // incorrect outcomes
         );
         assert!(result3.is_err());
         assert_eq!(
@@ -107,7 +117,7 @@ mod test {
         );
 
         assert_eq!(
-            Vec::<(u16, f64, u64)>::new(),
+            Vec::<(u16, Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_against)
         );
     }
@@ -118,18 +128,21 @@ mod test {
         market.market_outcomes_count = 3;
         let mut market_liquidities = mock_market_liquidities(Pubkey::new_unique());
         market_liquidities
-            .add_liquidity_for(0, 2.0, 100_000)
+            .add_liquidity_for(0, Price::new(2_000), 100_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(1, 3.0, 100_000)
+            .add_liquidity_for(1, Price::new(3_000), 100_000)
             .unwrap();
 
         assert_eq!(
-            vec!((0, 2.0, 100_000), (1, 3.0, 100_000)),
+            vec!(
+                (0, Price::new(2_000), 100_000),
+                (1, Price::new(3_000), 100_000)
+            ),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            Vec::<(u16, f64, u64)>::new(),
+            Vec::<(u16, Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_against)
         );
 
@@ -139,7 +152,10 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(0, 2.0), LiquiditySource::new(1, 3.0)],
+            vec![
+                LiquiditySource::new(0, Price::new(2_000)),
+                LiquiditySource::new(1, Price::new(3_000)),
+            ],
         )
         .expect("update_market_liquidities_with_cross_liquidity failed");
 
@@ -147,12 +163,15 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(1, 3.0), LiquiditySource::new(0, 2.0)],
+            vec![
+                LiquiditySource::new(1, Price::new(3_000)),
+                LiquiditySource::new(0, Price::new(2_000)),
+            ],
         )
         .expect("update_market_liquidities_with_cross_liquidity failed");
 
         assert_eq!(
-            vec!((2, 6.0, 33_000)),
+            vec!((2, Price::new(6_000), 33_000)),
             liquidities(&market_liquidities.liquidities_against)
         );
     }
@@ -162,14 +181,22 @@ mod test {
         let mut market = mock_market(MarketStatus::Open);
         market.market_outcomes_count = 2;
         let mut market_liquidities = mock_market_liquidities(Pubkey::new_unique());
-        market_liquidities.add_liquidity_for(0, 3.0, 1000).unwrap();
-        market_liquidities.add_liquidity_for(0, 3.5, 1000).unwrap();
         market_liquidities
-            .add_liquidity_for(0, 4.125, 1000)
+            .add_liquidity_for(0, Price::new(3_000), 1000)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_for(0, Price::new(3_500), 1000)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_for(0, Price::new(4_125), 1000)
             .unwrap();
 
         assert_eq!(
-            vec!((0, 3.0, 1000), (0, 3.5, 1000), (0, 4.125, 1000)),
+            vec!(
+                (0, Price::new(3_000), 1000),
+                (0, Price::new(3_500), 1000),
+                (0, Price::new(4_125), 1000)
+            ),
             liquidities(&market_liquidities.liquidities_for)
         );
 
@@ -179,7 +206,7 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(0, 3.0)],
+            vec![LiquiditySource::new(0, Price::new(3_000))],
         )
         .expect("update_market_liquidities_with_cross_liquidity failed");
 
@@ -187,7 +214,7 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(0, 3.5)],
+            vec![LiquiditySource::new(0, Price::new(3_500))],
         )
         .expect("update_market_liquidities_with_cross_liquidity failed");
 
@@ -195,12 +222,16 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(0, 4.125)],
+            vec![LiquiditySource::new(0, Price::new(4_125))],
         )
         .expect("update_market_liquidities_with_cross_liquidity failed");
 
         assert_eq!(
-            vec!((1, 1.5, 2000), (1, 1.4, 2000), (1, 1.32, 3000)),
+            vec!(
+                (1, Price::new(1_500), 2000),
+                (1, Price::new(1_400), 2000),
+                (1, Price::new(1_320), 3000)
+            ),
             liquidities(&market_liquidities.liquidities_against)
         );
     }
@@ -213,17 +244,21 @@ mod test {
         market.market_outcomes_count = 3;
         let mut market_liquidities = mock_market_liquidities(Pubkey::new_unique());
         market_liquidities
-            .add_liquidity_for(0, 2.0, 100_000)
+            .add_liquidity_for(0, Price::new(2_000), 100_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(0, 2.1, 100_000)
+            .add_liquidity_for(0, Price::new(2_100), 100_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(1, 3.0, 100_000)
+            .add_liquidity_for(1, Price::new(3_000), 100_000)
             .unwrap();
 
         assert_eq!(
-            vec!((0, 2.0, 100_000), (0, 2.1, 100_000), (1, 3.0, 100_000)),
+            vec!(
+                (0, Price::new(2_000), 100_000),
+                (0, Price::new(2_100), 100_000),
+                (1, Price::new(3_000), 100_000)
+            ),
             liquidities(&market_liquidities.liquidities_for)
         );
 
@@ -233,7 +268,10 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(0, 2.1), LiquiditySource::new(1, 3.0)],
+            vec![
+                LiquiditySource::new(0, Price::new(2_100)),
+                LiquiditySource::new(1, Price::new(3_000)),
+            ],
         )
         .expect("update_market_liquidities_with_cross_liquidity failed");
 
@@ -241,20 +279,26 @@ mod test {
             &market,
             &mut market_liquidities,
             true,
-            vec![LiquiditySource::new(0, 2.0), LiquiditySource::new(1, 3.0)],
+            vec![
+                LiquiditySource::new(0, Price::new(2_000)),
+                LiquiditySource::new(1, Price::new(3_000)),
+            ],
         )
         .expect("update_market_liquidities_with_cross_liquidity failed");
 
         assert_eq!(
-            vec!((2, 6.0, 33_000), (2, 5.25, 40_000)),
+            vec!(
+                (2, Price::new(6_000), 33_000),
+                (2, Price::new(5_250), 40_000)
+            ),
             liquidities(&market_liquidities.liquidities_against)
         );
     }
 
-    fn liquidities(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(u16, f64, u64)> {
+    fn liquidities(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(u16, Price, u64)> {
         liquidities
             .iter()
             .map(|v| (v.outcome, v.price, v.liquidity))
-            .collect::<Vec<(u16, f64, u64)>>()
+            .collect::<Vec<(u16, Price, u64)>>()
     }
 }
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketStatus};
+use crate::state::market_order_book::{MarketOrderBook, OrderBookLevel};
+use crate::state::market_outcome_account::MarketOutcome;
+use crate::state::price_ladder::PriceLadder;
+
+pub fn create_market_order_book_page(
+    market_pk: Pubkey,
+    market: &mut Market,
+    market_outcome: &MarketOutcome,
+    price_ladder: Option<&PriceLadder>,
+    market_order_book: &mut MarketOrderBook,
+    page_index: u16,
+) -> Result<()> {
+    // pages have to exist before any orders are placed to track all resting stake
+    require!(
+        market.market_status == MarketStatus::Initializing,
+        CoreError::OrderBookMarketNotInitializing
+    );
+
+    let prices = MarketOrderBook::outcome_page_prices(market_outcome, price_ladder, page_index);
+    require!(!prices.is_empty(), CoreError::OrderBookPageEmpty);
+
+    market_order_book.market = market_pk;
+    market_order_book.market_outcome_index = market_outcome.index;
+    market_order_book.page_index = page_index;
+    market_order_book.levels = prices.into_iter().map(OrderBookLevel::new).collect();
+    market_order_book.inplay = market.inplay;
+    market_order_book.suspension_timestamp = market.suspension_timestamp;
+
+    market.order_book_enabled = true;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::mock_market;
+    use crate::state::market_order_book::mock_market_order_book;
+    use crate::state::market_outcome_account::mock_market_outcome;
+    use crate::state::price::Price;
+
+    #[test]
+    fn test_create_market_order_book_page() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Initializing);
+        let mut market_outcome = mock_market_outcome(market_pk, 1);
+        market_outcome.price_ladder = vec![Price::new(3_000), Price::new(2_000)];
+        let mut market_order_book = mock_market_order_book(Pubkey::default(), 0, &[]);
+
+        let result = create_market_order_book_page(
+            market_pk,
+            &mut market,
+            &market_outcome,
+            None,
+            &mut market_order_book,
+            0,
+        );
+
+        assert!(result.is_ok());
+        assert!(market.order_book_enabled);
+        assert_eq!(market_pk, market_order_book.market);
+        assert_eq!(1, market_order_book.market_outcome_index);
+        assert_eq!(
+            vec![
+                OrderBookLevel::new(Price::new(2_000)),
+                OrderBookLevel::new(Price::new(3_000))
+            ],
+            market_order_book.levels
+        );
+    }
+
+    #[test]
+    fn test_create_market_order_book_page_price_ladder_account() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Initializing);
+        let market_outcome = mock_market_outcome(market_pk, 0);
+        let price_ladder = PriceLadder {
+            authority: Pubkey::new_unique(),
+            max_number_of_prices: 1,
+            prices: vec![Price::new(1_500)],
+        };
+        let mut market_order_book = mock_market_order_book(Pubkey::default(), 0, &[]);
+
+        let result = create_market_order_book_page(
+            market_pk,
+            &mut market,
+            &market_outcome,
+            Some(&price_ladder),
+            &mut market_order_book,
+            0,
+        );
+        assert!(result.is_ok());
+        assert_eq!(
+            vec![OrderBookLevel::new(Price::new(1_500))],
+            market_order_book.levels
+        );
+
+        let result = create_market_order_book_page(
+            market_pk,
+            &mut market,
+            &market_outcome,
+            Some(&price_ladder),
+            &mut market_order_book,
+            1,
+        );
+        assert_eq!(Err(error!(CoreError::OrderBookPageEmpty)), result);
+    }
+
+    #[test]
+    fn test_create_market_order_book_page_market_open() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        let market_outcome = mock_market_outcome(market_pk, 0);
+        let mut market_order_book = mock_market_order_book(Pubkey::default(), 0, &[]);
+
+        let result = create_market_order_book_page(
+            market_pk,
+            &mut market,
+            &market_outcome,
+            None,
+            &mut market_order_book,
+            0,
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::OrderBookMarketNotInitializing)),
+            result
+        );
+        assert!(!market.order_book_enabled);
+    }
+}
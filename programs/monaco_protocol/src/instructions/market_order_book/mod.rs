@@ -0,0 +1,4 @@
+pub mod create_market_order_book_page;
+pub mod update_market_order_book;
+pub use create_market_order_book_page::*;
+pub use update_market_order_book::*;
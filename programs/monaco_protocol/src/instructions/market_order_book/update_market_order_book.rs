@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::error::CoreError;
+use crate::state::market_account::Market;
+use crate::state::market_order_book::MarketOrderBook;
+use crate::state::order_account::Order;
+use crate::state::type_size::DISCRIMINATOR_SIZE;
+
+// adds stake of the order now resting on the book
+pub fn add_to_market_order_book(
+    market: &Market,
+    market_order_book: Option<&mut MarketOrderBook>,
+    order: &Order,
+    stake: u64,
+) -> Result<()> {
+    match order_book_page(market, market_order_book, order, stake)? {
+        Some(page) => page.add_liquidity(order.for_outcome, order.expected_price, stake),
+        None => Ok(()),
+    }
+}
+
+// removes stake of the order no longer resting on the book, i.e., cancelled or matched
+pub fn remove_from_market_order_book(
+    market: &Market,
+    market_order_book: Option<&mut MarketOrderBook>,
+    order: &Order,
+    stake: u64,
+) -> Result<()> {
+    match order_book_page(market, market_order_book, order, stake)? {
+        Some(page) => page.remove_liquidity(order.for_outcome, order.expected_price, stake),
+        None => Ok(()),
+    }
+}
+
+pub fn is_market_order_book(account: &AccountInfo) -> bool {
+    account.owner == &crate::ID
+        && account.try_borrow_data().is_ok_and(|data| {
+            data.len() >= DISCRIMINATOR_SIZE
+                && data[..DISCRIMINATOR_SIZE] == MarketOrderBook::discriminator()
+        })
+}
+
+fn order_book_page<'a>(
+    market: &Market,
+    market_order_book: Option<&'a mut MarketOrderBook>,
+    order: &Order,
+    stake: u64,
+) -> Result<Option<&'a mut MarketOrderBook>> {
+    if stake == 0 {
+        return Ok(None);
+    }
+    match market_order_book {
+        None => {
+            require!(!market.order_book_enabled, CoreError::OrderBookPageRequired);
+            Ok(None)
+        }
+        Some(page) => {
+            require!(
+                page.market == order.market
+                    && page.contains(order.market_outcome_index, order.expected_price),
+                CoreError::OrderBookPageMismatch
+            );
+            // !!! the market must be moved to inplay first
+            if market.inplay && !page.inplay {
+                page.move_to_inplay(&market.event_start_order_behaviour);
+            }
+            page.apply_suspension(market.suspension_timestamp);
+            // unmatched stake of a withdrawn outcome no longer rests on the book
+            if market.is_outcome_withdrawn(page.market_outcome_index) {
+                page.clear_liquidity();
+            }
+            Ok(Some(page))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::{
+        mock_market, MarketOrderBehaviour, MarketStatus, WithdrawnOutcome,
+    };
+    use crate::state::market_order_book::mock_market_order_book;
+    use crate::state::order_account::mock_order;
+    use crate::state::price::Price;
+
+    fn order_book_market() -> Market {
+        let mut market = mock_market(MarketStatus::Open);
+        market.order_book_enabled = true;
+        market
+    }
+
+    #[test]
+    fn test_add_and_remove() {
+        let market = order_book_market();
+        let market_pk = Pubkey::new_unique();
+        let mut page = mock_market_order_book(market_pk, 1, &[Price::new(2_000)]);
+        let order = mock_order(market_pk, 1, false, Price::new(2_000), 100, market_pk);
+
+        add_to_market_order_book(&market, Some(&mut page), &order, 100).unwrap();
+        remove_from_market_order_book(&market, Some(&mut page), &order, 30).unwrap();
+
+        assert_eq!(0, page.levels[0].liquidity_for);
+        assert_eq!(70, page.levels[0].liquidity_against);
+    }
+
+    #[test]
+    fn test_page_required() {
+        let mut market = order_book_market();
+        let order = mock_order(
+            Pubkey::new_unique(),
+            1,
+            true,
+            Price::new(2_000),
+            100,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::OrderBookPageRequired)),
+            add_to_market_order_book(&market, None, &order, 100)
+        );
+        assert!(add_to_market_order_book(&market, None, &order, 0).is_ok());
+
+        market.order_book_enabled = false;
+        assert!(remove_from_market_order_book(&market, None, &order, 100).is_ok());
+    }
+
+    #[test]
+    fn test_page_mismatch() {
+        let market = order_book_market();
+        let market_pk = Pubkey::new_unique();
+        let order = mock_order(market_pk, 1, true, Price::new(2_000), 100, market_pk);
+
+        let mut other_outcome_page = mock_market_order_book(market_pk, 0, &[Price::new(2_000)]);
+        let mut other_market_page =
+            mock_market_order_book(Pubkey::new_unique(), 1, &[Price::new(2_000)]);
+        let mut other_prices_page = mock_market_order_book(market_pk, 1, &[Price::new(2_500)]);
+
+        for page in [
+            &mut other_outcome_page,
+            &mut other_market_page,
+            &mut other_prices_page,
+        ] {
+            assert_eq!(
+                Err(error!(CoreError::OrderBookPageMismatch)),
+                add_to_market_order_book(&market, Some(page), &order, 100)
+            );
+        }
+    }
+
+    #[test]
+    fn test_page_moved_to_inplay() {
+        let mut market = order_book_market();
+        market.event_start_order_behaviour = MarketOrderBehaviour::CancelUnmatched;
+        let market_pk = Pubkey::new_unique();
+        let mut page = mock_market_order_book(market_pk, 1, &[Price::new(2_000)]);
+        let preplay_order = mock_order(market_pk, 1, true, Price::new(2_000), 100, market_pk);
+        add_to_market_order_book(&market, Some(&mut page), &preplay_order, 100).unwrap();
+
+        market.inplay = true;
+        let inplay_order = mock_order(market_pk, 1, false, Price::new(2_000), 20, market_pk);
+        add_to_market_order_book(&market, Some(&mut page), &inplay_order, 20).unwrap();
+
+        assert!(page.inplay);
+        assert_eq!(0, page.levels[0].liquidity_for);
+        assert_eq!(20, page.levels[0].liquidity_against);
+    }
+
+    #[test]
+    fn test_page_of_withdrawn_outcome_cleared() {
+        let mut market = order_book_market();
+        let market_pk = Pubkey::new_unique();
+        let mut page = mock_market_order_book(market_pk, 1, &[Price::new(2_000)]);
+        let order = mock_order(market_pk, 1, true, Price::new(2_000), 100, market_pk);
+        add_to_market_order_book(&market, Some(&mut page), &order, 100).unwrap();
+        let other_order = mock_order(market_pk, 1, false, Price::new(2_000), 50, market_pk);
+        add_to_market_order_book(&market, Some(&mut page), &other_order, 50).unwrap();
+
+        market.withdrawn_outcomes.push(WithdrawnOutcome {
+            outcome_index: 1,
+            reduction_factor: 1_000,
+            withdrawal_timestamp: 0,
+        });
+        remove_from_market_order_book(&market, Some(&mut page), &order, 100).unwrap();
+
+        assert_eq!(0, page.levels[0].liquidity_for);
+        assert_eq!(0, page.levels[0].liquidity_against);
+    }
+
+    #[test]
+    fn test_page_cleared_after_suspension() {
+        let mut market = order_book_market();
+        let market_pk = Pubkey::new_unique();
+        let mut page = mock_market_order_book(market_pk, 1, &[Price::new(2_000)]);
+        let order = mock_order(market_pk, 1, true, Price::new(2_000), 100, market_pk);
+        add_to_market_order_book(&market, Some(&mut page), &order, 100).unwrap();
+        let other_order = mock_order(market_pk, 1, false, Price::new(2_000), 50, market_pk);
+        add_to_market_order_book(&market, Some(&mut page), &other_order, 50).unwrap();
+
+        // unmatched stake resting before the suspension is cleared when the page is next updated
+        market.suspension_timestamp = 1_700_000_000;
+        let new_order = mock_order(market_pk, 1, true, Price::new(2_000), 30, market_pk);
+        add_to_market_order_book(&market, Some(&mut page), &new_order, 30).unwrap();
+
+        assert_eq!(30, page.levels[0].liquidity_for);
+        assert_eq!(0, page.levels[0].liquidity_against);
+        assert_eq!(1_700_000_000, page.suspension_timestamp);
+
+        // stake of orders cancelled after the suspension was already cleared
+        remove_from_market_order_book(&market, Some(&mut page), &other_order, 50).unwrap();
+        assert_eq!(30, page.levels[0].liquidity_for);
+    }
+}
@@ -27,7 +27,9 @@ pub fn create_market_position(
     market_position
         .unmatched_exposures
         .resize(market_outcomes_len, 0_u64);
+    market_position.winning_places = market.market_winning_places;
     market_position.paid = false;
+    market_position.schema_version = MarketPosition::SCHEMA_VERSION;
 
     if market_position.matched_risk == 0 {
         market_position.matched_risk_per_product =
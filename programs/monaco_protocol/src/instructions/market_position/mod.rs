@@ -3,6 +3,7 @@ pub mod settle_market_position;
 pub mod update_on_order_cancellation;
 pub mod update_on_order_match;
 pub mod update_on_order_request_creation;
+pub mod update_on_trade_void;
 pub mod update_product_commission_contributions;
 pub mod void_market_position;
 
@@ -11,5 +12,6 @@ pub use settle_market_position::*;
 pub use update_on_order_cancellation::*;
 pub use update_on_order_match::*;
 pub use update_on_order_request_creation::*;
+pub use update_on_trade_void::*;
 pub use update_product_commission_contributions::*;
 pub use void_market_position::*;
@@ -1,4 +1,7 @@
-use crate::instructions::{calculate_commission, calculate_post_commission_remainder, transfer};
+use crate::instructions::{
+    calculate_commission, calculate_dead_heat_common_denominator,
+    calculate_post_commission_remainder, transfer,
+};
 use crate::state::market_account::MarketStatus::ReadyForSettlement;
 use crate::SettleMarketPosition;
 use anchor_lang::prelude::*;
@@ -9,45 +12,75 @@ use std::convert::TryFrom;
 use std::ops::{Div, Mul};
 
 use crate::error::CoreError;
+use crate::events::market_position::PositionSettledEvent;
+use crate::state::market_account::{
+    Market, MarketWinningOutcome, OutcomeResult, WithdrawnOutcome,
+};
 use crate::state::market_position_account::{MarketPosition, ProductMatchedRiskAndRate};
 use crate::state::payments_queue::{PaymentInfo, PaymentQueue};
 
 pub fn settle_market_position(ctx: Context<SettleMarketPosition>) -> Result<()> {
-    let market_account = &mut ctx.accounts.market;
+    let market_pk = ctx.accounts.market.key();
+    let market_position_pk = ctx.accounts.market_position.key();
+
+    let total_payout = settle_market_position_common(
+        &market_pk,
+        &mut ctx.accounts.market,
+        &market_position_pk,
+        &mut ctx.accounts.market_position,
+        ctx.accounts.protocol_config.commission_rate,
+        &ctx.accounts.protocol_config.key(),
+        &ctx.accounts.market_escrow.key(),
+        &mut ctx.accounts.commission_payment_queue.payment_queue,
+    )?;
+
+    match total_payout {
+        Some(total_payout) => transfer::transfer_market_position(&ctx, total_payout),
+        None => Ok(()),
+    }
+}
+
+// settles the position enqueueing its commission payments, returning the payout due to the
+// purchaser or none if the position has already been paid out
+#[allow(clippy::too_many_arguments)]
+pub fn settle_market_position_common(
+    market_pk: &Pubkey,
+    market_account: &mut Market,
+    market_position_pk: &Pubkey,
+    market_position: &mut MarketPosition,
+    protocol_commission_rate: f64,
+    protocol_config_pk: &Pubkey,
+    market_escrow_pk: &Pubkey,
+    payment_queue: &mut PaymentQueue,
+) -> Result<Option<u64>> {
     // validate the market is ready for settlement
     require!(
         ReadyForSettlement.eq(&market_account.market_status),
         CoreError::SettlementMarketNotReadyForSettlement
     );
 
-    let market_position = &mut ctx.accounts.market_position;
     if market_position.paid {
         log::sol_log("market position has already been paid out");
-        return Ok(());
+        return Ok(None);
     }
 
-    let payment_queue = &mut ctx.accounts.commission_payment_queue.payment_queue;
-    let position_profit = market_position.market_outcome_sums
-        [market_account.market_winning_outcome_index.unwrap() as usize];
+    let position_profit = calculate_market_position_profit(market_account, market_position)?;
     let total_exposure = market_position.total_exposure();
 
-    let protocol_commission = calculate_commission(
-        ctx.accounts.protocol_config.commission_rate,
-        position_profit,
-    );
+    let protocol_commission = calculate_commission(protocol_commission_rate, position_profit);
     enqueue_payment(
         payment_queue,
         &PaymentInfo {
-            to: ctx.accounts.protocol_config.key(),
-            from: ctx.accounts.market_escrow.key(),
+            to: *protocol_config_pk,
+            from: *market_escrow_pk,
             amount: protocol_commission,
         },
     )?;
 
     let (total_product_commission, product_commission_payments) =
         calculate_product_commission_payments(
-            ctx.accounts.protocol_config.commission_rate,
-            ctx.accounts.market_escrow.key(),
+            protocol_commission_rate,
+            *market_escrow_pk,
             market_position,
             position_profit,
         );
@@ -69,7 +102,140 @@ pub fn settle_market_position(ctx: Context<SettleMarketPosition>) -> Result<()>
     market_position.paid = true;
     market_account.decrement_unsettled_accounts_count()?;
 
-    transfer::transfer_market_position(&ctx, total_payout_u64)
+    emit!(PositionSettledEvent {
+        market: *market_pk,
+        market_position: *market_position_pk,
+        purchaser: market_position.purchaser,
+        profit: position_profit,
+        protocol_commission,
+        product_commission: total_product_commission,
+        payout: total_payout_u64,
+    });
+
+    Ok(Some(total_payout_u64))
+}
+
+// profit of the position for the market's winning outcomes or outcome results, withdrawn outcomes
+// are applied to the position first
+pub fn calculate_market_position_profit(
+    market: &Market,
+    market_position: &mut MarketPosition,
+) -> Result<i128> {
+    if !market.withdrawn_outcomes.is_empty() {
+        market_position.apply_outcome_withdrawals(&market.withdrawn_outcomes)?;
+        // positions matched before migration have no outcome stakes and aren't reduced
+        if market_position.market_outcome_stakes.is_empty() {
+            return calculate_position_profit(
+                market_position,
+                market.market_winning_places,
+                &market.market_winning_outcomes,
+            );
+        }
+        calculate_position_profit(
+            &adjust_for_outcome_withdrawals(market_position, &market.withdrawn_outcomes)?,
+            market.market_winning_places,
+            &market.market_winning_outcomes,
+        )
+    } else if market.market_outcome_results.is_empty() {
+        calculate_position_profit(
+            market_position,
+            market.market_winning_places,
+            &market.market_winning_outcomes,
+        )
+    } else {
+        calculate_position_profit_from_results(market_position, &market.market_outcome_results)
+    }
+}
+
+// profit = sum(dead_heat_factor * market_outcome_sum) + (1 - winning_places) * no_winner_outcome_sum
+// calculated over the common denominator of the dead-heat factors and rounded down
+fn calculate_position_profit(
+    market_position: &MarketPosition,
+    winning_places: u16,
+    winning_outcomes: &[MarketWinningOutcome],
+) -> Result<i128> {
+    let denominators: Vec<u16> = winning_outcomes
+        .iter()
+        .map(|winning_outcome| winning_outcome.dead_heat_denominator)
+        .collect();
+    let common_denominator = calculate_dead_heat_common_denominator(&denominators)
+        .map(i128::from)
+        .ok_or(CoreError::SettlementPaymentCalculation)?;
+
+    let no_winner_profit = (1_i128 - i128::from(winning_places))
+        .checked_mul(market_position.no_winner_outcome_sum)
+        .and_then(|profit| profit.checked_mul(common_denominator))
+        .ok_or(CoreError::SettlementPaymentCalculation)?;
+
+    let profit = winning_outcomes
+        .iter()
+        .try_fold(no_winner_profit, |profit, winning_outcome| {
+            let dead_heat_factor = i128::from(winning_outcome.dead_heat_numerator)
+                .checked_mul(common_denominator)?
+                .checked_div(i128::from(winning_outcome.dead_heat_denominator))?;
+            market_position
+                .market_outcome_sums
+                .get(usize::from(winning_outcome.outcome_index))?
+                .checked_mul(dead_heat_factor)
+                .and_then(|outcome_profit| profit.checked_add(outcome_profit))
+        })
+        .ok_or(CoreError::SettlementPaymentCalculation)?;
+
+    Ok(profit.div_euclid(common_denominator))
+}
+
+// profit = sum(win_parts * market_outcome_sum) / STAKE_PARTS rounded down, results are complementary
+// so the pushed part of the stake nets to zero and is returned with the exposure
+fn calculate_position_profit_from_results(
+    market_position: &MarketPosition,
+    outcome_results: &[OutcomeResult],
+) -> Result<i128> {
+    let profit = outcome_results
+        .iter()
+        .zip(market_position.market_outcome_sums.iter())
+        .try_fold(0_i128, |profit, (outcome_result, outcome_sum)| {
+            outcome_sum
+                .checked_mul(i128::from(outcome_result.win_parts()))
+                .and_then(|outcome_profit| profit.checked_add(outcome_profit))
+        })
+        .ok_or(CoreError::SettlementPaymentCalculation)?;
+
+    Ok(profit.div_euclid(i128::from(OutcomeResult::STAKE_PARTS)))
+}
+
+// stakes matched on withdrawn outcomes are returned and the winnings on each remaining outcome are
+// reduced by the deductions recorded on withdrawal, rounded down
+fn adjust_for_outcome_withdrawals(
+    market_position: &MarketPosition,
+    withdrawn_outcomes: &[WithdrawnOutcome],
+) -> Result<MarketPosition> {
+    let withdrawn_stake = withdrawn_outcomes
+        .iter()
+        .try_fold(0_i128, |total, withdrawn_outcome| {
+            market_position
+                .market_outcome_stakes
+                .get(usize::from(withdrawn_outcome.outcome_index))
+                .and_then(|stake| total.checked_add(*stake))
+        })
+        .ok_or(CoreError::SettlementPaymentCalculation)?;
+    let scale = i128::from(WithdrawnOutcome::REDUCTION_FACTOR_SCALE);
+
+    let mut adjusted_position = market_position.clone();
+    for (index, market_outcome_sum) in adjusted_position.market_outcome_sums.iter_mut().enumerate()
+    {
+        *market_outcome_sum = market_position
+            .market_outcome_deductions
+            .get(index)
+            .and_then(|deduction| {
+                market_outcome_sum
+                    .checked_add(withdrawn_stake)?
+                    .checked_mul(scale)?
+                    .checked_sub(*deduction)
+            })
+            .map(|adjusted_sum| adjusted_sum.div_euclid(scale))
+            .ok_or(CoreError::SettlementPaymentCalculation)?;
+    }
+    Ok(adjusted_position)
 }
 
 fn enqueue_payment(payment_queue: &mut PaymentQueue, payment: &PaymentInfo) -> Result<()> {
@@ -156,13 +322,212 @@ fn available_profit_for_commission(
 #[cfg(test)]
 mod tests {
     use crate::instructions::market_position::settle_market_position::{
-        calculate_commission_for_risk_at_rate, calculate_product_commission_payments,
+        adjust_for_outcome_withdrawals, calculate_commission_for_risk_at_rate,
+        calculate_market_position_profit, calculate_position_profit,
+        calculate_position_profit_from_results, calculate_product_commission_payments,
+    };
+    use crate::state::market_account::{
+        mock_market, MarketStatus, MarketWinningOutcome, OutcomeResult, WithdrawnOutcome,
+    };
+    use crate::state::market_position_account::{
+        mock_market_position, MarketPosition, ProductMatchedRiskAndRate,
     };
-    use crate::state::market_position_account::{MarketPosition, ProductMatchedRiskAndRate};
     use crate::state::payments_queue::PaymentInfo;
     use protocol_product::state::product::Product;
     use solana_program::pubkey::Pubkey;
 
+    // calculate_position_profit
+
+    #[test]
+    fn position_profit_single_winner() {
+        let mut market_position = mock_market_position(3);
+        market_position.market_outcome_sums = vec![20, -10, -10]; // for 10 @ 3.0 on outcome 0
+
+        let winning_outcomes = vec![MarketWinningOutcome::new(0, 1, 1)];
+        let profit = calculate_position_profit(&market_position, 1, &winning_outcomes);
+        assert_eq!(Ok(20), profit);
+
+        let winning_outcomes = vec![MarketWinningOutcome::new(1, 1, 1)];
+        let profit = calculate_position_profit(&market_position, 1, &winning_outcomes);
+        assert_eq!(Ok(-10), profit);
+    }
+
+    #[test]
+    fn position_profit_dead_heat() {
+        let mut market_position = mock_market_position(3);
+        market_position.market_outcome_sums = vec![20, -10, -10]; // for 10 @ 3.0 on outcome 0
+
+        // half of the stake wins at 3.0 and half loses
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 2),
+            MarketWinningOutcome::new(1, 1, 2),
+        ];
+        let profit = calculate_position_profit(&market_position, 1, &winning_outcomes);
+        assert_eq!(Ok(5), profit);
+
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 3),
+            MarketWinningOutcome::new(1, 1, 3),
+            MarketWinningOutcome::new(2, 1, 3),
+        ];
+        let profit = calculate_position_profit(&market_position, 1, &winning_outcomes);
+        assert_eq!(Ok(0), profit);
+    }
+
+    #[test]
+    fn position_profit_dead_heat_rounded_down() {
+        let mut market_position = mock_market_position(3);
+        market_position.market_outcome_sums = vec![1, -1, 0];
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 2),
+            MarketWinningOutcome::new(2, 1, 2),
+        ];
+        let profit = calculate_position_profit(&market_position, 1, &winning_outcomes);
+        assert_eq!(Ok(0), profit);
+
+        market_position.market_outcome_sums = vec![-1, 1, 0];
+        let profit = calculate_position_profit(&market_position, 1, &winning_outcomes);
+        assert_eq!(Ok(-1), profit);
+    }
+
+    #[test]
+    fn position_profit_from_results() {
+        let mut market_position = mock_market_position(2);
+        market_position.market_outcome_sums = vec![20, -10]; // for 10 @ 3.0 on outcome 0
+
+        for (outcome_results, expected) in [
+            (vec![OutcomeResult::Win, OutcomeResult::Loss], 20),
+            (vec![OutcomeResult::HalfWin, OutcomeResult::HalfLoss], 10),
+            (vec![OutcomeResult::Push, OutcomeResult::Push], 0),
+            (vec![OutcomeResult::HalfLoss, OutcomeResult::HalfWin], -5),
+            (vec![OutcomeResult::Loss, OutcomeResult::Win], -10),
+        ] {
+            let profit = calculate_position_profit_from_results(&market_position, &outcome_results);
+            assert_eq!(Ok(expected), profit);
+        }
+    }
+
+    #[test]
+    fn position_profit_from_results_rounded_down() {
+        let mut market_position = mock_market_position(2);
+        market_position.market_outcome_sums = vec![-3, 1];
+
+        let outcome_results = vec![OutcomeResult::HalfWin, OutcomeResult::HalfLoss];
+        let profit = calculate_position_profit_from_results(&market_position, &outcome_results);
+        assert_eq!(Ok(-2), profit);
+    }
+
+    #[test]
+    fn position_profit_outcome_withdrawn() {
+        let withdrawn_outcomes = vec![WithdrawnOutcome {
+            outcome_index: 2,
+            reduction_factor: 2_000,
+            withdrawal_timestamp: 0,
+        }];
+        let mut market_position = mock_market_position(3);
+        // for 10 @ 3.0 on outcomes 0 and 2
+        market_position.market_outcome_sums = vec![10, -20, 10];
+        market_position.market_outcome_stakes = vec![10, 0, 10];
+        market_position
+            .apply_outcome_withdrawals(&withdrawn_outcomes)
+            .unwrap();
+        assert_eq!(1, market_position.outcome_withdrawals_applied);
+
+        let adjusted_position =
+            adjust_for_outcome_withdrawals(&market_position, &withdrawn_outcomes).unwrap();
+
+        // stake on outcome 2 returned and winnings of 20 on outcome 0 reduced by 20%
+        let winning_outcomes = vec![MarketWinningOutcome::new(0, 1, 1)];
+        let profit = calculate_position_profit(&adjusted_position, 1, &winning_outcomes);
+        assert_eq!(Ok(16), profit);
+
+        let winning_outcomes = vec![MarketWinningOutcome::new(1, 1, 1)];
+        let profit = calculate_position_profit(&adjusted_position, 1, &winning_outcomes);
+        assert_eq!(Ok(-10), profit);
+    }
+
+    #[test]
+    fn position_profit_outcome_withdrawn_after_match() {
+        let withdrawn_outcomes = vec![WithdrawnOutcome {
+            outcome_index: 2,
+            reduction_factor: 2_000,
+            withdrawal_timestamp: 0,
+        }];
+        let mut market_position = mock_market_position(3);
+        market_position
+            .apply_outcome_withdrawals(&withdrawn_outcomes)
+            .unwrap();
+        // against 10 @ 3.0 on outcome 0 matched after the withdrawal is not reduced
+        market_position.market_outcome_sums = vec![-20, 10, 10];
+        market_position.market_outcome_stakes = vec![-10, 0, 0];
+
+        let adjusted_position =
+            adjust_for_outcome_withdrawals(&market_position, &withdrawn_outcomes).unwrap();
+
+        let winning_outcomes = vec![MarketWinningOutcome::new(0, 1, 1)];
+        let profit = calculate_position_profit(&adjusted_position, 1, &winning_outcomes);
+        assert_eq!(Ok(-20), profit);
+    }
+
+    #[test]
+    fn position_profit_outcome_withdrawn_stakes_not_tracked() {
+        let mut market = mock_market(MarketStatus::ReadyForSettlement);
+        market.withdrawn_outcomes = vec![WithdrawnOutcome {
+            outcome_index: 2,
+            reduction_factor: 2_000,
+            withdrawal_timestamp: 0,
+        }];
+        market.market_winning_outcomes = vec![MarketWinningOutcome::new(0, 1, 1)];
+        // for 10 @ 3.0 on outcomes 0 and 2 matched before migration
+        let mut market_position = mock_market_position(3);
+        market_position.market_outcome_sums = vec![10, -20, 10];
+        market_position.market_outcome_stakes = vec![];
+        market_position.market_outcome_deductions = vec![];
+
+        let profit = calculate_market_position_profit(&market, &mut market_position);
+
+        assert_eq!(Ok(10), profit);
+        assert_eq!(1, market_position.outcome_withdrawals_applied);
+    }
+
+    #[test]
+    fn position_profit_multiple_winning_places() {
+        let mut market_position = mock_market_position(4);
+        // against 10 @ 2.0 on outcomes 0 and 1
+        market_position.market_outcome_sums = vec![0, 0, 20, 20];
+        market_position.no_winner_outcome_sum = 20;
+
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 1),
+            MarketWinningOutcome::new(1, 1, 1),
+        ];
+        let profit = calculate_position_profit(&market_position, 2, &winning_outcomes);
+        assert_eq!(Ok(-20), profit);
+
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 1),
+            MarketWinningOutcome::new(2, 1, 1),
+        ];
+        let profit = calculate_position_profit(&market_position, 2, &winning_outcomes);
+        assert_eq!(Ok(0), profit);
+
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(2, 1, 1),
+            MarketWinningOutcome::new(3, 1, 1),
+        ];
+        let profit = calculate_position_profit(&market_position, 2, &winning_outcomes);
+        assert_eq!(Ok(20), profit);
+
+        // outcome 0 placed, outcomes 1 and 2 tied for the last place
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 1),
+            MarketWinningOutcome::new(1, 1, 2),
+            MarketWinningOutcome::new(2, 1, 2),
+        ];
+        let profit = calculate_position_profit(&market_position, 2, &winning_outcomes);
+        assert_eq!(Ok(-10), profit);
+    }
+
     //  calculate_commission_at_rate
 
     #[test]
@@ -406,6 +771,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 10,
             matched_risk_per_product: product_matched_risk,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
         let position_profit = 100;
 
@@ -460,6 +832,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 10,
             matched_risk_per_product: matched_risk_for_product,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
         let position_profit = 100;
 
@@ -539,6 +918,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 20,
             matched_risk_per_product: product_matched_risks,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
         let position_profit = 400;
 
@@ -626,6 +1012,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 10,
             matched_risk_per_product: matched_risk_for_product,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
         let position_profit = 100;
         let (total_product_commission, payments) = calculate_product_commission_payments(
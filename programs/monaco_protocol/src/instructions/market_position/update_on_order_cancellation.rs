@@ -60,6 +60,12 @@ fn update_exposures(
                     .checked_sub(order_exposure)
                     .ok_or(CoreError::ArithmeticError)?;
             }
+            if market_position.winning_places > 1 {
+                market_position.no_winner_unmatched_exposure = market_position
+                    .no_winner_unmatched_exposure
+                    .checked_sub(order_exposure)
+                    .ok_or(CoreError::ArithmeticError)?;
+            }
         }
         false => {
             market_position.unmatched_exposures[outcome_index] = market_position
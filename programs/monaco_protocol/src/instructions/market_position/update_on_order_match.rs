@@ -2,13 +2,14 @@ use crate::error::CoreError;
 use crate::instructions::calculate_risk_from_stake;
 use crate::state::market_position_account::MarketPosition;
 use crate::state::order_account::*;
+use crate::state::price::Price;
 use anchor_lang::prelude::*;
 
 pub fn update_on_order_match(
     market_position: &mut MarketPosition,
     order: &Order,
     stake_matched: u64,
-    price_matched: f64,
+    price_matched: Price,
 ) -> Result<u64> {
     let total_exposure_before = market_position.total_exposure();
 
@@ -19,6 +20,14 @@ pub fn update_on_order_match(
     let unmatched_risk = calculate_risk_from_stake(stake_matched, price_unmatched);
     let matched_risk = calculate_risk_from_stake(stake_matched, price_matched);
 
+    market_position.update_outcome_stake(
+        outcome_index,
+        match for_outcome {
+            true => i128::from(stake_matched),
+            false => -i128::from(stake_matched),
+        },
+    )?;
+
     // update chosen outcome position
     match for_outcome {
         true => {
@@ -57,6 +66,18 @@ pub fn update_on_order_match(
             }
         }
     }
+    if market_position.winning_places > 1 {
+        market_position.no_winner_outcome_sum = match for_outcome {
+            true => market_position
+                .no_winner_outcome_sum
+                .checked_sub(stake_matched as i128)
+                .ok_or(CoreError::ArithmeticError)?,
+            false => market_position
+                .no_winner_outcome_sum
+                .checked_add(stake_matched as i128)
+                .ok_or(CoreError::ArithmeticError)?,
+        };
+    }
 
     // update unmatched_exposures
     match for_outcome {
@@ -71,6 +92,12 @@ pub fn update_on_order_match(
                     .checked_sub(stake_matched)
                     .ok_or(CoreError::ArithmeticError)?;
             }
+            if market_position.winning_places > 1 {
+                market_position.no_winner_unmatched_exposure = market_position
+                    .no_winner_unmatched_exposure
+                    .checked_sub(stake_matched)
+                    .ok_or(CoreError::ArithmeticError)?;
+            }
         }
         false => {
             market_position.unmatched_exposures[outcome_index] = market_position
@@ -97,7 +124,7 @@ mod tests {
 
     struct OrderData {
         outcome_index: usize,
-        price: f64,
+        price: Price,
         stake: u64,
         for_outcome: bool,
     }
@@ -106,123 +133,123 @@ mod tests {
     // Matching orders of the same outcome
     //
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 3.05, stake: 100, for_outcome: true},
-    OrderData{outcome_index: 0, price: 3.05, stake: 100, for_outcome: false}
+    OrderData{outcome_index: 0, price: Price::new(3_050), stake: 100, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(3_050), stake: 100, for_outcome: false}
     ]), vec![0,0,0] ; "For-Against: Same price and stakes")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 3.05, stake: 100, for_outcome: false},
-    OrderData{outcome_index: 0, price: 3.05, stake: 100, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(3_050), stake: 100, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(3_050), stake: 100, for_outcome: true}
     ]), vec![0,0,0] ; "Against-For: Same price and stakes")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 100, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake:  50, for_outcome: false}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 100, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake:  50, for_outcome: false}
     ]), vec![50,-50,-50] ; "For-Against: Same price, against stake is half")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake:  50, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0, stake: 100, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake:  50, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 100, for_outcome: true}
     ]), vec![50,-50,-50] ; "Against-For: Same price, against stake is half")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake:  50, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 100, for_outcome: false}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake:  50, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 100, for_outcome: false}
     ]), vec![-50,50,50] ; "For-Against: Same price, for stake is half")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 100, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0, stake:  50, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 100, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake:  50, for_outcome: true}
     ]), vec![-50,50,50] ; "Against-For: Same price, for stake is half")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 11.0, stake:  10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 100, for_outcome: false}
+    OrderData{outcome_index: 0, price: Price::new(11_000), stake:  10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 100, for_outcome: false}
     ]), vec![0,90,90] ; "For-Against: Diff price, same stake")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0,  stake: 100, for_outcome: false},
-    OrderData{outcome_index: 0, price: 11.0, stake:  10, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 100, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(11_000), stake:  10, for_outcome: true}
     ]), vec![0,90,90] ; "Against-For: Diff price, same stake")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 11.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 10, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 20, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 30, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 40, for_outcome: false}
+    OrderData{outcome_index: 0, price: Price::new(11_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 10, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 20, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 30, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 40, for_outcome: false}
     ]), vec![0,90,90] ; "For-Against: Diff price, same stake but split")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0,  stake: 10, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 20, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 30, for_outcome: false},
-    OrderData{outcome_index: 0, price: 2.0,  stake: 40, for_outcome: false},
-    OrderData{outcome_index: 0, price: 11.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 10, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 20, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 30, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(2_000),  stake: 40, for_outcome: false},
+    OrderData{outcome_index: 0, price: Price::new(11_000), stake: 10, for_outcome: true}
     ]), vec![0,90,90] ; "Against-For: Diff price, same stake but split")]
     //
     // Matching orders of different outcomes
     //
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![0,0,-20] ; "Same price (2.0), same stake, 2 different outcomes (0,1)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![0,0,-20] ; "Same price (2.0), same stake, 2 different outcomes (1,0)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-20,0,0] ; "Same price (2.0), same stake, 2 different outcomes (1,2)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-20,0,0] ; "Same price (2.0), same stake, 2 different outcomes (2,1)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![0,-20,0] ; "Same price (2.0), same stake, 2 different outcomes (0,2)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![0,-20,0] ; "Same price (2.0), same stake, 2 different outcomes (2,0)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-10,-10,-10] ; "Same price (2.0), same stake, 3 different outcomes (0,1,2)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-10,-10,-10] ; "Same price (2.0), same stake, 3 different outcomes (0,2,1)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-10,-10,-10] ; "Same price (2.0), same stake, 3 different outcomes (1,0,2)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-10,-10,-10] ; "Same price (2.0), same stake, 3 different outcomes (1,2,0)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-10,-10,-10] ; "Same price (2.0), same stake, 3 different outcomes (2,0,1)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 2, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 10, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 10, for_outcome: true}
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 10, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 10, for_outcome: true}
     ]), vec![-10,-10,-10] ; "Same price (2.0), same stake, 3 different outcomes (2,1,0)")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 1000000, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 1000000, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 1000000, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 1000000, for_outcome: false},
-    OrderData{outcome_index: 1, price: 2.0, stake: 1000000, for_outcome: false},
-    OrderData{outcome_index: 2, price: 2.0, stake: 1000000, for_outcome: false}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 1000000, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 1000000, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 1000000, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 1000000, for_outcome: false},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 1000000, for_outcome: false},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 1000000, for_outcome: false}
     ]), vec![0,0,0] ; "Same price, same stake, 3 different outcomes, then against them all to end up neutral")]
     #[test_case(Box::new([
-    OrderData{outcome_index: 0, price: 2.0, stake: 1000000, for_outcome: true},
-    OrderData{outcome_index: 0, price: 2.0, stake: 1000000, for_outcome: false},
-    OrderData{outcome_index: 1, price: 2.0, stake: 1000000, for_outcome: true},
-    OrderData{outcome_index: 1, price: 2.0, stake: 1000000, for_outcome: false},
-    OrderData{outcome_index: 2, price: 2.0, stake: 1000000, for_outcome: true},
-    OrderData{outcome_index: 2, price: 2.0, stake: 1000000, for_outcome: false}
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 1000000, for_outcome: true},
+    OrderData{outcome_index: 0, price: Price::new(2_000), stake: 1000000, for_outcome: false},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 1000000, for_outcome: true},
+    OrderData{outcome_index: 1, price: Price::new(2_000), stake: 1000000, for_outcome: false},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 1000000, for_outcome: true},
+    OrderData{outcome_index: 2, price: Price::new(2_000), stake: 1000000, for_outcome: false}
     ]), vec![0,0,0] ; "Same price, same stake, 3 different outcomes, for and against them in order to end up neutral")]
     fn test_update_on_match(orders: Box<[OrderData]>, expected_position: Vec<i128>) {
         let mut market_position = market_position(vec![0_i128; 3], vec![0_u64; 3]);
@@ -264,6 +291,45 @@ mod tests {
         assert_eq!(market_position.market_outcome_sums, expected_position);
     }
 
+    #[test]
+    fn test_update_on_match_multiple_winning_places() {
+        let mut market_position = market_position(vec![0_i128; 3], vec![0_u64; 3]);
+        market_position.winning_places = 2;
+
+        for (outcome_index, for_outcome) in [(0, false), (1, false), (2, true)] {
+            let order_request = mock_order_request(
+                Pubkey::new_unique(),
+                for_outcome,
+                outcome_index,
+                10,
+                Price::new(2_000),
+            );
+            market_position::update_on_order_request_creation(
+                &mut market_position,
+                order_request.market_outcome_index,
+                order_request.for_outcome,
+                order_request.stake,
+                order_request.expected_price,
+            )
+            .expect("not expecting failure");
+            let order = mock_order_from_order_request(
+                Pubkey::new_unique(),
+                order_request,
+                Pubkey::new_unique(),
+            );
+            update_on_order_match(&mut market_position, &order, 5, Price::new(2_000))
+                .expect("not expecting failure");
+        }
+
+        assert_eq!(market_position.market_outcome_sums, vec![-5, -5, 15]);
+        assert_eq!(market_position.no_winner_outcome_sum, 5);
+        assert_eq!(market_position.unmatched_exposures, vec![10, 10, 0]);
+        assert_eq!(market_position.no_winner_unmatched_exposure, 5);
+        assert_eq!(market_position.market_outcome_stakes, vec![-5, -5, 5]);
+        // outcomes 0 and 1 placing: all matched and unmatched stake/risk is lost
+        assert_eq!(market_position.total_exposure(), 30);
+    }
+
     fn market_position(
         market_outcome_sums: Vec<i128>,
         unmatched_exposures: Vec<u64>,
@@ -277,6 +343,13 @@ mod tests {
             payer: Pubkey::new_unique(),
             matched_risk_per_product: vec![],
             matched_risk: 0,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         }
     }
 }
@@ -2,23 +2,75 @@ use anchor_lang::prelude::*;
 
 use crate::error::CoreError;
 use crate::instructions::calculate_risk_from_stake;
+use crate::state::market_order_request_queue::OrderRequestData;
 use crate::state::market_position_account::MarketPosition;
+use crate::state::price::Price;
 
 pub fn update_on_order_request_creation(
     market_position: &mut MarketPosition,
     market_outcome_index: u16,
     for_outcome: bool,
     stake: u64,
-    expected_price: f64,
+    expected_price: Price,
 ) -> Result<u64> {
+    let total_exposure_before = market_position.total_exposure();
+
+    add_unmatched_exposure(
+        market_position,
+        market_outcome_index,
+        for_outcome,
+        stake,
+        expected_price,
+    )?;
+
+    // total_exposure_change change
+    let total_exposure_change = market_position
+        .total_exposure()
+        .checked_sub(total_exposure_before)
+        .ok_or(CoreError::ArithmeticError)?;
+
+    Ok(total_exposure_change)
+}
+
+// total exposure is only calculated once for the whole batch of order requests
+pub fn update_on_order_requests_creation(
+    market_position: &mut MarketPosition,
+    order_requests: &[OrderRequestData],
+) -> Result<u64> {
+    let total_exposure_before = market_position.total_exposure();
+
+    for order_request in order_requests {
+        add_unmatched_exposure(
+            market_position,
+            order_request.market_outcome_index,
+            order_request.for_outcome,
+            order_request.stake,
+            order_request.price,
+        )?;
+    }
+
+    // total_exposure_change change
+    let total_exposure_change = market_position
+        .total_exposure()
+        .checked_sub(total_exposure_before)
+        .ok_or(CoreError::ArithmeticError)?;
+
+    Ok(total_exposure_change)
+}
+
+fn add_unmatched_exposure(
+    market_position: &mut MarketPosition,
+    market_outcome_index: u16,
+    for_outcome: bool,
+    stake: u64,
+    expected_price: Price,
+) -> Result<()> {
     let outcome_index = market_outcome_index as usize;
     let order_exposure = match for_outcome {
         true => stake,
         false => calculate_risk_from_stake(stake, expected_price),
     };
 
-    let total_exposure_before = market_position.total_exposure();
-
     // update unmatched_exposures
     match for_outcome {
         true => {
@@ -32,6 +84,12 @@ pub fn update_on_order_request_creation(
                     .checked_add(order_exposure)
                     .ok_or(CoreError::ArithmeticError)?;
             }
+            if market_position.winning_places > 1 {
+                market_position.no_winner_unmatched_exposure = market_position
+                    .no_winner_unmatched_exposure
+                    .checked_add(order_exposure)
+                    .ok_or(CoreError::ArithmeticError)?;
+            }
         }
         false => {
             market_position.unmatched_exposures[outcome_index] = market_position
@@ -41,11 +99,88 @@ pub fn update_on_order_request_creation(
         }
     }
 
-    // total_exposure_change change
-    let total_exposure_change = market_position
-        .total_exposure()
-        .checked_sub(total_exposure_before)
-        .ok_or(CoreError::ArithmeticError)?;
+    Ok(())
+}
 
-    Ok(total_exposure_change)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_order_request_queue::TimeInForce;
+    use crate::state::market_position_account::mock_market_position;
+
+    fn order_request_data(
+        market_outcome_index: u16,
+        for_outcome: bool,
+        stake: u64,
+        price: Price,
+    ) -> OrderRequestData {
+        OrderRequestData {
+            market_outcome_index,
+            for_outcome,
+            stake,
+            price,
+            distinct_seed: [0; 16],
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+        }
+    }
+
+    #[test]
+    fn test_update_on_order_requests_creation() {
+        let mut market_position = mock_market_position(3);
+        let order_requests = vec![
+            order_request_data(0, true, 10, Price::new(2_000)),
+            order_request_data(1, false, 10, Price::new(3_000)),
+            order_request_data(2, true, 5, Price::new(2_000)),
+        ];
+
+        let result = update_on_order_requests_creation(&mut market_position, &order_requests);
+
+        assert_eq!(Ok(35), result);
+        assert_eq!(vec![5, 35, 10], market_position.unmatched_exposures);
+    }
+
+    #[test]
+    fn test_update_on_order_requests_creation_matches_individual_updates() {
+        let order_requests = vec![
+            order_request_data(0, true, 10, Price::new(2_000)),
+            order_request_data(0, false, 10, Price::new(2_000)),
+            order_request_data(1, true, 7, Price::new(4_500)),
+            order_request_data(2, false, 3, Price::new(1_500)),
+        ];
+
+        let mut market_position_batch = mock_market_position(3);
+        let batch_payment =
+            update_on_order_requests_creation(&mut market_position_batch, &order_requests).unwrap();
+
+        let mut market_position_single = mock_market_position(3);
+        let mut single_payment = 0_u64;
+        for order_request in &order_requests {
+            single_payment += update_on_order_request_creation(
+                &mut market_position_single,
+                order_request.market_outcome_index,
+                order_request.for_outcome,
+                order_request.stake,
+                order_request.price,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(single_payment, batch_payment);
+        assert_eq!(
+            market_position_single.unmatched_exposures,
+            market_position_batch.unmatched_exposures
+        );
+    }
+
+    #[test]
+    fn test_update_on_order_requests_creation_empty() {
+        let mut market_position = mock_market_position(3);
+
+        let result = update_on_order_requests_creation(&mut market_position, &[]);
+
+        assert_eq!(Ok(0), result);
+        assert_eq!(vec![0, 0, 0], market_position.unmatched_exposures);
+    }
 }
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::instructions::calculate_risk_from_stake;
+use crate::state::market_position_account::MarketPosition;
+use crate::state::order_account::Order;
+use crate::state::price::Price;
+
+// reverses the effect of matching stake_voided at price_matched for the order, returning the
+// exposure released by the void
+pub fn update_on_trade_void(
+    market_position: &mut MarketPosition,
+    order: &Order,
+    stake_voided: u64,
+    price_matched: Price,
+) -> Result<u64> {
+    let total_exposure_before = market_position.total_exposure();
+
+    let outcome_index = usize::from(order.market_outcome_index);
+    let risk_voided = calculate_risk_from_stake(stake_voided, price_matched);
+    let (outcome_change, other_outcomes_change) = match order.for_outcome {
+        true => (-i128::from(risk_voided), i128::from(stake_voided)),
+        false => (i128::from(risk_voided), -i128::from(stake_voided)),
+    };
+
+    market_position.update_outcome_stake(outcome_index, -other_outcomes_change)?;
+
+    for (index, market_outcome_sum) in market_position.market_outcome_sums.iter_mut().enumerate() {
+        let change = match index == outcome_index {
+            true => outcome_change,
+            false => other_outcomes_change,
+        };
+        *market_outcome_sum = market_outcome_sum
+            .checked_add(change)
+            .ok_or(CoreError::ArithmeticError)?;
+    }
+    if market_position.winning_places > 1 {
+        market_position.no_winner_outcome_sum = market_position
+            .no_winner_outcome_sum
+            .checked_add(other_outcomes_change)
+            .ok_or(CoreError::ArithmeticError)?;
+    }
+
+    let matched_risk_voided = match order.for_outcome {
+        true => stake_voided,
+        false => risk_voided,
+    };
+    market_position.matched_risk = market_position
+        .matched_risk
+        .checked_sub(matched_risk_voided)
+        .ok_or(CoreError::ArithmeticError)?;
+    // risk is only tracked per product while there is room, so may not have been recorded
+    if let Some(product_matched_risk) =
+        market_position
+            .matched_risk_per_product
+            .iter_mut()
+            .find(|product_matched_risk| {
+                Some(product_matched_risk.product) == order.product
+                    && product_matched_risk.rate == order.product_commission_rate
+            })
+    {
+        product_matched_risk.risk = product_matched_risk
+            .risk
+            .saturating_sub(matched_risk_voided);
+    }
+
+    // funds cannot be collected from the purchaser, so a void must not increase exposure
+    total_exposure_before
+        .checked_sub(market_position.total_exposure())
+        .ok_or_else(|| error!(CoreError::VoidTradeExposureIncrease))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::market_position::{
+        update_on_order_match, update_on_order_request_creation,
+        update_product_commission_contributions,
+    };
+    use crate::state::market_position_account::{mock_market_position, ProductMatchedRiskAndRate};
+    use crate::state::order_account::mock_order;
+
+    fn matched_position(order: &Order, price_matched: Price) -> MarketPosition {
+        let mut market_position = mock_market_position(3);
+        update_on_order_request_creation(
+            &mut market_position,
+            order.market_outcome_index,
+            order.for_outcome,
+            order.stake,
+            order.expected_price,
+        )
+        .expect("test setup");
+        update_on_order_match(&mut market_position, order, order.stake, price_matched)
+            .expect("test setup");
+        update_product_commission_contributions(
+            &mut market_position,
+            order,
+            match order.for_outcome {
+                true => order.stake,
+                false => calculate_risk_from_stake(order.stake, price_matched),
+            },
+        )
+        .expect("test setup");
+        market_position
+    }
+
+    #[test]
+    fn void_for_trade() {
+        let mut order = mock_order(
+            Pubkey::new_unique(),
+            1,
+            true,
+            Price::new(3_000),
+            100,
+            Pubkey::new_unique(),
+        );
+        order.product = Some(Pubkey::new_unique());
+        let mut market_position = matched_position(&order, Price::new(3_000));
+        assert_eq!(vec![-100, 200, -100], market_position.market_outcome_sums);
+
+        let refund =
+            update_on_trade_void(&mut market_position, &order, 40, Price::new(3_000)).unwrap();
+
+        assert_eq!(40, refund);
+        assert_eq!(vec![-60, 120, -60], market_position.market_outcome_sums);
+        assert_eq!(60, market_position.matched_risk);
+        assert_eq!(60, market_position.matched_risk_per_product[0].risk);
+    }
+
+    #[test]
+    fn void_against_trade() {
+        let order = mock_order(
+            Pubkey::new_unique(),
+            1,
+            false,
+            Price::new(3_000),
+            100,
+            Pubkey::new_unique(),
+        );
+        let mut market_position = matched_position(&order, Price::new(3_000));
+        assert_eq!(vec![100, -200, 100], market_position.market_outcome_sums);
+
+        let refund =
+            update_on_trade_void(&mut market_position, &order, 100, Price::new(3_000)).unwrap();
+
+        assert_eq!(200, refund);
+        assert_eq!(vec![0, 0, 0], market_position.market_outcome_sums);
+        assert_eq!(0, market_position.matched_risk);
+        assert_eq!(0, market_position.total_exposure());
+    }
+
+    #[test]
+    fn void_untracked_product_risk() {
+        let mut order = mock_order(
+            Pubkey::new_unique(),
+            0,
+            true,
+            Price::new(2_000),
+            100,
+            Pubkey::new_unique(),
+        );
+        order.product = Some(Pubkey::new_unique());
+        let mut market_position = matched_position(&order, Price::new(2_000));
+        market_position.matched_risk_per_product = vec![ProductMatchedRiskAndRate {
+            product: Pubkey::new_unique(),
+            rate: order.product_commission_rate,
+            risk: 50,
+        }];
+
+        let refund =
+            update_on_trade_void(&mut market_position, &order, 100, Price::new(2_000)).unwrap();
+
+        assert_eq!(100, refund);
+        assert_eq!(50, market_position.matched_risk_per_product[0].risk);
+    }
+
+    #[test]
+    fn void_hedged_trade_increases_exposure() {
+        let for_order = mock_order(
+            Pubkey::new_unique(),
+            0,
+            true,
+            Price::new(2_000),
+            100,
+            Pubkey::new_unique(),
+        );
+        let against_order = mock_order(
+            Pubkey::new_unique(),
+            0,
+            false,
+            Price::new(2_000),
+            100,
+            Pubkey::new_unique(),
+        );
+        let mut market_position = matched_position(&for_order, Price::new(2_000));
+        update_on_order_request_creation(&mut market_position, 0, false, 100, Price::new(2_000))
+            .expect("test setup");
+        update_on_order_match(&mut market_position, &against_order, 100, Price::new(2_000))
+            .expect("test setup");
+        update_product_commission_contributions(&mut market_position, &against_order, 100)
+            .expect("test setup");
+        assert_eq!(0, market_position.total_exposure());
+
+        let result =
+            update_on_trade_void(&mut market_position, &against_order, 100, Price::new(2_000));
+
+        assert_eq!(Err(error!(CoreError::VoidTradeExposureIncrease)), result);
+    }
+}
@@ -73,6 +73,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 0,
             matched_risk_per_product: vec![],
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         update_product_commission_contributions(&mut market_position, &order, stake_matched)
@@ -236,6 +243,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 0,
             matched_risk_per_product: matched_stake_per_rate.clone(),
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         update_product_commission_contributions(&mut market_position, &order, stake_matched)
@@ -273,6 +287,13 @@ mod tests {
             unmatched_exposures: vec![],
             payer: Default::default(),
             matched_risk: 0,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         update_product_commission_contributions(&mut market_position, &order, stake_matched)
@@ -331,6 +352,13 @@ mod tests {
             unmatched_exposures: vec![],
             payer: Default::default(),
             matched_risk: 0,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         update_product_commission_contributions(&mut market_position, &order, new_stake_matched)
@@ -387,6 +415,13 @@ mod tests {
             unmatched_exposures: vec![],
             payer: Default::default(),
             matched_risk: 0,
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         update_product_commission_contributions(
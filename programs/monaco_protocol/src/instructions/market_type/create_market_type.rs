@@ -1,4 +1,4 @@
-use crate::state::market_type::MarketType;
+use crate::state::market_type::{MarketTemplate, MarketType};
 use crate::CoreError;
 use anchor_lang::{require, Result};
 
@@ -7,14 +7,21 @@ pub fn create_market_type(
     name: String,
     requires_discriminator: bool,
     requires_value: bool,
+    template: MarketTemplate,
 ) -> Result<()> {
     require!(
         name.len() <= MarketType::NAME_MAX_LENGTH,
         CoreError::MarketTypeNameTooLong
     );
+    require!(
+        template == MarketTemplate::Standard || requires_value,
+        CoreError::MarketTypeTemplateRequiresValue
+    );
     market_type.name = name;
     market_type.requires_discriminator = requires_discriminator;
     market_type.requires_value = requires_value;
+    market_type.template = template;
+    market_type.schema_version = MarketType::SCHEMA_VERSION;
     Ok(())
 }
 
@@ -27,23 +34,47 @@ mod tests {
     fn test_create_market_type_success() {
         let expected_name = String::from("EventResultFullTime");
         let mut market_type = test_market_type();
-        let mut result = create_market_type(&mut market_type, expected_name.clone(), false, false);
+        let mut result = create_market_type(
+            &mut market_type,
+            expected_name.clone(),
+            false,
+            false,
+            MarketTemplate::Standard,
+        );
         assert!(result.is_ok());
         assert_eq!(market_type.name, expected_name);
         assert!(!market_type.requires_discriminator);
         assert!(!market_type.requires_value);
 
-        result = create_market_type(&mut market_type, expected_name.clone(), true, false);
+        result = create_market_type(
+            &mut market_type,
+            expected_name.clone(),
+            true,
+            false,
+            MarketTemplate::Standard,
+        );
         assert!(result.is_ok());
         assert!(market_type.requires_discriminator);
         assert!(!market_type.requires_value);
 
-        result = create_market_type(&mut market_type, expected_name.clone(), false, true);
+        result = create_market_type(
+            &mut market_type,
+            expected_name.clone(),
+            false,
+            true,
+            MarketTemplate::Standard,
+        );
         assert!(result.is_ok());
         assert!(!market_type.requires_discriminator);
         assert!(market_type.requires_value);
 
-        result = create_market_type(&mut market_type, expected_name.clone(), true, true);
+        result = create_market_type(
+            &mut market_type,
+            expected_name.clone(),
+            true,
+            true,
+            MarketTemplate::Standard,
+        );
         assert!(result.is_ok());
         assert!(market_type.requires_discriminator);
         assert!(market_type.requires_value);
@@ -53,7 +84,13 @@ mod tests {
     fn test_create_market_type_name_too_long() {
         let expected_name = "a".repeat(MarketType::NAME_MAX_LENGTH + 1).to_string();
         let mut market_type = test_market_type();
-        let result = create_market_type(&mut market_type, expected_name.clone(), false, false);
+        let result = create_market_type(
+            &mut market_type,
+            expected_name.clone(),
+            false,
+            false,
+            MarketTemplate::Standard,
+        );
         assert!(result.is_err());
         assert_eq!(
             result.err().unwrap(),
@@ -61,11 +98,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_market_type_template() {
+        let mut market_type = test_market_type();
+        let result = create_market_type(
+            &mut market_type,
+            "AsianHandicap".to_string(),
+            false,
+            true,
+            MarketTemplate::Handicap,
+        );
+        assert!(result.is_ok());
+        assert_eq!(MarketTemplate::Handicap, market_type.template);
+
+        let result = create_market_type(
+            &mut market_type,
+            "TotalGoals".to_string(),
+            false,
+            false,
+            MarketTemplate::Total,
+        );
+        assert_eq!(
+            Err(error!(CoreError::MarketTypeTemplateRequiresValue)),
+            result
+        );
+    }
+
     fn test_market_type() -> MarketType {
         MarketType {
             name: "".to_string(),
             requires_discriminator: false,
             requires_value: false,
+            template: MarketTemplate::Standard,
+            schema_version: MarketType::SCHEMA_VERSION,
         }
     }
 }
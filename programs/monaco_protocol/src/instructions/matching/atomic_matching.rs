@@ -0,0 +1,296 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::error::CoreError;
+use crate::instructions::market_liquidities::create_program_account;
+use crate::instructions::market_order_book::remove_from_market_order_book;
+use crate::instructions::matching::{on_order_match_maker, on_order_match_taker};
+use crate::instructions::transfer;
+use crate::state::market_account::Market;
+use crate::state::market_matching_pool_account::MarketMatchingPool;
+use crate::state::market_matching_queue_account::{MarketMatchingQueue, OrderMatch};
+use crate::state::market_order_book::MarketOrderBook;
+use crate::state::market_position_account::MarketPosition;
+use crate::state::order_account::Order;
+use crate::state::price::Price;
+use crate::state::trade_account::Trade;
+
+pub fn find_market_matching_pool_address(
+    market_pk: &Pubkey,
+    market_outcome_index: u16,
+    price: &Price,
+    for_outcome: bool,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            market_pk.as_ref(),
+            market_outcome_index.to_string().as_ref(),
+            b"-".as_ref(),
+            price.to_string().as_ref(),
+            for_outcome.to_string().as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_market_position_address(purchaser_pk: &Pubkey, market_pk: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[purchaser_pk.as_ref(), market_pk.as_ref()], &crate::ID)
+}
+
+// taker trades are seeded with the index of the match, maker trades with the taker order, so
+// trade addresses are known to clients before the transaction is sent
+pub fn atomic_taker_trade_seed(match_index: u64) -> [u8; 16] {
+    let mut seed = [0_u8; 16];
+    seed[..8].copy_from_slice(&match_index.to_le_bytes());
+    seed
+}
+
+pub fn atomic_maker_trade_seed(taker_order_pk: &Pubkey) -> [u8; 16] {
+    let mut seed = [0_u8; 16];
+    seed.copy_from_slice(&taker_order_pk.as_ref()[..16]);
+    seed
+}
+
+pub struct AtomicMatchingAccounts<'a, 'info> {
+    pub accounts: &'info [AccountInfo<'info>],
+    pub market_escrow: &'a Account<'info, TokenAccount>,
+    pub payer: &'a Signer<'info>,
+    pub system_program: &'a Program<'info, System>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+// completes every match queued by the taker order within the same transaction, so the matching
+// queue is left empty; maker orders, their market positions, matching pools, purchaser token
+// accounts and trade accounts are all looked up by address in the accounts given
+pub fn complete_order_matches<'info>(
+    atomic_accounts: &AtomicMatchingAccounts<'_, 'info>,
+    market: &mut Account<'info, Market>,
+    market_matching_queue: &mut MarketMatchingQueue,
+    mut market_order_book: Option<&mut MarketOrderBook>,
+    taker_order_pk: &Pubkey,
+    taker_order: &mut Order,
+    taker_market_position: &mut MarketPosition,
+) -> Result<()> {
+    let market_pk = market.key();
+    let mut taker_match_index = 0_u64;
+
+    while let Some(order_match) = market_matching_queue.matches.peek().copied() {
+        match order_match.pk {
+            Some(order_pk) => {
+                require!(
+                    order_pk == *taker_order_pk,
+                    CoreError::MatchingQueueHeadMismatch
+                );
+                let mut trade = Trade::default();
+                on_order_match_taker(
+                    &market_pk,
+                    market,
+                    market_matching_queue,
+                    taker_order_pk,
+                    taker_order,
+                    &mut trade,
+                    atomic_accounts.payer.key,
+                )?;
+                create_trade_account(
+                    atomic_accounts,
+                    taker_order_pk,
+                    &atomic_taker_trade_seed(taker_match_index),
+                    &trade,
+                )?;
+                taker_match_index += 1;
+            }
+            None => complete_maker_match(
+                atomic_accounts,
+                market,
+                market_matching_queue,
+                market_order_book.as_deref_mut(),
+                &order_match,
+                taker_order_pk,
+                taker_order,
+                taker_market_position,
+            )?,
+        }
+    }
+
+    Ok(())
+}
+
+// a maker match is filled by as many orders from the head of its matching pool as needed
+#[allow(clippy::too_many_arguments)]
+fn complete_maker_match<'info>(
+    atomic_accounts: &AtomicMatchingAccounts<'_, 'info>,
+    market: &mut Account<'info, Market>,
+    market_matching_queue: &mut MarketMatchingQueue,
+    mut market_order_book: Option<&mut MarketOrderBook>,
+    order_match: &OrderMatch,
+    taker_order_pk: &Pubkey,
+    taker_order: &Order,
+    taker_market_position: &mut MarketPosition,
+) -> Result<()> {
+    let market_pk = market.key();
+    let (market_matching_pool_pk, _) = find_market_matching_pool_address(
+        &market_pk,
+        order_match.outcome_index,
+        &order_match.price,
+        order_match.for_outcome,
+    );
+    let mut market_matching_pool = Account::<MarketMatchingPool>::try_from(find_account(
+        atomic_accounts.accounts,
+        &market_matching_pool_pk,
+    )?)?;
+    require!(
+        market_matching_pool.market == market_pk,
+        CoreError::MatchingMarketMismatch
+    );
+
+    while market_matching_queue
+        .matches
+        .peek()
+        .is_some_and(|head| is_same_maker_match(head, order_match))
+    {
+        let order_pk = *market_matching_pool
+            .orders
+            .peek(0)
+            .ok_or(CoreError::MatchingPoolHeadMismatch)?;
+        let mut order =
+            Account::<Order>::try_from(find_account(atomic_accounts.accounts, &order_pk)?)?;
+        require!(order.market == market_pk, CoreError::MatchingMarketMismatch);
+
+        let stake_unmatched = order.stake_unmatched;
+        let mut trade = Trade::default();
+        // the taker's own position is already loaded and must not be loaded a second time
+        let refund = if order.purchaser == taker_order.purchaser {
+            on_order_match_maker(
+                market,
+                market_matching_queue,
+                &mut market_matching_pool,
+                &order_pk,
+                &mut order,
+                taker_market_position,
+                &mut trade,
+                atomic_accounts.payer.key,
+            )?
+        } else {
+            let (market_position_pk, _) =
+                find_market_position_address(&order.purchaser, &market_pk);
+            let mut market_position = Account::<MarketPosition>::try_from(find_account(
+                atomic_accounts.accounts,
+                &market_position_pk,
+            )?)?;
+            let refund = on_order_match_maker(
+                market,
+                market_matching_queue,
+                &mut market_matching_pool,
+                &order_pk,
+                &mut order,
+                &mut market_position,
+                &mut trade,
+                atomic_accounts.payer.key,
+            )?;
+            market_position.exit(&crate::ID)?;
+            refund
+        };
+        remove_from_market_order_book(
+            market,
+            market_order_book.as_deref_mut(),
+            &order,
+            stake_unmatched.saturating_sub(order.stake_unmatched),
+        )?;
+        create_trade_account(
+            atomic_accounts,
+            &order_pk,
+            &atomic_maker_trade_seed(taker_order_pk),
+            &trade,
+        )?;
+
+        let purchaser_token = Account::<TokenAccount>::try_from(find_account(
+            atomic_accounts.accounts,
+            &get_associated_token_address(&order.purchaser, &market.mint_account),
+        )?)?;
+        transfer::transfer_from_market_escrow(
+            atomic_accounts.market_escrow,
+            &purchaser_token,
+            atomic_accounts.token_program,
+            market,
+            refund,
+        )?;
+
+        order.exit(&crate::ID)?;
+    }
+
+    market_matching_pool.exit(&crate::ID)
+}
+
+fn is_same_maker_match(head: &OrderMatch, order_match: &OrderMatch) -> bool {
+    head.pk.is_none()
+        && head.outcome_index == order_match.outcome_index
+        && head.for_outcome == order_match.for_outcome
+        && head.price == order_match.price
+}
+
+fn find_account<'info>(
+    accounts: &'info [AccountInfo<'info>],
+    address: &Pubkey,
+) -> Result<&'info AccountInfo<'info>> {
+    accounts
+        .iter()
+        .find(|account| account.key == address)
+        .ok_or(error!(CoreError::MatchingAtomicAccountNotProvided))
+}
+
+fn create_trade_account<'info>(
+    atomic_accounts: &AtomicMatchingAccounts<'_, 'info>,
+    order_pk: &Pubkey,
+    order_trade_seed: &[u8; 16],
+    trade: &Trade,
+) -> Result<()> {
+    let (trade_pk, bump) =
+        Pubkey::find_program_address(&[order_pk.as_ref(), order_trade_seed], &crate::ID);
+    let account = find_account(atomic_accounts.accounts, &trade_pk)?;
+
+    create_program_account(
+        account,
+        &[order_pk.as_ref(), order_trade_seed, &[bump]],
+        Trade::SIZE,
+        atomic_accounts.payer,
+        atomic_accounts.system_program,
+    )?;
+    trade.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_matching_queue_account::MatchingQueue;
+
+    #[test]
+    fn test_atomic_trade_seeds_are_distinct() {
+        let taker_order_pk = Pubkey::new_unique();
+
+        assert_ne!(atomic_taker_trade_seed(0), atomic_taker_trade_seed(1));
+        assert_eq!(
+            taker_order_pk.as_ref()[..16],
+            atomic_maker_trade_seed(&taker_order_pk)
+        );
+    }
+
+    #[test]
+    fn test_is_same_maker_match() {
+        let order_match = OrderMatch::maker(true, 0, Price::new(3_000), 10);
+        let mut queue = MatchingQueue::new(4);
+        queue.enqueue(OrderMatch::maker(true, 0, Price::new(3_000), 5));
+        queue.enqueue(OrderMatch::maker(true, 0, Price::new(2_800), 5));
+        queue.enqueue(OrderMatch::taker(
+            Pubkey::new_unique(),
+            true,
+            0,
+            Price::new(3_000),
+            5,
+        ));
+
+        assert!(is_same_maker_match(queue.dequeue().unwrap(), &order_match));
+        assert!(!is_same_maker_match(queue.dequeue().unwrap(), &order_match));
+        assert!(!is_same_maker_match(queue.dequeue().unwrap(), &order_match));
+    }
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::state::price::Price;
 use crate::state::trade_account::Trade;
 
 pub fn create_trade(
@@ -10,9 +11,10 @@ pub fn create_trade(
     outcome_index: u16,
     for_outcome: bool,
     stake: u64,
-    price: f64,
+    price: Price,
     creation_timestamp: i64,
     payer: Pubkey,
+    match_id: Pubkey,
 ) {
     trade.purchaser = *purchaser_pk;
     trade.market = *market_pk;
@@ -23,4 +25,5 @@ pub fn create_trade(
     trade.price = price;
     trade.creation_timestamp = creation_timestamp;
     trade.payer = payer;
+    trade.match_id = match_id;
 }
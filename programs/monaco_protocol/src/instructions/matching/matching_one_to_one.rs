@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::context::MatchOrders;
 use crate::error::CoreError;
+use crate::events::order::OrderMatchedEvent;
 use crate::events::trade::TradeEvent;
 use crate::instructions::market_position::update_product_commission_contributions;
 use crate::instructions::matching::create_trade::create_trade;
@@ -32,6 +33,12 @@ pub fn match_orders(ctx: &mut Context<MatchOrders>) -> Result<()> {
         order_for.market_outcome_index == order_against.market_outcome_index,
         CoreError::MatchingMarketOutcomeMismatch
     );
+    require!(
+        !ctx.accounts
+            .market
+            .is_outcome_withdrawn(order_for.market_outcome_index),
+        CoreError::MatchingMarketOutcomeWithdrawn
+    );
 
     require!(
         order_for.expected_price <= order_against.expected_price,
@@ -53,6 +60,8 @@ pub fn match_orders(ctx: &mut Context<MatchOrders>) -> Result<()> {
 
     let market_position_against = &mut ctx.accounts.market_position_against;
     let market_position_for = &mut ctx.accounts.market_position_for;
+    market_position_against.apply_outcome_withdrawals(&ctx.accounts.market.withdrawn_outcomes)?;
+    market_position_for.apply_outcome_withdrawals(&ctx.accounts.market.withdrawn_outcomes)?;
     // for orders from the same purchaser market-position passed is the same account
     let market_position_identical = market_position_against.key() == market_position_for.key();
 
@@ -145,6 +154,7 @@ pub fn match_orders(ctx: &mut Context<MatchOrders>) -> Result<()> {
 
     // 5. Initialize the trade accounts
     let now = current_timestamp();
+    let match_id = ctx.accounts.trade_for.key();
     create_trade(
         &mut ctx.accounts.trade_against,
         &ctx.accounts.order_against.purchaser,
@@ -156,6 +166,7 @@ pub fn match_orders(ctx: &mut Context<MatchOrders>) -> Result<()> {
         selected_price,
         now,
         ctx.accounts.crank_operator.key(),
+        match_id,
     );
     ctx.accounts.market.increment_unclosed_accounts_count()?;
     create_trade(
@@ -169,6 +180,7 @@ pub fn match_orders(ctx: &mut Context<MatchOrders>) -> Result<()> {
         selected_price,
         now,
         ctx.accounts.crank_operator.key(),
+        match_id,
     );
     ctx.accounts.market.increment_unclosed_accounts_count()?;
 
@@ -178,6 +190,23 @@ pub fn match_orders(ctx: &mut Context<MatchOrders>) -> Result<()> {
         market: ctx.accounts.market.key(),
     });
 
+    // the earlier order rested on the book, so it is the maker and set the price
+    let (maker_order, taker_order) = match ctx.accounts.order_for.creation_timestamp
+        < ctx.accounts.order_against.creation_timestamp
+    {
+        true => (&ctx.accounts.order_for, &ctx.accounts.order_against),
+        false => (&ctx.accounts.order_against, &ctx.accounts.order_for),
+    };
+    emit!(OrderMatchedEvent {
+        market: ctx.accounts.market.key(),
+        maker_order: maker_order.key(),
+        taker_order: Some(taker_order.key()),
+        market_outcome_index: maker_order.market_outcome_index,
+        for_outcome: maker_order.for_outcome,
+        price: selected_price,
+        stake: stake_matched,
+    });
+
     Ok(())
 }
 
@@ -186,6 +215,11 @@ fn copy_market_position(from: &MarketPosition, to: &mut MarketPosition) {
         to.market_outcome_sums[index] = from.market_outcome_sums[index];
         to.unmatched_exposures[index] = from.unmatched_exposures[index];
     }
+    to.no_winner_outcome_sum = from.no_winner_outcome_sum;
+    to.no_winner_unmatched_exposure = from.no_winner_unmatched_exposure;
+    to.market_outcome_stakes = from.market_outcome_stakes.clone();
+    to.market_outcome_deductions = from.market_outcome_deductions.clone();
+    to.outcome_withdrawals_applied = from.outcome_withdrawals_applied;
 }
 
 fn copy_product_commission_contributions(from: &MarketPosition, to: &mut MarketPosition) {
@@ -34,7 +34,8 @@ pub fn update_on_match(
 
 pub fn update_matching_pool_with_new_order(
     market_matching_pool: &mut MarketMatchingPool,
-    order_account: &Account<Order>,
+    order_pk: &Pubkey,
+    order_account: &Order,
 ) -> Result<()> {
     market_matching_pool.liquidity_amount = market_matching_pool
         .liquidity_amount
@@ -54,7 +55,7 @@ pub fn update_matching_pool_with_new_order(
     if order_account.stake_unmatched > 0 {
         market_matching_pool
             .orders
-            .enqueue(order_account.key())
+            .enqueue(*order_pk)
             .ok_or(CoreError::MatchingQueueIsFull)?;
     }
 
@@ -1,9 +1,11 @@
+pub mod atomic_matching;
 pub mod create_trade;
 pub mod matching_one_to_one;
 pub mod matching_pool;
 pub mod on_order_creation;
 pub mod on_order_match;
 
+pub use atomic_matching::*;
 pub use matching_one_to_one::*;
 pub use matching_pool::*;
 pub use on_order_creation::*;
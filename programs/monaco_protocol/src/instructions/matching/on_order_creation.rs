@@ -3,12 +3,10 @@ use anchor_lang::prelude::*;
 use crate::error::CoreError;
 use crate::error::CoreError::MatchingQueueIsFull;
 use crate::instructions::calculate_stake_cross;
-use crate::state::market_liquidities::MarketLiquidities;
+use crate::state::market_liquidities::{MarketLiquidities, MarketOutcomePriceLiquidity};
 use crate::state::market_matching_queue_account::*;
 use crate::state::order_account::*;
-
-#[cfg(test)]
-use crate::state::market_liquidities::MarketOutcomePriceLiquidity;
+use crate::state::price::Price;
 
 pub const MATCH_CAPACITY: usize = 10_usize; // an arbitrary number
 
@@ -17,7 +15,7 @@ pub fn on_order_creation(
     market_matching_queue: &mut MarketMatchingQueue,
     order_pk: &Pubkey,
     order: &mut Order,
-) -> Result<Vec<(u64, f64)>> {
+) -> Result<Vec<(u64, Price)>> {
     match order.for_outcome {
         true => match_for_order(market_liquidities, market_matching_queue, order_pk, order),
         false => match_against_order(market_liquidities, market_matching_queue, order_pk, order),
@@ -29,7 +27,7 @@ fn match_for_order(
     market_matching_queue: &mut MarketMatchingQueue,
     order_pk: &Pubkey,
     order: &mut Order,
-) -> Result<Vec<(u64, f64)>> {
+) -> Result<Vec<(u64, Price)>> {
     let mut order_matches = Vec::with_capacity(MATCH_CAPACITY);
     let order_outcome = order.market_outcome_index;
 
@@ -163,7 +161,7 @@ fn match_against_order(
     market_matching_queue: &mut MarketMatchingQueue,
     order_pk: &Pubkey,
     order: &mut Order,
-) -> Result<Vec<(u64, f64)>> {
+) -> Result<Vec<(u64, Price)>> {
     let mut order_matches = Vec::with_capacity(MATCH_CAPACITY);
     let order_outcome = order.market_outcome_index;
 
@@ -293,6 +291,218 @@ fn match_against_order(
         .collect())
 }
 
+// walks the best liquidity on the other side of the book one price at a time, re-pricing the cross
+// liquidity of the order's outcome from the best liquidity of every other outcome before each step,
+// cross prices are rounded onto ladder_prices, the prices of the order's outcome
+pub fn on_order_creation_with_price_improvement(
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_queue: &mut MarketMatchingQueue,
+    order_pk: &Pubkey,
+    order: &mut Order,
+    market_outcomes_count: u16,
+    ladder_prices: &[Price],
+) -> Result<Vec<(u64, Price)>> {
+    let mut order_matches = Vec::with_capacity(MATCH_CAPACITY);
+    let order_outcome = order.market_outcome_index;
+
+    while order.stake_unmatched > 0_u64 && order_matches.len() < order_matches.capacity() {
+        if market_liquidities.enable_cross_matching {
+            match order.for_outcome {
+                true => market_liquidities.update_best_cross_liquidity_against(
+                    order_outcome,
+                    market_outcomes_count,
+                    ladder_prices,
+                ),
+                false => market_liquidities.update_best_cross_liquidity_for(
+                    order_outcome,
+                    market_outcomes_count,
+                    ladder_prices,
+                ),
+            }
+        }
+
+        // FOR order matches AGAINST liquidity and vice versa, best price first
+        let liquidity = match order.for_outcome {
+            true => market_liquidities
+                .liquidities_against
+                .iter()
+                .find(|element| element.outcome == order_outcome)
+                .filter(|element| element.price >= order.expected_price),
+            false => market_liquidities
+                .liquidities_for
+                .iter()
+                .find(|element| element.outcome == order_outcome)
+                .filter(|element| element.price <= order.expected_price),
+        };
+        let liquidity = match liquidity {
+            Some(liquidity) => liquidity.clone(),
+            None => break,
+        };
+
+        let liquidity_value = match (liquidity.sources.is_empty(), order.for_outcome) {
+            (true, _) => liquidity.liquidity,
+            (false, true) => {
+                market_liquidities.get_cross_liquidity_against(&liquidity.sources, liquidity.price)
+            }
+            (false, false) => {
+                market_liquidities.get_cross_liquidity_for(&liquidity.sources, liquidity.price)
+            }
+        };
+        let stake_matched = liquidity_value.min(order.stake_unmatched);
+
+        if stake_matched > 0_u64 {
+            enqueue_order_matches(
+                market_matching_queue,
+                order_pk,
+                order,
+                &liquidity,
+                stake_matched,
+            )?;
+            order
+                .match_stake_unmatched(stake_matched, liquidity.price)
+                .map_err(|_| CoreError::MatchingPayoutAmountError)?;
+            remove_matched_liquidity(market_liquidities, order, &liquidity, stake_matched)?;
+            market_liquidities.update_stake_matched_total(stake_matched)?;
+            order_matches.push((stake_matched, liquidity.price));
+        } else {
+            // stale cross liquidity is dropped so the walk moves on to the next price
+            remove_matched_liquidity(market_liquidities, order, &liquidity, 0_u64)?;
+        }
+    }
+
+    // remainder is added to liquidities
+    if order.stake_unmatched > 0_u64 {
+        match order.for_outcome {
+            true => market_liquidities.add_liquidity_for(
+                order_outcome,
+                order.expected_price,
+                order.stake_unmatched,
+            )?,
+            false => market_liquidities.add_liquidity_against(
+                order_outcome,
+                order.expected_price,
+                order.stake_unmatched,
+            )?,
+        }
+    }
+
+    Ok(order_matches)
+}
+
+fn enqueue_order_matches(
+    market_matching_queue: &mut MarketMatchingQueue,
+    order_pk: &Pubkey,
+    order: &Order,
+    liquidity: &MarketOutcomePriceLiquidity,
+    stake_matched: u64,
+) -> Result<()> {
+    if liquidity.sources.is_empty() {
+        // direct match
+        market_matching_queue
+            .matches
+            .enqueue(OrderMatch::maker(
+                !order.for_outcome,
+                order.market_outcome_index,
+                liquidity.price,
+                stake_matched,
+            ))
+            .ok_or(MatchingQueueIsFull)?;
+    } else {
+        // cross match
+        for liquidity_source in &liquidity.sources {
+            market_matching_queue
+                .matches
+                .enqueue(OrderMatch::maker(
+                    order.for_outcome,
+                    liquidity_source.outcome,
+                    liquidity_source.price,
+                    calculate_stake_cross(stake_matched, liquidity.price, liquidity_source.price),
+                ))
+                .ok_or(MatchingQueueIsFull)?;
+        }
+    }
+
+    // record taker match
+    market_matching_queue
+        .matches
+        .enqueue(OrderMatch::taker(
+            *order_pk,
+            order.for_outcome,
+            order.market_outcome_index,
+            liquidity.price,
+            stake_matched,
+        ))
+        .ok_or(MatchingQueueIsFull)?;
+    Ok(())
+}
+
+fn remove_matched_liquidity(
+    market_liquidities: &mut MarketLiquidities,
+    order: &Order,
+    liquidity: &MarketOutcomePriceLiquidity,
+    stake_matched: u64,
+) -> Result<()> {
+    if liquidity.sources.is_empty() {
+        let stake_removed = match order.for_outcome {
+            true => market_liquidities.remove_liquidity_against(
+                order.market_outcome_index,
+                liquidity.price,
+                stake_matched,
+            ),
+            false => market_liquidities.remove_liquidity_for(
+                order.market_outcome_index,
+                liquidity.price,
+                stake_matched,
+            ),
+        }
+        .map_err(|_| CoreError::MatchingRemainingLiquidityTooSmall)?;
+        require_eq!(
+            stake_removed,
+            stake_matched,
+            CoreError::MatchingRemainingLiquidityTooSmall
+        );
+        return Ok(());
+    }
+
+    for source in &liquidity.sources {
+        if stake_matched == 0_u64 {
+            break;
+        }
+        let source_stake = calculate_stake_cross(stake_matched, liquidity.price, source.price);
+        let source_stake_removed = match order.for_outcome {
+            true => {
+                market_liquidities.remove_liquidity_for(source.outcome, source.price, source_stake)
+            }
+            false => market_liquidities.remove_liquidity_against(
+                source.outcome,
+                source.price,
+                source_stake,
+            ),
+        }
+        .map_err(|_| CoreError::MatchingRemainingLiquidityTooSmall)?;
+        require_eq!(
+            source_stake_removed,
+            source_stake,
+            CoreError::MatchingRemainingLiquidityTooSmall
+        );
+    }
+
+    // cross liquidity keeps its price, which may have been rounded onto the ladder
+    match order.for_outcome {
+        true => market_liquidities.set_cross_liquidity_against(
+            order.market_outcome_index,
+            liquidity.price,
+            &liquidity.sources,
+        ),
+        false => market_liquidities.set_cross_liquidity_for(
+            order.market_outcome_index,
+            liquidity.price,
+            &liquidity.sources,
+        ),
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test_match_for_order {
     use crate::instructions::matching::on_order_creation;
@@ -300,6 +510,7 @@ mod test_match_for_order {
     use crate::state::market_liquidities::{mock_market_liquidities, LiquiditySource};
     use crate::state::market_matching_queue_account::{MarketMatchingQueue, MatchingQueue};
     use crate::state::order_account::mock_order;
+    use crate::state::price::Price;
     use solana_program::pubkey::Pubkey;
 
     #[test]
@@ -308,18 +519,18 @@ mod test_match_for_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 1, true, 2.8, 100_000, payer_pk);
+        let mut order = mock_order(market_pk, 1, true, Price::new(2_800), 100_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_against(1, 2.8, 125_000)
+            .add_liquidity_against(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_against(2, 2.8, 125_000)
+            .add_liquidity_against(2, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_for(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -336,15 +547,18 @@ mod test_match_for_order {
         .expect("match_for_order");
 
         assert_eq!(
-            vec!((3.5, 100_000)), // TODO incorrect - should be 20
+            vec!((Price::new(3_500), 100_000)), // TODO incorrect - should be 20
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((2.8, 125_000), (2.8, 25_000)),
+            vec!((Price::new(2_800), 125_000), (Price::new(2_800), 25_000)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            vec![(false, 2.8, 100_000), (true, 2.8, 100_000),],
+            vec![
+                (false, Price::new(2_800), 100_000),
+                (true, Price::new(2_800), 100_000),
+            ],
             matches(&market_matching_queue.matches)
         );
 
@@ -358,18 +572,18 @@ mod test_match_for_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, true, 3.5, 80_000, payer_pk);
+        let mut order = mock_order(market_pk, 0, true, Price::new(3_500), 80_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_for(1, 2.8, 125_000)
+            .add_liquidity_for(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(2, 2.8, 125_000)
+            .add_liquidity_for(2, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_against(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -386,18 +600,18 @@ mod test_match_for_order {
         .expect("match_for_order");
 
         assert_eq!(
-            vec![(2.8, 25_000), (2.8, 25_000)],
+            vec![(Price::new(2_800), 25_000), (Price::new(2_800), 25_000)],
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec![(3.5, "2.80:2.80".to_string(), 20_000)],
+            vec![(Price::new(3_500), "2.800:2.800".to_string(), 20_000)],
             liquidities2(&market_liquidities.liquidities_against)
         );
         assert_eq!(
             vec![
-                (true, 2.8, 100_000),
-                (true, 2.8, 100_000),
-                (true, 3.5, 80_000)
+                (true, Price::new(2_800), 100_000),
+                (true, Price::new(2_800), 100_000),
+                (true, Price::new(3_500), 80_000)
             ],
             matches(&market_matching_queue.matches) // vec max length
         );
@@ -412,25 +626,25 @@ mod test_match_for_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, true, 3.5, 80_000, payer_pk);
+        let mut order = mock_order(market_pk, 0, true, Price::new(3_500), 80_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_for(1, 2.8, 250_000)
+            .add_liquidity_for(1, Price::new(2_800), 250_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(2, 2.8, 250_000)
+            .add_liquidity_for(2, Price::new(2_800), 250_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_against(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
         // following removals make cross liquidity to be too big
         market_liquidities
-            .remove_liquidity_for(1, 2.8, 125_000)
+            .remove_liquidity_for(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .remove_liquidity_for(2, 2.8, 125_000)
+            .remove_liquidity_for(2, Price::new(2_800), 125_000)
             .unwrap();
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -447,18 +661,21 @@ mod test_match_for_order {
         .expect("match_for_order");
 
         assert_eq!(
-            vec![(2.8, "".to_string(), 25_000), (2.8, "".to_string(), 25_000)],
+            vec![
+                (Price::new(2_800), "".to_string(), 25_000),
+                (Price::new(2_800), "".to_string(), 25_000)
+            ],
             liquidities2(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec![(3.5, "2.80:2.80".to_string(), 20_000)],
+            vec![(Price::new(3_500), "2.800:2.800".to_string(), 20_000)],
             liquidities2(&market_liquidities.liquidities_against)
         );
         assert_eq!(
             vec![
-                (true, 2.8, 100_000),
-                (true, 2.8, 100_000),
-                (true, 3.5, 80_000)
+                (true, Price::new(2_800), 100_000),
+                (true, Price::new(2_800), 100_000),
+                (true, Price::new(3_500), 80_000)
             ],
             matches(&market_matching_queue.matches) // vec max length
         );
@@ -473,30 +690,30 @@ mod test_match_for_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, true, 3.5, 80_000, payer_pk);
+        let mut order = mock_order(market_pk, 0, true, Price::new(3_500), 80_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_for(1, 2.8, 125_000)
+            .add_liquidity_for(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(2, 2.8, 125_000)
+            .add_liquidity_for(2, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_against(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
         assert_eq!(
-            vec![(3.5, "2.80:2.80".to_string(), 100_000)],
+            vec![(Price::new(3_500), "2.800:2.800".to_string(), 100_000)],
             liquidities2(&market_liquidities.liquidities_against)
         );
 
         // following removals make cross liquidity to be too big
         market_liquidities
-            .remove_liquidity_for(1, 2.8, 125_000)
+            .remove_liquidity_for(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .remove_liquidity_for(2, 2.8, 125_000)
+            .remove_liquidity_for(2, Price::new(2_800), 125_000)
             .unwrap();
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -513,15 +730,15 @@ mod test_match_for_order {
         .expect("match_for_order");
 
         assert_eq!(
-            vec![(3.5, "".to_string(), 80_000)],
+            vec![(Price::new(3_500), "".to_string(), 80_000)],
             liquidities2(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            Vec::<(f64, String, u64)>::new(),
+            Vec::<(Price, String, u64)>::new(),
             liquidities2(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            Vec::<(bool, f64, u64)>::new(),
+            Vec::<(bool, Price, u64)>::new(),
             matches(&market_matching_queue.matches) // vec max length
         );
 
@@ -535,22 +752,22 @@ mod test_match_for_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, true, 3.0, 120_000, payer_pk);
+        let mut order = mock_order(market_pk, 0, true, Price::new(3_000), 120_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_for(1, 3.6, 200_000)
+            .add_liquidity_for(1, Price::new(3_600), 200_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(2, 4.0, 180_000)
+            .add_liquidity_for(2, Price::new(4_000), 180_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(3, 7.2, 100_000)
+            .add_liquidity_for(3, Price::new(7_200), 100_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_against(&[
-            LiquiditySource::new(1, 3.6),
-            LiquiditySource::new(2, 4.0),
-            LiquiditySource::new(3, 7.2),
+            LiquiditySource::new(1, Price::new(3_600)),
+            LiquiditySource::new(2, Price::new(4_000)),
+            LiquiditySource::new(3, Price::new(7_200)),
         ]);
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -567,19 +784,23 @@ mod test_match_for_order {
         .expect("match_for_order");
 
         assert_eq!(
-            vec![(3.6, 100_000), (4.0, 90_000), (7.2, 50_000)],
+            vec![
+                (Price::new(3_600), 100_000),
+                (Price::new(4_000), 90_000),
+                (Price::new(7_200), 50_000)
+            ],
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec![(3.0, 120_000)],
+            vec![(Price::new(3_000), 120_000)],
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
             vec![
-                (true, 3.6, 100_000),
-                (true, 4.0, 90_000),
-                (true, 7.2, 50_000),
-                (true, 3.0, 120_000)
+                (true, Price::new(3_600), 100_000),
+                (true, Price::new(4_000), 90_000),
+                (true, Price::new(7_200), 50_000),
+                (true, Price::new(3_000), 120_000)
             ],
             matches(&market_matching_queue.matches)
         );
@@ -596,6 +817,7 @@ mod test_match_against_order {
     use crate::state::market_liquidities::{mock_market_liquidities, LiquiditySource};
     use crate::state::market_matching_queue_account::{MarketMatchingQueue, MatchingQueue};
     use crate::state::order_account::mock_order;
+    use crate::state::price::Price;
     use solana_program::pubkey::Pubkey;
 
     #[test]
@@ -604,18 +826,18 @@ mod test_match_against_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 1, false, 2.8, 100_000, payer_pk);
+        let mut order = mock_order(market_pk, 1, false, Price::new(2_800), 100_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_for(1, 2.8, 125_000)
+            .add_liquidity_for(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_for(2, 2.8, 125_000)
+            .add_liquidity_for(2, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_against(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -632,15 +854,18 @@ mod test_match_against_order {
         .expect("");
 
         assert_eq!(
-            vec!((2.8, 25_000), (2.8, 125_000)),
+            vec!((Price::new(2_800), 25_000), (Price::new(2_800), 125_000)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((3.5, 100_000)), // TODO incorrect - should be 20
+            vec!((Price::new(3_500), 100_000)), // TODO incorrect - should be 20
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            vec![(true, 2.8, 100_000), (false, 2.8, 100_000),],
+            vec![
+                (true, Price::new(2_800), 100_000),
+                (false, Price::new(2_800), 100_000),
+            ],
             matches(&market_matching_queue.matches)
         );
 
@@ -654,18 +879,18 @@ mod test_match_against_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, false, 3.5, 80_000, payer_pk);
+        let mut order = mock_order(market_pk, 0, false, Price::new(3_500), 80_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_against(1, 2.8, 125_000)
+            .add_liquidity_against(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_against(2, 2.8, 125_000)
+            .add_liquidity_against(2, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_for(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -682,18 +907,18 @@ mod test_match_against_order {
         .expect("");
 
         assert_eq!(
-            vec!((3.5, 20_000)),
+            vec!((Price::new(3_500), 20_000)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec![(2.8, 25_000), (2.8, 25_000)],
+            vec![(Price::new(2_800), 25_000), (Price::new(2_800), 25_000)],
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
             vec![
-                (false, 2.8, 100_000),
-                (false, 2.8, 100_000),
-                (false, 3.5, 80_000)
+                (false, Price::new(2_800), 100_000),
+                (false, Price::new(2_800), 100_000),
+                (false, Price::new(3_500), 80_000)
             ],
             matches(&market_matching_queue.matches) // vec max length
         );
@@ -708,25 +933,25 @@ mod test_match_against_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, false, 3.5, 80_000, payer_pk);
+        let mut order = mock_order(market_pk, 0, false, Price::new(3_500), 80_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_against(1, 2.8, 250_000)
+            .add_liquidity_against(1, Price::new(2_800), 250_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_against(2, 2.8, 250_000)
+            .add_liquidity_against(2, Price::new(2_800), 250_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_for(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
         // following removals make cross liquidity to be too big
         market_liquidities
-            .remove_liquidity_against(1, 2.8, 125_000)
+            .remove_liquidity_against(1, Price::new(2_800), 125_000)
             .unwrap();
         market_liquidities
-            .remove_liquidity_against(2, 2.8, 125_000)
+            .remove_liquidity_against(2, Price::new(2_800), 125_000)
             .unwrap();
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -743,18 +968,21 @@ mod test_match_against_order {
         .expect("");
 
         assert_eq!(
-            vec![(3.5, "2.80:2.80".to_string(), 20_000)],
+            vec![(Price::new(3_500), "2.800:2.800".to_string(), 20_000)],
             liquidities2(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec![(2.8, "".to_string(), 25_000), (2.8, "".to_string(), 25_000)],
+            vec![
+                (Price::new(2_800), "".to_string(), 25_000),
+                (Price::new(2_800), "".to_string(), 25_000)
+            ],
             liquidities2(&market_liquidities.liquidities_against)
         );
         assert_eq!(
             vec![
-                (false, 2.8, 100_000),
-                (false, 2.8, 100_000),
-                (false, 3.5, 80_000)
+                (false, Price::new(2_800), 100_000),
+                (false, Price::new(2_800), 100_000),
+                (false, Price::new(3_500), 80_000)
             ],
             matches(&market_matching_queue.matches) // vec max length
         );
@@ -769,25 +997,25 @@ mod test_match_against_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, false, 3.5, 80, payer_pk);
+        let mut order = mock_order(market_pk, 0, false, Price::new(3_500), 80, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_against(1, 2.8, 125)
+            .add_liquidity_against(1, Price::new(2_800), 125)
             .unwrap();
         market_liquidities
-            .add_liquidity_against(2, 2.8, 125)
+            .add_liquidity_against(2, Price::new(2_800), 125)
             .unwrap();
         market_liquidities.update_cross_liquidity_for(&[
-            LiquiditySource::new(1, 2.8),
-            LiquiditySource::new(2, 2.8),
+            LiquiditySource::new(1, Price::new(2_800)),
+            LiquiditySource::new(2, Price::new(2_800)),
         ]);
         // following removals make cross liquidity to be too big
         market_liquidities
-            .remove_liquidity_against(1, 2.8, 125)
+            .remove_liquidity_against(1, Price::new(2_800), 125)
             .unwrap();
         market_liquidities
-            .remove_liquidity_against(2, 2.8, 125)
+            .remove_liquidity_against(2, Price::new(2_800), 125)
             .unwrap();
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -804,15 +1032,15 @@ mod test_match_against_order {
         .expect("");
 
         assert_eq!(
-            Vec::<(f64, String, u64)>::new(),
+            Vec::<(Price, String, u64)>::new(),
             liquidities2(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec![(3.5, "".to_string(), 80)],
+            vec![(Price::new(3_500), "".to_string(), 80)],
             liquidities2(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            Vec::<(bool, f64, u64)>::new(),
+            Vec::<(bool, Price, u64)>::new(),
             matches(&market_matching_queue.matches) // vec max length
         );
 
@@ -826,22 +1054,22 @@ mod test_match_against_order {
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, 0, false, 3.0, 120_000, payer_pk);
+        let mut order = mock_order(market_pk, 0, false, Price::new(3_000), 120_000, payer_pk);
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         market_liquidities
-            .add_liquidity_against(1, 3.6, 200_000)
+            .add_liquidity_against(1, Price::new(3_600), 200_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_against(2, 4.0, 180_000)
+            .add_liquidity_against(2, Price::new(4_000), 180_000)
             .unwrap();
         market_liquidities
-            .add_liquidity_against(3, 7.2, 100_000)
+            .add_liquidity_against(3, Price::new(7_200), 100_000)
             .unwrap();
         market_liquidities.update_cross_liquidity_for(&[
-            LiquiditySource::new(1, 3.6),
-            LiquiditySource::new(2, 4.0),
-            LiquiditySource::new(3, 7.2),
+            LiquiditySource::new(1, Price::new(3_600)),
+            LiquiditySource::new(2, Price::new(4_000)),
+            LiquiditySource::new(3, Price::new(7_200)),
         ]);
 
         let mut market_matching_queue = MarketMatchingQueue {
@@ -858,19 +1086,23 @@ mod test_match_against_order {
         .expect("");
 
         assert_eq!(
-            vec![(3.0, 120_000)],
+            vec![(Price::new(3_000), 120_000)],
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec![(7.2, 50_000), (4.0, 90_000), (3.6, 100_000),],
+            vec![
+                (Price::new(7_200), 50_000),
+                (Price::new(4_000), 90_000),
+                (Price::new(3_600), 100_000),
+            ],
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
             vec![
-                (false, 3.6, 100_000),
-                (false, 4.0, 90_000),
-                (false, 7.2, 50_000),
-                (false, 3.0, 120_000)
+                (false, Price::new(3_600), 100_000),
+                (false, Price::new(4_000), 90_000),
+                (false, Price::new(7_200), 50_000),
+                (false, Price::new(3_000), 120_000)
             ],
             matches(&market_matching_queue.matches)
         );
@@ -891,11 +1123,18 @@ mod test {
     fn match_against_order_stop_after_fully_matched() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, false, 1.5, 10, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            false,
+            Price::new(1_500),
+            10,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -918,15 +1157,18 @@ mod test {
         .expect("");
 
         assert_eq!(
-            vec!((1.3, 10), (1.4, 10)),
+            vec!((Price::new(1_300), 10), (Price::new(1_400), 10)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            Vec::<(f64, u64)>::new(),
+            Vec::<(Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            vec!((true, 1.2, 10), (false, 1.2, 10)),
+            vec!(
+                (true, Price::new(1_200), 10),
+                (false, Price::new(1_200), 10)
+            ),
             matches(&market_matching_queue.matches)
         );
 
@@ -939,12 +1181,30 @@ mod test {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
         let market_price_ladder = vec![
-            1.2, 1.25, 1.3, 1.35, 1.4, 1.45, 1.5, 1.55, 1.6, 1.65, 1.7, 1.75,
+            Price::new(1_200),
+            Price::new(1_250),
+            Price::new(1_300),
+            Price::new(1_350),
+            Price::new(1_400),
+            Price::new(1_450),
+            Price::new(1_500),
+            Price::new(1_550),
+            Price::new(1_600),
+            Price::new(1_650),
+            Price::new(1_700),
+            Price::new(1_750),
         ];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, false, 1.8, 120, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            false,
+            Price::new(1_800),
+            120,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -968,36 +1228,36 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.7, 10), (1.75, 10)),
+            vec!((Price::new(1_700), 10), (Price::new(1_750), 10)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.8, 20)),
+            vec!((Price::new(1_800), 20)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(100_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec![
-                (true, 1.2, 10),
-                (false, 1.2, 10),
-                (true, 1.25, 10),
-                (false, 1.25, 10),
-                (true, 1.3, 10),
-                (false, 1.3, 10),
-                (true, 1.35, 10),
-                (false, 1.35, 10),
-                (true, 1.4, 10),
-                (false, 1.4, 10),
-                (true, 1.45, 10),
-                (false, 1.45, 10),
-                (true, 1.5, 10),
-                (false, 1.5, 10),
-                (true, 1.55, 10),
-                (false, 1.55, 10),
-                (true, 1.6, 10),
-                (false, 1.6, 10),
-                (true, 1.65, 10),
-                (false, 1.65, 10)
+                (true, Price::new(1_200), 10),
+                (false, Price::new(1_200), 10),
+                (true, Price::new(1_250), 10),
+                (false, Price::new(1_250), 10),
+                (true, Price::new(1_300), 10),
+                (false, Price::new(1_300), 10),
+                (true, Price::new(1_350), 10),
+                (false, Price::new(1_350), 10),
+                (true, Price::new(1_400), 10),
+                (false, Price::new(1_400), 10),
+                (true, Price::new(1_450), 10),
+                (false, Price::new(1_450), 10),
+                (true, Price::new(1_500), 10),
+                (false, Price::new(1_500), 10),
+                (true, Price::new(1_550), 10),
+                (false, Price::new(1_550), 10),
+                (true, Price::new(1_600), 10),
+                (false, Price::new(1_600), 10),
+                (true, Price::new(1_650), 10),
+                (false, Price::new(1_650), 10)
             ],
             matches(&market_matching_queue.matches) // vec max length
         );
@@ -1010,11 +1270,18 @@ mod test {
     fn match_against_order_with_price_1_1() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, false, 1.1, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            false,
+            Price::new(1_100),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1038,16 +1305,20 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.2, 10), (1.3, 10), (1.4, 10)),
+            vec!(
+                (Price::new(1_200), 10),
+                (Price::new(1_300), 10),
+                (Price::new(1_400), 10)
+            ),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.1, 100)),
+            vec!((Price::new(1_100), 100)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(0_u64, market_liquidities.stake_matched_total);
         assert_eq!(
-            Vec::<(bool, f64, u64)>::new(),
+            Vec::<(bool, Price, u64)>::new(),
             matches(&market_matching_queue.matches)
         );
 
@@ -1059,11 +1330,18 @@ mod test {
     fn match_against_order_with_price_1_2() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, false, 1.2, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            false,
+            Price::new(1_200),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1087,15 +1365,18 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.3, 10), (1.4, 10)),
+            vec!((Price::new(1_300), 10), (Price::new(1_400), 10)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.2, 90)),
+            vec!((Price::new(1_200), 90)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            vec!((true, 1.2, 10), (false, 1.2, 10)),
+            vec!(
+                (true, Price::new(1_200), 10),
+                (false, Price::new(1_200), 10)
+            ),
             matches(&market_matching_queue.matches)
         );
 
@@ -1107,11 +1388,18 @@ mod test {
     fn match_against_order_with_price_1_3() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, false, 1.3, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            false,
+            Price::new(1_300),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1135,20 +1423,20 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.4, 10)),
+            vec!((Price::new(1_400), 10)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.3, 80)),
+            vec!((Price::new(1_300), 80)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(20_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec!(
-                (true, 1.2, 10),
-                (false, 1.2, 10),
-                (true, 1.3, 10),
-                (false, 1.3, 10)
+                (true, Price::new(1_200), 10),
+                (false, Price::new(1_200), 10),
+                (true, Price::new(1_300), 10),
+                (false, Price::new(1_300), 10)
             ),
             matches(&market_matching_queue.matches)
         );
@@ -1161,11 +1449,18 @@ mod test {
     fn match_against_order_with_price_1_4() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, false, 1.4, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            false,
+            Price::new(1_400),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1189,22 +1484,22 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            Vec::<(f64, u64)>::new(),
+            Vec::<(Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.4, 70)),
+            vec!((Price::new(1_400), 70)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(30_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec!(
-                (true, 1.2, 10),
-                (false, 1.2, 10),
-                (true, 1.3, 10),
-                (false, 1.3, 10),
-                (true, 1.4, 10),
-                (false, 1.4, 10)
+                (true, Price::new(1_200), 10),
+                (false, Price::new(1_200), 10),
+                (true, Price::new(1_300), 10),
+                (false, Price::new(1_300), 10),
+                (true, Price::new(1_400), 10),
+                (false, Price::new(1_400), 10)
             ),
             matches(&market_matching_queue.matches)
         );
@@ -1217,11 +1512,18 @@ mod test {
     fn match_against_order_with_price_1_5() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, false, 1.5, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            false,
+            Price::new(1_500),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1245,22 +1547,22 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            Vec::<(f64, u64)>::new(),
+            Vec::<(Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.5, 70)),
+            vec!((Price::new(1_500), 70)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(30_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec!(
-                (true, 1.2, 10),
-                (false, 1.2, 10),
-                (true, 1.3, 10),
-                (false, 1.3, 10),
-                (true, 1.4, 10),
-                (false, 1.4, 10)
+                (true, Price::new(1_200), 10),
+                (false, Price::new(1_200), 10),
+                (true, Price::new(1_300), 10),
+                (false, Price::new(1_300), 10),
+                (true, Price::new(1_400), 10),
+                (false, Price::new(1_400), 10)
             ),
             matches(&market_matching_queue.matches)
         );
@@ -1273,11 +1575,18 @@ mod test {
     fn match_for_order_stop_after_fully_matched() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, true, 1.1, 10, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            true,
+            Price::new(1_100),
+            10,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1301,15 +1610,18 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            Vec::<(f64, u64)>::new(),
+            Vec::<(Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.3, 10), (1.2, 10)),
+            vec!((Price::new(1_300), 10), (Price::new(1_200), 10)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            vec!((false, 1.4, 10), (true, 1.4, 10)),
+            vec!(
+                (false, Price::new(1_400), 10),
+                (true, Price::new(1_400), 10)
+            ),
             matches(&market_matching_queue.matches)
         );
 
@@ -1321,11 +1633,25 @@ mod test {
     fn match_for_order_with_more_matches_than_alloc() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4, 1.5, 1.6, 1.7];
+        let market_price_ladder = vec![
+            Price::new(1_200),
+            Price::new(1_300),
+            Price::new(1_400),
+            Price::new(1_500),
+            Price::new(1_600),
+            Price::new(1_700),
+        ];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, true, 1.1, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            true,
+            Price::new(1_100),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1349,28 +1675,28 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.1, 40)),
+            vec!((Price::new(1_100), 40)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            Vec::<(f64, u64)>::new(),
+            Vec::<(Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(60_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec!(
-                (false, 1.7, 10),
-                (true, 1.7, 10),
-                (false, 1.6, 10),
-                (true, 1.6, 10),
-                (false, 1.5, 10),
-                (true, 1.5, 10),
-                (false, 1.4, 10),
-                (true, 1.4, 10),
-                (false, 1.3, 10),
-                (true, 1.3, 10),
-                (false, 1.2, 10),
-                (true, 1.2, 10)
+                (false, Price::new(1_700), 10),
+                (true, Price::new(1_700), 10),
+                (false, Price::new(1_600), 10),
+                (true, Price::new(1_600), 10),
+                (false, Price::new(1_500), 10),
+                (true, Price::new(1_500), 10),
+                (false, Price::new(1_400), 10),
+                (true, Price::new(1_400), 10),
+                (false, Price::new(1_300), 10),
+                (true, Price::new(1_300), 10),
+                (false, Price::new(1_200), 10),
+                (true, Price::new(1_200), 10)
             ),
             matches(&market_matching_queue.matches)
         );
@@ -1383,11 +1709,18 @@ mod test {
     fn match_for_order_with_price_1_1() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, true, 1.1, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            true,
+            Price::new(1_100),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1411,22 +1744,22 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.1, 70)),
+            vec!((Price::new(1_100), 70)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            Vec::<(f64, u64)>::new(),
+            Vec::<(Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(30_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec!(
-                (false, 1.4, 10),
-                (true, 1.4, 10),
-                (false, 1.3, 10),
-                (true, 1.3, 10),
-                (false, 1.2, 10),
-                (true, 1.2, 10)
+                (false, Price::new(1_400), 10),
+                (true, Price::new(1_400), 10),
+                (false, Price::new(1_300), 10),
+                (true, Price::new(1_300), 10),
+                (false, Price::new(1_200), 10),
+                (true, Price::new(1_200), 10)
             ),
             matches(&market_matching_queue.matches)
         );
@@ -1439,11 +1772,18 @@ mod test {
     fn match_for_order_with_price_1_2() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, true, 1.2, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            true,
+            Price::new(1_200),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1467,22 +1807,22 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.2, 70)),
+            vec!((Price::new(1_200), 70)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            Vec::<(f64, u64)>::new(),
+            Vec::<(Price, u64)>::new(),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(30_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec!(
-                (false, 1.4, 10),
-                (true, 1.4, 10),
-                (false, 1.3, 10),
-                (true, 1.3, 10),
-                (false, 1.2, 10),
-                (true, 1.2, 10)
+                (false, Price::new(1_400), 10),
+                (true, Price::new(1_400), 10),
+                (false, Price::new(1_300), 10),
+                (true, Price::new(1_300), 10),
+                (false, Price::new(1_200), 10),
+                (true, Price::new(1_200), 10)
             ),
             matches(&market_matching_queue.matches)
         );
@@ -1495,11 +1835,18 @@ mod test {
     fn match_for_order_with_price_1_3() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, true, 1.3, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            true,
+            Price::new(1_300),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1523,20 +1870,20 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.3, 80)),
+            vec!((Price::new(1_300), 80)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.2, 10)),
+            vec!((Price::new(1_200), 10)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(20_u64, market_liquidities.stake_matched_total);
         assert_eq!(
             vec!(
-                (false, 1.4, 10),
-                (true, 1.4, 10),
-                (false, 1.3, 10),
-                (true, 1.3, 10),
+                (false, Price::new(1_400), 10),
+                (true, Price::new(1_400), 10),
+                (false, Price::new(1_300), 10),
+                (true, Price::new(1_300), 10),
             ),
             matches(&market_matching_queue.matches)
         );
@@ -1549,11 +1896,18 @@ mod test {
     fn match_for_order_with_price_1_4() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, true, 1.4, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            true,
+            Price::new(1_400),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1577,15 +1931,18 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.4, 90)),
+            vec!((Price::new(1_400), 90)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.3, 10), (1.2, 10)),
+            vec!((Price::new(1_300), 10), (Price::new(1_200), 10)),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(
-            vec!((false, 1.4, 10), (true, 1.4, 10)),
+            vec!(
+                (false, Price::new(1_400), 10),
+                (true, Price::new(1_400), 10)
+            ),
             matches(&market_matching_queue.matches)
         );
 
@@ -1597,11 +1954,18 @@ mod test {
     fn match_for_order_with_price_1_5() {
         let market_pk = Pubkey::new_unique();
         let market_outcome_index = 1;
-        let market_price_ladder = vec![1.2, 1.3, 1.4];
+        let market_price_ladder = vec![Price::new(1_200), Price::new(1_300), Price::new(1_400)];
         let payer_pk = Pubkey::new_unique();
 
         let order_pk = Pubkey::new_unique();
-        let mut order = mock_order(market_pk, market_outcome_index, true, 1.5, 100, payer_pk);
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            true,
+            Price::new(1_500),
+            100,
+            payer_pk,
+        );
 
         let mut market_liquidities = mock_market_liquidities(market_pk);
         for price in market_price_ladder.iter() {
@@ -1625,16 +1989,20 @@ mod test {
         assert!(on_order_creation_result.is_ok());
 
         assert_eq!(
-            vec!((1.5, 100)),
+            vec!((Price::new(1_500), 100)),
             liquidities(&market_liquidities.liquidities_for)
         );
         assert_eq!(
-            vec!((1.4, 10), (1.3, 10), (1.2, 10)),
+            vec!(
+                (Price::new(1_400), 10),
+                (Price::new(1_300), 10),
+                (Price::new(1_200), 10)
+            ),
             liquidities(&market_liquidities.liquidities_against)
         );
         assert_eq!(0_u64, market_liquidities.stake_matched_total);
         assert_eq!(
-            Vec::<(bool, f64, u64)>::new(),
+            Vec::<(bool, Price, u64)>::new(),
             matches(&market_matching_queue.matches)
         );
 
@@ -1644,15 +2012,270 @@ mod test {
 }
 
 #[cfg(test)]
-fn liquidities(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(f64, u64)> {
+mod test_match_with_price_improvement {
+    use crate::instructions::matching::on_order_creation::{liquidities, matches};
+    use crate::instructions::matching::{
+        on_order_creation, on_order_creation_with_price_improvement,
+    };
+    use crate::state::market_liquidities::{mock_market_liquidities, MarketLiquidities};
+    use crate::state::market_matching_queue_account::{MarketMatchingQueue, MatchingQueue};
+    use crate::state::order_account::mock_order;
+    use crate::state::price::Price;
+    use crate::state::price_ladder::DEFAULT_PRICES;
+    use solana_program::pubkey::Pubkey;
+
+    // cross price for outcome 3 is 1 / (1 - (1/2 + 1/4 + 1/10)) = 6.666...
+    fn market_liquidities_4way(market_pk: Pubkey) -> MarketLiquidities {
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        market_liquidities
+            .add_liquidity_for(0, Price::new(2_000), 100_000)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_for(0, Price::new(2_100), 100_000)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_for(1, Price::new(4_000), 100_000)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_for(2, Price::new(10_000), 100_000)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_against(3, Price::new(6_000), 10_000)
+            .unwrap();
+        market_liquidities
+    }
+
+    #[test]
+    fn cross_match_rounded_onto_ladder() {
+        let market_pk = Pubkey::new_unique();
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            3,
+            true,
+            Price::new(6_000),
+            30_000,
+            Pubkey::new_unique(),
+        );
+        let mut market_liquidities = market_liquidities_4way(market_pk);
+        let mut market_matching_queue = MarketMatchingQueue {
+            market: market_pk,
+            matches: MatchingQueue::new(10),
+        };
+
+        let result = on_order_creation_with_price_improvement(
+            &mut market_liquidities,
+            &mut market_matching_queue,
+            &order_pk,
+            &mut order,
+            4,
+            &DEFAULT_PRICES,
+        );
+
+        assert_eq!(Ok(vec![(30_000, Price::new(6_600))]), result);
+        assert_eq!(
+            vec![
+                (true, Price::new(2_000), 99_000),
+                (true, Price::new(4_000), 49_500),
+                (true, Price::new(10_000), 19_800),
+                (true, Price::new(6_600), 30_000),
+            ],
+            matches(&market_matching_queue.matches)
+        );
+        assert_eq!(
+            vec![
+                (Price::new(2_000), 1_000),
+                (Price::new(2_100), 100_000),
+                (Price::new(4_000), 50_500),
+                (Price::new(10_000), 80_200),
+            ],
+            liquidities(&market_liquidities.liquidities_for)
+        );
+        // cross liquidity too small to match again is removed
+        assert_eq!(
+            vec![(Price::new(6_000), 10_000)],
+            liquidities(&market_liquidities.liquidities_against)
+        );
+        assert_eq!(30_000, market_liquidities.stake_matched_total);
+
+        assert_eq!(0_u64, order.stake_unmatched);
+        assert_eq!(198_000_u64, order.payout);
+    }
+
+    #[test]
+    fn walks_cross_and_direct_best_price_first() {
+        let market_pk = Pubkey::new_unique();
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            3,
+            true,
+            Price::new(6_000),
+            45_000,
+            Pubkey::new_unique(),
+        );
+        let mut market_liquidities = market_liquidities_4way(market_pk);
+        let mut market_matching_queue = MarketMatchingQueue {
+            market: market_pk,
+            matches: MatchingQueue::new(10),
+        };
+
+        let result = on_order_creation_with_price_improvement(
+            &mut market_liquidities,
+            &mut market_matching_queue,
+            &order_pk,
+            &mut order,
+            4,
+            &DEFAULT_PRICES,
+        );
+
+        assert_eq!(
+            Ok(vec![
+                (30_000, Price::new(6_600)),
+                (10_000, Price::new(6_000))
+            ]),
+            result
+        );
+        assert_eq!(
+            vec![
+                (true, Price::new(2_000), 99_000),
+                (true, Price::new(4_000), 49_500),
+                (true, Price::new(10_000), 19_800),
+                (true, Price::new(6_600), 30_000),
+                (false, Price::new(6_000), 10_000),
+                (true, Price::new(6_000), 10_000),
+            ],
+            matches(&market_matching_queue.matches)
+        );
+        assert!(market_liquidities.liquidities_against.is_empty());
+        // the remainder rests on the book
+        assert_eq!(
+            Some(5_000),
+            market_liquidities
+                .get_liquidity_for(3, Price::new(6_000))
+                .map(|liquidity| liquidity.liquidity)
+        );
+        assert_eq!(5_000_u64, order.stake_unmatched);
+    }
+
+    #[test]
+    fn cross_match_against_order_rounded_up() {
+        let market_pk = Pubkey::new_unique();
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            0,
+            false,
+            Price::new(5_000),
+            45_000,
+            Pubkey::new_unique(),
+        );
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        market_liquidities
+            .add_liquidity_against(1, Price::new(2_200), 100_000)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_against(2, Price::new(3_000), 100_000)
+            .unwrap();
+        let mut market_matching_queue = MarketMatchingQueue {
+            market: market_pk,
+            matches: MatchingQueue::new(10),
+        };
+
+        // 1 / (1 - (1/2.2 + 1/3)) = 4.714... rounded up to 4.8
+        let result = on_order_creation_with_price_improvement(
+            &mut market_liquidities,
+            &mut market_matching_queue,
+            &order_pk,
+            &mut order,
+            3,
+            &DEFAULT_PRICES,
+        );
+
+        assert_eq!(Ok(vec![(45_000, Price::new(4_800))]), result);
+        assert_eq!(
+            vec![
+                (false, Price::new(2_200), 98_181),
+                (false, Price::new(3_000), 72_000),
+                (false, Price::new(4_800), 45_000),
+            ],
+            matches(&market_matching_queue.matches)
+        );
+        assert_eq!(0_u64, order.stake_unmatched);
+    }
+
+    #[test]
+    fn unrepresentable_cross_price_not_matched_without_price_improvement() {
+        let market_pk = Pubkey::new_unique();
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            3,
+            true,
+            Price::new(6_000),
+            30_000,
+            Pubkey::new_unique(),
+        );
+        let mut market_liquidities = market_liquidities_4way(market_pk);
+        let mut market_matching_queue = MarketMatchingQueue {
+            market: market_pk,
+            matches: MatchingQueue::new(10),
+        };
+
+        let result = on_order_creation(
+            &mut market_liquidities,
+            &mut market_matching_queue,
+            &order_pk,
+            &mut order,
+        );
+
+        assert_eq!(Ok(vec![(10_000, Price::new(6_000))]), result);
+        assert_eq!(20_000_u64, order.stake_unmatched);
+    }
+
+    #[test]
+    fn cross_matching_disabled() {
+        let market_pk = Pubkey::new_unique();
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            3,
+            true,
+            Price::new(6_000),
+            30_000,
+            Pubkey::new_unique(),
+        );
+        let mut market_liquidities = market_liquidities_4way(market_pk);
+        market_liquidities.enable_cross_matching = false;
+        let mut market_matching_queue = MarketMatchingQueue {
+            market: market_pk,
+            matches: MatchingQueue::new(10),
+        };
+
+        let result = on_order_creation_with_price_improvement(
+            &mut market_liquidities,
+            &mut market_matching_queue,
+            &order_pk,
+            &mut order,
+            4,
+            &DEFAULT_PRICES,
+        );
+
+        assert_eq!(Ok(vec![(10_000, Price::new(6_000))]), result);
+        assert_eq!(20_000_u64, order.stake_unmatched);
+    }
+}
+
+#[cfg(test)]
+fn liquidities(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(Price, u64)> {
     liquidities
         .iter()
         .map(|v| (v.price, v.liquidity))
-        .collect::<Vec<(f64, u64)>>()
+        .collect::<Vec<(Price, u64)>>()
 }
 
 #[cfg(test)]
-fn liquidities2(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(f64, String, u64)> {
+fn liquidities2(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(Price, String, u64)> {
     liquidities
         .iter()
         .map(|v| {
@@ -1666,14 +2289,14 @@ fn liquidities2(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(f64, Str
                 v.liquidity,
             )
         })
-        .collect::<Vec<(f64, String, u64)>>()
+        .collect::<Vec<(Price, String, u64)>>()
 }
 
 #[cfg(test)]
-fn matches(matches: &MatchingQueue) -> Vec<(bool, f64, u64)> {
+fn matches(matches: &MatchingQueue) -> Vec<(bool, Price, u64)> {
     matches
         .to_vec()
         .iter()
         .map(|v| (v.for_outcome, v.price, v.stake))
-        .collect::<Vec<(bool, f64, u64)>>()
+        .collect::<Vec<(bool, Price, u64)>>()
 }
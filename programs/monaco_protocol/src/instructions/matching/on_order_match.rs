@@ -1,4 +1,5 @@
 use crate::error::CoreError;
+use crate::events::order::OrderMatchedEvent;
 use crate::events::trade::TradeEvent;
 use crate::instructions::market_position::update_product_commission_contributions;
 use crate::instructions::matching::create_trade::create_trade;
@@ -43,6 +44,7 @@ pub fn on_order_match_taker(
                 matched_price,
                 now,
                 *payer,
+                *order_pk,
             );
             market.increment_unclosed_accounts_count()?;
 
@@ -71,8 +73,17 @@ pub fn on_order_match_maker(
     payer: &Pubkey,
 ) -> Result<u64> {
     require!(!order.is_completed(), CoreError::StatusClosed);
+    // orders left in the pool after a suspension must be cancelled before the pool can match
+    require!(
+        !market.is_cancelled_by_suspension(order.creation_timestamp),
+        CoreError::MatchingPoolHeadCancelledBySuspension
+    );
 
     let now = current_timestamp();
+    let taker_order_pk = market_matching_queue
+        .matches
+        .peek_taker()
+        .and_then(|taker_match| taker_match.pk);
 
     match market_matching_queue.matches.peek_mut() {
         None => Err(error!(CoreError::MatchingQueueIsEmpty)),
@@ -82,6 +93,7 @@ pub fn on_order_match_maker(
 
             // update order
             order.match_stake_unmatched(matched_stake, matched_price)?;
+            market_position.apply_outcome_withdrawals(&market.withdrawn_outcomes)?;
             let refund = market_position::update_on_order_match(
                 market_position,
                 order,
@@ -123,9 +135,20 @@ pub fn on_order_match_maker(
                 matched_price,
                 now,
                 *payer,
+                taker_order_pk.unwrap_or_default(),
             );
             market.increment_unclosed_accounts_count()?;
 
+            emit!(OrderMatchedEvent {
+                market: order.market,
+                maker_order: *order_pk,
+                taker_order: taker_order_pk,
+                market_outcome_index: order.market_outcome_index,
+                for_outcome: order.for_outcome,
+                price: matched_price,
+                stake: matched_stake,
+            });
+
             // dequeue empty matches (needs to be last due to borrowing)
             if order_match.stake == 0_u64 {
                 market_matching_queue.matches.dequeue();
@@ -139,6 +162,7 @@ pub fn on_order_match_maker(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::state::price::Price;
     use crate::state::{
         market_account::{MarketOrderBehaviour, MarketStatus},
         market_matching_pool_account::Cirque,
@@ -152,7 +176,7 @@ mod test {
         let market_pk = Pubkey::new_unique();
         let mut market = mock_market();
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let payer_pk = Pubkey::new_unique();
 
         let order_request = mock_order_request(
@@ -160,7 +184,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
         let order_pk = Pubkey::new_unique();
         let mut order = mock_order_from_order_request(market_pk, order_request, payer_pk);
@@ -215,7 +239,7 @@ mod test {
 
         assert_eq!(100_u64, order.stake_unmatched);
         assert_eq!(0_u64, order.payout);
-        assert_eq!(2.4_f64, order.expected_price);
+        assert_eq!(Price::new(2_400), order.expected_price);
 
         assert_eq!(vec!(0, 140, 0), market_position.unmatched_exposures);
         assert_eq!(vec!(0, 0, 0), market_position.market_outcome_sums);
@@ -226,7 +250,7 @@ mod test {
 
         assert_eq!(false, maker_order_trade.for_outcome); // default value
         assert_eq!(0_u64, maker_order_trade.stake); // default value
-        assert_eq!(0.0_f64, maker_order_trade.price); // default value
+        assert_eq!(Price::new(0), maker_order_trade.price); // default value
     }
 
     #[test]
@@ -234,7 +258,7 @@ mod test {
         let market_pk = Pubkey::new_unique();
         let mut market = mock_market();
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let matched_stake = 10_u64;
         let payer_pk = Pubkey::new_unique();
 
@@ -243,7 +267,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
         let order_pk = Pubkey::new_unique();
         let mut order = mock_order_from_order_request(market_pk, order_request, payer_pk);
@@ -283,6 +307,14 @@ mod test {
             matched_price,
             matched_stake,
         ));
+        let taker_order_pk = Pubkey::new_unique();
+        market_matching_queue.matches.enqueue(OrderMatch::taker(
+            taker_order_pk,
+            false,
+            market_outcome_index,
+            matched_price,
+            matched_stake,
+        ));
 
         let mut maker_order_trade = Trade::default();
 
@@ -300,7 +332,7 @@ mod test {
 
         assert_eq!(90_u64, order.stake_unmatched);
         assert_eq!(22_u64, order.payout);
-        assert_eq!(2.4_f64, order.expected_price);
+        assert_eq!(Price::new(2_400), order.expected_price);
 
         assert_eq!(vec!(0, 126, 0), market_position.unmatched_exposures);
         assert_eq!(vec!(10, -12, 10), market_position.market_outcome_sums);
@@ -311,7 +343,8 @@ mod test {
 
         assert_eq!(false, maker_order_trade.for_outcome);
         assert_eq!(10_u64, maker_order_trade.stake);
-        assert_eq!(2.2_f64, maker_order_trade.price);
+        assert_eq!(Price::new(2_200), maker_order_trade.price);
+        assert_eq!(taker_order_pk, maker_order_trade.match_id);
     }
 
     #[test]
@@ -319,7 +352,7 @@ mod test {
         let market_pk = Pubkey::new_unique();
         let mut market = mock_market();
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let matched_stake = 100_u64;
         let payer_pk = Pubkey::new_unique();
 
@@ -328,7 +361,7 @@ mod test {
             false,
             market_outcome_index,
             10_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
         let order_pk = Pubkey::new_unique();
         let mut order = mock_order_from_order_request(market_pk, order_request, payer_pk);
@@ -385,7 +418,7 @@ mod test {
 
         assert_eq!(0_u64, order.stake_unmatched);
         assert_eq!(22_u64, order.payout);
-        assert_eq!(2.4_f64, order.expected_price);
+        assert_eq!(Price::new(2_400), order.expected_price);
 
         assert_eq!(vec!(0, 0, 0), market_position.unmatched_exposures);
         assert_eq!(vec!(10, -12, 10), market_position.market_outcome_sums);
@@ -396,7 +429,7 @@ mod test {
 
         assert_eq!(false, maker_order_trade.for_outcome);
         assert_eq!(10_u64, maker_order_trade.stake);
-        assert_eq!(2.2_f64, maker_order_trade.price);
+        assert_eq!(Price::new(2_200), maker_order_trade.price);
     }
 
     #[test]
@@ -404,7 +437,7 @@ mod test {
         let market_pk = Pubkey::new_unique();
         let mut market = mock_market();
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let payer_pk = Pubkey::new_unique();
 
         let order_request = mock_order_request(
@@ -412,7 +445,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
         let order_pk = Pubkey::new_unique();
         let mut order = mock_order_from_order_request(market_pk, order_request, payer_pk);
@@ -482,7 +515,8 @@ mod test {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::CancelUnmatched,
@@ -494,6 +528,23 @@ mod test {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 100,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         }
     }
 
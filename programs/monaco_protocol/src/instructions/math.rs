@@ -1,90 +1,112 @@
 use crate::error::CoreError;
+use crate::state::price::Price;
 use anchor_lang::{require, Result};
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
+use std::convert::TryFrom;
 use std::ops::{Div, Mul, Sub};
 
-/// Converts at most precision 3 float to an equivalent Decimal - e.g., converting price (f64) to Decimal
-fn price_to_decimal(price: f64) -> Decimal {
-    let mut decimal = Decimal::from_f64(price).unwrap();
-    decimal.rescale(3);
-    decimal
-}
-
 /// risk = stake * (price - 1)
-pub fn calculate_risk_from_stake(stake: u64, price: f64) -> u64 {
-    let price_decimal = price_to_decimal(price);
-    Decimal::from(stake)
-        .mul(price_decimal.sub(Decimal::one()))
-        .to_u64()
+pub fn calculate_risk_from_stake(stake: u64, price: Price) -> u64 {
+    let risk = u128::from(stake)
+        .checked_mul(u128::from(price.value() - Price::SCALE))
         .unwrap()
+        .div(u128::from(Price::SCALE));
+    u64::try_from(risk).unwrap()
 }
 
 /// payout = stake * price
-pub fn calculate_for_payout(stake: u64, price: f64) -> u64 {
-    let price_decimal = price_to_decimal(price);
-    Decimal::from(stake).mul(price_decimal).to_u64().unwrap()
+pub fn calculate_for_payout(stake: u64, price: Price) -> u64 {
+    let payout = u128::from(stake)
+        .checked_mul(u128::from(price.value()))
+        .unwrap()
+        .div(u128::from(Price::SCALE));
+    u64::try_from(payout).unwrap()
 }
 
 /// stake = payout / price
-pub fn calculate_stake_from_payout(payout: u64, price: f64) -> u64 {
-    let price_decimal = price_to_decimal(price);
-    Decimal::from(payout).div(price_decimal).to_u64().unwrap()
+pub fn calculate_stake_from_payout(payout: u64, price: Price) -> u64 {
+    let stake = u128::from(payout)
+        .checked_mul(u128::from(Price::SCALE))
+        .unwrap()
+        .div(u128::from(price.value()));
+    u64::try_from(stake).unwrap()
 }
 
 /// stake_cross = stake * price / price_cross
-pub fn calculate_stake_cross(stake: u64, price: f64, price_cross: f64) -> u64 {
-    let stake_matched_decimal = Decimal::from_u64(stake).unwrap();
-    let price_matched_decimal = price_to_decimal(price);
-    let price_cross_decimal = price_to_decimal(price_cross);
-
-    let stake_cross_decimal = stake_matched_decimal
-        .mul(price_matched_decimal)
-        .div(price_cross_decimal);
+pub fn calculate_stake_cross(stake: u64, price: Price, price_cross: Price) -> u64 {
+    let stake_cross = u128::from(stake)
+        .checked_mul(u128::from(price.value()))
+        .unwrap()
+        .div(u128::from(price_cross.value()));
+    u64::try_from(stake_cross).unwrap()
+}
 
-    stake_cross_decimal.to_u64().unwrap()
+/// price_cross = 1 / (1 - (1 / price_a + 1 / price_b + ...))
+/// with the sum of implied probabilities kept as a reduced fraction, so terms do not grow with the
+/// number of outcomes
+pub fn calculate_price_cross(prices: &[Price]) -> Option<Price> {
+    let (numerator, denominator) = price_cross_fraction(prices)?;
+    if numerator % denominator != 0 {
+        None // it needs to fit in 3 decimals
+    } else {
+        u64::try_from(numerator / denominator).ok().map(Price::new)
+    }
 }
 
-/// 2ways: price_cross = price_a / (price_a - 1)
-/// 3ways: price_cross = price_ab / (price_ab - price_a - price_b)
-/// 4ways: price_cross = price_abc / (price_abc - price_ab - price_bc - price_ac)
-pub fn calculate_price_cross(prices: &[f64]) -> Option<f64> {
-    let mut full = Decimal::ONE;
-    let mut partials = vec![Decimal::ONE; prices.len()];
+/// price_cross rounded to the nearest ladder price at which the cross match stays fully funded -
+/// down for cross liquidity matched by FOR orders and up for cross liquidity matched by AGAINST
+/// orders. This rounds against the taker: rounding in the taker's favour would pay out more than
+/// the makers of the cross match have put up, so the taker is only ever matched at a ladder price
+/// no better than the exact cross price
+pub fn calculate_price_cross_on_ladder(
+    prices: &[Price],
+    ladder: &[Price],
+    round_up: bool,
+) -> Option<Price> {
+    let (numerator, denominator) = price_cross_fraction(prices)?;
+    let value_floor = numerator / denominator;
+    let value_ceil = value_floor + u128::from(numerator % denominator != 0);
+
+    let price = if round_up {
+        ladder
+            .iter()
+            .filter(|price| u128::from(price.value()) >= value_ceil)
+            .min()
+    } else {
+        ladder
+            .iter()
+            .filter(|price| u128::from(price.value()) <= value_floor)
+            .max()
+    };
+    price.copied().filter(|price| *price > Price::ONE)
+}
 
-    for (price_index, price) in prices.iter().enumerate() {
-        let price_decimal = price_to_decimal(*price);
+// price_cross as a fraction of price values, None if the prices imply no cross price
+fn price_cross_fraction(prices: &[Price]) -> Option<(u128, u128)> {
+    let scale = u128::from(Price::SCALE);
+    let mut numerator = 0_u128;
+    let mut denominator = 1_u128;
 
-        full = full.mul(price_decimal);
-        for (index, partial) in partials.iter_mut().enumerate() {
-            if index != price_index {
-                *partial = partial.mul(price_decimal);
-            }
-        }
-    }
-    let mut full_sub_partials = full;
-    for partial in partials {
-        full_sub_partials = full_sub_partials.sub(partial);
-    }
+    for price in prices {
+        let price_value = u128::from(price.value());
 
-    let result = full.div(full_sub_partials);
-    let result_truncated = result.trunc_with_scale(3);
+        // n / d + scale / price = (n * price + scale * d) / (d * price)
+        numerator = numerator
+            .checked_mul(price_value)?
+            .checked_add(scale.checked_mul(denominator)?)?;
+        denominator = denominator.checked_mul(price_value)?;
 
-    if result.ne(&result_truncated) {
-        None // it needs to fit in 3 decimals
-    } else {
-        result_truncated.to_f64()
+        let divisor = greatest_common_divisor(numerator, denominator);
+        numerator /= divisor;
+        denominator /= divisor;
+    }
+    let denominator_sub_numerator = denominator.checked_sub(numerator)?;
+    if denominator_sub_numerator == 0 {
+        return None;
     }
-}
 
-pub fn price_precision_is_within_range(price: f64) -> Result<()> {
-    let decimal = Decimal::from_f64(price).ok_or(CoreError::ArithmeticError)?;
-    let decimal_with_scale = decimal.trunc_with_scale(3);
-    require!(
-        decimal.eq(&decimal_with_scale),
-        CoreError::PricePrecisionTooLarge
-    );
-    Ok(())
+    Some((denominator.checked_mul(scale)?, denominator_sub_numerator))
 }
 
 pub fn stake_precision_is_within_range(stake: u64, decimal_limit: u8) -> Result<bool> {
@@ -122,94 +144,202 @@ pub fn calculate_post_commission_remainder(commission_rate: f64, profit: i128) -
     profit_decimal.sub(commission_decimal).to_u64().unwrap()
 }
 
+/// least common multiple of dead-heat factor denominators, allowing factors to be summed exactly
+pub fn calculate_dead_heat_common_denominator(denominators: &[u16]) -> Option<u64> {
+    denominators
+        .iter()
+        .try_fold(1_u64, |common_denominator, denominator| {
+            let denominator = u64::from(*denominator);
+            if denominator == 0 {
+                return None;
+            }
+            let divisor =
+                greatest_common_divisor(u128::from(common_denominator), u128::from(denominator));
+            common_denominator
+                .checked_div(u64::try_from(divisor).ok()?)?
+                .checked_mul(denominator)
+        })
+}
+
+fn greatest_common_divisor(a: u128, b: u128) -> u128 {
+    match b {
+        0 => a,
+        _ => greatest_common_divisor(b, a % b),
+    }
+}
+
+/// dead_heat_amount = amount * numerator / denominator, rounded down
+pub fn calculate_dead_heat_amount(amount: u64, numerator: u16, denominator: u16) -> u64 {
+    let dead_heat_amount = u128::from(amount)
+        .checked_mul(u128::from(numerator))
+        .unwrap()
+        .checked_div(u128::from(denominator))
+        .unwrap();
+    u64::try_from(dead_heat_amount).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_price_to_decimal() {
-        let floats = vec![
-            1.001, 1.002, 1.003, 1.004, 1.005, 1.006, 1.007, 1.008, 1.009, 1.01, 1.02, 1.03, 1.04,
-            1.05, 1.06, 1.07, 1.08, 1.09, 1.1, 1.15, 1.2, 1.25, 1.3, 1.35, 1.4, 1.45, 1.5, 1.55,
-            1.6, 1.65, 1.7, 1.75, 1.8, 1.85, 1.9, 1.95, 2.0, 2.1, 2.2, 2.3, 2.4, 2.5, 2.6, 2.7,
-            2.8, 2.9, 3.0, 3.1, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 15.0, 20.0, 30.0, 40.0, 50.0,
-            60.0, 70.0, 80.0, 90.0, 100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 700.0, 800.0, 900.0,
-            1000.0,
-        ];
-        for x in floats {
-            assert_eq!(price_to_decimal(x).to_string(), format!("{:.3}", x));
-        }
-    }
-
     #[test]
     fn test_calculate_for_payout() {
-        assert_eq!(calculate_for_payout(100, 3.00), 300);
-        assert_eq!(calculate_for_payout(100, 3.22), 322);
-        assert_eq!(calculate_for_payout(100, 3.44), 344);
-        assert_eq!(calculate_for_payout(100, 3.66), 366);
-        assert_eq!(calculate_for_payout(1000, 3.00), 3000);
-        assert_eq!(calculate_for_payout(1000, 3.22), 3220);
-        assert_eq!(calculate_for_payout(1000, 3.44), 3440);
-        assert_eq!(calculate_for_payout(1000, 3.66), 3660);
-        assert_eq!(calculate_for_payout(10000, 3.00), 30000);
-        assert_eq!(calculate_for_payout(10000, 3.22), 32200);
-        assert_eq!(calculate_for_payout(10000, 3.44), 34400);
-        assert_eq!(calculate_for_payout(10000, 3.66), 36600);
+        assert_eq!(calculate_for_payout(100, Price::new(3_000)), 300);
+        assert_eq!(calculate_for_payout(100, Price::new(3_220)), 322);
+        assert_eq!(calculate_for_payout(100, Price::new(3_440)), 344);
+        assert_eq!(calculate_for_payout(100, Price::new(3_660)), 366);
+        assert_eq!(calculate_for_payout(1000, Price::new(3_000)), 3000);
+        assert_eq!(calculate_for_payout(1000, Price::new(3_220)), 3220);
+        assert_eq!(calculate_for_payout(1000, Price::new(3_440)), 3440);
+        assert_eq!(calculate_for_payout(1000, Price::new(3_660)), 3660);
+        assert_eq!(calculate_for_payout(10000, Price::new(3_000)), 30000);
+        assert_eq!(calculate_for_payout(10000, Price::new(3_220)), 32200);
+        assert_eq!(calculate_for_payout(10000, Price::new(3_440)), 34400);
+        assert_eq!(calculate_for_payout(10000, Price::new(3_660)), 36600);
     }
 
     #[test]
     fn test_calculate_stake_from_payout() {
-        assert_eq!(calculate_stake_from_payout(300, 3.00), 100);
-        assert_eq!(calculate_stake_from_payout(322, 3.22), 100);
-        assert_eq!(calculate_stake_from_payout(344, 3.44), 100);
-        assert_eq!(calculate_stake_from_payout(366, 3.66), 100);
+        assert_eq!(calculate_stake_from_payout(300, Price::new(3_000)), 100);
+        assert_eq!(calculate_stake_from_payout(322, Price::new(3_220)), 100);
+        assert_eq!(calculate_stake_from_payout(344, Price::new(3_440)), 100);
+        assert_eq!(calculate_stake_from_payout(366, Price::new(3_660)), 100);
     }
 
     #[test]
     fn test_calculate_stake_cross() {
         // 2.800, 2.800, 3.500
-        assert_eq!(calculate_stake_cross(100, 2.8, 3.5), 80);
-        assert_eq!(calculate_stake_cross(100, 3.5, 2.8), 125);
+        assert_eq!(
+            calculate_stake_cross(100, Price::new(2_800), Price::new(3_500)),
+            80
+        );
+        assert_eq!(
+            calculate_stake_cross(100, Price::new(3_500), Price::new(2_800)),
+            125
+        );
         // 2.700, 3.000, 3.375
-        assert_eq!(calculate_stake_cross(100, 2.7, 3.0), 90);
-        assert_eq!(calculate_stake_cross(100, 2.7, 3.375), 80);
-        assert_eq!(calculate_stake_cross(90, 3.0, 2.7), 100);
-        assert_eq!(calculate_stake_cross(90, 3.0, 3.375), 80);
-        assert_eq!(calculate_stake_cross(80, 3.375, 2.7), 100);
-        assert_eq!(calculate_stake_cross(80, 3.375, 3.0), 90);
+        assert_eq!(
+            calculate_stake_cross(100, Price::new(2_700), Price::new(3_000)),
+            90
+        );
+        assert_eq!(
+            calculate_stake_cross(100, Price::new(2_700), Price::new(3_375)),
+            80
+        );
+        assert_eq!(
+            calculate_stake_cross(90, Price::new(3_000), Price::new(2_700)),
+            100
+        );
+        assert_eq!(
+            calculate_stake_cross(90, Price::new(3_000), Price::new(3_375)),
+            80
+        );
+        assert_eq!(
+            calculate_stake_cross(80, Price::new(3_375), Price::new(2_700)),
+            100
+        );
+        assert_eq!(
+            calculate_stake_cross(80, Price::new(3_375), Price::new(3_000)),
+            90
+        );
     }
 
     #[test]
     fn test_calculate_price_cross() {
-        assert!(calculate_price_cross(&vec![3.1_f64]).is_none());
+        assert!(calculate_price_cross(&vec![Price::new(3_100)]).is_none());
+        assert!(calculate_price_cross(&vec![Price::new(1_100); 2]).is_none());
 
-        let cross_price_2way = calculate_price_cross(&vec![2.0_f64; 1]);
+        let cross_price_2way = calculate_price_cross(&vec![Price::new(2_000); 1]);
         assert!(cross_price_2way.is_some());
-        assert_eq!(2.0_f64, cross_price_2way.unwrap());
+        assert_eq!(Price::new(2_000), cross_price_2way.unwrap());
 
-        let cross_price_3way = calculate_price_cross(&vec![3.0_f64; 2]);
+        let cross_price_3way = calculate_price_cross(&vec![Price::new(3_000); 2]);
         assert!(cross_price_3way.is_some());
-        assert_eq!(3.0_f64, cross_price_3way.unwrap());
+        assert_eq!(Price::new(3_000), cross_price_3way.unwrap());
 
-        let cross_price_4way = calculate_price_cross(&vec![4.0_f64; 3]);
+        let cross_price_4way = calculate_price_cross(&vec![Price::new(4_000); 3]);
         assert!(cross_price_4way.is_some());
-        assert_eq!(4.0_f64, cross_price_4way.unwrap());
+        assert_eq!(Price::new(4_000), cross_price_4way.unwrap());
 
-        let cross_price_5way = calculate_price_cross(&vec![5.0_f64; 4]);
+        let cross_price_5way = calculate_price_cross(&vec![Price::new(5_000); 4]);
         assert!(cross_price_5way.is_some());
-        assert_eq!(5.0_f64, cross_price_5way.unwrap());
+        assert_eq!(Price::new(5_000), cross_price_5way.unwrap());
 
-        let cross_price_6way = calculate_price_cross(&vec![6.0_f64; 5]);
+        let cross_price_6way = calculate_price_cross(&vec![Price::new(6_000); 5]);
         assert!(cross_price_6way.is_some());
-        assert_eq!(6.0_f64, cross_price_6way.unwrap());
+        assert_eq!(Price::new(6_000), cross_price_6way.unwrap());
 
-        let cross_price_7way = calculate_price_cross(&vec![7.0_f64; 6]);
+        let cross_price_7way = calculate_price_cross(&vec![Price::new(7_000); 6]);
         assert!(cross_price_7way.is_some());
-        assert_eq!(7.0_f64, cross_price_7way.unwrap());
+        assert_eq!(Price::new(7_000), cross_price_7way.unwrap());
 
-        let cross_price_8way = calculate_price_cross(&vec![8.0_f64; 7]);
+        let cross_price_8way = calculate_price_cross(&vec![Price::new(8_000); 7]);
         assert!(cross_price_8way.is_some());
-        assert_eq!(8.0_f64, cross_price_8way.unwrap());
+        assert_eq!(Price::new(8_000), cross_price_8way.unwrap());
+
+        let cross_price_20way = calculate_price_cross(&[Price::new(20_000); 19]);
+        assert_eq!(Some(Price::new(20_000)), cross_price_20way);
+
+        // 1 / (1 - (1/2 + 1/4 + 1/10)) = 6.666... does not fit in 3 decimals
+        assert!(
+            calculate_price_cross(&[Price::new(2_000), Price::new(4_000), Price::new(10_000)])
+                .is_none()
+        );
+        // 1 / (1 - (1/2 + 1/4 + 1/8)) = 8
+        assert_eq!(
+            Some(Price::new(8_000)),
+            calculate_price_cross(&[Price::new(2_000), Price::new(4_000), Price::new(8_000)])
+        );
+    }
+
+    #[test]
+    fn test_calculate_price_cross_on_ladder() {
+        let ladder = [
+            Price::new(6_000),
+            Price::new(6_600),
+            Price::new(6_800),
+            Price::new(8_000),
+        ];
+        // 1 / (1 - (1/2 + 1/4 + 1/10)) = 6.666...
+        let prices = [Price::new(2_000), Price::new(4_000), Price::new(10_000)];
+        assert_eq!(
+            Some(Price::new(6_600)),
+            calculate_price_cross_on_ladder(&prices, &ladder, false)
+        );
+        assert_eq!(
+            Some(Price::new(6_800)),
+            calculate_price_cross_on_ladder(&prices, &ladder, true)
+        );
+
+        // exact prices on the ladder are kept in both directions
+        let prices = [Price::new(2_000), Price::new(4_000), Price::new(8_000)];
+        assert_eq!(
+            Some(Price::new(8_000)),
+            calculate_price_cross_on_ladder(&prices, &ladder, false)
+        );
+        assert_eq!(
+            Some(Price::new(8_000)),
+            calculate_price_cross_on_ladder(&prices, &ladder, true)
+        );
+
+        // no ladder price on the funded side
+        let prices = [Price::new(2_000)];
+        assert_eq!(
+            None,
+            calculate_price_cross_on_ladder(&prices, &ladder, false)
+        );
+        let prices = [Price::new(1_100)];
+        assert_eq!(
+            None,
+            calculate_price_cross_on_ladder(&prices, &ladder, true)
+        );
+
+        // no cross price at all
+        let prices = [Price::new(1_100); 2];
+        assert_eq!(
+            None,
+            calculate_price_cross_on_ladder(&prices, &ladder, false)
+        );
     }
 
     #[test]
@@ -232,15 +362,6 @@ mod tests {
         assert!(stake_precision_is_within_range(test_case, 3).unwrap());
     }
 
-    #[test]
-    fn test_price_precision_is_within_range() {
-        assert!(price_precision_is_within_range(1_f64).is_ok());
-        assert!(price_precision_is_within_range(1.1_f64).is_ok());
-        assert!(price_precision_is_within_range(1.11_f64).is_ok());
-        assert!(price_precision_is_within_range(1.111_f64).is_ok());
-        assert!(price_precision_is_within_range(1.1111_f64).is_err());
-    }
-
     #[test]
     fn test_calculate_commission() {
         assert_eq!(calculate_commission(5.00, 100), 5);
@@ -264,4 +385,23 @@ mod tests {
             calculate_post_commission_remainder(commission_rate, profit)
         );
     }
+
+    #[test]
+    fn test_calculate_dead_heat_common_denominator() {
+        assert_eq!(Some(1), calculate_dead_heat_common_denominator(&[]));
+        assert_eq!(Some(1), calculate_dead_heat_common_denominator(&[1, 1]));
+        assert_eq!(Some(2), calculate_dead_heat_common_denominator(&[2, 2]));
+        assert_eq!(Some(6), calculate_dead_heat_common_denominator(&[1, 2, 3]));
+        assert_eq!(Some(12), calculate_dead_heat_common_denominator(&[4, 6]));
+        assert_eq!(None, calculate_dead_heat_common_denominator(&[2, 0]));
+    }
+
+    #[test]
+    fn test_calculate_dead_heat_amount() {
+        assert_eq!(100, calculate_dead_heat_amount(100, 1, 1));
+        assert_eq!(50, calculate_dead_heat_amount(100, 1, 2));
+        assert_eq!(66, calculate_dead_heat_amount(100, 2, 3));
+        assert_eq!(0, calculate_dead_heat_amount(100, 0, 3));
+        assert_eq!(u64::MAX, calculate_dead_heat_amount(u64::MAX, 3, 3));
+    }
 }
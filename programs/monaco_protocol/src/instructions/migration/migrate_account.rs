@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::error::CoreError;
+use crate::state::market_account::Market;
+use crate::state::market_position_account::MarketPosition;
+use crate::state::market_type::MarketType;
+use crate::state::migration::{
+    migrate_authorised_operators, migrate_market, migrate_market_position, migrate_market_type,
+    migrate_order, migrate_trade,
+};
+use crate::state::operator_account::AuthorisedOperators;
+use crate::state::order_account::Order;
+use crate::state::trade_account::Trade;
+use crate::state::type_size::DISCRIMINATOR_SIZE;
+
+pub fn migrate_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    require!(
+        account.owner == &crate::ID,
+        CoreError::MigrationInvalidAccount
+    );
+
+    let migrated_data = migrate_account_data(&account.try_borrow_data()?)?;
+    let new_size = migrated_data.len();
+
+    // top up rent for the new size before growing the account
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_size);
+    let top_up = rent_exempt_lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.realloc(new_size, false)?;
+    account
+        .try_borrow_mut_data()?
+        .copy_from_slice(&migrated_data);
+
+    Ok(())
+}
+
+// returns the account data rewritten in the current layout, sized for the migrated account
+pub fn migrate_account_data(data: &[u8]) -> Result<Vec<u8>> {
+    require!(
+        data.len() >= DISCRIMINATOR_SIZE,
+        CoreError::MigrationInvalidAccount
+    );
+
+    let discriminator = &data[..DISCRIMINATOR_SIZE];
+    if discriminator == Market::discriminator() {
+        serialize(&migrate_market(data)?, Market::SIZE)
+    } else if discriminator == Order::discriminator() {
+        serialize(&migrate_order(data)?, Order::SIZE)
+    } else if discriminator == MarketPosition::discriminator() {
+        let market_position = migrate_market_position(data)?;
+        let size = MarketPosition::size_for(market_position.market_outcome_sums.len());
+        serialize(&market_position, size)
+    } else if discriminator == MarketType::discriminator() {
+        let market_type = migrate_market_type(data)?;
+        let size = MarketType::size_for(market_type.name.len());
+        serialize(&market_type, size)
+    } else if discriminator == AuthorisedOperators::discriminator() {
+        serialize(
+            &migrate_authorised_operators(data)?,
+            AuthorisedOperators::SIZE,
+        )
+    } else if discriminator == Trade::discriminator() {
+        serialize(&migrate_trade(data)?, Trade::SIZE)
+    } else {
+        Err(error!(CoreError::MigrationInvalidAccount))
+    }
+}
+
+fn serialize<T: AccountSerialize>(account: &T, size: usize) -> Result<Vec<u8>> {
+    let mut data = Vec::with_capacity(size);
+    account.try_serialize(&mut data)?;
+    data.resize(size, 0);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_position_account::mock_market_position;
+    use crate::state::migration::{MarketPositionV0, OrderV0};
+    use crate::state::order_account::{mock_order_default, OrderStatus};
+
+    #[test]
+    fn test_migrate_account_data_order_v0() {
+        let order_v0 = OrderV0 {
+            purchaser: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            market_outcome_index: 0,
+            for_outcome: true,
+            order_status: OrderStatus::Open,
+            product: None,
+            stake: 10,
+            voided_stake: 0,
+            expected_price: 2.5,
+            creation_timestamp: 0,
+            stake_unmatched: 10,
+            payout: 0,
+            payer: Pubkey::new_unique(),
+            product_commission_rate: 0.0,
+        };
+        let mut data = Order::discriminator().to_vec();
+        order_v0.serialize(&mut data).unwrap();
+        data.resize(OrderV0::SIZE, 0);
+
+        let result = migrate_account_data(&data).unwrap();
+
+        assert_eq!(Order::SIZE, result.len());
+        let order = Order::try_deserialize(&mut &result[..]).unwrap();
+        assert_eq!(order_v0.purchaser, order.purchaser);
+        assert_eq!(Order::SCHEMA_VERSION, order.schema_version);
+    }
+
+    #[test]
+    fn test_migrate_account_data_market_position_v0() {
+        let market_position_v0 = MarketPositionV0 {
+            purchaser: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            paid: false,
+            market_outcome_sums: vec![0; 3],
+            unmatched_exposures: vec![0; 3],
+            payer: Pubkey::new_unique(),
+            matched_risk: 0,
+            matched_risk_per_product: vec![],
+        };
+        let mut data = MarketPosition::discriminator().to_vec();
+        market_position_v0.serialize(&mut data).unwrap();
+        data.resize(MarketPositionV0::size_for(3), 0);
+
+        let result = migrate_account_data(&data).unwrap();
+
+        assert_eq!(MarketPosition::size_for(3), result.len());
+        let market_position = MarketPosition::try_deserialize(&mut &result[..]).unwrap();
+        assert_eq!(market_position_v0.purchaser, market_position.purchaser);
+        assert_eq!(3, market_position.market_outcome_sums.len());
+    }
+
+    #[test]
+    fn test_migrate_account_data_up_to_date() {
+        let order = mock_order_default();
+        let data = serialize(&order, Order::SIZE).unwrap();
+
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_account_data(&data).map(|_| ())
+        );
+
+        let market_position = MarketPosition {
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            ..mock_market_position(2)
+        };
+        let data = serialize(&market_position, MarketPosition::size_for(2)).unwrap();
+
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_account_data(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_account_data_invalid_account() {
+        assert_eq!(
+            Err(error!(CoreError::MigrationInvalidAccount)),
+            migrate_account_data(&[0; 4]).map(|_| ())
+        );
+        assert_eq!(
+            Err(error!(CoreError::MigrationInvalidAccount)),
+            migrate_account_data(&[0; 100]).map(|_| ())
+        );
+    }
+}
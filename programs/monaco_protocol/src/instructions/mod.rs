@@ -6,18 +6,21 @@ pub use payment::*;
 pub use transfer::*;
 
 pub(crate) mod close;
-pub(crate) mod market;
+pub(crate) mod market_order_book;
 pub(crate) mod market_type;
-pub(crate) mod matching;
-pub(crate) mod order;
-pub(crate) mod order_request;
+pub(crate) mod migration;
 pub(crate) mod price_ladder;
+pub(crate) mod trade;
 
 mod clock;
 mod math;
 mod operator;
 mod payment;
 
+pub mod market;
 pub mod market_liquidities;
 pub mod market_position;
+pub mod matching;
+pub mod order;
+pub mod order_request;
 pub mod transfer;
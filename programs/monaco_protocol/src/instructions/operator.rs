@@ -1,6 +1,6 @@
 use crate::context::AuthoriseOperator;
 use crate::error::CoreError;
-use crate::state::operator_account::OperatorType;
+use crate::state::operator_account::{OperatorPermission, OperatorType};
 use crate::AuthorisedOperators;
 use anchor_lang::prelude::*;
 use solana_program::pubkey::Pubkey;
@@ -16,11 +16,31 @@ pub fn authorise_operator(
 
     // TODO This field is redundant
     authorised_operators.authority = authority.key();
-    let result = authorised_operators.insert(operator);
+    let result = authorised_operators.insert(operator, OperatorPermission::ALL);
     require!(result, CoreError::AuthorisedOperatorListFull);
     Ok(())
 }
 
+pub fn update_operator_permissions(
+    authorised_operators: &mut AuthorisedOperators,
+    operator: Pubkey,
+    operator_type: String,
+    permissions: u16,
+) -> Result<()> {
+    validate_operator_type(operator_type)?;
+    require!(
+        OperatorPermission::is_valid(permissions),
+        CoreError::InvalidOperatorPermissions
+    );
+    require!(
+        authorised_operators.contains(&operator),
+        CoreError::AuthorisedOperatorNotFound
+    );
+
+    authorised_operators.insert(operator, permissions);
+    Ok(())
+}
+
 pub fn remove_authorised_operator(
     ctx: Context<AuthoriseOperator>,
     operator: Pubkey,
@@ -31,17 +51,6 @@ pub fn remove_authorised_operator(
     Ok(())
 }
 
-pub fn verify_operator_authority(
-    operator: &Pubkey,
-    authorised_operators: &AuthorisedOperators,
-) -> Result<()> {
-    if !authorised_operators.contains(operator) {
-        msg!("Operator is not authorised to carry out this operation.");
-        return Err(error!(CoreError::UnauthorisedOperator));
-    }
-    Ok(())
-}
-
 fn validate_operator_type(operator_type: String) -> Result<()> {
     let result = OperatorType::from_str(&operator_type);
     require!(result.is_ok(), CoreError::InvalidOperatorType);
@@ -50,7 +59,7 @@ fn validate_operator_type(operator_type: String) -> Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use crate::instructions::operator::validate_operator_type;
+    use super::*;
 
     #[test]
     fn valid_operator_type() {
@@ -69,4 +78,39 @@ mod tests {
         let result = validate_operator_type(String::from("SECRET_AGENT_007"));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_update_operator_permissions() {
+        let operator = Pubkey::new_unique();
+        let mut authorised_operators = AuthorisedOperators::default();
+        authorised_operators.insert(operator, OperatorPermission::ALL);
+
+        let result = update_operator_permissions(
+            &mut authorised_operators,
+            operator,
+            String::from("MARKET"),
+            OperatorPermission::SETTLE,
+        );
+        assert!(result.is_ok());
+        assert_eq!(
+            OperatorPermission::SETTLE,
+            authorised_operators.permissions(&operator)
+        );
+
+        let result = update_operator_permissions(
+            &mut authorised_operators,
+            operator,
+            String::from("MARKET"),
+            1 << 15,
+        );
+        assert_eq!(Err(error!(CoreError::InvalidOperatorPermissions)), result);
+
+        let result = update_operator_permissions(
+            &mut authorised_operators,
+            Pubkey::new_unique(),
+            String::from("MARKET"),
+            OperatorPermission::SETTLE,
+        );
+        assert_eq!(Err(error!(CoreError::AuthorisedOperatorNotFound)), result);
+    }
 }
@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use protocol_product::state::product::Product;
+use solana_program::clock::UnixTimestamp;
+use std::ops::Deref;
+
+use crate::error::CoreError;
+use crate::instructions::order::cancel_order;
+use crate::instructions::order_request::{
+    enqueue_order_request, validate_market_position_exposure, validate_order_request,
+};
+use crate::instructions::{current_timestamp, market_position};
+use crate::state::market_account::Market;
+use crate::state::market_limits::MarketLimits;
+use crate::state::market_liquidities::MarketLiquidities;
+use crate::state::market_matching_pool_account::MarketMatchingPool;
+use crate::state::market_matching_queue_account::MarketMatchingQueue;
+use crate::state::market_order_request_queue::{MarketOrderRequestQueue, OrderRequestData};
+use crate::state::market_outcome_account::MarketOutcome;
+use crate::state::market_position_account::MarketPosition;
+use crate::state::order_account::Order;
+use crate::state::price_ladder::PriceLadder;
+
+// returns (refund, payment) - the refund for the cancelled stake is netted against the payment
+// for the replacement request, including its crank fee, so at most one of them is non-zero
+#[allow(clippy::too_many_arguments)]
+pub fn amend_order(
+    market: &mut Market,
+    order_pk: &Pubkey,
+    order: &mut Order,
+    product: &Option<Account<Product>>,
+    market_position: &mut MarketPosition,
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_queue: &MarketMatchingQueue,
+    market_matching_pool: &mut MarketMatchingPool,
+    market_outcome: &MarketOutcome,
+    price_ladder: &Option<Account<PriceLadder>>,
+    market_limits: Option<&MarketLimits>,
+    order_request_queue: &mut MarketOrderRequestQueue,
+    data: OrderRequestData,
+) -> Result<(u64, u64)> {
+    require!(
+        data.market_outcome_index == order.market_outcome_index
+            && data.for_outcome == order.for_outcome,
+        CoreError::AmendmentMarketOutcomeMismatch
+    );
+
+    let refund = cancel_order(
+        market,
+        order_pk,
+        order,
+        market_position,
+        market_liquidities,
+        market_matching_queue,
+        market_matching_pool,
+    )?;
+
+    let now: UnixTimestamp = current_timestamp();
+    // unpack account optionals (works only for non-mut)
+    let price_ladder_account = price_ladder.as_ref().map(|v| v.deref());
+    validate_order_request(
+        market,
+        market_outcome,
+        &price_ladder_account,
+        market_limits,
+        &data,
+        now,
+    )?;
+
+    let order_request = enqueue_order_request(
+        market,
+        &order.purchaser,
+        product,
+        order_request_queue,
+        data,
+        now,
+    )?;
+
+    let payment = market_position::update_on_order_request_creation(
+        market_position,
+        order_request.market_outcome_index,
+        order_request.for_outcome,
+        order_request.stake,
+        order_request.expected_price,
+    )?;
+    validate_market_position_exposure(market_limits, market_position)?;
+    // the replacement request costs a crank step like any other
+    let payment = payment
+        .checked_add(market.collect_crank_fee()?)
+        .ok_or(CoreError::ArithmeticError)?;
+
+    Ok(net_refund_and_payment(refund, payment))
+}
+
+fn net_refund_and_payment(refund: u64, payment: u64) -> (u64, u64) {
+    if refund > payment {
+        (refund - payment, 0)
+    } else {
+        (0, payment - refund)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_account::{mock_market, MarketStatus};
+    use crate::state::market_liquidities::mock_market_liquidities;
+    use crate::state::market_matching_pool_account::mock_market_matching_pool;
+    use crate::state::market_matching_queue_account::mock_market_matching_queue;
+    use crate::state::market_order_request_queue::mock_order_request_queue;
+    use crate::state::market_order_request_queue::TimeInForce;
+    use crate::state::market_position_account::mock_market_position;
+    use crate::state::order_account::{mock_order, OrderStatus};
+    use crate::state::price::Price;
+
+    struct AmendOrderTestContext {
+        market: Market,
+        order_pk: Pubkey,
+        order: Order,
+        market_position: MarketPosition,
+        market_liquidities: MarketLiquidities,
+        market_matching_queue: MarketMatchingQueue,
+        market_matching_pool: MarketMatchingPool,
+        market_outcome: MarketOutcome,
+        order_request_queue: MarketOrderRequestQueue,
+    }
+
+    // open for order on outcome 1 of a 3 outcome market with its stake fully unmatched
+    fn setup(for_outcome: bool, price: Price, stake: u64) -> AmendOrderTestContext {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        market.market_lock_timestamp = i64::MAX;
+
+        let order_pk = Pubkey::new_unique();
+        let order = mock_order(
+            market_pk,
+            1,
+            for_outcome,
+            price,
+            stake,
+            Pubkey::new_unique(),
+        );
+
+        let mut market_position = mock_market_position(3);
+        market_position::update_on_order_request_creation(
+            &mut market_position,
+            1,
+            for_outcome,
+            stake,
+            price,
+        )
+        .unwrap();
+
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        match for_outcome {
+            true => market_liquidities.add_liquidity_for(1, price, stake),
+            false => market_liquidities.add_liquidity_against(1, price, stake),
+        }
+        .unwrap();
+
+        let mut market_matching_pool = mock_market_matching_pool(market_pk, 1, for_outcome, price);
+        market_matching_pool.orders.enqueue(order_pk);
+        market_matching_pool.liquidity_amount = stake;
+
+        AmendOrderTestContext {
+            market,
+            order_pk,
+            order,
+            market_position,
+            market_liquidities,
+            market_matching_queue: mock_market_matching_queue(market_pk),
+            market_matching_pool,
+            market_outcome: MarketOutcome {
+                market: market_pk,
+                index: 1,
+                title: "title".to_string(),
+                prices: None,
+                price_ladder: vec![],
+            },
+            order_request_queue: mock_order_request_queue(market_pk),
+        }
+    }
+
+    fn amend(context: &mut AmendOrderTestContext, data: OrderRequestData) -> Result<(u64, u64)> {
+        amend_order(
+            &mut context.market,
+            &context.order_pk,
+            &mut context.order,
+            &None,
+            &mut context.market_position,
+            &mut context.market_liquidities,
+            &context.market_matching_queue,
+            &mut context.market_matching_pool,
+            &context.market_outcome,
+            &None,
+            None,
+            &mut context.order_request_queue,
+            data,
+        )
+    }
+
+    fn order_request_data(for_outcome: bool, stake: u64, price: Price) -> OrderRequestData {
+        OrderRequestData {
+            market_outcome_index: 1,
+            for_outcome,
+            stake,
+            price,
+            distinct_seed: [1; 16],
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+        }
+    }
+
+    #[test]
+    fn test_amend_order_increase_stake() {
+        let mut context = setup(true, Price::new(3_000), 10);
+        assert_eq!(vec![10, 0, 10], context.market_position.unmatched_exposures);
+
+        let result = amend(
+            &mut context,
+            order_request_data(true, 15, Price::new(2_000)),
+        );
+
+        assert_eq!(Ok((0, 5)), result);
+        assert_eq!(OrderStatus::Cancelled, context.order.order_status);
+        assert_eq!(0, context.order.stake_unmatched);
+        assert_eq!(vec![15, 0, 15], context.market_position.unmatched_exposures);
+        assert_eq!(0, context.market_liquidities.liquidities_for.len());
+        assert_eq!(0, context.market_matching_pool.orders.len());
+
+        assert_eq!(1, context.order_request_queue.order_requests.len());
+        let order_request = context.order_request_queue.order_requests.peek_front();
+        assert_eq!(15, order_request.unwrap().stake);
+        assert_eq!(Price::new(2_000), order_request.unwrap().expected_price);
+        assert_eq!(context.order.purchaser, order_request.unwrap().purchaser);
+    }
+
+    #[test]
+    fn test_amend_order_decrease_exposure() {
+        let mut context = setup(false, Price::new(3_000), 10);
+        assert_eq!(vec![0, 20, 0], context.market_position.unmatched_exposures);
+
+        let result = amend(
+            &mut context,
+            order_request_data(false, 10, Price::new(2_000)),
+        );
+
+        assert_eq!(Ok((10, 0)), result);
+        assert_eq!(OrderStatus::Cancelled, context.order.order_status);
+        assert_eq!(vec![0, 10, 0], context.market_position.unmatched_exposures);
+        assert_eq!(1, context.order_request_queue.order_requests.len());
+    }
+
+    #[test]
+    fn test_amend_order_collects_crank_fee() {
+        let mut context = setup(false, Price::new(3_000), 10);
+        context.market.crank_fee = 2;
+
+        let result = amend(
+            &mut context,
+            order_request_data(false, 10, Price::new(2_000)),
+        );
+
+        assert_eq!(Ok((8, 0)), result);
+        assert_eq!(2, context.market.crank_fee_balance);
+
+        let mut context = setup(true, Price::new(3_000), 10);
+        context.market.crank_fee = 2;
+
+        let result = amend(
+            &mut context,
+            order_request_data(true, 15, Price::new(2_000)),
+        );
+
+        assert_eq!(Ok((0, 7)), result);
+        assert_eq!(2, context.market.crank_fee_balance);
+    }
+
+    #[test]
+    fn test_amend_order_outcome_mismatch() {
+        let mut context = setup(true, Price::new(3_000), 10);
+
+        let result = amend(
+            &mut context,
+            order_request_data(false, 10, Price::new(2_000)),
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::AmendmentMarketOutcomeMismatch)),
+            result
+        );
+        assert_eq!(10, context.order.stake_unmatched);
+        assert_eq!(0, context.order_request_queue.order_requests.len());
+    }
+
+    #[test]
+    fn test_amend_order_invalid_replacement() {
+        let mut context = setup(true, Price::new(3_000), 10);
+
+        let result = amend(&mut context, order_request_data(true, 0, Price::new(2_000)));
+
+        assert_eq!(Err(error!(CoreError::CreationStakeZeroOrLess)), result);
+    }
+
+    #[test]
+    fn test_net_refund_and_payment() {
+        assert_eq!((5, 0), net_refund_and_payment(10, 5));
+        assert_eq!((0, 5), net_refund_and_payment(5, 10));
+        assert_eq!((0, 0), net_refund_and_payment(10, 10));
+    }
+}
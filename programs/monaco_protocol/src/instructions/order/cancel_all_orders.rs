@@ -0,0 +1,451 @@
+use anchor_lang::prelude::*;
+use std::ops::DerefMut;
+
+use crate::error::CoreError;
+use crate::instructions::market_order_book::{is_market_order_book, remove_from_market_order_book};
+use crate::instructions::order::cancel_order;
+use crate::state::market_account::Market;
+use crate::state::market_liquidities::MarketLiquidities;
+use crate::state::market_matching_pool_account::MarketMatchingPool;
+use crate::state::market_matching_queue_account::MarketMatchingQueue;
+use crate::state::market_order_book::MarketOrderBook;
+use crate::state::market_position_account::MarketPosition;
+use crate::state::order_account::*;
+
+// remaining_accounts are expected to be (order, market_matching_pool) pairs, orders with no
+// unmatched stake are skipped so that the same set of orders can be resubmitted after matching;
+// order book pages other than market_order_book and the payers of orders that were never matched
+// are looked for in trailing_accounts
+#[allow(clippy::too_many_arguments)]
+pub fn cancel_all_orders<'info>(
+    market_pk: &Pubkey,
+    market: &mut Market,
+    purchaser: &Pubkey,
+    market_position: &mut MarketPosition,
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_queue: &MarketMatchingQueue,
+    market_order_book: Option<&mut Account<'info, MarketOrderBook>>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    trailing_accounts: &'info [AccountInfo<'info>],
+) -> Result<u64> {
+    let account_pairs = remaining_accounts.chunks_exact(2);
+    require!(
+        !remaining_accounts.is_empty() && account_pairs.remainder().is_empty(),
+        CoreError::CancelationInvalidOrderAccounts
+    );
+
+    let mut orders: Vec<(Pubkey, Account<Order>, usize)> = vec![];
+    let mut market_matching_pools: Vec<Account<MarketMatchingPool>> = vec![];
+    for accounts in account_pairs {
+        let (order_info, market_matching_pool_info) = (&accounts[0], &accounts[1]);
+        require!(
+            !orders
+                .iter()
+                .any(|(order_pk, _, _)| order_pk == order_info.key),
+            CoreError::CancelationDuplicateOrder
+        );
+
+        let order = Account::<Order>::try_from(order_info)?;
+        require!(
+            order.purchaser == *purchaser,
+            CoreError::CancelationPurchaserMismatch
+        );
+        require!(
+            order.market == *market_pk,
+            CoreError::CancelationMarketMismatch
+        );
+
+        // the same matching pool can be shared by several orders so it is only loaded once
+        let market_matching_pool_index =
+            match market_matching_pools
+                .iter()
+                .position(|market_matching_pool| {
+                    market_matching_pool.key() == *market_matching_pool_info.key
+                }) {
+                Some(index) => index,
+                None => {
+                    market_matching_pools.push(Account::<MarketMatchingPool>::try_from(
+                        market_matching_pool_info,
+                    )?);
+                    market_matching_pools.len() - 1
+                }
+            };
+
+        orders.push((*order_info.key, order, market_matching_pool_index));
+    }
+
+    let market_order_book_pk = market_order_book.as_ref().map(|page| page.key());
+    let mut market_order_books: Vec<Account<MarketOrderBook>> = vec![];
+    for account in trailing_accounts {
+        if is_market_order_book(account)
+            && market_order_book_pk != Some(*account.key)
+            && !market_order_books
+                .iter()
+                .any(|page| page.key() == *account.key)
+        {
+            market_order_books.push(Account::<MarketOrderBook>::try_from(account)?);
+        }
+    }
+
+    let (refund, closed_orders) = {
+        let mut pages: Vec<&mut MarketOrderBook> = market_order_book
+            .map(|page| page.deref_mut())
+            .into_iter()
+            .chain(market_order_books.iter_mut().map(|page| page.deref_mut()))
+            .collect();
+        cancel_orders(
+            market,
+            market_position,
+            market_liquidities,
+            market_matching_queue,
+            &mut orders,
+            &mut market_matching_pools,
+            &mut pages,
+        )?
+    };
+
+    for (order_pk, order, _) in orders.iter() {
+        if closed_orders.contains(order_pk) {
+            let payer = trailing_accounts
+                .iter()
+                .find(|account| *account.key == order.payer)
+                .ok_or(CoreError::CancelationPayerMismatch)?;
+            order.close(payer.clone())?;
+        } else {
+            order.exit(&crate::ID)?;
+        }
+    }
+    for market_matching_pool in market_matching_pools.iter() {
+        market_matching_pool.exit(&crate::ID)?;
+    }
+    for market_order_book in market_order_books.iter() {
+        market_order_book.exit(&crate::ID)?;
+    }
+
+    Ok(refund)
+}
+
+fn cancel_orders<O, P, B>(
+    market: &mut Market,
+    market_position: &mut MarketPosition,
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_queue: &MarketMatchingQueue,
+    orders: &mut [(Pubkey, O, usize)],
+    market_matching_pools: &mut [P],
+    market_order_books: &mut [B],
+) -> Result<(u64, Vec<Pubkey>)>
+where
+    O: DerefMut<Target = Order>,
+    P: DerefMut<Target = MarketMatchingPool>,
+    B: DerefMut<Target = MarketOrderBook>,
+{
+    let mut refund = 0_u64;
+    let mut closed_orders = vec![];
+
+    for (order_pk, order, market_matching_pool_index) in orders.iter_mut() {
+        let market_matching_pool = market_matching_pools[*market_matching_pool_index].deref_mut();
+        require!(
+            market_matching_pool.market == order.market
+                && market_matching_pool.market_outcome_index == order.market_outcome_index
+                && market_matching_pool.for_outcome == order.for_outcome
+                && market_matching_pool.price == order.expected_price,
+            CoreError::CancelationMarketMatchingPoolMismatch
+        );
+
+        let cancellable = [OrderStatus::Open, OrderStatus::Matched].contains(&order.order_status)
+            && order.stake_unmatched > 0_u64;
+        if !cancellable {
+            continue;
+        }
+
+        let stake_unmatched = order.stake_unmatched;
+        let order_refund = cancel_order(
+            market,
+            order_pk,
+            order,
+            market_position,
+            market_liquidities,
+            market_matching_queue,
+            market_matching_pool,
+        )?;
+        let market_order_book = market_order_books
+            .iter_mut()
+            .map(|page| page.deref_mut())
+            .find(|page| {
+                page.market == order.market
+                    && page.contains(order.market_outcome_index, order.expected_price)
+            });
+        remove_from_market_order_book(
+            market,
+            market_order_book,
+            order,
+            stake_unmatched.saturating_sub(order.stake_unmatched),
+        )?;
+        refund = refund
+            .checked_add(order_refund)
+            .ok_or(CoreError::ArithmeticError)?;
+
+        // if never matched, close
+        if order.order_status == OrderStatus::Cancelled {
+            market.decrement_account_counts()?;
+            closed_orders.push(*order_pk);
+        }
+    }
+
+    Ok((refund, closed_orders))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::market_position;
+    use crate::state::market_account::{mock_market, MarketStatus};
+    use crate::state::market_liquidities::mock_market_liquidities;
+    use crate::state::market_matching_pool_account::{mock_market_matching_pool, Cirque};
+    use crate::state::market_matching_queue_account::mock_market_matching_queue;
+    use crate::state::market_order_book::mock_market_order_book;
+    use crate::state::market_position_account::mock_market_position;
+    use crate::state::price::Price;
+
+    fn add_order(
+        market_position: &mut MarketPosition,
+        market_liquidities: &mut MarketLiquidities,
+        market_matching_pool: &mut MarketMatchingPool,
+        order_pk: Pubkey,
+        order: &Order,
+    ) {
+        market_position::update_on_order_request_creation(
+            market_position,
+            order.market_outcome_index,
+            order.for_outcome,
+            order.stake,
+            order.expected_price,
+        )
+        .unwrap();
+        match order.for_outcome {
+            true => market_liquidities.add_liquidity_for(
+                order.market_outcome_index,
+                order.expected_price,
+                order.stake,
+            ),
+            false => market_liquidities.add_liquidity_against(
+                order.market_outcome_index,
+                order.expected_price,
+                order.stake,
+            ),
+        }
+        .unwrap();
+        market_matching_pool.orders.enqueue(order_pk);
+        market_matching_pool.liquidity_amount += order.stake;
+    }
+
+    #[test]
+    fn test_cancel_all_orders() {
+        let market_pk = Pubkey::new_unique();
+        let payer_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.unsettled_accounts_count = 4;
+        market.unclosed_accounts_count = 4;
+        let mut market_position = mock_market_position(3);
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let mut pool_for = mock_market_matching_pool(market_pk, 0, true, Price::new(3_000));
+        pool_for.orders = Cirque::new(2);
+        let mut pool_against = mock_market_matching_pool(market_pk, 1, false, Price::new(2_000));
+
+        let order_pk_1 = Pubkey::new_unique();
+        let mut order_1 = mock_order(market_pk, 0, true, Price::new(3_000), 10, payer_pk);
+        let order_pk_2 = Pubkey::new_unique();
+        let mut order_2 = mock_order(market_pk, 0, true, Price::new(3_000), 5, payer_pk);
+        let order_pk_3 = Pubkey::new_unique();
+        let mut order_3 = mock_order(market_pk, 1, false, Price::new(2_000), 10, payer_pk);
+        let order_pk_4 = Pubkey::new_unique();
+        let mut order_4 = mock_order(market_pk, 1, false, Price::new(2_000), 10, payer_pk);
+        order_4.order_status = OrderStatus::Cancelled;
+        order_4.stake_unmatched = 0;
+        order_4.voided_stake = 10;
+
+        add_order(
+            &mut market_position,
+            &mut market_liquidities,
+            &mut pool_for,
+            order_pk_1,
+            &order_1,
+        );
+        add_order(
+            &mut market_position,
+            &mut market_liquidities,
+            &mut pool_for,
+            order_pk_2,
+            &order_2,
+        );
+        add_order(
+            &mut market_position,
+            &mut market_liquidities,
+            &mut pool_against,
+            order_pk_3,
+            &order_3,
+        );
+        assert_eq!(vec![0, 25, 15], market_position.unmatched_exposures);
+
+        let mut orders = vec![
+            (order_pk_1, &mut order_1, 0),
+            (order_pk_2, &mut order_2, 0),
+            (order_pk_3, &mut order_3, 1),
+            (order_pk_4, &mut order_4, 1),
+        ];
+        let mut market_matching_pools = vec![&mut pool_for, &mut pool_against];
+
+        let result = cancel_orders(
+            &mut market,
+            &mut market_position,
+            &mut market_liquidities,
+            &market_matching_queue,
+            &mut orders,
+            &mut market_matching_pools,
+            &mut Vec::<&mut MarketOrderBook>::new(),
+        );
+
+        assert_eq!(Ok((25, vec![order_pk_1, order_pk_2, order_pk_3])), result);
+        assert_eq!(1, market.unsettled_accounts_count);
+        assert_eq!(1, market.unclosed_accounts_count);
+        assert_eq!(vec![0, 0, 0], market_position.unmatched_exposures);
+        assert_eq!(0, market_liquidities.liquidities_for.len());
+        assert_eq!(0, market_liquidities.liquidities_against.len());
+        assert_eq!(0, pool_for.liquidity_amount);
+        assert_eq!(0, pool_for.orders.len());
+        assert_eq!(0, pool_against.liquidity_amount);
+        assert_eq!(0, pool_against.orders.len());
+        assert_eq!(OrderStatus::Cancelled, order_1.order_status);
+        assert_eq!(10, order_1.voided_stake);
+        assert_eq!(OrderStatus::Cancelled, order_2.order_status);
+        assert_eq!(OrderStatus::Cancelled, order_3.order_status);
+        assert_eq!(10, order_4.voided_stake);
+    }
+
+    #[test]
+    fn test_cancel_all_orders_removes_from_order_book() {
+        let market_pk = Pubkey::new_unique();
+        let payer_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.order_book_enabled = true;
+        market.unsettled_accounts_count = 2;
+        market.unclosed_accounts_count = 2;
+        let mut market_position = mock_market_position(3);
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let mut pool_for = mock_market_matching_pool(market_pk, 0, true, Price::new(3_000));
+        let mut pool_against = mock_market_matching_pool(market_pk, 1, false, Price::new(2_000));
+        let mut page_0 = mock_market_order_book(market_pk, 0, &[Price::new(3_000)]);
+        page_0.add_liquidity(true, Price::new(3_000), 10).unwrap();
+        let mut page_1 = mock_market_order_book(market_pk, 1, &[Price::new(2_000)]);
+        page_1.add_liquidity(false, Price::new(2_000), 10).unwrap();
+
+        let order_pk_1 = Pubkey::new_unique();
+        let mut order_1 = mock_order(market_pk, 0, true, Price::new(3_000), 10, payer_pk);
+        let order_pk_2 = Pubkey::new_unique();
+        let mut order_2 = mock_order(market_pk, 1, false, Price::new(2_000), 10, payer_pk);
+
+        add_order(
+            &mut market_position,
+            &mut market_liquidities,
+            &mut pool_for,
+            order_pk_1,
+            &order_1,
+        );
+        add_order(
+            &mut market_position,
+            &mut market_liquidities,
+            &mut pool_against,
+            order_pk_2,
+            &order_2,
+        );
+
+        let result = cancel_orders(
+            &mut market,
+            &mut market_position,
+            &mut market_liquidities,
+            &market_matching_queue,
+            &mut [(order_pk_1, &mut order_1, 0), (order_pk_2, &mut order_2, 1)],
+            &mut [&mut pool_for, &mut pool_against],
+            &mut [&mut page_1, &mut page_0],
+        );
+
+        assert_eq!(Ok((20, vec![order_pk_1, order_pk_2])), result);
+        assert_eq!(0, page_0.levels[0].liquidity_for);
+        assert_eq!(0, page_1.levels[0].liquidity_against);
+    }
+
+    #[test]
+    fn test_cancel_all_orders_matching_pool_mismatch() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        let mut market_position = mock_market_position(3);
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let mut pool = mock_market_matching_pool(market_pk, 0, true, Price::new(2_000));
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            0,
+            true,
+            Price::new(3_000),
+            10,
+            Pubkey::new_unique(),
+        );
+
+        let result = cancel_orders(
+            &mut market,
+            &mut market_position,
+            &mut market_liquidities,
+            &market_matching_queue,
+            &mut [(order_pk, &mut order, 0)],
+            &mut [&mut pool],
+            &mut Vec::<&mut MarketOrderBook>::new(),
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::CancelationMarketMatchingPoolMismatch)),
+            result
+        );
+        assert_eq!(10, order.stake_unmatched);
+    }
+
+    #[test]
+    fn test_cancel_all_orders_market_not_open() {
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Settled);
+        let mut market_position = mock_market_position(3);
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let market_matching_queue = mock_market_matching_queue(market_pk);
+
+        let mut pool = mock_market_matching_pool(market_pk, 0, true, Price::new(3_000));
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            0,
+            true,
+            Price::new(3_000),
+            10,
+            Pubkey::new_unique(),
+        );
+
+        let result = cancel_orders(
+            &mut market,
+            &mut market_position,
+            &mut market_liquidities,
+            &market_matching_queue,
+            &mut [(order_pk, &mut order, 0)],
+            &mut [&mut pool],
+            &mut Vec::<&mut MarketOrderBook>::new(),
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::CancelationMarketStatusInvalid)),
+            result
+        );
+    }
+}
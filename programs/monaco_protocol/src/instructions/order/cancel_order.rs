@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::error::CoreError;
+use crate::events::order::OrderCancelledEvent;
 use crate::instructions::market::move_market_to_inplay_if_needed;
 use crate::instructions::{market_position, matching};
 use crate::state::market_account::{Market, MarketStatus};
@@ -33,6 +34,11 @@ pub fn cancel_order(
         order.stake_unmatched > 0_u64,
         CoreError::CancelOrderNotCancellable
     );
+    // unmatched stake is no longer in the market liquidities
+    require!(
+        !market.is_cancelled_by_suspension(order.creation_timestamp),
+        CoreError::CancelationOrderCancelledBySuspension
+    );
 
     move_market_to_inplay_if_needed(market, market_liquidities)?;
     // move_market_matching_pool_to_inplay_if_needed
@@ -44,6 +50,14 @@ pub fn cancel_order(
         );
         market_matching_pool.move_to_inplay(&market.event_start_order_behaviour);
     }
+    if market.is_outcome_withdrawn(order.market_outcome_index) {
+        return cancel_order_on_withdrawn_outcome(
+            market_matching_pool,
+            order_pk,
+            order,
+            market_position,
+        );
+    }
 
     cancel_order_common(
         market_liquidities,
@@ -54,6 +68,29 @@ pub fn cancel_order(
     )
 }
 
+// liquidity of a withdrawn outcome was cleared on withdrawal, so only the matching pool is
+// cleared and the unmatched stake voided
+pub fn cancel_order_on_withdrawn_outcome(
+    market_matching_pool: &mut MarketMatchingPool,
+    order_pk: &Pubkey,
+    order: &mut Order,
+    market_position: &mut MarketPosition,
+) -> Result<u64> {
+    market_matching_pool.clear_liquidity();
+    let stake_to_void = order.stake_unmatched;
+    order.void_stake_unmatched()?;
+
+    emit!(OrderCancelledEvent {
+        market: order.market,
+        order: *order_pk,
+        purchaser: order.purchaser,
+        stake_voided: stake_to_void,
+        stake_unmatched: order.stake_unmatched,
+    });
+
+    market_position::update_on_order_cancellation(market_position, order, stake_to_void)
+}
+
 pub fn cancel_order_common(
     market_liquidities: &mut MarketLiquidities,
     market_matching_pool: &mut MarketMatchingPool,
@@ -100,6 +137,14 @@ pub fn cancel_order_common(
         }
     }
 
+    emit!(OrderCancelledEvent {
+        market: order.market,
+        order: *order_pk,
+        purchaser: order.purchaser,
+        stake_voided: stake_to_void,
+        stake_unmatched: order.stake_unmatched,
+    });
+
     // calculate refund
     market_position::update_on_order_cancellation(market_position, order, stake_to_void)
 }
@@ -110,12 +155,13 @@ mod test_cancel_order_common {
     use crate::state::market_liquidities::{mock_market_liquidities, MarketOutcomePriceLiquidity};
     use crate::state::market_matching_pool_account::mock_market_matching_pool;
     use crate::state::market_position_account::mock_market_position;
+    use crate::state::price::Price;
 
     #[test]
     fn cancellation_full() {
         let market_outcome_index = 1;
         let for_outcome = true;
-        let price = 3.0_f64;
+        let price = Price::new(3_000);
         let stake = 10_u64;
         let payer_pk = Pubkey::new_unique();
 
@@ -156,7 +202,7 @@ mod test_cancel_order_common {
             .add_liquidity_for(market_outcome_index, price, stake)
             .expect("");
         assert_eq!(
-            vec!((1, 3.0, 10)),
+            vec!((1, Price::new(3_000), 10)),
             liquidities(&market_liquidities.liquidities_for)
         );
 
@@ -183,7 +229,7 @@ mod test_cancel_order_common {
     fn cancellation_partial() {
         let market_outcome_index = 1;
         let for_outcome = true;
-        let price = 3.0_f64;
+        let price = Price::new(3_000);
         let stake = 10_u64;
         let payer_pk = Pubkey::new_unique();
 
@@ -224,7 +270,7 @@ mod test_cancel_order_common {
             .add_liquidity_for(market_outcome_index, price, stake - 1) // less than unmatched stake
             .expect("");
         assert_eq!(
-            vec!((1, 3.0, 9)),
+            vec!((1, Price::new(3_000), 9)),
             liquidities(&market_liquidities.liquidities_for)
         );
 
@@ -251,7 +297,7 @@ mod test_cancel_order_common {
     fn cancellation_none() {
         let market_outcome_index = 1;
         let for_outcome = true;
-        let price = 3.0_f64;
+        let price = Price::new(3_000);
         let stake = 10_u64;
         let payer_pk = Pubkey::new_unique();
 
@@ -312,23 +358,26 @@ mod test_cancel_order_common {
         assert_eq!(1, market_matching_pool.orders.len());
     }
 
-    fn liquidities(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(u16, f64, u64)> {
+    fn liquidities(liquidities: &Vec<MarketOutcomePriceLiquidity>) -> Vec<(u16, Price, u64)> {
         liquidities
             .iter()
             .map(|v| (v.outcome, v.price, v.liquidity))
-            .collect::<Vec<(u16, f64, u64)>>()
+            .collect::<Vec<(u16, Price, u64)>>()
     }
 }
 
 #[cfg(test)]
 mod test {
     use crate::instructions::market_position;
-    use crate::state::market_account::{mock_market, MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_account::{
+        mock_market, MarketOrderBehaviour, MarketStatus, WithdrawnOutcome,
+    };
     use crate::state::market_liquidities::mock_market_liquidities;
     use crate::state::market_matching_pool_account::mock_market_matching_pool;
     use crate::state::market_matching_queue_account::mock_market_matching_queue;
     use crate::state::market_position_account::mock_market_position;
     use crate::state::order_account::{mock_order, OrderStatus};
+    use crate::state::price::Price;
 
     use super::*;
 
@@ -444,13 +493,50 @@ mod test {
         );
     }
 
+    #[test]
+    fn ok_order_on_withdrawn_outcome() {
+        let (
+            market_outcome_index,
+            _for_outcome,
+            _price,
+            mut market,
+            order_pk,
+            mut order,
+            mut market_position,
+            mut market_matching_pool,
+            mut market_liquidities,
+            market_matching_queue,
+        ) = setup_for_cancellation(100, 10);
+        market.withdrawn_outcomes.push(WithdrawnOutcome {
+            outcome_index: market_outcome_index,
+            reduction_factor: 1_000,
+            withdrawal_timestamp: 0,
+        });
+        market_liquidities.clear_outcome_liquidities(market_outcome_index);
+
+        let result = cancel_order(
+            &mut market,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &mut market_liquidities,
+            &market_matching_queue,
+            &mut market_matching_pool,
+        );
+        assert_eq!(Ok(14), result);
+        assert_eq!(10, order.voided_stake);
+        assert_eq!(0, order.stake_unmatched);
+        assert_eq!(0, market_matching_pool.orders.len());
+        assert_eq!(0, market_matching_pool.liquidity_amount);
+    }
+
     fn setup_for_cancellation<'test>(
         stake: u64,
         stake_unmatched: u64,
     ) -> (
         u16,
         bool,
-        f64,
+        Price,
         Market,
         Pubkey,
         Order,
@@ -461,7 +547,7 @@ mod test {
     ) {
         let market_outcome_index = 1;
         let for_outcome = false;
-        let price = 2.4_f64;
+        let price = Price::new(2_400);
 
         let market_pk = Pubkey::new_unique();
         let mut market = mock_market(MarketStatus::Open);
@@ -1,6 +1,6 @@
 use crate::error::CoreError;
 use crate::instructions::clock::current_timestamp;
-use crate::instructions::order::cancel_order_common;
+use crate::instructions::order::{cancel_order_common, cancel_order_on_withdrawn_outcome};
 use crate::state::market_account::{Market, MarketOrderBehaviour, MarketStatus};
 use crate::state::market_liquidities::MarketLiquidities;
 use crate::state::market_matching_pool_account::MarketMatchingPool;
@@ -46,6 +46,19 @@ pub fn cancel_order_post_market_lock(
         order.stake_unmatched > 0_u64,
         CoreError::CancelOrderNotCancellable
     );
+    // unmatched stake is no longer in the market liquidities
+    require!(
+        !market.is_cancelled_by_suspension(order.creation_timestamp),
+        CoreError::CancelationOrderCancelledBySuspension
+    );
+    if market.is_outcome_withdrawn(order.market_outcome_index) {
+        return cancel_order_on_withdrawn_outcome(
+            market_matching_pool,
+            order_pk,
+            order,
+            market_position,
+        );
+    }
 
     cancel_order_common(
         market_liquidities,
@@ -65,9 +78,43 @@ mod test {
     use crate::state::market_order_request_queue::{mock_order_request, mock_order_request_queue};
     use crate::state::market_position_account::mock_market_position;
     use crate::state::order_account::{mock_order, OrderStatus};
+    use crate::state::price::Price;
 
     use super::*;
 
+    #[test]
+    fn error_order_cancelled_by_suspension() {
+        let (
+            _,
+            _,
+            _,
+            mut market,
+            order_pk,
+            mut order,
+            mut market_position,
+            mut market_matching_pool,
+            mut market_liquidities,
+            market_order_request_queue,
+        ) = setup_for_cancellation(100, 10);
+        market.suspension_timestamp = order.creation_timestamp + 1;
+
+        let result = cancel_order_post_market_lock(
+            &mut market,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &mut market_liquidities,
+            &mut market_matching_pool,
+            &market_order_request_queue,
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::CancelationOrderCancelledBySuspension)),
+            result
+        );
+        assert_eq!(10, order.stake_unmatched);
+    }
+
     #[test]
     fn error_market_order_request_queue_not_empty() {
         let (
@@ -351,7 +398,7 @@ mod test {
     ) -> (
         u16,
         bool,
-        f64,
+        Price,
         Market,
         Pubkey,
         Order,
@@ -362,7 +409,7 @@ mod test {
     ) {
         let market_outcome_index = 1;
         let for_outcome = false;
-        let price = 2.4_f64;
+        let price = Price::new(2_400);
 
         let market_pk = Pubkey::new_unique();
         let mut market = mock_market(MarketStatus::Open);
@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+
+use crate::error::CoreError;
+use crate::events::order::OrderCancelledEvent;
+use crate::instructions::market::move_market_to_inplay_if_needed;
+use crate::instructions::market_position;
+use crate::state::market_account::{Market, MarketStatus};
+use crate::state::market_liquidities::MarketLiquidities;
+use crate::state::market_matching_pool_account::MarketMatchingPool;
+use crate::state::market_matching_queue_account::MarketMatchingQueue;
+use crate::state::market_position_account::MarketPosition;
+use crate::state::order_account::Order;
+use crate::state::order_account::OrderStatus;
+
+pub fn cancel_order_post_suspension(
+    market: &mut Market,
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_pool: &mut MarketMatchingPool,
+    order_pk: &Pubkey,
+    order: &mut Order,
+    market_position: &mut MarketPosition,
+    matching_queue: &MarketMatchingQueue,
+) -> Result<u64> {
+    // market is open + order was resting when a suspension cancelled unmatched orders
+    require!(
+        [MarketStatus::Open].contains(&market.market_status),
+        CoreError::CancelationMarketStatusInvalid
+    );
+    require!(
+        market.is_cancelled_by_suspension(order.creation_timestamp),
+        CoreError::CancelationOrderCreatedAfterMarketSuspension
+    );
+
+    // order is (open or matched) + there is remaining stake to be refunded
+    require!(
+        [OrderStatus::Open, OrderStatus::Matched].contains(&order.order_status),
+        CoreError::CancelationOrderStatusInvalid
+    );
+    require!(
+        order.stake_unmatched > 0_u64,
+        CoreError::CancelOrderNotCancellable
+    );
+
+    move_market_to_inplay_if_needed(market, market_liquidities)?;
+    // move_market_matching_pool_to_inplay_if_needed
+    // !!! move_market_to_inplay_if_needed needs to be called first
+    if market.inplay && !market_matching_pool.inplay {
+        require!(
+            matching_queue.matches.is_empty(),
+            CoreError::InplayTransitionMarketMatchingQueueIsNotEmpty
+        );
+        market_matching_pool.move_to_inplay(&market.event_start_order_behaviour);
+    }
+
+    // liquidity was cleared on suspension, so only the matching pool is updated, unless the
+    // order was already dropped from it by the move to inplay
+    let stake_to_void = order.stake_unmatched;
+    if market_matching_pool.orders.remove(order_pk).is_some() {
+        market_matching_pool.liquidity_amount = market_matching_pool
+            .liquidity_amount
+            .checked_sub(stake_to_void)
+            .ok_or(CoreError::MatchingLiquidityAmountUpdateError)?;
+    }
+    order.void_stake_unmatched()?;
+
+    emit!(OrderCancelledEvent {
+        market: order.market,
+        order: *order_pk,
+        purchaser: order.purchaser,
+        stake_voided: stake_to_void,
+        stake_unmatched: order.stake_unmatched,
+    });
+
+    market_position::update_on_order_cancellation(market_position, order, stake_to_void)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::state::market_account::{mock_market, MarketOrderBehaviour};
+    use crate::state::market_liquidities::mock_market_liquidities;
+    use crate::state::market_matching_pool_account::mock_market_matching_pool;
+    use crate::state::market_matching_queue_account::mock_market_matching_queue;
+    use crate::state::market_position_account::mock_market_position;
+    use crate::state::order_account::mock_order;
+    use crate::state::price::Price;
+
+    use super::*;
+
+    #[test]
+    fn ok_order_created_before_suspension() {
+        let (market_pk, mut market, order_pk, mut order, mut market_position, mut pool) =
+            setup_for_cancellation(100, 40);
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = cancel_order_post_suspension(
+            &mut market,
+            &mut market_liquidities,
+            &mut pool,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &matching_queue,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(40, result.unwrap());
+        assert_eq!(0, order.stake_unmatched);
+        assert_eq!(40, order.voided_stake);
+        assert_eq!(OrderStatus::Matched, order.order_status);
+        assert_eq!(0, pool.orders.len());
+        assert_eq!(0, pool.liquidity_amount);
+    }
+
+    #[test]
+    fn ok_order_no_longer_in_matching_pool() {
+        let (market_pk, mut market, order_pk, mut order, mut market_position, mut pool) =
+            setup_for_cancellation(100, 100);
+        pool.orders.remove(&order_pk);
+        pool.liquidity_amount = 0;
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = cancel_order_post_suspension(
+            &mut market,
+            &mut market_liquidities,
+            &mut pool,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &matching_queue,
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(100, result.unwrap());
+        assert_eq!(OrderStatus::Cancelled, order.order_status);
+        assert_eq!(0, pool.liquidity_amount);
+    }
+
+    #[test]
+    fn error_order_created_after_suspension() {
+        let (market_pk, mut market, order_pk, mut order, mut market_position, mut pool) =
+            setup_for_cancellation(100, 100);
+        order.creation_timestamp = market.suspension_timestamp + 1;
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = cancel_order_post_suspension(
+            &mut market,
+            &mut market_liquidities,
+            &mut pool,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &matching_queue,
+        );
+
+        assert_eq!(
+            Err(error!(
+                CoreError::CancelationOrderCreatedAfterMarketSuspension
+            )),
+            result
+        );
+        assert_eq!(100, order.stake_unmatched);
+        assert_eq!(1, pool.orders.len());
+    }
+
+    #[test]
+    fn error_market_not_suspended_with_cancellation() {
+        let (market_pk, mut market, order_pk, mut order, mut market_position, mut pool) =
+            setup_for_cancellation(100, 100);
+        market.suspension_timestamp = 0;
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = cancel_order_post_suspension(
+            &mut market,
+            &mut market_liquidities,
+            &mut pool,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &matching_queue,
+        );
+
+        assert_eq!(
+            Err(error!(
+                CoreError::CancelationOrderCreatedAfterMarketSuspension
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn error_market_status_invalid() {
+        let (market_pk, mut market, order_pk, mut order, mut market_position, mut pool) =
+            setup_for_cancellation(100, 100);
+        market.market_status = MarketStatus::Settled;
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = cancel_order_post_suspension(
+            &mut market,
+            &mut market_liquidities,
+            &mut pool,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &matching_queue,
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::CancelationMarketStatusInvalid)),
+            result
+        );
+    }
+
+    #[test]
+    fn error_order_fully_matched() {
+        let (market_pk, mut market, order_pk, mut order, mut market_position, mut pool) =
+            setup_for_cancellation(100, 0);
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        let matching_queue = mock_market_matching_queue(market_pk);
+
+        let result = cancel_order_post_suspension(
+            &mut market,
+            &mut market_liquidities,
+            &mut pool,
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &matching_queue,
+        );
+
+        assert_eq!(Err(error!(CoreError::CancelOrderNotCancellable)), result);
+    }
+
+    fn setup_for_cancellation(
+        stake: u64,
+        stake_unmatched: u64,
+    ) -> (
+        Pubkey,
+        Market,
+        Pubkey,
+        Order,
+        MarketPosition,
+        MarketMatchingPool,
+    ) {
+        let market_outcome_index = 1;
+        let for_outcome = true;
+        let price = Price::new(2_400);
+
+        let market_pk = Pubkey::new_unique();
+        let mut market = mock_market(MarketStatus::Open);
+        market.suspension_order_behaviour = MarketOrderBehaviour::CancelUnmatched;
+        market.suspension_timestamp = 1_000;
+
+        let order_pk = Pubkey::new_unique();
+        let mut order = mock_order(
+            market_pk,
+            market_outcome_index,
+            for_outcome,
+            price,
+            stake,
+            Pubkey::new_unique(),
+        );
+        order.creation_timestamp = 900;
+        order.stake_unmatched = stake_unmatched;
+        if stake_unmatched < stake {
+            order.order_status = OrderStatus::Matched;
+        }
+
+        let mut market_position = mock_market_position(3);
+        let _ = market_position::update_on_order_request_creation(
+            &mut market_position,
+            market_outcome_index,
+            for_outcome,
+            stake,
+            price,
+        );
+
+        let mut market_matching_pool =
+            mock_market_matching_pool(market_pk, market_outcome_index, for_outcome, price);
+        if stake_unmatched > 0 {
+            market_matching_pool.orders.enqueue(order_pk);
+            market_matching_pool.liquidity_amount = stake_unmatched;
+        }
+
+        (
+            market_pk,
+            market,
+            order_pk,
+            order,
+            market_position,
+            market_matching_pool,
+        )
+    }
+}
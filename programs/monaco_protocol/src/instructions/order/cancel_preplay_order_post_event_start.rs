@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::error::CoreError;
+use crate::events::order::OrderCancelledEvent;
 use crate::instructions::market::move_market_to_inplay_if_needed;
 use crate::instructions::market_position;
 use crate::state::market_account::{Market, MarketOrderBehaviour, MarketStatus};
@@ -16,6 +17,7 @@ pub fn cancel_preplay_order_post_event_start(
     market: &mut Market,
     market_liquidities: &mut MarketLiquidities,
     market_matching_pool: &mut MarketMatchingPool,
+    order_pk: &Pubkey,
     order: &mut Order,
     market_position: &mut MarketPosition,
     matching_queue: &MarketMatchingQueue,
@@ -68,6 +70,15 @@ pub fn cancel_preplay_order_post_event_start(
     // liquidity check is not needed since all matches were processed before we got here
     let stake_to_void = order.stake_unmatched;
     order.void_stake_unmatched()?;
+
+    emit!(OrderCancelledEvent {
+        market: order.market,
+        order: *order_pk,
+        purchaser: order.purchaser,
+        stake_voided: stake_to_void,
+        stake_unmatched: order.stake_unmatched,
+    });
+
     market_position::update_on_order_cancellation(market_position, order, stake_to_void)
 }
 
@@ -79,13 +90,14 @@ mod test {
     use crate::state::market_matching_queue_account::{mock_market_matching_queue, OrderMatch};
     use crate::state::market_order_request_queue::{mock_order_request, mock_order_request_queue};
     use crate::state::order_account::OrderStatus;
+    use crate::state::price::Price;
 
     use super::*;
 
     #[test]
     fn error_order_status_invalid() {
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let payer_pk = Pubkey::new_unique();
 
         let market_pk = Pubkey::new_unique();
@@ -98,7 +110,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
 
         let mut order = Order {
@@ -109,13 +121,14 @@ mod test {
             order_status: OrderStatus::SettledWin,
             product: None,
             product_commission_rate: 0.0,
-            expected_price: 2.4_f64,
+            expected_price: Price::new(2_400),
             stake: 100_u64,
             stake_unmatched: 0_u64,
             voided_stake: 0_u64,
             payout: 0_u64,
             creation_timestamp: 0,
             payer: payer_pk,
+            schema_version: Order::SCHEMA_VERSION,
         };
         let matching_queue = &mock_market_matching_queue(market_pk);
         let order_request_queue = &mock_order_request_queue(market_pk);
@@ -141,6 +154,7 @@ mod test {
             &mut market,
             &mut market_liquidities,
             &mut market_matching_pool,
+            &Pubkey::new_unique(),
             &mut order,
             &mut market_position,
             &matching_queue,
@@ -158,7 +172,7 @@ mod test {
     #[test]
     fn ok_cancel_remaining_unmatched_stake() {
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let payer_pk = Pubkey::new_unique();
 
         let market_pk = Pubkey::new_unique();
@@ -171,7 +185,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
 
         let mut order = Order {
@@ -182,13 +196,14 @@ mod test {
             order_status: OrderStatus::Matched,
             product: None,
             product_commission_rate: 0.0,
-            expected_price: 2.4_f64,
+            expected_price: Price::new(2_400),
             stake: 100_u64,
             stake_unmatched: 10_u64,
             voided_stake: 0_u64,
             payout: 216_u64,
             creation_timestamp: 0,
             payer: payer_pk,
+            schema_version: Order::SCHEMA_VERSION,
         };
 
         let matching_queue = &mock_market_matching_queue(market_pk);
@@ -219,6 +234,7 @@ mod test {
             &mut market,
             &mut market_liquidities,
             &mut market_matching_pool,
+            &Pubkey::new_unique(),
             &mut order,
             &mut market_position,
             &matching_queue,
@@ -235,6 +251,7 @@ mod test {
             &mut market,
             &mut market_liquidities,
             &mut market_matching_pool,
+            &Pubkey::new_unique(),
             &mut order,
             &mut market_position,
             &matching_queue,
@@ -252,7 +269,7 @@ mod test {
     #[test]
     fn ok_inplay_order_requests_exist() {
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let payer_pk = Pubkey::new_unique();
 
         let market_pk = Pubkey::new_unique();
@@ -264,7 +281,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
         order_request.creation_timestamp = market.event_start_timestamp + 1;
 
@@ -276,13 +293,14 @@ mod test {
             order_status: OrderStatus::Matched,
             product: None,
             product_commission_rate: 0.0,
-            expected_price: 2.4_f64,
+            expected_price: Price::new(2_400),
             stake: 100_u64,
             stake_unmatched: 10_u64,
             voided_stake: 0_u64,
             payout: 0_u64,
             creation_timestamp: 0,
             payer: payer_pk,
+            schema_version: Order::SCHEMA_VERSION,
         };
 
         let matching_queue = &mock_market_matching_queue(market_pk);
@@ -309,6 +327,7 @@ mod test {
             &mut market,
             &mut market_liquidities,
             &mut market_matching_pool,
+            &Pubkey::new_unique(),
             &mut order,
             &mut market_position,
             &matching_queue,
@@ -323,7 +342,7 @@ mod test {
     #[test]
     fn error_unprocessed_preplay_order_requests() {
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let payer_pk = Pubkey::new_unique();
 
         let market_pk = Pubkey::new_unique();
@@ -335,7 +354,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
 
         let mut order = Order {
@@ -346,13 +365,14 @@ mod test {
             order_status: OrderStatus::Matched,
             product: None,
             product_commission_rate: 0.0,
-            expected_price: 2.4_f64,
+            expected_price: Price::new(2_400),
             stake: 100_u64,
             stake_unmatched: 10_u64,
             voided_stake: 0_u64,
             payout: 0_u64,
             creation_timestamp: 0,
             payer: payer_pk,
+            schema_version: Order::SCHEMA_VERSION,
         };
         let matching_queue = &mock_market_matching_queue(market_pk);
         let order_request_queue = &mut mock_order_request_queue(market_pk);
@@ -379,6 +399,7 @@ mod test {
             &mut market,
             &mut market_liquidities,
             &mut market_matching_pool,
+            &Pubkey::new_unique(),
             &mut order,
             &mut market_position,
             &matching_queue,
@@ -396,7 +417,7 @@ mod test {
     #[test]
     fn error_matching_queue_not_empty() {
         let market_outcome_index = 1;
-        let matched_price = 2.2_f64;
+        let matched_price = Price::new(2_200);
         let payer_pk = Pubkey::new_unique();
 
         let market_pk = Pubkey::new_unique();
@@ -408,7 +429,7 @@ mod test {
             false,
             market_outcome_index,
             100_u64,
-            2.4_f64,
+            Price::new(2_400),
         );
 
         let mut order = Order {
@@ -419,18 +440,19 @@ mod test {
             order_status: OrderStatus::Matched,
             product: None,
             product_commission_rate: 0.0,
-            expected_price: 2.4_f64,
+            expected_price: Price::new(2_400),
             stake: 100_u64,
             stake_unmatched: 10_u64,
             voided_stake: 0_u64,
             payout: 0_u64,
             creation_timestamp: 0,
             payer: payer_pk,
+            schema_version: Order::SCHEMA_VERSION,
         };
         let matching_queue = &mut mock_market_matching_queue(market_pk);
         matching_queue
             .matches
-            .enqueue(OrderMatch::maker(false, 0, 0.0, 0));
+            .enqueue(OrderMatch::maker(false, 0, Price::new(0), 0));
         let order_request_queue = &mock_order_request_queue(market_pk);
 
         let mut market_position = MarketPosition::default();
@@ -454,6 +476,7 @@ mod test {
             &mut market,
             &mut market_liquidities,
             &mut market_matching_pool,
+            &Pubkey::new_unique(),
             &mut order,
             &mut market_position,
             &matching_queue,
@@ -484,7 +507,8 @@ mod test {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::CancelUnmatched,
@@ -496,6 +520,23 @@ mod test {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 100,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         }
     }
 
@@ -509,7 +550,7 @@ mod test {
     fn mock_market_matching_pool(
         market_pk: Pubkey,
         market_outcome_index: u16,
-        price: f64,
+        price: Price,
     ) -> MarketMatchingPool {
         MarketMatchingPool {
             market: market_pk,
@@ -2,19 +2,18 @@ use anchor_lang::prelude::*;
 use solana_program::clock::UnixTimestamp;
 
 use crate::instructions::current_timestamp;
-use crate::state::market_account::*;
 use crate::state::market_order_request_queue::OrderRequest;
 use crate::state::order_account::*;
 
 pub fn initialize_order(
-    order: &mut Account<Order>,
-    market: &Account<Market>,
+    order: &mut Order,
+    market_pk: Pubkey,
     fee_payer: Pubkey,
     order_request: OrderRequest,
 ) -> Result<()> {
     let now: UnixTimestamp = current_timestamp();
 
-    order.market = market.key();
+    order.market = market_pk;
     order.market_outcome_index = order_request.market_outcome_index;
     order.for_outcome = order_request.for_outcome;
 
@@ -30,6 +29,7 @@ pub fn initialize_order(
 
     order.product = order_request.product;
     order.product_commission_rate = order_request.product_commission_rate;
+    order.schema_version = Order::SCHEMA_VERSION;
 
     Ok(())
 }
@@ -4,12 +4,13 @@ use crate::error::CoreError;
 use crate::instructions::market_position;
 use crate::state::market_position_account::MarketPosition;
 use crate::state::order_account::{Order, OrderStatus};
+use crate::state::price::Price;
 
 pub fn match_order(
     order: &mut Account<Order>,
     market_position: &mut MarketPosition,
     stake_matched: u64,
-    price_matched: f64,
+    price_matched: Price,
 ) -> Result<u64> {
     // validate that status is open or matched (for partial matches)
     if order.order_status != OrderStatus::Open && order.order_status != OrderStatus::Matched {
@@ -1,13 +1,19 @@
+pub mod amend_order;
+pub mod cancel_all_orders;
 pub mod cancel_order;
 pub mod cancel_order_post_market_lock;
+pub mod cancel_order_post_suspension;
 pub mod cancel_preplay_order_post_event_start;
 pub mod create_order;
 pub mod match_order;
 pub mod settle_order;
 pub mod void_order;
 
+pub use amend_order::*;
+pub use cancel_all_orders::*;
 pub use cancel_order::*;
 pub use cancel_order_post_market_lock::*;
+pub use cancel_order_post_suspension::*;
 pub use cancel_preplay_order_post_event_start::*;
 pub use create_order::*;
 pub use match_order::*;
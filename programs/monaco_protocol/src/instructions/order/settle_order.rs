@@ -1,14 +1,44 @@
 use crate::context::SettleOrder;
 use crate::error::CoreError;
+use crate::events::order::OrderSettledEvent;
+use crate::instructions::calculate_dead_heat_amount;
 use crate::state::market_account::MarketStatus::ReadyForSettlement;
-use crate::state::order_account::OrderStatus::{Cancelled, Open, SettledLose, SettledWin};
+use crate::state::market_account::OutcomeResult;
+use crate::state::order_account::OrderStatus::{Cancelled, Open, SettledLose, SettledWin, Voided};
 use crate::{Market, Order};
 use anchor_lang::prelude::*;
 use solana_program::log;
 
 pub fn settle_order(ctx: Context<SettleOrder>) -> Result<()> {
-    let market_account = &mut ctx.accounts.market;
+    let market_pk = ctx.accounts.market.key();
+    let order_pk = ctx.accounts.order.key();
 
+    let close_order = settle_order_common(
+        &market_pk,
+        &mut ctx.accounts.market,
+        &order_pk,
+        &mut ctx.accounts.order,
+    )?;
+
+    // if never matched close
+    if close_order {
+        return ctx
+            .accounts
+            .order
+            .close(ctx.accounts.payer.to_account_info());
+    }
+
+    Ok(())
+}
+
+// settles the order against the market's result, returning true if the order was never matched
+// and should be closed
+pub fn settle_order_common(
+    market_pk: &Pubkey,
+    market_account: &mut Market,
+    order_pk: &Pubkey,
+    order: &mut Order,
+) -> Result<bool> {
     // validate the market is ready for settlement
     require!(
         ReadyForSettlement.eq(&market_account.market_status),
@@ -16,60 +46,300 @@ pub fn settle_order(ctx: Context<SettleOrder>) -> Result<()> {
     );
 
     // exit early if order already settled
-    if Cancelled.eq(&ctx.accounts.order.order_status) {
+    if Cancelled.eq(&order.order_status) {
         log::sol_log("order already cancelled");
-        return Ok(());
+        return Ok(false);
     }
-    if SettledLose.eq(&ctx.accounts.order.order_status) {
+    if SettledLose.eq(&order.order_status) {
         log::sol_log("order already settled as loss");
-        return Ok(());
+        return Ok(false);
     }
-    if SettledWin.eq(&ctx.accounts.order.order_status) {
+    if SettledWin.eq(&order.order_status) {
         log::sol_log("order already settled as win");
-        return Ok(());
+        return Ok(false);
+    }
+    if Voided.eq(&order.order_status) {
+        log::sol_log("order already voided");
+        return Ok(false);
     }
 
     // if never matched close
-    if Open.eq(&ctx.accounts.order.order_status) {
+    if Open.eq(&order.order_status) {
         market_account.decrement_account_counts()?;
-        return ctx
-            .accounts
-            .order
-            .close(ctx.accounts.payer.to_account_info());
+        return Ok(true);
     }
 
-    if ctx.accounts.order.stake_unmatched > 0_u64 {
-        ctx.accounts.order.void_stake_unmatched()?;
+    if order.stake_unmatched > 0_u64 {
+        order.void_stake_unmatched()?;
+    }
+    if market_account.is_outcome_withdrawn(order.market_outcome_index) {
+        void_withdrawn_order(order);
+    } else if let Some(outcome_result) = order_outcome_result(order, market_account) {
+        apply_outcome_result(order, outcome_result)?;
+    } else {
+        match is_winning_order(order, market_account) {
+            true => {
+                apply_dead_heat_to_payout(order, market_account);
+                order.order_status = SettledWin
+            }
+            false => order.order_status = SettledLose,
+        };
     }
-    match is_winning_order(&ctx.accounts.order, market_account) {
-        true => ctx.accounts.order.order_status = SettledWin,
-        false => ctx.accounts.order.order_status = SettledLose,
-    };
 
     market_account.decrement_unsettled_accounts_count()?;
 
-    Ok(())
+    emit!(OrderSettledEvent {
+        market: *market_pk,
+        order: *order_pk,
+        purchaser: order.purchaser,
+        order_status: order.order_status.clone(),
+        payout: order.payout,
+    });
+
+    Ok(false)
 }
 
 fn is_winning_order(order: &Order, market: &Market) -> bool {
+    let (win_numerator, _) = winning_factor(order, market);
+    win_numerator > 0
+}
+
+// dead-heat: only the winning fraction of the order is paid out
+fn apply_dead_heat_to_payout(order: &mut Order, market: &Market) {
+    let (win_numerator, win_denominator) = winning_factor(order, market);
+    if win_numerator < win_denominator {
+        order.payout = calculate_dead_heat_amount(order.payout, win_numerator, win_denominator);
+    }
+}
+
+// result of the order's side of the line, against orders take the other side of their outcome
+fn order_outcome_result(order: &Order, market: &Market) -> Option<OutcomeResult> {
+    market
+        .outcome_result(order.market_outcome_index)
+        .map(|outcome_result| match order.for_outcome {
+            true => outcome_result,
+            false => outcome_result.opposite(),
+        })
+}
+
+// pushed part of the matched stake is voided and only the won part of the payout is paid out
+fn apply_outcome_result(order: &mut Order, outcome_result: OutcomeResult) -> Result<()> {
+    let stake_matched = order
+        .stake
+        .checked_sub(order.voided_stake)
+        .ok_or(CoreError::ArithmeticError)?;
+    let stake_pushed = calculate_dead_heat_amount(
+        stake_matched,
+        outcome_result.push_parts(),
+        OutcomeResult::STAKE_PARTS,
+    );
+    order.voided_stake = order
+        .voided_stake
+        .checked_add(stake_pushed)
+        .ok_or(CoreError::ArithmeticError)?;
+    order.payout = calculate_dead_heat_amount(
+        order.payout,
+        outcome_result.win_parts(),
+        OutcomeResult::STAKE_PARTS,
+    );
+
+    order.order_status = if outcome_result.win_parts() > 0 {
+        SettledWin
+    } else if outcome_result.push_parts() == OutcomeResult::STAKE_PARTS {
+        Voided
+    } else {
+        SettledLose
+    };
+    Ok(())
+}
+
+// matched stake on a withdrawn outcome is returned in full
+fn void_withdrawn_order(order: &mut Order) {
+    order.voided_stake = order.stake;
+    order.payout = 0_u64;
+    order.order_status = Voided;
+}
+
+// fraction of the order's stake settled as a win
+fn winning_factor(order: &Order, market: &Market) -> (u16, u16) {
+    let (numerator, denominator) = market
+        .winning_outcome(order.market_outcome_index)
+        .map(|winning_outcome| {
+            (
+                winning_outcome.dead_heat_numerator,
+                winning_outcome.dead_heat_denominator,
+            )
+        })
+        .unwrap_or((0, 1));
+
     match order.for_outcome {
-        true => order.market_outcome_index == market.market_winning_outcome_index.unwrap(),
-        false => order.market_outcome_index != market.market_winning_outcome_index.unwrap(),
+        true => (numerator, denominator),
+        false => (denominator - numerator, denominator),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::market_account::{MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_account::{
+        mock_market, MarketOrderBehaviour, MarketStatus, MarketWinningOutcome,
+    };
     use crate::state::order_account::mock_order;
+    use crate::state::price::Price;
     use anchor_lang::prelude::Pubkey;
     use solana_program::clock::UnixTimestamp;
 
+    /*
+       Test - fn apply_outcome_result(order: &mut Order, outcome_result: OutcomeResult)
+    */
+
+    fn matched_line_order(for_outcome: bool) -> Order {
+        let mut order = mock_order(
+            Pubkey::new_unique(),
+            0,
+            for_outcome,
+            Price::new(2_000),
+            100_000_000,
+            Pubkey::new_unique(),
+        );
+        order
+            .match_stake_unmatched(60_000_000, Price::new(2_000))
+            .expect("test setup");
+        order.void_stake_unmatched().expect("test setup");
+        order
+    }
+
+    #[test]
+    fn test_settle_order_outcome_results() {
+        for (outcome_result, status, payout, voided_stake) in [
+            (OutcomeResult::Win, SettledWin, 120_000_000, 40_000_000),
+            (OutcomeResult::HalfWin, SettledWin, 60_000_000, 70_000_000),
+            (OutcomeResult::Push, Voided, 0, 100_000_000),
+            (OutcomeResult::HalfLoss, SettledLose, 0, 70_000_000),
+            (OutcomeResult::Loss, SettledLose, 0, 40_000_000),
+        ] {
+            let mut order = matched_line_order(true);
+
+            apply_outcome_result(&mut order, outcome_result).expect("settle");
+
+            assert_eq!(status, order.order_status);
+            assert_eq!(payout, order.payout);
+            assert_eq!(voided_stake, order.voided_stake);
+        }
+    }
+
+    #[test]
+    fn test_settle_order_outcome_results_against_order() {
+        let mut market = mock_market(MarketStatus::ReadyForSettlement);
+        market.market_outcomes_count = 2;
+        market.market_outcome_results = vec![OutcomeResult::HalfLoss, OutcomeResult::HalfWin];
+
+        let mut order = matched_line_order(false);
+        let outcome_result = order_outcome_result(&order, &market);
+        assert_eq!(Some(OutcomeResult::HalfWin), outcome_result);
+
+        apply_outcome_result(&mut order, outcome_result.unwrap()).expect("settle");
+        assert_eq!(SettledWin, order.order_status);
+        assert_eq!(60_000_000, order.payout);
+
+        market.market_outcome_results = vec![];
+        assert_eq!(None, order_outcome_result(&order, &market));
+    }
+
+    #[test]
+    fn test_settle_order_outcome_withdrawn() {
+        let mut order = matched_line_order(false);
+
+        void_withdrawn_order(&mut order);
+
+        assert_eq!(Voided, order.order_status);
+        assert_eq!(0, order.payout);
+        assert_eq!(order.stake, order.voided_stake);
+    }
+
     /*
        Test - fn is_winning_order(order: &Order, market: &Market) -> bool
     */
 
+    #[test]
+    fn test_settle_order_dead_heat_for_order() {
+        let mut order = mock_order(
+            Pubkey::new_unique(),
+            1,
+            true,
+            Price::new(3_000),
+            100_000_000,
+            Pubkey::new_unique(),
+        );
+        order
+            .match_stake_unmatched(100_000_000, Price::new(3_000))
+            .expect("test setup");
+        let mut market = mock_market(MarketStatus::ReadyForSettlement);
+        market.market_outcomes_count = 3;
+        market.market_winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 2),
+            MarketWinningOutcome::new(1, 1, 2),
+        ];
+
+        assert!(is_winning_order(&order, &market));
+        apply_dead_heat_to_payout(&mut order, &market);
+        assert_eq!(150_000_000, order.payout);
+    }
+
+    #[test]
+    fn test_settle_order_dead_heat_against_order() {
+        let mut order = mock_order(
+            Pubkey::new_unique(),
+            1,
+            false,
+            Price::new(3_000),
+            100_000_000,
+            Pubkey::new_unique(),
+        );
+        order
+            .match_stake_unmatched(100_000_000, Price::new(3_000))
+            .expect("test setup");
+        let mut market = mock_market(MarketStatus::ReadyForSettlement);
+        market.market_outcomes_count = 3;
+        market.market_winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 2, 3),
+            MarketWinningOutcome::new(1, 1, 3),
+        ];
+
+        assert!(is_winning_order(&order, &market));
+        apply_dead_heat_to_payout(&mut order, &market);
+        assert_eq!(200_000_000, order.payout);
+    }
+
+    #[test]
+    fn test_settle_order_multiple_winning_places() {
+        let mut order = mock_order(
+            Pubkey::new_unique(),
+            1,
+            true,
+            Price::new(3_000),
+            100_000_000,
+            Pubkey::new_unique(),
+        );
+        order
+            .match_stake_unmatched(100_000_000, Price::new(3_000))
+            .expect("test setup");
+        let mut market = mock_market(MarketStatus::ReadyForSettlement);
+        market.market_outcomes_count = 3;
+        market.market_winning_places = 2;
+        market.market_winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 1),
+            MarketWinningOutcome::new(1, 1, 1),
+        ];
+
+        assert!(is_winning_order(&order, &market));
+        apply_dead_heat_to_payout(&mut order, &market);
+        assert_eq!(300_000_000, order.payout);
+
+        order.for_outcome = false;
+        assert!(!is_winning_order(&order, &market));
+    }
+
     #[test]
     fn test_settle_order_win_for_order() {
         // when
@@ -77,12 +347,12 @@ mod tests {
             Pubkey::new_unique(),
             1,
             true,
-            2.10,
+            Price::new(2_100),
             100_000_000,
             Pubkey::new_unique(),
         );
         order
-            .match_stake_unmatched(100_000_000, 2.10)
+            .match_stake_unmatched(100_000_000, Price::new(2_100))
             .expect("test setup");
         let market = Market {
             authority: Pubkey::new_unique(),
@@ -90,7 +360,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -111,6 +382,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         // then
@@ -124,12 +412,12 @@ mod tests {
             Pubkey::new_unique(),
             1,
             true,
-            2.10,
+            Price::new(2_100),
             100_000_000,
             Pubkey::new_unique(),
         );
         order
-            .match_stake_unmatched(100_000_000, 2.10)
+            .match_stake_unmatched(100_000_000, Price::new(2_100))
             .expect("test setup");
         let market = Market {
             authority: Pubkey::new_unique(),
@@ -137,7 +425,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(2),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(2, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -158,6 +447,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         // then
@@ -171,12 +477,12 @@ mod tests {
             Pubkey::new_unique(),
             1,
             false,
-            2.10,
+            Price::new(2_100),
             100000000,
             Pubkey::new_unique(),
         );
         order
-            .match_stake_unmatched(100_000_000, 2.10)
+            .match_stake_unmatched(100_000_000, Price::new(2_100))
             .expect("test setup");
         let market = Market {
             authority: Pubkey::new_unique(),
@@ -184,7 +490,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(0),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(0, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -205,6 +512,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         // then
@@ -218,12 +542,12 @@ mod tests {
             Pubkey::new_unique(),
             1,
             false,
-            2.10,
+            Price::new(2_100),
             100000000,
             Pubkey::new_unique(),
         );
         order
-            .match_stake_unmatched(100_000_000, 2.10)
+            .match_stake_unmatched(100_000_000, Price::new(2_100))
             .expect("test setup");
         let market = Market {
             authority: Pubkey::new_unique(),
@@ -231,7 +555,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index: Some(1),
+            market_winning_places: 1,
+            market_winning_outcomes: vec![MarketWinningOutcome::new(1, 1, 1)],
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -252,6 +577,23 @@ mod tests {
             market_lock_order_behaviour: MarketOrderBehaviour::None,
             unclosed_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         // then
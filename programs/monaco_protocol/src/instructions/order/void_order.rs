@@ -136,7 +136,8 @@ mod tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -147,6 +148,23 @@ mod tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         }
     }
 }
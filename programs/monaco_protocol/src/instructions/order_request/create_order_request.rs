@@ -3,41 +3,48 @@ use protocol_product::state::product::Product;
 use solana_program::clock::UnixTimestamp;
 
 use crate::error::CoreError;
-use crate::instructions::{
-    current_timestamp, market_position, price_precision_is_within_range,
-    stake_precision_is_within_range,
-};
+use crate::events::order::OrderRequestCreatedEvent;
+use crate::instructions::{current_timestamp, market_position, stake_precision_is_within_range};
+use crate::state::market_access_control::MarketAccessControl;
 use crate::state::market_account::{Market, MarketStatus};
+use crate::state::market_limits::MarketLimits;
 use crate::state::market_order_request_queue::{
-    MarketOrderRequestQueue, OrderRequest, OrderRequestData,
+    MarketOrderRequestQueue, OrderRequest, OrderRequestData, TimeInForce,
 };
 use crate::state::market_outcome_account::MarketOutcome;
 use crate::state::market_position_account::MarketPosition;
+use crate::state::price::Price;
 use crate::state::price_ladder::{PriceLadder, DEFAULT_PRICES};
-use std::ops::Deref;
+use anchor_spl::token::spl_token::state::Account as TokenAccountState;
 
 pub fn create_order_request(
     market_pk: Pubkey,
     market: &mut Market,
-    payer: &Signer,
-    purchaser: &Signer,
+    payer: &Pubkey,
+    purchaser: &Pubkey,
     product: &Option<Account<Product>>,
     market_position: &mut MarketPosition,
     market_outcome: &MarketOutcome,
-    price_ladder: &Option<Account<PriceLadder>>,
+    price_ladder: Option<&PriceLadder>,
+    market_limits: Option<&MarketLimits>,
     order_request_queue: &mut MarketOrderRequestQueue,
     data: OrderRequestData,
 ) -> Result<u64> {
     let now: UnixTimestamp = current_timestamp();
-    // unpack account optionals (works only for non-mut)
-    let price_ladder_account = price_ladder.as_ref().map(|v| v.deref());
-    validate_order_request(market, market_outcome, &price_ladder_account, &data, now)?;
+    validate_order_request(
+        market,
+        market_outcome,
+        &price_ladder,
+        market_limits,
+        &data,
+        now,
+    )?;
 
     // initialize market position if needed
     if market_position.payer == Pubkey::default() {
         market_position::create_market_position(
-            purchaser.key,
-            payer.key,
+            purchaser,
+            payer,
             market_pk,
             market,
             market_position,
@@ -46,7 +53,36 @@ pub fn create_order_request(
     }
 
     // initialize and enqueue order request on to order_request_queue
-    let order_request = initialize_order_request(market, purchaser.key, product, data, now)?;
+    let order_request =
+        enqueue_order_request(market, purchaser, product, order_request_queue, data, now)?;
+
+    let payment = market_position::update_on_order_request_creation(
+        market_position,
+        order_request.market_outcome_index,
+        order_request.for_outcome,
+        order_request.stake,
+        order_request.expected_price,
+    )?;
+    validate_market_position_exposure(market_limits, market_position)?;
+
+    // the crank fee is paid on top of the exposure and is not refunded
+    let crank_fee = market.collect_crank_fee()?;
+    let payment = payment
+        .checked_add(crank_fee)
+        .ok_or(CoreError::ArithmeticError)?;
+
+    Ok(payment)
+}
+
+pub(crate) fn enqueue_order_request(
+    market: &Market,
+    purchaser: &Pubkey,
+    product: &Option<Account<Product>>,
+    order_request_queue: &mut MarketOrderRequestQueue,
+    data: OrderRequestData,
+    now: UnixTimestamp,
+) -> Result<OrderRequest> {
+    let order_request = initialize_order_request(market, purchaser, product, data, now)?;
     require!(
         !order_request_queue.order_requests.contains(&order_request),
         CoreError::OrderRequestCreationDuplicateRequest
@@ -57,13 +93,17 @@ pub fn create_order_request(
         .enqueue(order_request)
         .ok_or(CoreError::OrderRequestCreationQueueFull)?;
 
-    market_position::update_on_order_request_creation(
-        market_position,
-        order_request.market_outcome_index,
-        order_request.for_outcome,
-        order_request.stake,
-        order_request.expected_price,
-    )
+    emit!(OrderRequestCreatedEvent {
+        market: order_request_queue.market,
+        purchaser: order_request.purchaser,
+        market_outcome_index: order_request.market_outcome_index,
+        for_outcome: order_request.for_outcome,
+        stake: order_request.stake,
+        price: order_request.expected_price,
+        distinct_seed: order_request.distinct_seed,
+    });
+
+    Ok(order_request)
 }
 
 fn initialize_order_request(
@@ -89,6 +129,8 @@ fn initialize_order_request(
     order_request.distinct_seed = data.distinct_seed;
     order_request.creation_timestamp = now;
     order_request.expires_on = data.expires_on;
+    order_request.time_in_force = data.time_in_force;
+    order_request.post_only = data.post_only;
 
     match product {
         Some(product_account) => {
@@ -104,20 +146,36 @@ fn initialize_order_request(
     Ok(*order_request)
 }
 
-fn validate_order_request(
+pub fn validate_order_request(
     market: &Market,
     market_outcome: &MarketOutcome,
     price_ladder: &Option<&PriceLadder>,
+    market_limits: Option<&MarketLimits>,
     data: &OrderRequestData,
     now: UnixTimestamp,
 ) -> Result<()> {
     validate_market_for_order_request(market, now)?;
+    // limits must be provided for markets they are enabled on so they cannot be bypassed
+    if market.limits_enabled {
+        let market_limits = market_limits.ok_or(CoreError::MarketLimitsRequired)?;
+        market_limits.validate_order_stake(data.stake)?;
+        market_limits.validate_outcome_open_for_matching(data.market_outcome_index)?;
+    }
+    require!(
+        !market.is_outcome_withdrawn(market_outcome.index),
+        CoreError::CreationMarketOutcomeWithdrawn
+    );
 
     if let Some(expires_on) = data.expires_on {
         require!(expires_on > now, CoreError::CreationExpired);
     }
+    // a post-only order has to rest on the book, so its unmatched stake can't be voided
+    require!(
+        !data.post_only || data.time_in_force == TimeInForce::GoodTillCancelled,
+        CoreError::CreationPostOnlyInvalidTimeInForce
+    );
     require!(data.stake > 0_u64, CoreError::CreationStakeZeroOrLess);
-    require!(data.price > 1_f64, CoreError::CreationPriceOneOrLess);
+    require!(data.price > Price::ONE, CoreError::CreationPriceOneOrLess);
     let stake_precision_check_result =
         stake_precision_is_within_range(data.stake, market.decimal_limit)?;
     require!(
@@ -130,14 +188,12 @@ fn validate_order_request(
         // No prices included on the outcome, use a PriceLadder or default prices
         match price_ladder {
             Some(price_ladder_account) => {
-                if price_ladder_account.prices.is_empty() {
-                    price_precision_is_within_range(data.price)?
-                } else {
-                    require!(
-                        price_ladder_account.prices.contains(&data.price),
-                        CoreError::CreationInvalidPrice
-                    )
-                }
+                // an empty price ladder accepts any price
+                require!(
+                    price_ladder_account.prices.is_empty()
+                        || price_ladder_account.prices.contains(&data.price),
+                    CoreError::CreationInvalidPrice
+                )
             }
             None => require!(
                 DEFAULT_PRICES.contains(&data.price),
@@ -155,6 +211,35 @@ fn validate_order_request(
     Ok(())
 }
 
+// access control must be provided for markets it is enabled on so it cannot be bypassed
+pub(crate) fn validate_purchaser_access(
+    market: &Market,
+    market_access_control: Option<&MarketAccessControl>,
+    purchaser: &Pubkey,
+    purchaser_gate_token: Option<&TokenAccountState>,
+) -> Result<()> {
+    if !market.access_control_enabled {
+        return Ok(());
+    }
+    market_access_control
+        .ok_or(CoreError::MarketAccessControlRequired)?
+        .validate_purchaser(purchaser, purchaser_gate_token)
+}
+
+// checked once the request has been added to the market position, matching can only lower the
+// exposure reserved for it
+pub(crate) fn validate_market_position_exposure(
+    market_limits: Option<&MarketLimits>,
+    market_position: &MarketPosition,
+) -> Result<()> {
+    match market_limits {
+        Some(market_limits) => {
+            market_limits.validate_position_exposure(market_position.total_exposure())
+        }
+        None => Ok(()),
+    }
+}
+
 pub fn validate_market_for_order_request(market: &Market, now: UnixTimestamp) -> Result<()> {
     let market_lock_timestamp = &market.market_lock_timestamp;
     let status = &market.market_status;
@@ -165,7 +250,7 @@ pub fn validate_market_for_order_request(market: &Market, now: UnixTimestamp) ->
     );
 
     require!(
-        market.market_winning_outcome_index.is_none(),
+        market.market_winning_outcomes.is_empty(),
         CoreError::CreationMarketHasWinningOutcome
     );
 
@@ -182,14 +267,19 @@ pub fn validate_market_for_order_request(market: &Market, now: UnixTimestamp) ->
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::market_account::{MarketOrderBehaviour, MarketStatus};
+    use crate::state::market_access_control::{mock_market_access_control, PurchaserListMode};
+    use crate::state::market_account::{
+        MarketOrderBehaviour, MarketStatus, MarketWinningOutcome, WithdrawnOutcome,
+    };
+    use crate::state::market_limits::mock_market_limits;
+    use crate::state::market_position_account::mock_market_position;
 
     #[test]
     fn test_market_valid_() {
         let now: i64 = 1575975177;
         let time_in_future: i64 = 43041841910;
 
-        let market = create_test_market(time_in_future, false, MarketStatus::Open, None);
+        let market = create_test_market(time_in_future, false, MarketStatus::Open, vec![]);
         let market_outcome = MarketOutcome {
             market: Pubkey::new_unique(),
             index: 0,
@@ -208,26 +298,243 @@ mod tests {
             market_outcome_index: 0,
             for_outcome: true,
             stake: 100000_u64,
-            price: 2.1111_f64,
+            price: Price::new(2_111),
             distinct_seed: [0_u8; 16],
             expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
         };
 
-        let result =
-            validate_order_request(&market, &market_outcome, &Some(&price_ladder), &data, now);
-        assert!(result.is_err());
+        // an empty price ladder accepts any price, precision is bounded by the price type
+        let result = validate_order_request(
+            &market,
+            &market_outcome,
+            &Some(&price_ladder),
+            None,
+            &data,
+            now,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_market_outcome_withdrawn() {
+        let now: i64 = 1575975177;
+        let time_in_future: i64 = 43041841910;
+
+        let mut market = create_test_market(time_in_future, false, MarketStatus::Open, vec![]);
+        market.withdrawn_outcomes.push(WithdrawnOutcome {
+            outcome_index: 1,
+            reduction_factor: 1_000,
+            withdrawal_timestamp: now,
+        });
+        let market_outcome = MarketOutcome {
+            market: Pubkey::new_unique(),
+            index: 1,
+            title: "title".to_string(),
+            prices: None,
+            price_ladder: vec![],
+        };
+
+        let data = OrderRequestData {
+            market_outcome_index: 1,
+            for_outcome: true,
+            stake: 100000_u64,
+            price: Price::new(2_100),
+            distinct_seed: [0_u8; 16],
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+        };
+
+        let result = validate_order_request(&market, &market_outcome, &None, None, &data, now);
         assert_eq!(
-            result.unwrap_err(),
-            error!(CoreError::PricePrecisionTooLarge)
+            Err(error!(CoreError::CreationMarketOutcomeWithdrawn)),
+            result
         );
     }
 
+    #[test]
+    fn test_post_only_requires_good_till_cancelled() {
+        let now: i64 = 1575975177;
+        let time_in_future: i64 = 43041841910;
+
+        let market = create_test_market(time_in_future, false, MarketStatus::Open, vec![]);
+        let market_outcome = MarketOutcome {
+            market: Pubkey::new_unique(),
+            index: 0,
+            title: "title".to_string(),
+            prices: None,
+            price_ladder: vec![],
+        };
+
+        let mut data = OrderRequestData {
+            market_outcome_index: 0,
+            for_outcome: true,
+            stake: 100000_u64,
+            price: Price::new(2_100),
+            distinct_seed: [0_u8; 16],
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: true,
+        };
+
+        let result = validate_order_request(&market, &market_outcome, &None, None, &data, now);
+        assert!(result.is_ok());
+
+        data.time_in_force = TimeInForce::ImmediateOrCancel;
+        let result = validate_order_request(&market, &market_outcome, &None, None, &data, now);
+        assert_eq!(
+            Err(error!(CoreError::CreationPostOnlyInvalidTimeInForce)),
+            result
+        );
+
+        data.time_in_force = TimeInForce::FillOrKill;
+        let result = validate_order_request(&market, &market_outcome, &None, None, &data, now);
+        assert_eq!(
+            Err(error!(CoreError::CreationPostOnlyInvalidTimeInForce)),
+            result
+        );
+    }
+
+    #[test]
+    fn test_market_limits() {
+        let now: i64 = 1575975177;
+        let time_in_future: i64 = 43041841910;
+
+        let mut market = create_test_market(time_in_future, false, MarketStatus::Open, vec![]);
+        market.limits_enabled = true;
+        let market_outcome = MarketOutcome {
+            market: Pubkey::new_unique(),
+            index: 1,
+            title: "title".to_string(),
+            prices: None,
+            price_ladder: vec![],
+        };
+        let mut market_limits = mock_market_limits(Pubkey::new_unique(), 2, 100_000, 0, 500_000);
+
+        let mut data = OrderRequestData {
+            market_outcome_index: 1,
+            for_outcome: true,
+            stake: 100000_u64,
+            price: Price::new(2_100),
+            distinct_seed: [0_u8; 16],
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+        };
+
+        let result = validate_order_request(&market, &market_outcome, &None, None, &data, now);
+        assert_eq!(Err(error!(CoreError::MarketLimitsRequired)), result);
+
+        let result = validate_order_request(
+            &market,
+            &market_outcome,
+            &None,
+            Some(&market_limits),
+            &data,
+            now,
+        );
+        assert!(result.is_ok());
+
+        data.stake = 100001_u64;
+        let result = validate_order_request(
+            &market,
+            &market_outcome,
+            &None,
+            Some(&market_limits),
+            &data,
+            now,
+        );
+        assert_eq!(
+            Err(error!(CoreError::CreationStakeAboveMarketLimit)),
+            result
+        );
+
+        data.stake = 100000_u64;
+        market_limits.outcome_stakes_matched[1] = 500_000;
+        let result = validate_order_request(
+            &market,
+            &market_outcome,
+            &None,
+            Some(&market_limits),
+            &data,
+            now,
+        );
+        assert_eq!(
+            Err(error!(CoreError::CreationOutcomeStakeMatchedAtMarketLimit)),
+            result
+        );
+    }
+
+    #[test]
+    fn test_market_position_exposure_limit() {
+        let market_limits = mock_market_limits(Pubkey::new_unique(), 2, 0, 150, 0);
+        let mut market_position = mock_market_position(2);
+
+        market_position::update_on_order_request_creation(
+            &mut market_position,
+            0,
+            false,
+            100,
+            Price::new(3_000),
+        )
+        .unwrap();
+        let result = validate_market_position_exposure(Some(&market_limits), &market_position);
+        assert_eq!(
+            Err(error!(CoreError::CreationExposureAboveMarketLimit)),
+            result
+        );
+        assert!(validate_market_position_exposure(None, &market_position).is_ok());
+
+        let mut market_position = mock_market_position(2);
+        market_position::update_on_order_request_creation(
+            &mut market_position,
+            0,
+            false,
+            100,
+            Price::new(1_500),
+        )
+        .unwrap();
+        let result = validate_market_position_exposure(Some(&market_limits), &market_position);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_purchaser_access() {
+        let purchaser = Pubkey::new_unique();
+        let mut market = create_test_market(43041841910, false, MarketStatus::Open, vec![]);
+        let market_access_control =
+            mock_market_access_control(Pubkey::new_unique(), PurchaserListMode::Allow, vec![]);
+
+        // not enabled on the market
+        let result = validate_purchaser_access(&market, None, &purchaser, None);
+        assert!(result.is_ok());
+
+        market.access_control_enabled = true;
+        let result = validate_purchaser_access(&market, None, &purchaser, None);
+        assert_eq!(Err(error!(CoreError::MarketAccessControlRequired)), result);
+
+        let result =
+            validate_purchaser_access(&market, Some(&market_access_control), &purchaser, None);
+        assert_eq!(Err(error!(CoreError::CreationPurchaserNotAllowed)), result);
+
+        let market_access_control = mock_market_access_control(
+            Pubkey::new_unique(),
+            PurchaserListMode::Allow,
+            vec![purchaser],
+        );
+        let result =
+            validate_purchaser_access(&market, Some(&market_access_control), &purchaser, None);
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_market_valid() {
         let now: i64 = 1575975177;
         let time_in_future: i64 = 43041841910;
 
-        let market = create_test_market(time_in_future, false, MarketStatus::Open, None);
+        let market = create_test_market(time_in_future, false, MarketStatus::Open, vec![]);
 
         let result = validate_market_for_order_request(&market, now);
         assert!(result.is_ok());
@@ -238,7 +545,7 @@ mod tests {
         let time_in_past: i64 = 1575975177;
         let time_in_future: i64 = 43041841910;
 
-        let market = create_test_market(time_in_past, false, MarketStatus::Open, None);
+        let market = create_test_market(time_in_past, false, MarketStatus::Open, vec![]);
 
         let result = validate_market_for_order_request(&market, time_in_future);
 
@@ -254,7 +561,7 @@ mod tests {
         let now: i64 = 1575975177;
         let time_in_future: i64 = 43041841910;
 
-        let market = create_test_market(time_in_future, false, MarketStatus::Settled, None);
+        let market = create_test_market(time_in_future, false, MarketStatus::Settled, vec![]);
 
         let result = validate_market_for_order_request(&market, now);
 
@@ -270,7 +577,7 @@ mod tests {
         let now: i64 = 1575975177;
         let time_in_future: i64 = 43041841910;
 
-        let market = create_test_market(time_in_future, true, MarketStatus::Open, None);
+        let market = create_test_market(time_in_future, true, MarketStatus::Open, vec![]);
 
         let result = validate_market_for_order_request(&market, now);
 
@@ -286,7 +593,12 @@ mod tests {
         let now: i64 = 1575975177;
         let time_in_future: i64 = 43041841910;
 
-        let market = create_test_market(time_in_future, false, MarketStatus::Open, Some(1));
+        let market = create_test_market(
+            time_in_future,
+            false,
+            MarketStatus::Open,
+            vec![MarketWinningOutcome::new(1, 1, 1)],
+        );
 
         let result = validate_market_for_order_request(&market, now);
 
@@ -301,7 +613,7 @@ mod tests {
         market_lock_timestamp: UnixTimestamp,
         suspended: bool,
         market_status: MarketStatus,
-        market_winning_outcome_index: Option<u16>,
+        market_winning_outcomes: Vec<MarketWinningOutcome>,
     ) -> Market {
         Market {
             authority: Pubkey::new_unique(),
@@ -309,7 +621,8 @@ mod tests {
             mint_account: Default::default(),
             decimal_limit: 2,
             market_outcomes_count: 3_u16,
-            market_winning_outcome_index,
+            market_winning_places: 1,
+            market_winning_outcomes,
             market_type: Default::default(),
             market_type_discriminator: None,
             market_type_value: None,
@@ -330,6 +643,23 @@ mod tests {
             unclosed_accounts_count: 0,
             unsettled_accounts_count: 0,
             funding_account_bump: 0,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         }
     }
 }
@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use protocol_product::state::product::Product;
+use solana_program::clock::UnixTimestamp;
+use solana_program::system_program;
+
+use crate::error::CoreError;
+use crate::instructions::order_request::create_order_request::{
+    enqueue_order_request, validate_market_position_exposure, validate_order_request,
+};
+use crate::instructions::{current_timestamp, market_position};
+use crate::state::market_account::Market;
+use crate::state::market_limits::MarketLimits;
+use crate::state::market_order_request_queue::{MarketOrderRequestQueue, OrderRequestData};
+use crate::state::market_outcome_account::MarketOutcome;
+use crate::state::market_position_account::MarketPosition;
+use crate::state::price_ladder::PriceLadder;
+use std::ops::Deref;
+
+// remaining_accounts are expected to hold one (not yet existing) order account per order request,
+// in the same order as the requests, followed by the market outcome accounts used by the requests
+pub fn create_order_requests<'info>(
+    market_pk: Pubkey,
+    market: &mut Market,
+    payer: &Signer,
+    purchaser: &Signer,
+    product: &Option<Account<Product>>,
+    market_position: &mut MarketPosition,
+    price_ladder: &Option<Account<PriceLadder>>,
+    market_limits: Option<&MarketLimits>,
+    order_request_queue: &mut MarketOrderRequestQueue,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    data: Vec<OrderRequestData>,
+) -> Result<u64> {
+    require!(!data.is_empty(), CoreError::OrderRequestCreationNoRequests);
+    require!(
+        remaining_accounts.len() > data.len(),
+        CoreError::OrderRequestCreationInvalidMarketOutcome
+    );
+
+    let now: UnixTimestamp = current_timestamp();
+    // unpack account optionals (works only for non-mut)
+    let price_ladder_account = price_ladder.as_ref().map(|v| v.deref());
+
+    let (order_accounts, market_outcome_accounts) = remaining_accounts.split_at(data.len());
+    let market_outcomes = market_outcome_accounts
+        .iter()
+        .map(|account_info| {
+            Account::<MarketOutcome>::try_from(account_info)
+                .map_err(|_| error!(CoreError::OrderRequestCreationInvalidMarketOutcome))
+        })
+        .collect::<Result<Vec<Account<MarketOutcome>>>>()?;
+
+    for (order_request_data, order_account) in data.iter().zip(order_accounts) {
+        validate_order_account(
+            &market_pk,
+            purchaser.key,
+            &order_request_data.distinct_seed,
+            order_account,
+        )?;
+
+        let market_outcome = find_market_outcome(
+            &market_pk,
+            &market_outcomes,
+            order_request_data.market_outcome_index,
+        )?;
+        validate_market_outcome_price_ladder(market_outcome, price_ladder)?;
+        validate_order_request(
+            market,
+            market_outcome,
+            &price_ladder_account,
+            market_limits,
+            order_request_data,
+            now,
+        )?;
+    }
+
+    // initialize market position if needed
+    if market_position.payer == Pubkey::default() {
+        market_position::create_market_position(
+            purchaser.key,
+            payer.key,
+            market_pk,
+            market,
+            market_position,
+        )?;
+        market.increment_account_counts()?;
+    }
+
+    // initialize and enqueue order requests on to order_request_queue
+    let mut crank_fees = 0_u64;
+    for order_request_data in data.iter() {
+        enqueue_order_request(
+            market,
+            purchaser.key,
+            product,
+            order_request_queue,
+            order_request_data.clone(),
+            now,
+        )?;
+        crank_fees = crank_fees
+            .checked_add(market.collect_crank_fee()?)
+            .ok_or(CoreError::ArithmeticError)?;
+    }
+
+    let payment = market_position::update_on_order_requests_creation(market_position, &data)?;
+    validate_market_position_exposure(market_limits, market_position)?;
+
+    let payment = payment
+        .checked_add(crank_fees)
+        .ok_or(CoreError::ArithmeticError)?;
+
+    Ok(payment)
+}
+
+// same guarantee the reserved order account gives create_order_request - the order account
+// created when the request is processed must not already exist
+fn validate_order_account(
+    market_pk: &Pubkey,
+    purchaser_pk: &Pubkey,
+    distinct_seed: &[u8; 16],
+    order_account: &AccountInfo,
+) -> Result<()> {
+    let (order_pk, _) = Pubkey::find_program_address(
+        &[
+            market_pk.as_ref(),
+            purchaser_pk.as_ref(),
+            distinct_seed.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require!(
+        order_pk == order_account.key()
+            && system_program::check_id(order_account.owner)
+            && order_account.data_is_empty(),
+        CoreError::OrderRequestCreationInvalidOrderAccount
+    );
+    Ok(())
+}
+
+fn find_market_outcome<'a>(
+    market_pk: &Pubkey,
+    market_outcomes: &'a [Account<MarketOutcome>],
+    market_outcome_index: u16,
+) -> Result<&'a MarketOutcome> {
+    market_outcomes
+        .iter()
+        .map(|market_outcome| market_outcome.deref())
+        .find(|market_outcome| {
+            market_outcome.market == *market_pk && market_outcome.index == market_outcome_index
+        })
+        .ok_or_else(|| error!(CoreError::OrderRequestCreationInvalidMarketOutcome))
+}
+
+fn validate_market_outcome_price_ladder(
+    market_outcome: &MarketOutcome,
+    price_ladder: &Option<Account<PriceLadder>>,
+) -> Result<()> {
+    match (market_outcome.prices, price_ladder) {
+        (None, _) => Ok(()),
+        (Some(prices), Some(price_ladder)) if prices == price_ladder.key() => Ok(()),
+        _ => Err(error!(CoreError::CreationInvalidPriceLadder)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_order_account() {
+        let market_pk = Pubkey::new_unique();
+        let purchaser_pk = Pubkey::new_unique();
+        let distinct_seed = [1; 16];
+        let (order_pk, _) = Pubkey::find_program_address(
+            &[
+                market_pk.as_ref(),
+                purchaser_pk.as_ref(),
+                distinct_seed.as_ref(),
+            ],
+            &crate::ID,
+        );
+
+        let mut lamports = 0_u64;
+        let mut data = vec![];
+        let owner = system_program::ID;
+        let order_account = AccountInfo::new(
+            &order_pk,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        let result =
+            validate_order_account(&market_pk, &purchaser_pk, &distinct_seed, &order_account);
+        assert!(result.is_ok());
+
+        let result = validate_order_account(&market_pk, &purchaser_pk, &[2; 16], &order_account);
+        assert_eq!(
+            Err(error!(CoreError::OrderRequestCreationInvalidOrderAccount)),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_order_account_already_exists() {
+        let market_pk = Pubkey::new_unique();
+        let purchaser_pk = Pubkey::new_unique();
+        let distinct_seed = [1; 16];
+        let (order_pk, _) = Pubkey::find_program_address(
+            &[
+                market_pk.as_ref(),
+                purchaser_pk.as_ref(),
+                distinct_seed.as_ref(),
+            ],
+            &crate::ID,
+        );
+
+        let mut lamports = 1_000_u64;
+        let mut data = vec![1_u8; 8];
+        let owner = crate::ID;
+        let order_account = AccountInfo::new(
+            &order_pk,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        let result =
+            validate_order_account(&market_pk, &purchaser_pk, &distinct_seed, &order_account);
+        assert_eq!(
+            Err(error!(CoreError::OrderRequestCreationInvalidOrderAccount)),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_market_outcome_price_ladder() {
+        let price_ladder_pk = Pubkey::new_unique();
+        let market_outcome = |prices: Option<Pubkey>| MarketOutcome {
+            market: Pubkey::new_unique(),
+            index: 0,
+            title: "title".to_string(),
+            prices,
+            price_ladder: vec![],
+        };
+
+        assert!(validate_market_outcome_price_ladder(&market_outcome(None), &None).is_ok());
+        assert_eq!(
+            Err(error!(CoreError::CreationInvalidPriceLadder)),
+            validate_market_outcome_price_ladder(&market_outcome(Some(price_ladder_pk)), &None)
+        );
+    }
+}
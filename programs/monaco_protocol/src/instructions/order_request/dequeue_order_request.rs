@@ -26,11 +26,12 @@ pub fn dequeue_order_request(
 mod tests {
     use super::*;
     use crate::state::market_order_request_queue::{mock_order_request, OrderRequestQueue};
+    use crate::state::price::Price;
 
     #[test]
     fn dequeue_order_request_ok() {
         let purchaser = Pubkey::new_unique();
-        let order_request = mock_order_request(purchaser, true, 0, 10_u64, 3.0_f64);
+        let order_request = mock_order_request(purchaser, true, 0, 10_u64, Price::new(3_000));
         let expected_refund = order_request.stake;
 
         let order_request_queue = &mut MarketOrderRequestQueue {
@@ -48,6 +49,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 0,
             matched_risk_per_product: vec![],
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         let result = dequeue_order_request(order_request_queue, market_position);
@@ -57,7 +65,8 @@ mod tests {
 
     #[test]
     fn dequeue_order_request_purchaser_mismatch() {
-        let order_request = mock_order_request(Pubkey::new_unique(), true, 0, 10_u64, 3.0_f64);
+        let order_request =
+            mock_order_request(Pubkey::new_unique(), true, 0, 10_u64, Price::new(3_000));
 
         let order_request_queue = &mut MarketOrderRequestQueue {
             market: Pubkey::new_unique(),
@@ -74,6 +83,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 0,
             matched_risk_per_product: vec![],
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         let result = dequeue_order_request(order_request_queue, market_position);
@@ -100,6 +116,13 @@ mod tests {
             payer: Default::default(),
             matched_risk: 0,
             matched_risk_per_product: vec![],
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
         };
 
         let result = dequeue_order_request(order_request_queue, market_position);
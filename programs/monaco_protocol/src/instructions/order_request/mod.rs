@@ -1,7 +1,9 @@
 pub mod create_order_request;
+pub mod create_order_requests;
 pub mod dequeue_order_request;
 pub mod process_order_request;
 
 pub use create_order_request::*;
+pub use create_order_requests::*;
 pub use dequeue_order_request::*;
 pub use process_order_request::*;
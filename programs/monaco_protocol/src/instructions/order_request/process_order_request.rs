@@ -1,19 +1,24 @@
 use anchor_lang::prelude::*;
 
 use crate::error::CoreError;
+use crate::events::order::OrderCreatedEvent;
 use crate::instructions::market::move_market_to_inplay;
+use crate::instructions::market_order_book::add_to_market_order_book;
 use crate::instructions::market_position::update_product_commission_contributions;
-use crate::instructions::order::initialize_order;
+use crate::instructions::order::{cancel_order_common, initialize_order};
 use crate::instructions::{
     calculate_risk_from_stake, current_timestamp, market, market_position, matching,
 };
 use crate::state::market_account::*;
+use crate::state::market_limits::MarketLimits;
 use crate::state::market_liquidities::MarketLiquidities;
 use crate::state::market_matching_pool_account::MarketMatchingPool;
 use crate::state::market_matching_queue_account::MarketMatchingQueue;
-use crate::state::market_order_request_queue::MarketOrderRequestQueue;
+use crate::state::market_order_book::MarketOrderBook;
+use crate::state::market_order_request_queue::{MarketOrderRequestQueue, TimeInForce};
 use crate::state::market_position_account::MarketPosition;
 use crate::state::order_account::*;
+use crate::state::price::Price;
 
 pub fn process_order_request<'info>(
     order: &mut Account<'info, Order>,
@@ -24,7 +29,59 @@ pub fn process_order_request<'info>(
     fee_payer: &Signer<'info>,
     matching_pool: &mut Account<MarketMatchingPool>,
     order_request_queue: &mut Account<MarketOrderRequestQueue>,
+    market_order_book: Option<&mut MarketOrderBook>,
+    market_limits: Option<&mut MarketLimits>,
+    ladder_prices: Option<&[Price]>,
 ) -> Result<u64> {
+    let (refund, order_closed) = process_order_request_common(
+        &order.key(),
+        order,
+        market_position,
+        &market.key(),
+        market,
+        market_liquidities,
+        market_matching_queue,
+        fee_payer.key,
+        matching_pool,
+        order_request_queue,
+        market_order_book,
+        market_limits,
+        ladder_prices,
+    )?;
+    if order_closed {
+        order.close(fee_payer.to_account_info())?;
+    }
+
+    Ok(refund)
+}
+
+// processes the order request at the front of the queue against plain account state, returning
+// the refund due to the purchaser and whether the order account is to be closed; ladder_prices are
+// the prices of the order's outcome, which cross prices are rounded onto with price improvement
+#[allow(clippy::too_many_arguments)]
+pub fn process_order_request_common(
+    order_pk: &Pubkey,
+    order: &mut Order,
+    market_position: &mut MarketPosition,
+    market_pk: &Pubkey,
+    market: &mut Market,
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_queue: &mut MarketMatchingQueue,
+    fee_payer: &Pubkey,
+    matching_pool: &mut MarketMatchingPool,
+    order_request_queue: &mut MarketOrderRequestQueue,
+    market_order_book: Option<&mut MarketOrderBook>,
+    market_limits: Option<&mut MarketLimits>,
+    ladder_prices: Option<&[Price]>,
+) -> Result<(u64, bool)> {
+    require!(
+        !market.limits_enabled || market_limits.is_some(),
+        CoreError::MarketLimitsRequired
+    );
+    require!(
+        !market.price_improvement_enabled || ladder_prices.is_some(),
+        CoreError::MatchingPriceImprovementMarketOutcomeRequired
+    );
     let now = current_timestamp();
     let order_request = order_request_queue
         .order_requests
@@ -37,12 +94,19 @@ pub fn process_order_request<'info>(
                 market_position,
                 order_request,
             )?;
-            order.close(fee_payer.to_account_info())?;
 
-            return Ok(refund);
+            return Ok((refund, true));
         }
     }
 
+    // requests queued before a suspension cancelling unmatched orders are cancelled with them
+    if market.is_cancelled_by_suspension(order_request.creation_timestamp) {
+        let refund =
+            market_position::update_on_order_request_cancellation(market_position, order_request)?;
+
+        return Ok((refund, true));
+    }
+
     if market.is_inplay() {
         // if market is inplay, but the inplay flag hasn't been flipped yet, do it now
         // and zero liquidities before processing the order request if that's
@@ -60,12 +124,63 @@ pub fn process_order_request<'info>(
         }
     }
 
-    initialize_order(order, market, fee_payer.key(), *order_request)?;
+    initialize_order(order, *market_pk, *fee_payer, *order_request)?;
+
+    // fill-or-kill requests are rejected unless the whole stake can be matched, post-only
+    // requests are rejected if any of it can and requests matching beyond the outcome limit are
+    // rejected, in all cases without touching the book
+    let outcome_limited = matches!(
+        market_limits.as_ref(),
+        Some(market_limits) if market_limits.max_outcome_stake_matched > 0
+    );
+    if order_request.time_in_force == TimeInForce::FillOrKill
+        || order_request.post_only
+        || outcome_limited
+    {
+        let stake_unmatched = simulate_stake_unmatched(
+            market,
+            market_liquidities,
+            market_matching_queue,
+            order_pk,
+            order,
+            ladder_prices,
+        )?;
+        let fill_or_kill_rejected =
+            order_request.time_in_force == TimeInForce::FillOrKill && stake_unmatched > 0;
+        let post_only_rejected = order_request.post_only && stake_unmatched < order.stake;
+        let outcome_limit_rejected = match market_limits.as_ref() {
+            Some(market_limits) => !market_limits.is_outcome_stake_matched_within_limit(
+                order.market_outcome_index,
+                order.stake - stake_unmatched,
+            )?,
+            None => false,
+        };
+
+        if fill_or_kill_rejected || post_only_rejected || outcome_limit_rejected {
+            let refund = market_position::update_on_order_request_cancellation(
+                market_position,
+                order_request,
+            )?;
+
+            return Ok((refund, true));
+        }
+    }
+
+    emit!(OrderCreatedEvent {
+        market: order.market,
+        order: *order_pk,
+        purchaser: order.purchaser,
+        market_outcome_index: order.market_outcome_index,
+        for_outcome: order.for_outcome,
+        stake: order.stake,
+        price: order.expected_price,
+    });
+
     market.increment_account_counts()?;
 
     // if this pool is new, initialize it
     if matching_pool.orders.capacity() == 0 {
-        market::initialize_market_matching_pool(matching_pool, market, order)?;
+        market::initialize_market_matching_pool(matching_pool, *market_pk, market, order)?;
         market.increment_unclosed_accounts_count()?;
     }
     if market.is_inplay() && !matching_pool.inplay {
@@ -76,15 +191,25 @@ pub fn process_order_request<'info>(
         matching_pool.move_to_inplay(&market.event_start_order_behaviour);
     }
 
-    let order_matches = matching::on_order_creation(
+    let order_matches = match_order(
+        market,
         market_liquidities,
         market_matching_queue,
-        &order.key(),
+        order_pk,
         order,
+        ladder_prices,
     )?;
-    matching::update_matching_pool_with_new_order(matching_pool, order)?;
+    matching::update_matching_pool_with_new_order(matching_pool, order_pk, order)?;
+    if let Some(market_limits) = market_limits {
+        let stake_matched = order_matches
+            .iter()
+            .try_fold(0_u64, |total, (stake, _)| total.checked_add(*stake))
+            .ok_or(CoreError::ArithmeticError)?;
+        market_limits.update_outcome_stake_matched(order.market_outcome_index, stake_matched)?;
+    }
 
     // calculate payment
+    market_position.apply_outcome_withdrawals(&market.withdrawn_outcomes)?;
     let mut total_refund = 0_u64;
     for (matched_stake, matched_price) in order_matches {
         let refund = market_position::update_on_order_match(
@@ -108,5 +233,184 @@ pub fn process_order_request<'info>(
         )?;
     }
 
-    Ok(total_refund)
+    // immediate-or-cancel requests void whatever was not matched on creation
+    let mut order_closed = false;
+    if order_request.time_in_force == TimeInForce::ImmediateOrCancel && order.stake_unmatched > 0 {
+        let refund = cancel_order_common(
+            market_liquidities,
+            matching_pool,
+            order_pk,
+            order,
+            market_position,
+        )?;
+        total_refund = total_refund
+            .checked_add(refund)
+            .ok_or(CoreError::CreationTransferAmountError)?;
+
+        // if never matched, close
+        if order.order_status == OrderStatus::Cancelled {
+            market.decrement_account_counts()?;
+            order_closed = true;
+        }
+    }
+
+    // whatever is still unmatched is now resting on the book
+    add_to_market_order_book(market, market_order_book, order, order.stake_unmatched)?;
+
+    Ok((total_refund, order_closed))
+}
+
+// runs matching against copies of the liquidities and matching queue to see how much of the
+// stake would be left unmatched
+fn simulate_stake_unmatched(
+    market: &Market,
+    market_liquidities: &MarketLiquidities,
+    market_matching_queue: &MarketMatchingQueue,
+    order_pk: &Pubkey,
+    order: &Order,
+    ladder_prices: Option<&[Price]>,
+) -> Result<u64> {
+    let mut market_liquidities = market_liquidities.clone();
+    let mut market_matching_queue = market_matching_queue.clone();
+    let mut order = order.clone();
+
+    match_order(
+        market,
+        &mut market_liquidities,
+        &mut market_matching_queue,
+        order_pk,
+        &mut order,
+        ladder_prices,
+    )?;
+
+    Ok(order.stake_unmatched)
+}
+
+fn match_order(
+    market: &Market,
+    market_liquidities: &mut MarketLiquidities,
+    market_matching_queue: &mut MarketMatchingQueue,
+    order_pk: &Pubkey,
+    order: &mut Order,
+    ladder_prices: Option<&[Price]>,
+) -> Result<Vec<(u64, Price)>> {
+    match (market.price_improvement_enabled, ladder_prices) {
+        (true, Some(ladder_prices)) => matching::on_order_creation_with_price_improvement(
+            market_liquidities,
+            market_matching_queue,
+            order_pk,
+            order,
+            market.market_outcomes_count,
+            ladder_prices,
+        ),
+        (true, None) => Err(error!(
+            CoreError::MatchingPriceImprovementMarketOutcomeRequired
+        )),
+        (false, _) => {
+            matching::on_order_creation(market_liquidities, market_matching_queue, order_pk, order)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_liquidities::mock_market_liquidities;
+    use crate::state::market_matching_queue_account::MatchingQueue;
+
+    #[test]
+    fn test_simulate_stake_unmatched() {
+        let market = mock_market(MarketStatus::Open);
+        let market_pk = Pubkey::new_unique();
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        market_liquidities
+            .add_liquidity_against(0, Price::new(3_000), 5)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_against(0, Price::new(2_800), 5)
+            .unwrap();
+        let market_matching_queue = MarketMatchingQueue {
+            market: market_pk,
+            matches: MatchingQueue::new(10),
+        };
+        let order_pk = Pubkey::new_unique();
+
+        let order = mock_order(
+            market_pk,
+            0,
+            true,
+            Price::new(2_800),
+            10,
+            Pubkey::new_unique(),
+        );
+        let result = simulate_stake_unmatched(
+            &market,
+            &market_liquidities,
+            &market_matching_queue,
+            &order_pk,
+            &order,
+            None,
+        );
+        assert_eq!(Ok(0), result);
+
+        let order = mock_order(
+            market_pk,
+            0,
+            true,
+            Price::new(2_800),
+            11,
+            Pubkey::new_unique(),
+        );
+        let result = simulate_stake_unmatched(
+            &market,
+            &market_liquidities,
+            &market_matching_queue,
+            &order_pk,
+            &order,
+            None,
+        );
+        assert_eq!(Ok(1), result);
+
+        let order = mock_order(
+            market_pk,
+            0,
+            true,
+            Price::new(3_000),
+            10,
+            Pubkey::new_unique(),
+        );
+        let result = simulate_stake_unmatched(
+            &market,
+            &market_liquidities,
+            &market_matching_queue,
+            &order_pk,
+            &order,
+            None,
+        );
+        assert_eq!(Ok(5), result);
+
+        // nothing available at a price this high, so nothing would match
+        let order = mock_order(
+            market_pk,
+            0,
+            true,
+            Price::new(3_200),
+            10,
+            Pubkey::new_unique(),
+        );
+        let result = simulate_stake_unmatched(
+            &market,
+            &market_liquidities,
+            &market_matching_queue,
+            &order_pk,
+            &order,
+            None,
+        );
+        assert_eq!(Ok(10), result);
+
+        // liquidities and matching queue are left untouched
+        assert_eq!(2, market_liquidities.liquidities_against.len());
+        assert_eq!(0, market_matching_queue.matches.len());
+        assert_eq!(10, order.stake_unmatched);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::error::CoreError;
+use crate::events::payment::CommissionPaidEvent;
 use crate::instructions;
 use crate::state::market_account::Market;
 use crate::state::payments_queue::PaymentQueue;
@@ -37,5 +38,13 @@ pub fn process_commission_payment<'a>(
         token_program,
         market,
         payment_info.amount,
-    )
+    )?;
+
+    emit!(CommissionPaidEvent {
+        market: market.key(),
+        product: product.key(),
+        amount: payment_info.amount,
+    });
+
+    Ok(())
 }
@@ -16,6 +16,7 @@ pub fn create_price_ladder(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::price::Price;
 
     #[test]
     fn test_create_price_ladder() {
@@ -25,12 +26,12 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(price_ladder.authority, authority);
         assert_eq!(price_ladder.max_number_of_prices, 3);
-        assert_eq!(price_ladder.prices, vec![] as Vec<f64>);
+        assert_eq!(price_ladder.prices, vec![] as Vec<Price>);
     }
 
     fn price_ladder() -> PriceLadder {
         PriceLadder {
-            prices: vec![1.0],
+            prices: vec![Price::new(1_000)],
             max_number_of_prices: 0,
             authority: Pubkey::new_unique(),
         }
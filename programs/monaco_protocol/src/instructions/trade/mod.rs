@@ -0,0 +1,3 @@
+pub mod void_trades;
+
+pub use void_trades::*;
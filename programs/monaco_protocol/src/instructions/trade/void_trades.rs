@@ -0,0 +1,353 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+use crate::error::CoreError;
+use crate::events::trade::TradesVoidedEvent;
+use crate::instructions::market_position::update_on_trade_void;
+use crate::state::market_account::{Market, MarketStatus};
+use crate::state::market_position_account::MarketPosition;
+use crate::state::order_account::Order;
+use crate::state::trade_account::Trade;
+
+// voids the stake common to a for and against trade of the same outcome and price, so that the
+// escrow released to each purchaser is exactly what the other side no longer needs to be paid;
+// returns the refunds due to the for and against purchasers
+pub fn void_trades(
+    market_pk: &Pubkey,
+    market: &Market,
+    for_trade_pk: &Pubkey,
+    for_trade: &mut Trade,
+    for_order: &mut Order,
+    for_market_position: &mut MarketPosition,
+    against_trade_pk: &Pubkey,
+    against_trade: &mut Trade,
+    against_order: &mut Order,
+    against_market_position: &mut MarketPosition,
+    now: UnixTimestamp,
+) -> Result<(u64, u64)> {
+    require!(
+        matches!(
+            market.market_status,
+            MarketStatus::Open | MarketStatus::Locked
+        ),
+        CoreError::VoidTradeMarketNotOpenOrLocked
+    );
+    require!(
+        for_trade.for_outcome
+            && !against_trade.for_outcome
+            && for_trade.market_outcome_index == against_trade.market_outcome_index
+            && for_trade.price == against_trade.price
+            // trades created before matches were recorded cannot be paired
+            && for_trade.match_id != Pubkey::default()
+            && for_trade.match_id == against_trade.match_id
+            // self-matched trades net out and share a market position
+            && for_trade.purchaser != against_trade.purchaser,
+        CoreError::VoidTradeMismatch
+    );
+    require!(
+        within_void_window(market, for_trade, now)
+            && within_void_window(market, against_trade, now),
+        CoreError::VoidTradeWindowExpired
+    );
+    // reductions for outcome withdrawals have already been applied to earlier trades
+    require!(
+        !matched_before_withdrawal(market, for_trade)
+            && !matched_before_withdrawal(market, against_trade),
+        CoreError::VoidTradeMatchedBeforeOutcomeWithdrawal
+    );
+
+    let stake_voided = for_trade.stake.min(against_trade.stake);
+    require!(stake_voided > 0, CoreError::VoidTradeNoStake);
+
+    let for_refund = void_trade(
+        market,
+        for_trade,
+        for_order,
+        for_market_position,
+        stake_voided,
+    )?;
+    let against_refund = void_trade(
+        market,
+        against_trade,
+        against_order,
+        against_market_position,
+        stake_voided,
+    )?;
+
+    emit!(TradesVoidedEvent {
+        market: *market_pk,
+        for_trade: *for_trade_pk,
+        against_trade: *against_trade_pk,
+        stake: stake_voided,
+        price: for_trade.price,
+    });
+
+    Ok((for_refund, against_refund))
+}
+
+fn within_void_window(market: &Market, trade: &Trade, now: UnixTimestamp) -> bool {
+    let void_window_end = trade
+        .creation_timestamp
+        .checked_add(market.trade_void_window);
+    market.trade_void_window > 0 && matches!(void_window_end, Some(end) if now <= end)
+}
+
+fn matched_before_withdrawal(market: &Market, trade: &Trade) -> bool {
+    market
+        .withdrawn_outcomes
+        .iter()
+        .any(|withdrawn_outcome| trade.creation_timestamp <= withdrawn_outcome.withdrawal_timestamp)
+}
+
+fn void_trade(
+    market: &Market,
+    trade: &mut Trade,
+    order: &mut Order,
+    market_position: &mut MarketPosition,
+    stake_voided: u64,
+) -> Result<u64> {
+    market_position.apply_outcome_withdrawals(&market.withdrawn_outcomes)?;
+    trade.stake = trade
+        .stake
+        .checked_sub(stake_voided)
+        .ok_or(CoreError::ArithmeticError)?;
+    order.void_stake_matched(stake_voided, trade.price)?;
+    update_on_trade_void(market_position, order, stake_voided, trade.price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::market_position::{
+        update_on_order_match, update_on_order_request_creation,
+        update_product_commission_contributions,
+    };
+    use crate::instructions::{calculate_risk_from_stake, current_timestamp};
+    use crate::state::market_account::{mock_market, WithdrawnOutcome};
+    use crate::state::market_position_account::mock_market_position;
+    use crate::state::order_account::mock_order;
+    use crate::state::price::Price;
+
+    struct TradeSide {
+        trade: Trade,
+        order: Order,
+        market_position: MarketPosition,
+    }
+
+    const MATCH_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    fn matched_side(
+        for_outcome: bool,
+        stake: u64,
+        price: Price,
+        creation_timestamp: i64,
+    ) -> TradeSide {
+        let mut order = mock_order(
+            Pubkey::new_unique(),
+            1,
+            for_outcome,
+            price,
+            stake,
+            Pubkey::new_unique(),
+        );
+        let mut market_position = mock_market_position(3);
+        update_on_order_request_creation(&mut market_position, 1, for_outcome, stake, price)
+            .expect("test setup");
+        order
+            .match_stake_unmatched(stake, price)
+            .expect("test setup");
+        update_on_order_match(&mut market_position, &order, stake, price).expect("test setup");
+        update_product_commission_contributions(
+            &mut market_position,
+            &order,
+            match for_outcome {
+                true => stake,
+                false => calculate_risk_from_stake(stake, price),
+            },
+        )
+        .expect("test setup");
+
+        let trade = Trade {
+            purchaser: order.purchaser,
+            market: order.market,
+            market_outcome_index: 1,
+            for_outcome,
+            stake,
+            price,
+            creation_timestamp,
+            match_id: MATCH_ID,
+            ..Trade::default()
+        };
+        TradeSide {
+            trade,
+            order,
+            market_position,
+        }
+    }
+
+    fn void(
+        market: &Market,
+        for_side: &mut TradeSide,
+        against_side: &mut TradeSide,
+        now: i64,
+    ) -> Result<(u64, u64)> {
+        void_trades(
+            &Pubkey::new_unique(),
+            market,
+            &Pubkey::new_unique(),
+            &mut for_side.trade,
+            &mut for_side.order,
+            &mut for_side.market_position,
+            &Pubkey::new_unique(),
+            &mut against_side.trade,
+            &mut against_side.order,
+            &mut against_side.market_position,
+            now,
+        )
+    }
+
+    fn market_with_void_window(trade_void_window: i64) -> Market {
+        let mut market = mock_market(MarketStatus::Open);
+        market.market_outcomes_count = 3;
+        market.trade_void_window = trade_void_window;
+        market
+    }
+
+    #[test]
+    fn void_trades_success() {
+        let market = market_with_void_window(60);
+        let now = current_timestamp();
+        let mut for_side = matched_side(true, 100, Price::new(3_000), now - 30);
+        let mut against_side = matched_side(false, 100, Price::new(3_000), now - 60);
+
+        let result = void(&market, &mut for_side, &mut against_side, now);
+
+        assert_eq!(Ok((100, 200)), result);
+        assert_eq!(0, for_side.trade.stake);
+        assert_eq!(100, for_side.order.voided_stake);
+        assert_eq!(0, for_side.order.payout);
+        assert_eq!(vec![0, 0, 0], for_side.market_position.market_outcome_sums);
+        assert_eq!(0, for_side.market_position.matched_risk);
+        assert_eq!(
+            vec![0, 0, 0],
+            for_side.market_position.market_outcome_stakes
+        );
+        assert_eq!(0, against_side.trade.stake);
+        assert_eq!(
+            vec![0, 0, 0],
+            against_side.market_position.market_outcome_sums
+        );
+        assert_eq!(0, against_side.market_position.matched_risk);
+    }
+
+    #[test]
+    fn void_trades_partial() {
+        let market = market_with_void_window(60);
+        let now = current_timestamp();
+        let mut for_side = matched_side(true, 100, Price::new(2_500), now);
+        let mut against_side = matched_side(false, 40, Price::new(2_500), now);
+
+        let result = void(&market, &mut for_side, &mut against_side, now);
+
+        assert_eq!(Ok((40, 60)), result);
+        assert_eq!(60, for_side.trade.stake);
+        assert_eq!(150, for_side.order.payout);
+        assert_eq!(0, against_side.trade.stake);
+
+        let result = void(&market, &mut for_side, &mut against_side, now);
+        assert_eq!(Err(error!(CoreError::VoidTradeNoStake)), result);
+    }
+
+    #[test]
+    fn void_trades_window() {
+        let now = current_timestamp();
+        let mut for_side = matched_side(true, 100, Price::new(3_000), now - 61);
+        let mut against_side = matched_side(false, 100, Price::new(3_000), now);
+
+        let result = void(
+            &market_with_void_window(60),
+            &mut for_side,
+            &mut against_side,
+            now,
+        );
+        assert_eq!(Err(error!(CoreError::VoidTradeWindowExpired)), result);
+
+        for_side.trade.creation_timestamp = now;
+        let result = void(
+            &market_with_void_window(0),
+            &mut for_side,
+            &mut against_side,
+            now,
+        );
+        assert_eq!(Err(error!(CoreError::VoidTradeWindowExpired)), result);
+        assert_eq!(100, for_side.trade.stake);
+    }
+
+    #[test]
+    fn void_trades_mismatch() {
+        let market = market_with_void_window(60);
+        let now = current_timestamp();
+
+        let mut for_side = matched_side(true, 100, Price::new(3_000), now);
+        let mut against_side = matched_side(false, 100, Price::new(3_100), now);
+        let result = void(&market, &mut for_side, &mut against_side, now);
+        assert_eq!(Err(error!(CoreError::VoidTradeMismatch)), result);
+
+        let mut against_side = matched_side(true, 100, Price::new(3_000), now);
+        let result = void(&market, &mut for_side, &mut against_side, now);
+        assert_eq!(Err(error!(CoreError::VoidTradeMismatch)), result);
+
+        // matched against a different order at the same price
+        let mut against_side = matched_side(false, 100, Price::new(3_000), now);
+        against_side.trade.match_id = Pubkey::new_unique();
+        let result = void(&market, &mut for_side, &mut against_side, now);
+        assert_eq!(Err(error!(CoreError::VoidTradeMismatch)), result);
+
+        // created before matches were recorded
+        let mut against_side = matched_side(false, 100, Price::new(3_000), now);
+        for_side.trade.match_id = Pubkey::default();
+        against_side.trade.match_id = Pubkey::default();
+        let result = void(&market, &mut for_side, &mut against_side, now);
+        assert_eq!(Err(error!(CoreError::VoidTradeMismatch)), result);
+        assert_eq!(100, for_side.trade.stake);
+    }
+
+    #[test]
+    fn void_trades_market_status() {
+        let mut market = market_with_void_window(60);
+        market.market_status = MarketStatus::ReadyForSettlement;
+        let now = current_timestamp();
+        let mut for_side = matched_side(true, 100, Price::new(3_000), now);
+        let mut against_side = matched_side(false, 100, Price::new(3_000), now);
+
+        let result = void(&market, &mut for_side, &mut against_side, now);
+
+        assert_eq!(
+            Err(error!(CoreError::VoidTradeMarketNotOpenOrLocked)),
+            result
+        );
+    }
+
+    #[test]
+    fn void_trades_matched_before_outcome_withdrawal() {
+        let mut market = market_with_void_window(60);
+        let now = current_timestamp();
+        market.withdrawn_outcomes.push(WithdrawnOutcome {
+            outcome_index: 2,
+            reduction_factor: 1_000,
+            withdrawal_timestamp: now - 10,
+        });
+        let mut for_side = matched_side(true, 100, Price::new(3_000), now - 10);
+        let mut against_side = matched_side(false, 100, Price::new(3_000), now);
+
+        let result = void(&market, &mut for_side, &mut against_side, now);
+        assert_eq!(
+            Err(error!(CoreError::VoidTradeMatchedBeforeOutcomeWithdrawal)),
+            result
+        );
+
+        for_side.trade.creation_timestamp = now;
+        let result = void(&market, &mut for_side, &mut against_side, now);
+        assert_eq!(Ok((100, 200)), result);
+    }
+}
@@ -3,8 +3,58 @@ use anchor_spl::token;
 use anchor_spl::token::{Token, TokenAccount};
 
 use crate::context::{MatchOrders, SettleMarketPosition, VoidMarketPosition};
+use crate::error::CoreError;
 use crate::state::market_account::Market;
 
+pub fn order_request_creation_payment<'info>(
+    market_escrow: &Account<'info, TokenAccount>,
+    purchaser: &Signer<'info>,
+    purchaser_token: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    market: &Account<'info, Market>,
+    amount: u64,
+) -> Result<()> {
+    // if PDA owner then do PDA transfer else do regular token account transfer
+    if purchaser_token.owner == purchaser_token.key() {
+        // Verify PDA is the correct account
+        Pubkey::create_program_address(
+            &[
+                b"funding",
+                market.key().as_ref(),
+                &[market.funding_account_bump],
+            ],
+            &crate::ID,
+        )
+        .map_or_else(
+            |_| Err(CoreError::OrderRequestCreationInvalidPayerTokenAccount.into()),
+            |pk| {
+                require!(
+                    pk == purchaser_token.key(),
+                    CoreError::OrderRequestCreationInvalidPayerTokenAccount
+                );
+                Ok(())
+            },
+        )?;
+
+        funding_account_order_creation_payment(
+            market_escrow,
+            purchaser_token,
+            token_program,
+            &market.key(),
+            market.funding_account_bump,
+            amount,
+        )
+    } else {
+        order_creation_payment(
+            market_escrow,
+            purchaser,
+            purchaser_token,
+            token_program,
+            amount,
+        )
+    }
+}
+
 pub fn order_creation_payment<'info>(
     market_escrow: &Account<'info, TokenAccount>,
     purchaser: &Signer<'info>,
@@ -74,6 +124,27 @@ pub fn order_cancelation_refund<'info>(
     )
 }
 
+pub fn crank_reward_payment<'info>(
+    market_escrow: &Account<'info, TokenAccount>,
+    crank_operator_token: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    market: &mut Account<Market>,
+) -> Result<()> {
+    // without a token account to pay it to, the reward stays in the balance for later steps
+    let crank_operator_token = match crank_operator_token {
+        Some(crank_operator_token) => crank_operator_token,
+        None => return Ok(()),
+    };
+    let reward = market.take_crank_reward();
+    transfer_from_market_escrow(
+        market_escrow,
+        crank_operator_token,
+        token_program,
+        market,
+        reward,
+    )
+}
+
 pub fn order_for_matching_refund(ctx: &Context<MatchOrders>, amount: u64) -> Result<()> {
     let accounts = &ctx.accounts;
 
@@ -1,17 +1,22 @@
 use anchor_lang::prelude::*;
+use std::ops::Deref;
 
 use crate::context::*;
 use crate::error::CoreError;
-use crate::instructions::market::verify_market_authority;
 use crate::instructions::market_position;
 use crate::instructions::transfer;
-use crate::instructions::verify_operator_authority;
-use crate::state::market_account::{Market, MarketOrderBehaviour};
+use crate::state::market_access_control::PurchaserListMode;
+use crate::state::market_account::{
+    Market, MarketOrderBehaviour, MarketWinningOutcome, OutcomeResult,
+};
 use crate::state::market_liquidities::LiquiditySource;
 use crate::state::market_order_request_queue::{MarketOrderRequestQueue, OrderRequestData};
 use crate::state::market_position_account::MarketPosition;
+use crate::state::market_settlement_proposal::SettlementProposalAction;
+use crate::state::market_type::MarketTemplate;
 use crate::state::operator_account::AuthorisedOperators;
 use crate::state::order_account::Order;
+use crate::state::price::Price;
 use crate::state::trade_account::Trade;
 
 pub mod context;
@@ -45,64 +50,82 @@ pub mod monaco_protocol {
         ctx: Context<CreateOrderRequest>,
         data: OrderRequestData,
     ) -> Result<()> {
+        instructions::order_request::validate_purchaser_access(
+            &ctx.accounts.market,
+            ctx.accounts.market_access_control.as_deref(),
+            ctx.accounts.purchaser.key,
+            ctx.accounts
+                .purchaser_gate_token
+                .as_deref()
+                .map(|token| token.deref()),
+        )?;
+
         let payment = instructions::order_request::create_order_request(
             ctx.accounts.market.key(),
             &mut ctx.accounts.market,
-            &ctx.accounts.payer,
-            &ctx.accounts.purchaser,
+            ctx.accounts.payer.key,
+            ctx.accounts.purchaser.key,
             &ctx.accounts.product,
             &mut ctx.accounts.market_position,
             &ctx.accounts.market_outcome,
-            &ctx.accounts.price_ladder,
+            ctx.accounts.price_ladder.as_deref(),
+            ctx.accounts.market_limits.as_deref(),
             &mut ctx.accounts.order_request_queue,
             data,
         )?;
 
-        // if PDA owner then do PDA transfer else do regular token account transfer
-        if ctx.accounts.purchaser_token.owner == ctx.accounts.purchaser_token.key() {
-            // Verify PDA is the correct account
-            let market = ctx.accounts.market.key();
-            Pubkey::create_program_address(
-                &[
-                    b"funding",
-                    market.key().as_ref(),
-                    &[ctx.accounts.market.funding_account_bump],
-                ],
-                &monaco_protocol::ID,
-            )
-            .map_or_else(
-                |_| Err(CoreError::OrderRequestCreationInvalidPayerTokenAccount.into()),
-                |pk| {
-                    require!(
-                        pk == ctx.accounts.purchaser_token.key(),
-                        CoreError::OrderRequestCreationInvalidPayerTokenAccount
-                    );
-                    Ok(())
-                },
-            )?;
+        transfer::order_request_creation_payment(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.purchaser,
+            &ctx.accounts.purchaser_token,
+            &ctx.accounts.token_program,
+            &ctx.accounts.market,
+            payment,
+        )?;
 
-            transfer::funding_account_order_creation_payment(
-                &ctx.accounts.market_escrow,
-                &ctx.accounts.purchaser_token,
-                &ctx.accounts.token_program,
-                &ctx.accounts.market.key(),
-                ctx.accounts.market.funding_account_bump,
-                payment,
-            )?;
-        } else {
-            transfer::order_creation_payment(
-                &ctx.accounts.market_escrow,
-                &ctx.accounts.purchaser,
-                &ctx.accounts.purchaser_token,
-                &ctx.accounts.token_program,
-                payment,
-            )?;
-        }
         ctx.accounts
             .reserved_order
             .close(ctx.accounts.payer.to_account_info())
     }
 
+    pub fn create_order_requests<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateOrderRequests<'info>>,
+        data: Vec<OrderRequestData>,
+    ) -> Result<()> {
+        instructions::order_request::validate_purchaser_access(
+            &ctx.accounts.market,
+            ctx.accounts.market_access_control.as_deref(),
+            ctx.accounts.purchaser.key,
+            ctx.accounts
+                .purchaser_gate_token
+                .as_deref()
+                .map(|token| token.deref()),
+        )?;
+
+        let payment = instructions::order_request::create_order_requests(
+            ctx.accounts.market.key(),
+            &mut ctx.accounts.market,
+            &ctx.accounts.payer,
+            &ctx.accounts.purchaser,
+            &ctx.accounts.product,
+            &mut ctx.accounts.market_position,
+            &ctx.accounts.price_ladder,
+            ctx.accounts.market_limits.as_deref(),
+            &mut ctx.accounts.order_request_queue,
+            ctx.remaining_accounts,
+            data,
+        )?;
+
+        transfer::order_request_creation_payment(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.purchaser,
+            &ctx.accounts.purchaser_token,
+            &ctx.accounts.token_program,
+            &ctx.accounts.market,
+            payment,
+        )
+    }
+
     pub fn create_market_position(ctx: Context<CreateMarketPosition>) -> Result<()> {
         market_position::create_market_position(
             &ctx.accounts.purchaser.key(),
@@ -114,7 +137,43 @@ pub mod monaco_protocol {
         ctx.accounts.market.increment_account_counts()
     }
 
-    pub fn process_order_request(ctx: Context<ProcessOrderRequest>) -> Result<()> {
+    pub fn process_order_request<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessOrderRequest<'info>>,
+    ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        // atomic matching completes all matches in this transaction, so none can be outstanding
+        require!(
+            !ctx.accounts.market.atomic_matching_enabled
+                || ctx.accounts.market_matching_queue.matches.is_empty(),
+            CoreError::MatchingAtomicQueueNotEmpty
+        );
+        let market_outcome_index = ctx
+            .accounts
+            .order_request_queue
+            .order_requests
+            .peek_front()
+            .ok_or(CoreError::OrderRequestQueueIsEmpty)?
+            .market_outcome_index;
+        let matching_outcomes = instructions::market_liquidities::matching_outcomes(
+            &ctx.accounts.market,
+            &ctx.accounts.market_liquidities,
+            market_outcome_index,
+        );
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &matching_outcomes,
+            )?;
+
+        let price_ladder = ctx.accounts.price_ladder.as_deref();
+        let ladder_prices = ctx
+            .accounts
+            .market_outcome
+            .as_deref()
+            .map(|market_outcome| market_outcome.ladder_prices(price_ladder));
         let refund = instructions::order_request::process_order_request(
             &mut ctx.accounts.order,
             &mut ctx.accounts.market_position,
@@ -124,6 +183,33 @@ pub mod monaco_protocol {
             &ctx.accounts.crank_operator,
             &mut ctx.accounts.market_matching_pool,
             &mut ctx.accounts.order_request_queue,
+            ctx.accounts.market_order_book.as_deref_mut(),
+            ctx.accounts.market_limits.as_deref_mut(),
+            ladder_prices,
+        )?;
+        if ctx.accounts.market.atomic_matching_enabled {
+            instructions::matching::complete_order_matches(
+                &instructions::matching::AtomicMatchingAccounts {
+                    accounts: ctx.remaining_accounts,
+                    market_escrow: &ctx.accounts.market_escrow,
+                    payer: &ctx.accounts.crank_operator,
+                    system_program: &ctx.accounts.system_program,
+                    token_program: &ctx.accounts.token_program,
+                },
+                &mut ctx.accounts.market,
+                &mut ctx.accounts.market_matching_queue,
+                ctx.accounts.market_order_book.as_deref_mut(),
+                &ctx.accounts.order.key(),
+                &mut ctx.accounts.order,
+                &mut ctx.accounts.market_position,
+            )?;
+        }
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            Some((&ctx.accounts.crank_operator, &ctx.accounts.system_program)),
         )?;
 
         transfer::order_creation_refund(
@@ -132,19 +218,16 @@ pub mod monaco_protocol {
             &ctx.accounts.token_program,
             &ctx.accounts.market,
             refund,
+        )?;
+        transfer::crank_reward_payment(
+            &ctx.accounts.market_escrow,
+            ctx.accounts.crank_operator_token.as_ref(),
+            &ctx.accounts.token_program,
+            &mut ctx.accounts.market,
         )
     }
 
     pub fn dequeue_order_request(ctx: Context<DequeueOrderRequest>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         let refund_amount = instructions::order_request::dequeue_order_request(
             &mut ctx.accounts.order_request_queue,
             &mut ctx.accounts.market_position,
@@ -169,7 +252,17 @@ pub mod monaco_protocol {
         )
     }
 
-    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+    pub fn cancel_order<'info>(ctx: Context<'_, '_, '_, 'info, CancelOrder<'info>>) -> Result<()> {
+        let stake_unmatched = ctx.accounts.order.stake_unmatched;
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &[],
+            )?;
         let refund_amount = instructions::order::cancel_order(
             &mut ctx.accounts.market,
             &ctx.accounts.order.key(),
@@ -179,6 +272,19 @@ pub mod monaco_protocol {
             &ctx.accounts.market_matching_queue,
             &mut ctx.accounts.market_matching_pool,
         )?;
+        instructions::market_order_book::remove_from_market_order_book(
+            &ctx.accounts.market,
+            ctx.accounts.market_order_book.as_deref_mut(),
+            &ctx.accounts.order,
+            stake_unmatched.saturating_sub(ctx.accounts.order.stake_unmatched),
+        )?;
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            None,
+        )?;
 
         transfer::transfer_from_market_escrow(
             &ctx.accounts.market_escrow,
@@ -199,7 +305,156 @@ pub mod monaco_protocol {
         Ok(())
     }
 
-    pub fn cancel_order_post_market_lock(ctx: Context<CancelOrderPostMarketLock>) -> Result<()> {
+    pub fn amend_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, AmendOrder<'info>>,
+        data: OrderRequestData,
+    ) -> Result<()> {
+        instructions::order_request::validate_purchaser_access(
+            &ctx.accounts.market,
+            ctx.accounts.market_access_control.as_deref(),
+            ctx.accounts.purchaser.key,
+            ctx.accounts
+                .purchaser_gate_token
+                .as_deref()
+                .map(|token| token.deref()),
+        )?;
+
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &[],
+            )?;
+
+        let stake_unmatched = ctx.accounts.order.stake_unmatched;
+        let (refund_amount, payment_amount) = instructions::order::amend_order(
+            &mut ctx.accounts.market,
+            &ctx.accounts.order.key(),
+            &mut ctx.accounts.order,
+            &ctx.accounts.product,
+            &mut ctx.accounts.market_position,
+            &mut ctx.accounts.market_liquidities,
+            &ctx.accounts.market_matching_queue,
+            &mut ctx.accounts.market_matching_pool,
+            &ctx.accounts.market_outcome,
+            &ctx.accounts.price_ladder,
+            ctx.accounts.market_limits.as_deref(),
+            &mut ctx.accounts.order_request_queue,
+            data,
+        )?;
+        // the replacement stake is added to the book when its request is processed
+        instructions::market_order_book::remove_from_market_order_book(
+            &ctx.accounts.market,
+            ctx.accounts.market_order_book.as_deref_mut(),
+            &ctx.accounts.order,
+            stake_unmatched.saturating_sub(ctx.accounts.order.stake_unmatched),
+        )?;
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            None,
+        )?;
+
+        transfer::transfer_from_market_escrow(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.purchaser_token_account,
+            &ctx.accounts.token_program,
+            &ctx.accounts.market,
+            refund_amount,
+        )?;
+        transfer::order_creation_payment(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.purchaser,
+            &ctx.accounts.purchaser_token_account,
+            &ctx.accounts.token_program,
+            payment_amount,
+        )?;
+
+        // if never matched, close
+        if ctx.accounts.order.order_status == OrderStatus::Cancelled {
+            ctx.accounts.market.decrement_account_counts()?;
+            ctx.accounts
+                .order
+                .close(ctx.accounts.payer.to_account_info())?;
+        }
+
+        ctx.accounts
+            .reserved_order
+            .close(ctx.accounts.purchaser.to_account_info())
+    }
+
+    pub fn cancel_all_orders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelAllOrders<'info>>,
+    ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &[],
+            )?;
+        // outcome liquidities, order book page and order payer accounts are passed after the order
+        // accounts
+        let order_accounts_count = ctx.remaining_accounts.len()
+            - ctx
+                .remaining_accounts
+                .iter()
+                .rev()
+                .take_while(|account| {
+                    instructions::market_liquidities::is_market_outcome_liquidities(account)
+                        || instructions::market_order_book::is_market_order_book(account)
+                        || account.owner == &anchor_lang::system_program::ID
+                })
+                .count();
+
+        let refund_amount = instructions::order::cancel_all_orders(
+            &market_pk,
+            &mut ctx.accounts.market,
+            ctx.accounts.purchaser.key,
+            &mut ctx.accounts.market_position,
+            &mut ctx.accounts.market_liquidities,
+            &ctx.accounts.market_matching_queue,
+            ctx.accounts.market_order_book.as_mut(),
+            &ctx.remaining_accounts[..order_accounts_count],
+            &ctx.remaining_accounts[order_accounts_count..],
+        )?;
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            None,
+        )?;
+
+        transfer::transfer_from_market_escrow(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.purchaser_token_account,
+            &ctx.accounts.token_program,
+            &ctx.accounts.market,
+            refund_amount,
+        )
+    }
+
+    pub fn cancel_order_post_market_lock<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelOrderPostMarketLock<'info>>,
+    ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &[],
+            )?;
+
         let market = &mut ctx.accounts.market;
         let order = &mut ctx.accounts.order;
         let market_position = &mut ctx.accounts.market_position;
@@ -210,6 +465,7 @@ pub mod monaco_protocol {
         let purchaser_token = &ctx.accounts.purchaser_token;
         let token_program = &ctx.accounts.token_program;
 
+        let stake_unmatched = order.stake_unmatched;
         let refund_amount = instructions::order::cancel_order_post_market_lock(
             market,
             &order.key(),
@@ -219,6 +475,19 @@ pub mod monaco_protocol {
             market_matching_pool,
             order_request_queue,
         )?;
+        instructions::market_order_book::remove_from_market_order_book(
+            market,
+            ctx.accounts.market_order_book.as_deref_mut(),
+            order,
+            stake_unmatched.saturating_sub(order.stake_unmatched),
+        )?;
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            market,
+            market_liquidities,
+            market_outcome_liquidities,
+            None,
+        )?;
 
         transfer::transfer_from_market_escrow(
             escrow,
@@ -236,18 +505,43 @@ pub mod monaco_protocol {
         Ok(())
     }
 
-    pub fn cancel_preplay_order_post_event_start(
-        ctx: Context<CancelPreplayOrderPostEventStart>,
+    pub fn cancel_preplay_order_post_event_start<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelPreplayOrderPostEventStart<'info>>,
     ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &[],
+            )?;
+
+        let stake_unmatched = ctx.accounts.order.stake_unmatched;
         let refund_amount = instructions::order::cancel_preplay_order_post_event_start(
             &mut ctx.accounts.market,
             &mut ctx.accounts.market_liquidities,
             &mut ctx.accounts.market_matching_pool,
+            &ctx.accounts.order.key(),
             &mut ctx.accounts.order,
             &mut ctx.accounts.market_position,
             &ctx.accounts.matching_queue,
             &ctx.accounts.order_request_queue,
         )?;
+        instructions::market_order_book::remove_from_market_order_book(
+            &ctx.accounts.market,
+            ctx.accounts.market_order_book.as_deref_mut(),
+            &ctx.accounts.order,
+            stake_unmatched.saturating_sub(ctx.accounts.order.stake_unmatched),
+        )?;
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            None,
+        )?;
 
         transfer::transfer_from_market_escrow(
             &ctx.accounts.market_escrow,
@@ -265,6 +559,62 @@ pub mod monaco_protocol {
         Ok(())
     }
 
+    pub fn cancel_order_post_suspension<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelOrderPostSuspension<'info>>,
+    ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &[],
+            )?;
+
+        let stake_unmatched = ctx.accounts.order.stake_unmatched;
+        let refund_amount = instructions::order::cancel_order_post_suspension(
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            &mut ctx.accounts.market_matching_pool,
+            &ctx.accounts.order.key(),
+            &mut ctx.accounts.order,
+            &mut ctx.accounts.market_position,
+            &ctx.accounts.matching_queue,
+        )?;
+        instructions::market_order_book::remove_from_market_order_book(
+            &ctx.accounts.market,
+            ctx.accounts.market_order_book.as_deref_mut(),
+            &ctx.accounts.order,
+            stake_unmatched.saturating_sub(ctx.accounts.order.stake_unmatched),
+        )?;
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            None,
+        )?;
+
+        transfer::transfer_from_market_escrow(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.purchaser_token,
+            &ctx.accounts.token_program,
+            &ctx.accounts.market,
+            refund_amount,
+        )?;
+
+        // if never matched, close
+        if ctx.accounts.order.order_status == OrderStatus::Cancelled {
+            ctx.accounts.market.decrement_account_counts()?;
+            ctx.accounts
+                .order
+                .close(ctx.accounts.payer.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
     pub fn settle_order(ctx: Context<SettleOrder>) -> Result<()> {
         instructions::order::settle_order(ctx)
     }
@@ -281,16 +631,41 @@ pub mod monaco_protocol {
         instructions::order::void_order(&mut ctx.accounts.order, &mut ctx.accounts.market)
     }
 
+    pub fn void_trades(ctx: Context<VoidTrades>) -> Result<()> {
+        let (for_refund, against_refund) = instructions::trade::void_trades(
+            &ctx.accounts.market.key(),
+            &ctx.accounts.market,
+            &ctx.accounts.for_trade.key(),
+            &mut ctx.accounts.for_trade,
+            &mut ctx.accounts.for_order,
+            &mut ctx.accounts.for_market_position,
+            &ctx.accounts.against_trade.key(),
+            &mut ctx.accounts.against_trade,
+            &mut ctx.accounts.against_order,
+            &mut ctx.accounts.against_market_position,
+            current_timestamp(),
+        )?;
+
+        transfer::transfer_from_market_escrow(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.for_purchaser_token,
+            &ctx.accounts.token_program,
+            &ctx.accounts.market,
+            for_refund,
+        )?;
+        transfer::transfer_from_market_escrow(
+            &ctx.accounts.market_escrow,
+            &ctx.accounts.against_purchaser_token,
+            &ctx.accounts.token_program,
+            &ctx.accounts.market,
+            against_refund,
+        )
+    }
+
     pub fn authorise_admin_operator(
         ctx: Context<AuthoriseAdminOperator>,
         operator: Pubkey,
     ) -> Result<()> {
-        if !ctx.accounts.authorised_operators.operator_list.is_empty() {
-            verify_operator_authority(
-                ctx.accounts.admin_operator.key,
-                &ctx.accounts.authorised_operators,
-            )?;
-        }
         instructions::authorise_operator(
             ctx.accounts.admin_operator.key(),
             &mut ctx.accounts.authorised_operators,
@@ -309,10 +684,6 @@ pub mod monaco_protocol {
             !operator_type.eq_ignore_ascii_case("admin"),
             CoreError::InvalidOperatorType
         );
-        verify_operator_authority(
-            ctx.accounts.admin_operator.key,
-            &ctx.accounts.admin_operators,
-        )?;
         instructions::authorise_operator(
             ctx.accounts.admin_operator.key(),
             &mut ctx.accounts.authorised_operators,
@@ -322,15 +693,25 @@ pub mod monaco_protocol {
         Ok(())
     }
 
+    pub fn update_operator_permissions(
+        ctx: Context<AuthoriseOperator>,
+        operator_type: String,
+        operator: Pubkey,
+        permissions: u16,
+    ) -> Result<()> {
+        instructions::update_operator_permissions(
+            &mut ctx.accounts.authorised_operators,
+            operator,
+            operator_type,
+            permissions,
+        )
+    }
+
     pub fn remove_authorised_operator(
         ctx: Context<AuthoriseOperator>,
         operator_type: String,
         operator: Pubkey,
     ) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.admin_operator.key,
-            &ctx.accounts.admin_operators,
-        )?;
         // Admins cannot remove themselves
         require!(
             !(ctx.accounts.authorised_operators.key() == ctx.accounts.admin_operators.key()
@@ -341,6 +722,14 @@ pub mod monaco_protocol {
         Ok(())
     }
 
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_account(
+            &ctx.accounts.account,
+            &ctx.accounts.admin_operator,
+            &ctx.accounts.system_program,
+        )
+    }
+
     #[allow(unused_variables)]
     pub fn process_order_match_taker(
         ctx: Context<ProcessOrderMatchTaker>,
@@ -364,6 +753,7 @@ pub mod monaco_protocol {
         ctx: Context<ProcessOrderMatchMaker>,
         order_trade_seed: [u8; 16],
     ) -> Result<()> {
+        let stake_unmatched = ctx.accounts.order.stake_unmatched;
         let refund_amount = instructions::matching::on_order_match_maker(
             &mut ctx.accounts.market,
             &mut ctx.accounts.market_matching_queue,
@@ -374,6 +764,12 @@ pub mod monaco_protocol {
             &mut ctx.accounts.order_trade,
             &ctx.accounts.crank_operator.key(),
         )?;
+        instructions::market_order_book::remove_from_market_order_book(
+            &ctx.accounts.market,
+            ctx.accounts.market_order_book.as_deref_mut(),
+            &ctx.accounts.order,
+            stake_unmatched.saturating_sub(ctx.accounts.order.stake_unmatched),
+        )?;
 
         transfer::transfer_from_market_escrow(
             &ctx.accounts.market_escrow,
@@ -382,15 +778,29 @@ pub mod monaco_protocol {
             &ctx.accounts.market,
             refund_amount,
         )?;
-
-        Ok(())
+        transfer::crank_reward_payment(
+            &ctx.accounts.market_escrow,
+            ctx.accounts.crank_operator_token.as_ref(),
+            &ctx.accounts.token_program,
+            &mut ctx.accounts.market,
+        )
     }
 
-    pub fn update_market_liquidities_with_cross_liquidity(
-        ctx: Context<UpdateMarketLiquidities>,
+    pub fn update_market_liquidities_with_cross_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateMarketLiquidities<'info>>,
         source_for_outcome: bool,
         source_liquidities: Vec<LiquiditySource>,
     ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &(0..ctx.accounts.market.market_outcomes_count).collect::<Vec<u16>>(),
+            )?;
+
         instructions::market_liquidities::update_market_liquidities_with_cross_liquidity(
             &ctx.accounts.market,
             &mut ctx.accounts.market_liquidities,
@@ -398,7 +808,13 @@ pub mod monaco_protocol {
             source_liquidities,
         )?;
 
-        Ok(())
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            Some((&ctx.accounts.crank_operator, &ctx.accounts.system_program)),
+        )
     }
 
     #[allow(unused_variables)]
@@ -407,9 +823,13 @@ pub mod monaco_protocol {
         trade_for_seed: [u8; 16],
         trade_against_seed: [u8; 16],
     ) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.crank_operator.key,
-            &ctx.accounts.authorised_operators,
+        // liquidities are only read here, so outcome liquidities accounts are not stored back
+        instructions::market_liquidities::load_market_outcome_liquidities(
+            &ctx.accounts.market.key(),
+            &ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            ctx.remaining_accounts,
+            &[],
         )?;
 
         let for_stake_unmatched = ctx.accounts.order_for.stake_unmatched;
@@ -448,12 +868,14 @@ pub mod monaco_protocol {
         name: String,
         requires_discriminator: bool,
         requires_value: bool,
+        template: MarketTemplate,
     ) -> Result<()> {
         instructions::market_type::create_market_type(
             &mut ctx.accounts.market_type,
             name,
             requires_discriminator,
             requires_value,
+            template,
         )
     }
 
@@ -471,7 +893,7 @@ pub mod monaco_protocol {
 
     pub fn add_prices_to_price_ladder(
         ctx: Context<UpdatePriceLadder>,
-        prices_to_add: Vec<f64>,
+        prices_to_add: Vec<Price>,
     ) -> Result<()> {
         instructions::price_ladder::add_prices_to_price_ladder(
             &mut ctx.accounts.price_ladder,
@@ -481,7 +903,7 @@ pub mod monaco_protocol {
 
     pub fn remove_prices_from_price_ladder(
         ctx: Context<UpdatePriceLadder>,
-        prices_to_remove: Vec<f64>,
+        prices_to_remove: Vec<Price>,
     ) -> Result<()> {
         instructions::price_ladder::remove_prices_from_price_ladder(
             &mut ctx.accounts.price_ladder,
@@ -518,10 +940,6 @@ pub mod monaco_protocol {
         event_start_order_behaviour: MarketOrderBehaviour,
         market_lock_order_behaviour: MarketOrderBehaviour,
     ) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
         let market_type = ctx.accounts.market_type.key();
         instructions::market::create(
             ctx,
@@ -545,14 +963,6 @@ pub mod monaco_protocol {
         title: String,
     ) -> Result<()> {
         msg!("Initializing market outcome");
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
 
         instructions::market::initialize_outcome(ctx, title)?;
         msg!("Initialized market outcome");
@@ -562,93 +972,239 @@ pub mod monaco_protocol {
     pub fn add_prices_to_market_outcome(
         ctx: Context<UpdateMarketOutcome>,
         _outcome_index: u16,
-        new_prices: Vec<f64>,
+        new_prices: Vec<Price>,
     ) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         instructions::market::add_prices_to_market_outcome(&mut ctx.accounts.outcome, new_prices)?;
         Ok(())
     }
 
-    pub fn update_market_title(ctx: Context<UpdateMarket>, title: String) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
+    pub fn create_market_order_book_page(
+        ctx: Context<CreateMarketOrderBookPage>,
+        _outcome_index: u16,
+        page_index: u16,
+    ) -> Result<()> {
+        instructions::market_order_book::create_market_order_book_page(
+            ctx.accounts.market.key(),
+            &mut ctx.accounts.market,
+            &ctx.accounts.market_outcome,
+            ctx.accounts.price_ladder.as_deref(),
+            &mut ctx.accounts.market_order_book,
+            page_index,
+        )
+    }
+
+    pub fn create_market_limits(
+        ctx: Context<CreateMarketLimits>,
+        max_order_stake: u64,
+        max_position_exposure: u64,
+        max_outcome_stake_matched: u64,
+    ) -> Result<()> {
+        instructions::market::create_market_limits(
+            ctx.accounts.market.key(),
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_limits,
+            max_order_stake,
+            max_position_exposure,
+            max_outcome_stake_matched,
+        )
+    }
+
+    pub fn update_market_limits(
+        ctx: Context<UpdateMarketLimits>,
+        max_order_stake: u64,
+        max_position_exposure: u64,
+        max_outcome_stake_matched: u64,
+    ) -> Result<()> {
+        instructions::market::update_market_limits(
+            &ctx.accounts.market,
+            &mut ctx.accounts.market_limits,
+            max_order_stake,
+            max_position_exposure,
+            max_outcome_stake_matched,
+        )
+    }
 
+    pub fn create_market_access_control(
+        ctx: Context<CreateMarketAccessControl>,
+        purchaser_list_mode: PurchaserListMode,
+        token_gate_mint: Option<Pubkey>,
+        purchasers_capacity: u16,
+    ) -> Result<()> {
+        instructions::market::create_market_access_control(
+            ctx.accounts.market.key(),
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_access_control,
+            purchaser_list_mode,
+            token_gate_mint,
+            purchasers_capacity,
+        )
+    }
+
+    pub fn update_market_access_control(
+        ctx: Context<UpdateMarketAccessControl>,
+        purchaser_list_mode: PurchaserListMode,
+        token_gate_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::market::update_market_access_control(
+            &mut ctx.accounts.market_access_control,
+            purchaser_list_mode,
+            token_gate_mint,
+        )
+    }
+
+    pub fn add_market_access_purchasers(
+        ctx: Context<UpdateMarketAccessControl>,
+        purchasers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::market::add_market_access_purchasers(
+            &mut ctx.accounts.market_access_control,
+            purchasers,
+        )
+    }
+
+    pub fn remove_market_access_purchasers(
+        ctx: Context<UpdateMarketAccessControl>,
+        purchasers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::market::remove_market_access_purchasers(
+            &mut ctx.accounts.market_access_control,
+            purchasers,
+        )
+    }
+
+    pub fn delegate_market_operator(
+        ctx: Context<UpdateMarketOperatorDelegation>,
+        operator: Pubkey,
+        permissions: u16,
+    ) -> Result<()> {
+        let authority_permissions = ctx
+            .accounts
+            .authorised_operators
+            .permissions(ctx.accounts.market_operator.key);
+        instructions::market::delegate_market_operator(
+            &mut ctx.accounts.market,
+            authority_permissions,
+            operator,
+            permissions,
+        )
+    }
+
+    pub fn revoke_market_operator_delegation(
+        ctx: Context<UpdateMarketOperatorDelegation>,
+        operator: Pubkey,
+    ) -> Result<()> {
+        instructions::market::revoke_market_operator_delegation(&mut ctx.accounts.market, operator)
+    }
+
+    pub fn update_market_title(ctx: Context<UpdateMarket>, title: String) -> Result<()> {
         instructions::market::update_title(ctx, title)
     }
 
-    pub fn update_market_locktime(ctx: Context<UpdateMarket>, lock_time: i64) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
+    pub fn update_market_trade_void_window(
+        ctx: Context<UpdateMarket>,
+        trade_void_window: i64,
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        instructions::market::update_trade_void_window(market, trade_void_window)
+    }
 
+    pub fn update_market_price_improvement(
+        ctx: Context<UpdateMarket>,
+        price_improvement_enabled: bool,
+    ) -> Result<()> {
         let market = &mut ctx.accounts.market;
-        instructions::market::update_locktime(market, lock_time)
+        instructions::market::update_price_improvement(market, price_improvement_enabled)
     }
 
-    pub fn update_market_locktime_to_now(ctx: Context<UpdateMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
+    pub fn update_market_crank_fee(
+        ctx: Context<UpdateMarket>,
+        crank_fee: u64,
+        crank_reward: u64,
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        instructions::market::update_crank_fee(market, crank_fee, crank_reward)
+    }
+
+    pub fn update_market_atomic_matching(
+        ctx: Context<UpdateMarket>,
+        atomic_matching_enabled: bool,
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        instructions::market::update_atomic_matching(market, atomic_matching_enabled)
+    }
+
+    pub fn update_market_suspension_order_behaviour(
+        ctx: Context<UpdateMarket>,
+        suspension_order_behaviour: MarketOrderBehaviour,
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        instructions::market::update_suspension_order_behaviour(market, suspension_order_behaviour)
+    }
+
+    pub fn withdraw_market_outcome<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawMarketOutcome<'info>>,
+        outcome_index: u16,
+        reduction_factor: u16,
+    ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        let market_outcome_liquidities =
+            instructions::market_liquidities::load_market_outcome_liquidities(
+                &market_pk,
+                &ctx.accounts.market,
+                &mut ctx.accounts.market_liquidities,
+                ctx.remaining_accounts,
+                &[outcome_index],
+            )?;
+        instructions::market::withdraw_market_outcome(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            &ctx.accounts.market_matching_queue,
+            &ctx.accounts.order_request_queue,
+            outcome_index,
+            reduction_factor,
+            current_timestamp(),
         )?;
+        instructions::market_liquidities::store_market_outcome_liquidities(
+            &market_pk,
+            &mut ctx.accounts.market,
+            &mut ctx.accounts.market_liquidities,
+            market_outcome_liquidities,
+            None,
+        )
+    }
+
+    pub fn update_market_locktime(ctx: Context<UpdateMarketTimes>, lock_time: i64) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        instructions::market::update_locktime(market, lock_time)
+    }
 
+    pub fn update_market_locktime_to_now(ctx: Context<UpdateMarketTimes>) -> Result<()> {
         let market = &mut ctx.accounts.market;
         instructions::market::update_locktime_to_now(market)
     }
 
     pub fn update_market_event_start_time(
-        ctx: Context<UpdateMarket>,
+        ctx: Context<UpdateMarketTimes>,
         event_start_time: i64,
     ) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         let market = &mut ctx.accounts.market;
         instructions::market::update_market_event_start_time(market, event_start_time)
     }
 
-    pub fn update_market_event_start_time_to_now(ctx: Context<UpdateMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
+    pub fn update_market_event_start_time_to_now(ctx: Context<UpdateMarketTimes>) -> Result<()> {
         let market = &mut ctx.accounts.market;
         instructions::market::update_market_event_start_time_to_now(market)
     }
 
+    pub fn update_market_winning_places(
+        ctx: Context<UpdateMarket>,
+        winning_places: u16,
+    ) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        instructions::market::update_market_winning_places(market, winning_places)
+    }
+
     pub fn move_market_to_inplay(ctx: Context<MoveMarketToInplay>) -> Result<()> {
         instructions::market::move_market_to_inplay(
             &mut ctx.accounts.market,
@@ -657,15 +1213,6 @@ pub mod monaco_protocol {
     }
 
     pub fn open_market(ctx: Context<OpenMarket>, enable_cross_matching: bool) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         instructions::market::open(
             &ctx.accounts.market.key(),
             &mut ctx.accounts.market,
@@ -677,45 +1224,49 @@ pub mod monaco_protocol {
         )
     }
 
-    pub fn settle_market(ctx: Context<SettleMarket>, winning_outcome_index: u16) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
+    pub fn settle_market(
+        ctx: Context<SettleMarket>,
+        winning_outcomes: Vec<MarketWinningOutcome>,
+    ) -> Result<()> {
         let settle_time = current_timestamp();
         instructions::market::settle(
+            &ctx.accounts.market.key(),
+            &mut ctx.accounts.market,
+            &ctx.accounts.market_matching_queue,
+            &ctx.accounts.order_request_queue,
+            winning_outcomes,
+            settle_time,
+        )
+    }
+
+    pub fn settle_market_with_results(
+        ctx: Context<SettleMarketWithResults>,
+        outcome_results: Vec<OutcomeResult>,
+    ) -> Result<()> {
+        let settle_time = current_timestamp();
+        instructions::market::settle_with_results(
+            &ctx.accounts.market.key(),
             &mut ctx.accounts.market,
+            &ctx.accounts.market_type,
             &ctx.accounts.market_matching_queue,
             &ctx.accounts.order_request_queue,
-            winning_outcome_index,
+            outcome_results,
             settle_time,
         )
     }
 
     pub fn complete_market_settlement(ctx: Context<CompleteMarketSettlement>) -> Result<()> {
         instructions::market::complete_settlement(
+            &ctx.accounts.market.key(),
             &mut ctx.accounts.market,
             &ctx.accounts.commission_payments_queue,
         )
     }
 
     pub fn void_market(ctx: Context<VoidMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         let void_time = current_timestamp();
         instructions::market::void(
+            &ctx.accounts.market.key(),
             &mut ctx.accounts.market,
             void_time,
             &ctx.accounts
@@ -730,17 +1281,9 @@ pub mod monaco_protocol {
     }
 
     pub fn force_void_market(ctx: Context<ForceVoidMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         let void_time = current_timestamp();
         instructions::market::force_void(
+            &ctx.accounts.market.key(),
             &mut ctx.accounts.market,
             void_time,
             &ctx.accounts
@@ -762,16 +1305,95 @@ pub mod monaco_protocol {
         Ok(())
     }
 
-    pub fn force_unsettled_count(ctx: Context<ForceUnsettledCount>, new_count: u32) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
+    pub fn update_market_settlement_approvals(
+        ctx: Context<UpdateMarketSettlementApprovals>,
+        approvals_required: u8,
+        challenge_period: i64,
+    ) -> Result<()> {
+        instructions::market::update_settlement_approval_settings(
+            &mut ctx.accounts.market,
             &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
+            approvals_required,
+            challenge_period,
+        )
+    }
+
+    pub fn propose_market_settlement(
+        ctx: Context<ProposeMarketSettlement>,
+        action: SettlementProposalAction,
+        winning_outcomes: Vec<MarketWinningOutcome>,
+    ) -> Result<()> {
+        instructions::market::propose_settlement(
+            &mut ctx.accounts.market_settlement_proposal,
+            ctx.accounts.market.key(),
+            &ctx.accounts.market,
+            ctx.accounts.market_operator.key(),
+            action,
+            winning_outcomes,
+            current_timestamp(),
+        )
+    }
+
+    pub fn approve_market_settlement_proposal(
+        ctx: Context<ApproveMarketSettlementProposal>,
+    ) -> Result<()> {
+        ctx.accounts
+            .market_settlement_proposal
+            .approve(ctx.accounts.market_operator.key(), current_timestamp())
+    }
 
+    pub fn execute_market_settlement_proposal(
+        ctx: Context<ExecuteMarketSettlementProposal>,
+    ) -> Result<()> {
+        let now = current_timestamp();
+        let proposal = &ctx.accounts.market_settlement_proposal;
+        proposal.validate_executable(now)?;
+
+        match proposal.action {
+            SettlementProposalAction::Settle => {
+                let (Some(matching_queue), Some(order_request_queue)) = (
+                    &ctx.accounts.market_matching_queue,
+                    &ctx.accounts.order_request_queue,
+                ) else {
+                    return err!(CoreError::SettlementProposalQueuesNotProvided);
+                };
+                instructions::market::settle(
+                    &ctx.accounts.market.key(),
+                    &mut ctx.accounts.market,
+                    matching_queue,
+                    order_request_queue,
+                    proposal.winning_outcomes.clone(),
+                    now,
+                )
+            }
+            SettlementProposalAction::ForceVoid => {
+                instructions::market::force_void(
+                    &ctx.accounts.market.key(),
+                    &mut ctx.accounts.market,
+                    now,
+                    &ctx.accounts
+                        .order_request_queue
+                        .clone()
+                        .map(|queue: Account<MarketOrderRequestQueue>| queue.into_inner()),
+                )?;
+
+                // clear matching queue
+                if let Some(queue) = ctx.accounts.market_matching_queue.as_mut() {
+                    queue.matches.clear();
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn cancel_market_settlement_proposal(
+        _ctx: Context<CancelMarketSettlementProposal>,
+    ) -> Result<()> {
+        // proposal is closed to the proposer by the account constraints
+        Ok(())
+    }
+
+    pub fn force_unsettled_count(ctx: Context<ForceUnsettledCount>, new_count: u32) -> Result<()> {
         instructions::market::force_unsettled_count(
             &mut ctx.accounts.market,
             ctx.accounts.market_escrow.amount,
@@ -780,72 +1402,60 @@ pub mod monaco_protocol {
     }
 
     pub fn complete_market_void(ctx: Context<CompleteMarketVoid>) -> Result<()> {
-        instructions::market::complete_void(&mut ctx.accounts.market)
+        instructions::market::complete_void(&ctx.accounts.market.key(), &mut ctx.accounts.market)
     }
 
     pub fn publish_market(ctx: Context<UpdateMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         instructions::market::publish(ctx)
     }
 
     pub fn unpublish_market(ctx: Context<UpdateMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         instructions::market::unpublish(ctx)
     }
 
-    pub fn suspend_market(ctx: Context<UpdateMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
-        instructions::market::suspend(ctx)
+    pub fn suspend_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, SuspendMarket<'info>>,
+    ) -> Result<()> {
+        let market_pk = ctx.accounts.market.key();
+        match ctx.accounts.market_liquidities.as_mut() {
+            Some(market_liquidities) => {
+                // every outcome is loaded so that no liquidity is left behind
+                let market_outcome_liquidities =
+                    instructions::market_liquidities::load_market_outcome_liquidities(
+                        &market_pk,
+                        &ctx.accounts.market,
+                        market_liquidities,
+                        ctx.remaining_accounts,
+                        &(0..ctx.accounts.market.market_outcomes_count).collect::<Vec<u16>>(),
+                    )?;
+                instructions::market::suspend(
+                    &mut ctx.accounts.market,
+                    Some(market_liquidities),
+                    ctx.accounts.market_matching_queue.as_deref(),
+                )?;
+                instructions::market_liquidities::store_market_outcome_liquidities(
+                    &market_pk,
+                    &mut ctx.accounts.market,
+                    market_liquidities,
+                    market_outcome_liquidities,
+                    None,
+                )
+            }
+            None => instructions::market::suspend(
+                &mut ctx.accounts.market,
+                None,
+                ctx.accounts.market_matching_queue.as_deref(),
+            ),
+        }
     }
 
-    pub fn unsuspend_market(ctx: Context<UpdateMarket>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
-        instructions::market::unsuspend(ctx)
+    pub fn unsuspend_market(ctx: Context<SuspendMarket>) -> Result<()> {
+        instructions::market::unsuspend(&mut ctx.accounts.market)
     }
 
     pub fn set_market_ready_to_close(ctx: Context<SetMarketReadyToClose>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         instructions::market::ready_to_close(
+            &ctx.accounts.market.key(),
             &mut ctx.accounts.market,
             &ctx.accounts.market_escrow,
             &ctx.accounts.market_funding,
@@ -853,17 +1463,8 @@ pub mod monaco_protocol {
     }
 
     pub fn transfer_market_token_surplus(ctx: Context<TransferMarketTokenSurplus>) -> Result<()> {
-        verify_operator_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.authorised_operators,
-        )?;
-        verify_market_authority(
-            ctx.accounts.market_operator.key,
-            &ctx.accounts.market.authority,
-        )?;
-
         instructions::market::transfer_market_token_surplus(
-            &ctx.accounts.market,
+            &mut ctx.accounts.market,
             &ctx.accounts.market_escrow,
             &ctx.accounts.market_funding,
             &ctx.accounts.market_authority_token,
@@ -905,6 +1506,12 @@ pub mod monaco_protocol {
         instructions::close::close_market_child_account(&mut ctx.accounts.market)
     }
 
+    pub fn close_market_outcome_liquidities(
+        ctx: Context<CloseMarketOutcomeLiquidities>,
+    ) -> Result<()> {
+        instructions::close::close_market_child_account(&mut ctx.accounts.market)
+    }
+
     pub fn close_market_outcome(ctx: Context<CloseMarketOutcome>) -> Result<()> {
         instructions::close::close_market_child_account(&mut ctx.accounts.market)
     }
@@ -0,0 +1,220 @@
+use crate::error::CoreError;
+use crate::state::type_size::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::state::Account as TokenAccountState;
+
+/// Restricts which purchasers can request orders on a market, by a list of purchasers allowed or
+/// denied and/or by requiring a token of the gate mint to be held
+#[account]
+pub struct MarketAccessControl {
+    pub market: Pubkey,
+    pub purchaser_list_mode: PurchaserListMode,
+    pub token_gate_mint: Option<Pubkey>,
+    pub purchasers_capacity: u16,
+    pub purchasers: Vec<Pubkey>,
+}
+
+impl MarketAccessControl {
+    pub const PURCHASERS_MAX_CAPACITY: u16 = 300;
+
+    pub fn size_for(purchasers_capacity: u16) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUB_KEY_SIZE // market
+            + ENUM_SIZE // purchaser_list_mode
+            + option_size(PUB_KEY_SIZE) // token_gate_mint
+            + U16_SIZE // purchasers_capacity
+            + vec_size(PUB_KEY_SIZE, usize::from(purchasers_capacity)) // purchasers
+    }
+
+    pub fn add_purchasers(&mut self, purchasers: &[Pubkey]) -> Result<()> {
+        for purchaser in purchasers {
+            if !self.purchasers.contains(purchaser) {
+                require!(
+                    self.purchasers.len() < usize::from(self.purchasers_capacity),
+                    CoreError::MarketAccessControlPurchasersFull
+                );
+                self.purchasers.push(*purchaser);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove_purchasers(&mut self, purchasers: &[Pubkey]) {
+        self.purchasers
+            .retain(|purchaser| !purchasers.contains(purchaser));
+    }
+
+    pub fn validate_purchaser(
+        &self,
+        purchaser: &Pubkey,
+        purchaser_gate_token: Option<&TokenAccountState>,
+    ) -> Result<()> {
+        match self.purchaser_list_mode {
+            PurchaserListMode::None => {}
+            PurchaserListMode::Allow => require!(
+                self.purchasers.contains(purchaser),
+                CoreError::CreationPurchaserNotAllowed
+            ),
+            PurchaserListMode::Deny => require!(
+                !self.purchasers.contains(purchaser),
+                CoreError::CreationPurchaserDenied
+            ),
+        }
+
+        if let Some(token_gate_mint) = self.token_gate_mint {
+            let token_held = matches!(
+                purchaser_gate_token,
+                Some(token_account) if token_account.mint == token_gate_mint
+                    && token_account.owner == *purchaser
+                    && token_account.amount > 0
+            );
+            require!(token_held, CoreError::CreationPurchaserTokenGateNotHeld);
+        }
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaserListMode {
+    None,
+    Allow,
+    Deny,
+}
+
+#[cfg(test)]
+pub fn mock_market_access_control(
+    market: Pubkey,
+    purchaser_list_mode: PurchaserListMode,
+    purchasers: Vec<Pubkey>,
+) -> MarketAccessControl {
+    MarketAccessControl {
+        market,
+        purchaser_list_mode,
+        token_gate_mint: None,
+        purchasers_capacity: 10,
+        purchasers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allow_list() {
+        let purchaser = Pubkey::new_unique();
+        let access_control =
+            mock_market_access_control(Pubkey::new_unique(), PurchaserListMode::Allow, vec![]);
+
+        assert_eq!(
+            Err(error!(CoreError::CreationPurchaserNotAllowed)),
+            access_control.validate_purchaser(&purchaser, None)
+        );
+
+        let access_control = mock_market_access_control(
+            Pubkey::new_unique(),
+            PurchaserListMode::Allow,
+            vec![purchaser],
+        );
+        assert!(access_control.validate_purchaser(&purchaser, None).is_ok());
+    }
+
+    #[test]
+    fn test_deny_list() {
+        let purchaser = Pubkey::new_unique();
+        let access_control = mock_market_access_control(
+            Pubkey::new_unique(),
+            PurchaserListMode::Deny,
+            vec![purchaser],
+        );
+
+        assert_eq!(
+            Err(error!(CoreError::CreationPurchaserDenied)),
+            access_control.validate_purchaser(&purchaser, None)
+        );
+        assert!(access_control
+            .validate_purchaser(&Pubkey::new_unique(), None)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_list_disabled() {
+        let purchaser = Pubkey::new_unique();
+        let access_control = mock_market_access_control(
+            Pubkey::new_unique(),
+            PurchaserListMode::None,
+            vec![purchaser],
+        );
+
+        assert!(access_control.validate_purchaser(&purchaser, None).is_ok());
+        assert!(access_control
+            .validate_purchaser(&Pubkey::new_unique(), None)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_token_gate() {
+        let purchaser = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mut access_control =
+            mock_market_access_control(Pubkey::new_unique(), PurchaserListMode::None, vec![]);
+        access_control.token_gate_mint = Some(mint);
+
+        let token_account = TokenAccountState {
+            mint,
+            owner: purchaser,
+            amount: 1,
+            ..Default::default()
+        };
+        assert!(access_control
+            .validate_purchaser(&purchaser, Some(&token_account))
+            .is_ok());
+
+        assert_eq!(
+            Err(error!(CoreError::CreationPurchaserTokenGateNotHeld)),
+            access_control.validate_purchaser(&purchaser, None)
+        );
+        let empty_token_account = TokenAccountState {
+            amount: 0,
+            ..token_account
+        };
+        assert_eq!(
+            Err(error!(CoreError::CreationPurchaserTokenGateNotHeld)),
+            access_control.validate_purchaser(&purchaser, Some(&empty_token_account))
+        );
+        let other_mint_token_account = TokenAccountState {
+            mint: Pubkey::new_unique(),
+            ..token_account
+        };
+        assert_eq!(
+            Err(error!(CoreError::CreationPurchaserTokenGateNotHeld)),
+            access_control.validate_purchaser(&purchaser, Some(&other_mint_token_account))
+        );
+        assert_eq!(
+            Err(error!(CoreError::CreationPurchaserTokenGateNotHeld)),
+            access_control.validate_purchaser(&Pubkey::new_unique(), Some(&token_account))
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_purchasers() {
+        let purchaser_1 = Pubkey::new_unique();
+        let purchaser_2 = Pubkey::new_unique();
+        let mut access_control =
+            mock_market_access_control(Pubkey::new_unique(), PurchaserListMode::Allow, vec![]);
+        access_control.purchasers_capacity = 2;
+
+        let result = access_control.add_purchasers(&[purchaser_1, purchaser_1, purchaser_2]);
+        assert!(result.is_ok());
+        assert_eq!(vec![purchaser_1, purchaser_2], access_control.purchasers);
+
+        let result = access_control.add_purchasers(&[Pubkey::new_unique()]);
+        assert_eq!(
+            Err(error!(CoreError::MarketAccessControlPurchasersFull)),
+            result
+        );
+
+        access_control.remove_purchasers(&[purchaser_1, Pubkey::new_unique()]);
+        assert_eq!(vec![purchaser_2], access_control.purchasers);
+    }
+}
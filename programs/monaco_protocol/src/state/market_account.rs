@@ -1,5 +1,6 @@
 use crate::error::CoreError;
 use crate::instructions::current_timestamp;
+use crate::state::operator_account::OperatorPermission;
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 use solana_program::clock::UnixTimestamp;
@@ -25,7 +26,8 @@ pub struct Market {
     pub suspended: bool,
 
     pub market_outcomes_count: u16,
-    pub market_winning_outcome_index: Option<u16>,
+    pub market_winning_places: u16,
+    pub market_winning_outcomes: Vec<MarketWinningOutcome>,
     pub market_lock_timestamp: i64,
     pub market_settle_timestamp: Option<i64>,
 
@@ -42,11 +44,35 @@ pub struct Market {
     pub escrow_account_bump: u8,
     pub funding_account_bump: u8,
     pub event_start_timestamp: i64,
+    // new fields must be appended to the end of the layout, with schema_version bumped and a
+    // migration from the previous layout added to state::migration
+    pub schema_version: u8,
+    pub market_outcome_results: Vec<OutcomeResult>,
+    pub trade_void_window: i64, // seconds after matching that trades can be voided, 0 disables
+    pub withdrawn_outcomes: Vec<WithdrawnOutcome>,
+    pub order_book_enabled: bool, // order book pages must be kept current by order instructions
+    pub price_improvement_enabled: bool, // taker orders are matched best price first across the book
+    pub limits_enabled: bool, // market limits account must be provided to order request instructions
+    pub access_control_enabled: bool, // purchasers are checked against the market access control account
+    pub operator_delegations: Vec<MarketOperatorDelegation>,
+    pub settlement_approvals_required: u8, // settlement is by approved proposal only if non zero
+    pub settlement_challenge_period: i64,
+    pub atomic_matching_enabled: bool, // matches are completed when the taker order is processed
+    pub crank_fee: u64,                // charged on each order request, funding crank rewards
+    pub crank_reward: u64, // paid to the crank for each order request and maker match processed
+    pub crank_fee_balance: u64, // crank fees held in escrow and not yet paid out as rewards
+    pub suspension_order_behaviour: MarketOrderBehaviour,
+    pub suspension_timestamp: i64, // last suspension which cancelled unmatched orders, 0 if none
 }
 
 impl Market {
     pub const TYPE_FIELD_MAX_LENGTH: usize = 16;
     pub const TITLE_MAX_LENGTH: usize = 100;
+    pub const WINNING_OUTCOMES_MAX_LENGTH: usize = 20;
+    pub const OUTCOME_RESULTS_MAX_LENGTH: usize = 2;
+    pub const WITHDRAWN_OUTCOMES_MAX_LENGTH: usize = 10;
+    pub const OPERATOR_DELEGATIONS_MAX_LENGTH: usize = 4;
+    pub const SCHEMA_VERSION: u8 = 13;
 
     pub const SIZE: usize = DISCRIMINATOR_SIZE
         + (PUB_KEY_SIZE * 3) // authority, event and mint
@@ -60,7 +86,8 @@ impl Market {
         + U8_SIZE // version
         + BOOL_SIZE * 2 // published + suspended
         + U16_SIZE // market_outcomes_count
-        + option_size(U16_SIZE) // market_winning_outcome_index
+        + U16_SIZE // market_winning_places
+        + vec_size(MarketWinningOutcome::SIZE, Market::WINNING_OUTCOMES_MAX_LENGTH) // market_winning_outcomes
         + I64_SIZE // market_lock_timestamp
         + option_size(I64_SIZE) // market_settle_timestamp
         + ENUM_SIZE * 2 // event_start and market_lock _order_behaviour
@@ -68,7 +95,24 @@ impl Market {
         + vec_size(CHAR_SIZE, Market::TITLE_MAX_LENGTH) // title
         + U8_SIZE * 2// bumps
         + I64_SIZE // event_start_timestamp
-        + U32_SIZE * 2; // unsettled_accounts + unclosed_accounts
+        + U32_SIZE * 2 // unsettled_accounts + unclosed_accounts
+        + U8_SIZE // schema_version
+        + vec_size(ENUM_SIZE, Market::OUTCOME_RESULTS_MAX_LENGTH) // market_outcome_results
+        + I64_SIZE // trade_void_window
+        + vec_size(WithdrawnOutcome::SIZE, Market::WITHDRAWN_OUTCOMES_MAX_LENGTH) // withdrawn_outcomes
+        + BOOL_SIZE // order_book_enabled
+        + BOOL_SIZE // price_improvement_enabled
+        + BOOL_SIZE // limits_enabled
+        + BOOL_SIZE // access_control_enabled
+        + vec_size(MarketOperatorDelegation::SIZE, Market::OPERATOR_DELEGATIONS_MAX_LENGTH) // operator_delegations
+        + U8_SIZE // settlement_approvals_required
+        + I64_SIZE // settlement_challenge_period
+        + BOOL_SIZE // atomic_matching_enabled
+        + U64_SIZE // crank_fee
+        + U64_SIZE // crank_reward
+        + U64_SIZE // crank_fee_balance
+        + ENUM_SIZE // suspension_order_behaviour
+        + I64_SIZE; // suspension_timestamp
 
     pub fn increment_market_outcomes_count(&mut self) -> Result<u16> {
         self.market_outcomes_count = self
@@ -122,6 +166,28 @@ impl Market {
         Ok(())
     }
 
+    // adds the crank fee for an order request to the balance held for rewards, returning the fee
+    pub fn collect_crank_fee(&mut self) -> Result<u64> {
+        self.crank_fee_balance = self
+            .crank_fee_balance
+            .checked_add(self.crank_fee)
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(self.crank_fee)
+    }
+
+    // takes the reward for a crank step from the balance, paying out no more than is held
+    pub fn take_crank_reward(&mut self) -> u64 {
+        let reward = self.crank_reward.min(self.crank_fee_balance);
+        self.crank_fee_balance -= reward;
+        reward
+    }
+
+    // unmatched stake of orders created up to a suspension cancelling unmatched orders no longer
+    // has liquidity and can only be cancelled
+    pub fn is_cancelled_by_suspension(&self, order_creation_timestamp: UnixTimestamp) -> bool {
+        self.suspension_timestamp > 0 && order_creation_timestamp <= self.suspension_timestamp
+    }
+
     pub fn is_inplay(&self) -> bool {
         Market::market_is_inplay(self, current_timestamp())
     }
@@ -133,6 +199,134 @@ impl Market {
     pub fn move_to_inplay(&mut self) {
         self.inplay = true;
     }
+
+    pub fn winning_outcome(&self, outcome_index: u16) -> Option<&MarketWinningOutcome> {
+        self.market_winning_outcomes
+            .iter()
+            .find(|winning_outcome| winning_outcome.outcome_index == outcome_index)
+    }
+
+    pub fn is_outcome_withdrawn(&self, outcome_index: u16) -> bool {
+        self.withdrawn_outcomes
+            .iter()
+            .any(|withdrawn_outcome| withdrawn_outcome.outcome_index == outcome_index)
+    }
+
+    pub fn total_reduction_factor(&self) -> Option<u16> {
+        self.withdrawn_outcomes
+            .iter()
+            .try_fold(0_u16, |total, withdrawn_outcome| {
+                total.checked_add(withdrawn_outcome.reduction_factor)
+            })
+    }
+
+    pub fn outcome_result(&self, outcome_index: u16) -> Option<OutcomeResult> {
+        self.market_outcome_results
+            .get(usize::from(outcome_index))
+            .copied()
+    }
+
+    // the market authority holds every permission on its market, other operators only those
+    // delegated to them
+    pub fn is_operator_permitted(&self, operator: &Pubkey, permission: u16) -> bool {
+        self.authority == *operator
+            || self.operator_delegations.iter().any(|delegation| {
+                delegation.operator == *operator
+                    && OperatorPermission::includes(delegation.permissions, permission)
+            })
+    }
+}
+
+/// Outcome paying out on settlement; the dead-heat factor is the fraction of stake settled as a
+/// win, e.g., 1/2 for two runners tied for a single place, with the remainder settled as a loss
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MarketWinningOutcome {
+    pub outcome_index: u16,
+    pub dead_heat_numerator: u16,
+    pub dead_heat_denominator: u16,
+}
+
+impl MarketWinningOutcome {
+    pub const SIZE: usize = U16_SIZE * 3;
+
+    pub fn new(outcome_index: u16, dead_heat_numerator: u16, dead_heat_denominator: u16) -> Self {
+        MarketWinningOutcome {
+            outcome_index,
+            dead_heat_numerator,
+            dead_heat_denominator,
+        }
+    }
+}
+
+/// Outcome withdrawn after matching started; winnings on trades matched on the remaining outcomes
+/// before the withdrawal are reduced by the reduction factor, in basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawnOutcome {
+    pub outcome_index: u16,
+    pub reduction_factor: u16,
+    pub withdrawal_timestamp: i64,
+}
+
+impl WithdrawnOutcome {
+    pub const SIZE: usize = U16_SIZE * 2 + I64_SIZE;
+    pub const REDUCTION_FACTOR_SCALE: u16 = 10_000;
+    pub const MAX_TOTAL_REDUCTION_FACTOR: u16 = 7_500;
+}
+
+/// Operator other than the market authority permitted to carry out operations on the market,
+/// limited to the permissions delegated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MarketOperatorDelegation {
+    pub operator: Pubkey,
+    pub permissions: u16,
+}
+
+impl MarketOperatorDelegation {
+    pub const SIZE: usize = PUB_KEY_SIZE + U16_SIZE;
+}
+
+/// Result of an outcome of a handicap or total market; half results settle half of the stake as a
+/// win or loss and refund (push) the other half
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutcomeResult {
+    Win,
+    HalfWin,
+    Push,
+    HalfLoss,
+    Loss,
+}
+
+impl OutcomeResult {
+    pub const STAKE_PARTS: u16 = 2;
+
+    // parts of the stake backing the outcome settled as a win, out of STAKE_PARTS
+    pub fn win_parts(&self) -> u16 {
+        match self {
+            OutcomeResult::Win => 2,
+            OutcomeResult::HalfWin => 1,
+            _ => 0,
+        }
+    }
+
+    // parts of the stake refunded, out of STAKE_PARTS
+    pub fn push_parts(&self) -> u16 {
+        match self {
+            OutcomeResult::Push => 2,
+            OutcomeResult::HalfWin | OutcomeResult::HalfLoss => 1,
+            _ => 0,
+        }
+    }
+
+    // result for the other side of a two outcome market, or for orders against the outcome
+    pub fn opposite(&self) -> OutcomeResult {
+        match self {
+            OutcomeResult::Win => OutcomeResult::Loss,
+            OutcomeResult::HalfWin => OutcomeResult::HalfLoss,
+            OutcomeResult::Push => OutcomeResult::Push,
+            OutcomeResult::HalfLoss => OutcomeResult::HalfWin,
+            OutcomeResult::Loss => OutcomeResult::Win,
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
@@ -182,7 +376,8 @@ mod tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -194,6 +389,23 @@ mod tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: now + 1000,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         assert!(Market::market_is_inplay(&market, now));
@@ -220,7 +432,8 @@ mod tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -232,6 +445,23 @@ mod tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: now + 1000,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         assert!(!Market::market_is_inplay(&market, now));
@@ -258,7 +488,8 @@ mod tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -270,6 +501,23 @@ mod tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: now,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         assert!(Market::market_is_inplay(&market, now));
@@ -296,7 +544,8 @@ mod tests {
             published: false,
             suspended: false,
             market_outcomes_count: 0,
-            market_winning_outcome_index: None,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
             market_lock_timestamp: 0,
             market_settle_timestamp: None,
             event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -308,6 +557,23 @@ mod tests {
             escrow_account_bump: 0,
             funding_account_bump: 0,
             event_start_timestamp: now,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
         };
 
         assert!(!Market::market_is_inplay(&market, now))
@@ -366,6 +632,24 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(0, market.unclosed_accounts_count);
     }
+
+    #[test]
+    fn test_crank_fee_and_reward() {
+        let mut market = mock_market(MarketStatus::Open);
+        market.crank_fee = 5;
+        market.crank_reward = 3;
+
+        assert_eq!(0, market.take_crank_reward());
+
+        let result = market.collect_crank_fee();
+        assert_eq!(Ok(5), result);
+        assert_eq!(5, market.crank_fee_balance);
+
+        assert_eq!(3, market.take_crank_reward());
+        assert_eq!(2, market.take_crank_reward());
+        assert_eq!(0, market.take_crank_reward());
+        assert_eq!(0, market.crank_fee_balance);
+    }
 }
 
 #[cfg(test)]
@@ -385,7 +669,8 @@ pub fn mock_market(market_status: MarketStatus) -> Market {
         published: false,
         suspended: false,
         market_outcomes_count: 0,
-        market_winning_outcome_index: None,
+        market_winning_places: 1,
+        market_winning_outcomes: vec![],
         market_lock_timestamp: 0,
         market_settle_timestamp: None,
         event_start_order_behaviour: MarketOrderBehaviour::None,
@@ -397,5 +682,22 @@ pub fn mock_market(market_status: MarketStatus) -> Market {
         escrow_account_bump: 0,
         funding_account_bump: 0,
         event_start_timestamp: 0,
+        schema_version: Market::SCHEMA_VERSION,
+        market_outcome_results: vec![],
+        trade_void_window: 0,
+        withdrawn_outcomes: vec![],
+        order_book_enabled: false,
+        price_improvement_enabled: false,
+        limits_enabled: false,
+        access_control_enabled: false,
+        operator_delegations: vec![],
+        settlement_approvals_required: 0,
+        settlement_challenge_period: 0,
+        atomic_matching_enabled: false,
+        crank_fee: 0,
+        crank_reward: 0,
+        crank_fee_balance: 0,
+        suspension_order_behaviour: MarketOrderBehaviour::None,
+        suspension_timestamp: 0,
     }
 }
@@ -0,0 +1,167 @@
+use crate::error::CoreError;
+use crate::state::type_size::*;
+use anchor_lang::prelude::*;
+
+/// Risk limits for orders on a market along with the matched stake they are checked against; a
+/// limit of 0 is not enforced
+#[account]
+pub struct MarketLimits {
+    pub market: Pubkey,
+    pub max_order_stake: u64,
+    pub max_position_exposure: u64,
+    pub max_outcome_stake_matched: u64,
+    pub outcome_stakes_matched: Vec<u64>,
+}
+
+impl MarketLimits {
+    pub fn size_for(number_of_outcomes: usize) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUB_KEY_SIZE // market
+            + U64_SIZE * 3 // max_order_stake, max_position_exposure, max_outcome_stake_matched
+            + vec_size(U64_SIZE, number_of_outcomes) // outcome_stakes_matched
+    }
+
+    pub fn validate_order_stake(&self, stake: u64) -> Result<()> {
+        require!(
+            self.max_order_stake == 0 || stake <= self.max_order_stake,
+            CoreError::CreationStakeAboveMarketLimit
+        );
+        Ok(())
+    }
+
+    pub fn validate_position_exposure(&self, exposure: u64) -> Result<()> {
+        require!(
+            self.max_position_exposure == 0 || exposure <= self.max_position_exposure,
+            CoreError::CreationExposureAboveMarketLimit
+        );
+        Ok(())
+    }
+
+    pub fn outcome_stake_matched(&self, outcome_index: u16) -> Result<u64> {
+        self.outcome_stakes_matched
+            .get(usize::from(outcome_index))
+            .copied()
+            .ok_or_else(|| error!(CoreError::MarketOutcomeIndexInvalid))
+    }
+
+    pub fn is_outcome_stake_matched_within_limit(
+        &self,
+        outcome_index: u16,
+        stake_matched: u64,
+    ) -> Result<bool> {
+        if self.max_outcome_stake_matched == 0 {
+            return Ok(true);
+        }
+        let outcome_stake_matched = self
+            .outcome_stake_matched(outcome_index)?
+            .checked_add(stake_matched)
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(outcome_stake_matched <= self.max_outcome_stake_matched)
+    }
+
+    pub fn validate_outcome_open_for_matching(&self, outcome_index: u16) -> Result<()> {
+        require!(
+            self.max_outcome_stake_matched == 0
+                || self.outcome_stake_matched(outcome_index)? < self.max_outcome_stake_matched,
+            CoreError::CreationOutcomeStakeMatchedAtMarketLimit
+        );
+        Ok(())
+    }
+
+    pub fn update_outcome_stake_matched(
+        &mut self,
+        outcome_index: u16,
+        stake_matched: u64,
+    ) -> Result<()> {
+        require!(
+            self.is_outcome_stake_matched_within_limit(outcome_index, stake_matched)?,
+            CoreError::MarketLimitsOutcomeStakeMatchedExceeded
+        );
+        let outcome_stake_matched = &mut self.outcome_stakes_matched[usize::from(outcome_index)];
+        *outcome_stake_matched = outcome_stake_matched
+            .checked_add(stake_matched)
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub fn mock_market_limits(
+    market: Pubkey,
+    number_of_outcomes: usize,
+    max_order_stake: u64,
+    max_position_exposure: u64,
+    max_outcome_stake_matched: u64,
+) -> MarketLimits {
+    MarketLimits {
+        market,
+        max_order_stake,
+        max_position_exposure,
+        max_outcome_stake_matched,
+        outcome_stakes_matched: vec![0; number_of_outcomes],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits_of_zero_not_enforced() {
+        let mut market_limits = mock_market_limits(Pubkey::new_unique(), 2, 0, 0, 0);
+
+        assert!(market_limits.validate_order_stake(u64::MAX).is_ok());
+        assert!(market_limits.validate_position_exposure(u64::MAX).is_ok());
+        assert!(market_limits.validate_outcome_open_for_matching(1).is_ok());
+        assert!(market_limits
+            .update_outcome_stake_matched(1, 1_000_000)
+            .is_ok());
+        assert_eq!(vec![0, 1_000_000], market_limits.outcome_stakes_matched);
+    }
+
+    #[test]
+    fn test_order_stake_and_position_exposure() {
+        let market_limits = mock_market_limits(Pubkey::new_unique(), 2, 100, 500, 0);
+
+        assert!(market_limits.validate_order_stake(100).is_ok());
+        assert_eq!(
+            Err(error!(CoreError::CreationStakeAboveMarketLimit)),
+            market_limits.validate_order_stake(101)
+        );
+        assert!(market_limits.validate_position_exposure(500).is_ok());
+        assert_eq!(
+            Err(error!(CoreError::CreationExposureAboveMarketLimit)),
+            market_limits.validate_position_exposure(501)
+        );
+    }
+
+    #[test]
+    fn test_outcome_stake_matched() {
+        let mut market_limits = mock_market_limits(Pubkey::new_unique(), 2, 0, 0, 100);
+
+        assert!(market_limits.update_outcome_stake_matched(0, 60).is_ok());
+        assert_eq!(
+            Ok(true),
+            market_limits.is_outcome_stake_matched_within_limit(0, 40)
+        );
+        assert_eq!(
+            Ok(false),
+            market_limits.is_outcome_stake_matched_within_limit(0, 41)
+        );
+        assert_eq!(
+            Err(error!(CoreError::MarketLimitsOutcomeStakeMatchedExceeded)),
+            market_limits.update_outcome_stake_matched(0, 41)
+        );
+
+        assert!(market_limits.update_outcome_stake_matched(0, 40).is_ok());
+        assert_eq!(
+            Err(error!(CoreError::CreationOutcomeStakeMatchedAtMarketLimit)),
+            market_limits.validate_outcome_open_for_matching(0)
+        );
+        assert!(market_limits.validate_outcome_open_for_matching(1).is_ok());
+        assert_eq!(
+            Err(error!(CoreError::MarketOutcomeIndexInvalid)),
+            market_limits.validate_outcome_open_for_matching(2)
+        );
+    }
+}
@@ -1,8 +1,12 @@
 use crate::error::CoreError;
 use crate::instructions::{
-    calculate_for_payout, calculate_price_cross, calculate_stake_from_payout,
+    calculate_for_payout, calculate_price_cross, calculate_price_cross_on_ladder,
+    calculate_stake_from_payout,
 };
 use crate::state::market_account::MarketOrderBehaviour;
+use crate::state::market_matching_queue_account::MarketMatchingQueue;
+use crate::state::market_outcome_liquidities::MarketOutcomeLiquidities;
+use crate::state::price::Price;
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 use rust_decimal::prelude::ToPrimitive;
@@ -20,6 +24,8 @@ pub struct MarketLiquidities {
 
 impl MarketLiquidities {
     const LIQUIDITIES_VEC_LENGTH: usize = 30_usize;
+    // a single cross match queues a match for every outcome of the market
+    pub const CROSS_MATCHING_OUTCOMES_MAX: u16 = MarketMatchingQueue::QUEUE_LENGTH as u16;
     pub const SIZE: usize = DISCRIMINATOR_SIZE
         + PUB_KEY_SIZE // market
         + BOOL_SIZE // enable_cross_matching
@@ -30,7 +36,7 @@ impl MarketLiquidities {
     pub fn get_liquidity_for(
         &self,
         outcome: u16,
-        price: f64,
+        price: Price,
     ) -> Option<&MarketOutcomePriceLiquidity> {
         self.liquidities_for
             .binary_search_by(Self::sorter_for(outcome, price, &[]))
@@ -41,7 +47,7 @@ impl MarketLiquidities {
     pub fn get_liquidity_against(
         &self,
         outcome: u16,
-        price: f64,
+        price: Price,
     ) -> Option<&MarketOutcomePriceLiquidity> {
         self.liquidities_against
             .binary_search_by(Self::sorter_against(outcome, price, &[]))
@@ -49,8 +55,8 @@ impl MarketLiquidities {
             .map(|index| &self.liquidities_against[index])
     }
 
-    pub fn add_liquidity_for(&mut self, outcome: u16, price: f64, liquidity: u64) -> Result<()> {
-        let is_full = self.is_full();
+    pub fn add_liquidity_for(&mut self, outcome: u16, price: Price, liquidity: u64) -> Result<()> {
+        let is_full = self.is_full(outcome);
         let liquidities = &mut self.liquidities_for;
         let sources = [];
         Self::add_liquidity(
@@ -67,10 +73,10 @@ impl MarketLiquidities {
     pub fn add_liquidity_against(
         &mut self,
         outcome: u16,
-        price: f64,
+        price: Price,
         liquidity: u64,
     ) -> Result<()> {
-        let is_full = self.is_full();
+        let is_full = self.is_full(outcome);
         let liquidities = &mut self.liquidities_against;
         let sources = [];
         Self::add_liquidity(
@@ -88,7 +94,7 @@ impl MarketLiquidities {
         liquidities: &mut Vec<MarketOutcomePriceLiquidity>,
         search_function: impl FnMut(&MarketOutcomePriceLiquidity) -> Ordering,
         outcome: u16,
-        price: f64,
+        price: Price,
         sources: &[LiquiditySource],
         liquidity: u64,
         is_full: bool,
@@ -123,7 +129,7 @@ impl MarketLiquidities {
 
     // this method does not validate parameters so value returned might not be real
     // assumption is that all (n-1) different sources were passed for the n-outcome market and the price is of the n-th outcome
-    pub fn get_cross_liquidity_for(&self, sources: &[LiquiditySource], price: f64) -> u64 {
+    pub fn get_cross_liquidity_for(&self, sources: &[LiquiditySource], price: Price) -> u64 {
         let amount = calculate_stake_from_payout(
             sources
                 .iter()
@@ -141,7 +147,7 @@ impl MarketLiquidities {
 
     // this method does not validate parameters so value returned might not be real
     // assumption is that all (n-1) different sources were passed for the n-outcome market and the price is of the n-th outcome
-    pub fn get_cross_liquidity_against(&self, sources: &[LiquiditySource], price: f64) -> u64 {
+    pub fn get_cross_liquidity_against(&self, sources: &[LiquiditySource], price: Price) -> u64 {
         let amount = calculate_stake_from_payout(
             sources
                 .iter()
@@ -169,17 +175,9 @@ impl MarketLiquidities {
         let source_prices = sources
             .iter()
             .map(|source| source.price)
-            .collect::<Vec<f64>>();
+            .collect::<Vec<Price>>();
         if let Some(cross_price) = calculate_price_cross(&source_prices) {
-            let cross_liquidity = self.get_cross_liquidity_for(sources, cross_price);
-            Self::set_liquidity(
-                &mut self.liquidities_for,
-                Self::sorter_for(outcome, cross_price, sources),
-                outcome,
-                cross_price,
-                cross_liquidity,
-                sources.to_vec(),
-            );
+            self.set_cross_liquidity_for(outcome, cross_price, sources);
         }
     }
 
@@ -195,25 +193,119 @@ impl MarketLiquidities {
         let source_prices = sources
             .iter()
             .map(|source| source.price)
-            .collect::<Vec<f64>>();
+            .collect::<Vec<Price>>();
         if let Some(cross_price) = calculate_price_cross(&source_prices) {
-            let cross_liquidity = self.get_cross_liquidity_against(sources, cross_price);
-            Self::set_liquidity(
-                &mut self.liquidities_against,
-                Self::sorter_against(outcome, cross_price, sources),
-                outcome,
-                cross_price,
-                cross_liquidity,
-                sources.to_vec(),
-            )
+            self.set_cross_liquidity_against(outcome, cross_price, sources);
         }
     }
 
+    // recalculates cross liquidity for the given sources at the given price, which may be a price
+    // rounded onto the ladder rather than the exact cross price
+    pub fn set_cross_liquidity_for(
+        &mut self,
+        outcome: u16,
+        price: Price,
+        sources: &[LiquiditySource],
+    ) {
+        let cross_liquidity = self.get_cross_liquidity_for(sources, price);
+        Self::set_liquidity(
+            &mut self.liquidities_for,
+            Self::sorter_for(outcome, price, sources),
+            outcome,
+            price,
+            cross_liquidity,
+            sources.to_vec(),
+        );
+    }
+
+    // recalculates cross liquidity for the given sources at the given price, which may be a price
+    // rounded onto the ladder rather than the exact cross price
+    pub fn set_cross_liquidity_against(
+        &mut self,
+        outcome: u16,
+        price: Price,
+        sources: &[LiquiditySource],
+    ) {
+        let cross_liquidity = self.get_cross_liquidity_against(sources, price);
+        Self::set_liquidity(
+            &mut self.liquidities_against,
+            Self::sorter_against(outcome, price, sources),
+            outcome,
+            price,
+            cross_liquidity,
+            sources.to_vec(),
+        )
+    }
+
+    // sets the cross liquidity for the outcome made of the best direct liquidity of every other
+    // outcome, a price which does not fit in 3 decimals is rounded onto the outcome's ladder so
+    // that the liquidity is still available to match
+    pub fn update_best_cross_liquidity_for(
+        &mut self,
+        outcome: u16,
+        market_outcomes_count: u16,
+        ladder_prices: &[Price],
+    ) {
+        let sources =
+            match Self::best_sources(&self.liquidities_against, outcome, market_outcomes_count) {
+                Some(sources) => sources,
+                None => return,
+            };
+        let source_prices: Vec<Price> = sources.iter().map(|source| source.price).collect();
+        let cross_price = calculate_price_cross(&source_prices)
+            .or_else(|| calculate_price_cross_on_ladder(&source_prices, ladder_prices, true));
+        if let Some(cross_price) = cross_price {
+            self.set_cross_liquidity_for(outcome, cross_price, &sources);
+        }
+    }
+
+    // sets the cross liquidity for the outcome made of the best direct liquidity of every other
+    // outcome, a price which does not fit in 3 decimals is rounded onto the outcome's ladder so
+    // that the liquidity is still available to match
+    pub fn update_best_cross_liquidity_against(
+        &mut self,
+        outcome: u16,
+        market_outcomes_count: u16,
+        ladder_prices: &[Price],
+    ) {
+        let sources =
+            match Self::best_sources(&self.liquidities_for, outcome, market_outcomes_count) {
+                Some(sources) => sources,
+                None => return,
+            };
+        let source_prices: Vec<Price> = sources.iter().map(|source| source.price).collect();
+        let cross_price = calculate_price_cross(&source_prices)
+            .or_else(|| calculate_price_cross_on_ladder(&source_prices, ladder_prices, false));
+        if let Some(cross_price) = cross_price {
+            self.set_cross_liquidity_against(outcome, cross_price, &sources);
+        }
+    }
+
+    // liquidities are sorted best price first within each outcome, so the first direct liquidity
+    // of each other outcome is the best source
+    fn best_sources(
+        liquidities: &[MarketOutcomePriceLiquidity],
+        outcome: u16,
+        market_outcomes_count: u16,
+    ) -> Option<Vec<LiquiditySource>> {
+        (0..market_outcomes_count)
+            .filter(|source_outcome| *source_outcome != outcome)
+            .map(|source_outcome| {
+                liquidities
+                    .iter()
+                    .find(|liquidity| {
+                        liquidity.outcome == source_outcome && liquidity.sources.is_empty()
+                    })
+                    .map(|liquidity| LiquiditySource::new(source_outcome, liquidity.price))
+            })
+            .collect()
+    }
+
     fn set_liquidity(
         liquidities: &mut Vec<MarketOutcomePriceLiquidity>,
         search_function: impl FnMut(&MarketOutcomePriceLiquidity) -> Ordering,
         outcome: u16,
-        price: f64,
+        price: Price,
         liquidity: u64,
         sources: Vec<LiquiditySource>,
     ) {
@@ -298,7 +390,7 @@ impl MarketLiquidities {
     pub fn remove_liquidity_for(
         &mut self,
         outcome: u16,
-        price: f64,
+        price: Price,
         liquidity: u64,
     ) -> Result<u64> {
         let liquidities = &mut self.liquidities_for;
@@ -309,7 +401,7 @@ impl MarketLiquidities {
     pub fn remove_liquidity_against(
         &mut self,
         outcome: u16,
-        price: f64,
+        price: Price,
         liquidity: u64,
     ) -> Result<u64> {
         let liquidities = &mut self.liquidities_against;
@@ -341,7 +433,7 @@ impl MarketLiquidities {
 
     fn sorter_for(
         outcome: u16,
-        price: f64,
+        price: Price,
         sources: &[LiquiditySource],
     ) -> impl FnMut(&MarketOutcomePriceLiquidity) -> Ordering + '_ {
         move |liquidity| {
@@ -372,7 +464,7 @@ impl MarketLiquidities {
 
     fn sorter_against(
         outcome: u16,
-        price: f64,
+        price: Price,
         sources: &[LiquiditySource],
     ) -> impl FnMut(&MarketOutcomePriceLiquidity) -> Ordering + '_ {
         move |liquidity| {
@@ -401,7 +493,7 @@ impl MarketLiquidities {
         }
     }
 
-    fn source_prices(sources: &[LiquiditySource]) -> Vec<f64> {
+    fn source_prices(sources: &[LiquiditySource]) -> Vec<Price> {
         sources.iter().map(|source| source.price).collect()
     }
 
@@ -409,9 +501,88 @@ impl MarketLiquidities {
         sources.iter().map(|source| source.outcome).sum()
     }
 
-    fn is_full(&self) -> bool {
-        Self::LIQUIDITIES_VEC_LENGTH + Self::LIQUIDITIES_VEC_LENGTH
-            <= self.liquidities_for.len() + self.liquidities_against.len()
+    // capacity is per outcome, as each outcome's liquidities can be kept in its own account
+    fn is_full(&self, outcome: u16) -> bool {
+        let outcome_liquidities_count = self
+            .liquidities_for
+            .iter()
+            .chain(self.liquidities_against.iter())
+            .filter(|liquidity| liquidity.outcome == outcome)
+            .count();
+        Self::LIQUIDITIES_VEC_LENGTH + Self::LIQUIDITIES_VEC_LENGTH <= outcome_liquidities_count
+    }
+
+    pub fn size(&self) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUB_KEY_SIZE // market
+            + BOOL_SIZE // enable_cross_matching
+            + U64_SIZE // stake_matched_total
+            + MarketOutcomePriceLiquidity::liquidities_size(&self.liquidities_for)
+            + MarketOutcomePriceLiquidity::liquidities_size(&self.liquidities_against)
+    }
+
+    // moves liquidities held by the outcome account in, merging any already present for the
+    // same outcome, price and sources
+    pub fn load_outcome_liquidities(
+        &mut self,
+        outcome_liquidities: &mut MarketOutcomeLiquidities,
+    ) -> Result<()> {
+        for liquidity in std::mem::take(&mut outcome_liquidities.liquidities_for) {
+            let sorter = Self::sorter_for(liquidity.outcome, liquidity.price, &liquidity.sources);
+            let index = self.liquidities_for.binary_search_by(sorter);
+            Self::merge_liquidity(&mut self.liquidities_for, index, liquidity)?;
+        }
+        for liquidity in std::mem::take(&mut outcome_liquidities.liquidities_against) {
+            let sorter =
+                Self::sorter_against(liquidity.outcome, liquidity.price, &liquidity.sources);
+            let index = self.liquidities_against.binary_search_by(sorter);
+            Self::merge_liquidity(&mut self.liquidities_against, index, liquidity)?;
+        }
+        Ok(())
+    }
+
+    fn merge_liquidity(
+        liquidities: &mut Vec<MarketOutcomePriceLiquidity>,
+        search_result: std::result::Result<usize, usize>,
+        liquidity: MarketOutcomePriceLiquidity,
+    ) -> Result<()> {
+        match search_result {
+            Ok(index) => {
+                let value = &mut liquidities[index];
+                value.liquidity = if liquidity.sources.is_empty() {
+                    value
+                        .liquidity
+                        .checked_add(liquidity.liquidity)
+                        .ok_or(CoreError::MarketLiquiditiesUpdateError)?
+                } else {
+                    // cross liquidity is derived from its sources, so copies are not additive
+                    value.liquidity.max(liquidity.liquidity)
+                };
+            }
+            Err(index) => liquidities.insert(index, liquidity),
+        }
+        Ok(())
+    }
+
+    // moves all liquidities of the outcome out to the outcome account
+    pub fn store_outcome_liquidities(
+        &mut self,
+        outcome_liquidities: &mut MarketOutcomeLiquidities,
+    ) {
+        let outcome = outcome_liquidities.market_outcome_index;
+        let (liquidities_for, remaining_for): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.liquidities_for)
+                .into_iter()
+                .partition(|liquidity| liquidity.outcome == outcome);
+        let (liquidities_against, remaining_against): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.liquidities_against)
+                .into_iter()
+                .partition(|liquidity| liquidity.outcome == outcome);
+
+        self.liquidities_for = remaining_for;
+        self.liquidities_against = remaining_against;
+        outcome_liquidities.liquidities_for = liquidities_for;
+        outcome_liquidities.liquidities_against = liquidities_against;
     }
 
     pub fn update_stake_matched_total(&mut self, stake_matched: u64) -> Result<()> {
@@ -424,6 +595,18 @@ impl MarketLiquidities {
         Ok(())
     }
 
+    pub fn clear_liquidities(&mut self) {
+        self.liquidities_for = Vec::new();
+        self.liquidities_against = Vec::new();
+    }
+
+    pub fn clear_outcome_liquidities(&mut self, outcome: u16) {
+        self.liquidities_for
+            .retain(|liquidity| liquidity.outcome != outcome);
+        self.liquidities_against
+            .retain(|liquidity| liquidity.outcome != outcome);
+    }
+
     pub fn move_to_inplay(&mut self, market_event_start_order_behaviour: &MarketOrderBehaviour) {
         // Reset liquidities when market moves to inplay if that's the desired behaviour
         if market_event_start_order_behaviour.eq(&MarketOrderBehaviour::CancelUnmatched) {
@@ -436,11 +619,11 @@ impl MarketLiquidities {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct LiquiditySource {
     pub outcome: u16,
-    pub price: f64,
+    pub price: Price,
 }
 
 impl LiquiditySource {
-    pub fn new(outcome: u16, price: f64) -> LiquiditySource {
+    pub fn new(outcome: u16, price: Price) -> LiquiditySource {
         LiquiditySource { outcome, price }
     }
 }
@@ -448,16 +631,32 @@ impl LiquiditySource {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct MarketOutcomePriceLiquidity {
     pub outcome: u16,
-    pub price: f64,
+    pub price: Price,
     pub sources: Vec<LiquiditySource>,
     pub liquidity: u64,
 }
 
 impl MarketOutcomePriceLiquidity {
     pub const SIZE: usize = U16_SIZE // outcome
-        + F64_SIZE // price
-        + vec_size(U16_SIZE + F64_SIZE, 3) // sources: sized to work for 3 and 4 way markets
+        + Price::SIZE // price
+        + vec_size(U16_SIZE + Price::SIZE, 3) // sources: sized to work for 3 and 4 way markets
         + U64_SIZE; // liquidity
+
+    // serialized size, as sources grow with the number of outcomes of cross matching markets
+    pub fn size(&self) -> usize {
+        U16_SIZE // outcome
+            + Price::SIZE // price
+            + vec_size(U16_SIZE + Price::SIZE, self.sources.len()) // sources
+            + U64_SIZE // liquidity
+    }
+
+    pub fn liquidities_size(liquidities: &[MarketOutcomePriceLiquidity]) -> usize {
+        vec_size(0, 0)
+            + liquidities
+                .iter()
+                .map(|liquidity| liquidity.size())
+                .sum::<usize>()
+    }
 }
 
 #[cfg(test)]
@@ -472,7 +671,7 @@ pub fn mock_market_liquidities(market_pk: Pubkey) -> MarketLiquidities {
 }
 
 #[cfg(test)]
-pub fn mock_liquidity(outcome: u16, price: f64, liquidity: u64) -> MarketOutcomePriceLiquidity {
+pub fn mock_liquidity(outcome: u16, price: Price, liquidity: u64) -> MarketOutcomePriceLiquidity {
     MarketOutcomePriceLiquidity {
         outcome,
         price,
@@ -484,7 +683,7 @@ pub fn mock_liquidity(outcome: u16, price: f64, liquidity: u64) -> MarketOutcome
 #[cfg(test)]
 pub fn mock_liquidity_with_sources(
     outcome: u16,
-    price: f64,
+    price: Price,
     sources: &[LiquiditySource],
     liquidity: u64,
 ) -> MarketOutcomePriceLiquidity {
@@ -499,25 +698,35 @@ pub fn mock_liquidity_with_sources(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::price_ladder::DEFAULT_PRICES;
 
     #[test]
     fn test_add_liquidity() {
-        let sources01 = [LiquiditySource::new(0, 2.8), LiquiditySource::new(1, 2.8)];
+        let sources01 = [
+            LiquiditySource::new(0, Price::new(2_800)),
+            LiquiditySource::new(1, Price::new(2_800)),
+        ];
         let mut mls = mock_market_liquidities(Pubkey::default());
 
-        mls.add_liquidity_for(0, 2.8, 5_000).unwrap();
-        mls.add_liquidity_for(0, 2.8, 5_000).unwrap();
-        mls.add_liquidity_for(0, 2.9, 15_000).unwrap();
-        mls.add_liquidity_for(1, 2.8, 20_000).unwrap();
-        mls.add_liquidity_for(2, 2.8, 25_000).unwrap();
-        mls.add_liquidity_for(2, 3.5, 30_000).unwrap();
-
-        mls.add_liquidity_against(0, 2.8, 5_000).unwrap();
-        mls.add_liquidity_against(0, 2.8, 5_000).unwrap();
-        mls.add_liquidity_against(0, 2.9, 15_000).unwrap();
-        mls.add_liquidity_against(1, 2.8, 20_000).unwrap();
-        mls.add_liquidity_against(2, 2.8, 25_000).unwrap();
-        mls.add_liquidity_against(2, 3.5, 30_000).unwrap();
+        mls.add_liquidity_for(0, Price::new(2_800), 5_000).unwrap();
+        mls.add_liquidity_for(0, Price::new(2_800), 5_000).unwrap();
+        mls.add_liquidity_for(0, Price::new(2_900), 15_000).unwrap();
+        mls.add_liquidity_for(1, Price::new(2_800), 20_000).unwrap();
+        mls.add_liquidity_for(2, Price::new(2_800), 25_000).unwrap();
+        mls.add_liquidity_for(2, Price::new(3_500), 30_000).unwrap();
+
+        mls.add_liquidity_against(0, Price::new(2_800), 5_000)
+            .unwrap();
+        mls.add_liquidity_against(0, Price::new(2_800), 5_000)
+            .unwrap();
+        mls.add_liquidity_against(0, Price::new(2_900), 15_000)
+            .unwrap();
+        mls.add_liquidity_against(1, Price::new(2_800), 20_000)
+            .unwrap();
+        mls.add_liquidity_against(2, Price::new(2_800), 25_000)
+            .unwrap();
+        mls.add_liquidity_against(2, Price::new(3_500), 30_000)
+            .unwrap();
 
         mls.update_cross_liquidity_for(&sources01);
         mls.update_cross_liquidity_against(&sources01);
@@ -525,24 +734,24 @@ mod tests {
         // the order of the results is important
         assert_eq!(
             vec![
-                mock_liquidity(0, 2.8, 10_000),
-                mock_liquidity(0, 2.9, 15_000),
-                mock_liquidity(1, 2.8, 20_000),
-                mock_liquidity(2, 2.8, 25_000),
-                mock_liquidity(2, 3.5, 30_000),
-                mock_liquidity_with_sources(2, 3.5, &sources01, 8_000),
+                mock_liquidity(0, Price::new(2_800), 10_000),
+                mock_liquidity(0, Price::new(2_900), 15_000),
+                mock_liquidity(1, Price::new(2_800), 20_000),
+                mock_liquidity(2, Price::new(2_800), 25_000),
+                mock_liquidity(2, Price::new(3_500), 30_000),
+                mock_liquidity_with_sources(2, Price::new(3_500), &sources01, 8_000),
             ],
             mls.liquidities_for
         );
         // the order of the results is important
         assert_eq!(
             vec![
-                mock_liquidity(2, 3.5, 30_000),
-                mock_liquidity_with_sources(2, 3.5, &sources01, 8_000),
-                mock_liquidity(2, 2.8, 25_000),
-                mock_liquidity(1, 2.8, 20_000),
-                mock_liquidity(0, 2.9, 15_000),
-                mock_liquidity(0, 2.8, 10_000),
+                mock_liquidity(2, Price::new(3_500), 30_000),
+                mock_liquidity_with_sources(2, Price::new(3_500), &sources01, 8_000),
+                mock_liquidity(2, Price::new(2_800), 25_000),
+                mock_liquidity(1, Price::new(2_800), 20_000),
+                mock_liquidity(0, Price::new(2_900), 15_000),
+                mock_liquidity(0, Price::new(2_800), 10_000),
             ],
             mls.liquidities_against
         );
@@ -552,120 +761,257 @@ mod tests {
     fn test_add_liquidity_when_full() {
         let mut market_liquidities = mock_market_liquidities(Pubkey::default());
 
-        let mut price = 2.01;
+        let mut price = 2_010;
         for _ in 0..60 {
-            market_liquidities.add_liquidity_for(0, price, 1).unwrap();
-            price += 0.01;
+            market_liquidities
+                .add_liquidity_for(0, Price::new(price), 1)
+                .unwrap();
+            price += 10;
         }
 
-        let result = market_liquidities.add_liquidity_for(0, price, 1);
+        let result = market_liquidities.add_liquidity_for(0, Price::new(price), 1);
         assert!(result.is_err());
         assert_eq!(Err(error!(CoreError::MarketLiquiditiesIsFull)), result);
+
+        // capacity is per outcome
+        market_liquidities
+            .add_liquidity_against(1, Price::new(price), 1)
+            .unwrap();
     }
 
     #[test]
     fn test_update_cross_liquidity_for() {
-        let sources1 = [LiquiditySource::new(0, 2.7), LiquiditySource::new(1, 3.0)];
-        let sources2 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 2.7)];
+        let sources1 = [
+            LiquiditySource::new(0, Price::new(2_700)),
+            LiquiditySource::new(1, Price::new(3_000)),
+        ];
+        let sources2 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(2_700)),
+        ];
 
         let mut mls: MarketLiquidities = mock_market_liquidities(Pubkey::default());
-        mls.add_liquidity_against(0, 2.700, 100_000).unwrap();
-        mls.add_liquidity_against(1, 3.000, 90_000).unwrap();
-        mls.add_liquidity_against(0, 3.000, 45_000).unwrap();
-        mls.add_liquidity_against(1, 2.700, 50_000).unwrap();
+        mls.add_liquidity_against(0, Price::new(2_700), 100_000)
+            .unwrap();
+        mls.add_liquidity_against(1, Price::new(3_000), 90_000)
+            .unwrap();
+        mls.add_liquidity_against(0, Price::new(3_000), 45_000)
+            .unwrap();
+        mls.add_liquidity_against(1, Price::new(2_700), 50_000)
+            .unwrap();
 
         mls.update_cross_liquidity_for(&sources1);
         mls.update_cross_liquidity_for(&sources2);
 
         assert_eq!(
             vec![
-                mock_liquidity_with_sources(2, 3.375, &sources1, 80_000,),
-                mock_liquidity_with_sources(2, 3.375, &sources2, 40_000,),
+                mock_liquidity_with_sources(2, Price::new(3_375), &sources1, 80_000,),
+                mock_liquidity_with_sources(2, Price::new(3_375), &sources2, 40_000,),
             ],
             mls.liquidities_for
         );
 
-        mls.remove_liquidity_against(0, 2.700, 100_000).unwrap();
+        mls.remove_liquidity_against(0, Price::new(2_700), 100_000)
+            .unwrap();
         mls.update_cross_liquidity_for(&sources1);
         mls.update_cross_liquidity_for(&sources2);
 
         assert_eq!(
-            vec![mock_liquidity_with_sources(2, 3.375, &sources2, 40_000,),],
+            vec![mock_liquidity_with_sources(
+                2,
+                Price::new(3_375),
+                &sources2,
+                40_000,
+            ),],
             mls.liquidities_for
         );
     }
 
     #[test]
     fn test_update_cross_liquidity_against() {
-        let sources1 = [LiquiditySource::new(0, 2.7), LiquiditySource::new(1, 3.0)];
-        let sources2 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 2.7)];
+        let sources1 = [
+            LiquiditySource::new(0, Price::new(2_700)),
+            LiquiditySource::new(1, Price::new(3_000)),
+        ];
+        let sources2 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(2_700)),
+        ];
 
         let mut mls: MarketLiquidities = mock_market_liquidities(Pubkey::default());
-        mls.add_liquidity_for(0, 2.700, 100_000).unwrap();
-        mls.add_liquidity_for(1, 3.000, 90_000).unwrap();
-        mls.add_liquidity_for(0, 3.000, 45_000).unwrap();
-        mls.add_liquidity_for(1, 2.700, 50_000).unwrap();
+        mls.add_liquidity_for(0, Price::new(2_700), 100_000)
+            .unwrap();
+        mls.add_liquidity_for(1, Price::new(3_000), 90_000).unwrap();
+        mls.add_liquidity_for(0, Price::new(3_000), 45_000).unwrap();
+        mls.add_liquidity_for(1, Price::new(2_700), 50_000).unwrap();
 
         mls.update_cross_liquidity_against(&sources1);
         mls.update_cross_liquidity_against(&sources2);
 
         assert_eq!(
             vec![
-                mock_liquidity_with_sources(2, 3.375, &sources1, 80_000,),
-                mock_liquidity_with_sources(2, 3.375, &sources2, 40_000,),
+                mock_liquidity_with_sources(2, Price::new(3_375), &sources1, 80_000,),
+                mock_liquidity_with_sources(2, Price::new(3_375), &sources2, 40_000,),
             ],
             mls.liquidities_against
         );
 
-        mls.remove_liquidity_for(0, 2.700, 100_000).unwrap();
+        mls.remove_liquidity_for(0, Price::new(2_700), 100_000)
+            .unwrap();
         mls.update_cross_liquidity_against(&sources1);
         mls.update_cross_liquidity_against(&sources2);
 
         assert_eq!(
-            vec![mock_liquidity_with_sources(2, 3.375, &sources2, 40_000,),],
+            vec![mock_liquidity_with_sources(
+                2,
+                Price::new(3_375),
+                &sources2,
+                40_000,
+            ),],
             mls.liquidities_against
         );
     }
 
+    #[test]
+    fn test_update_best_cross_liquidity_against() {
+        let mut mls: MarketLiquidities = mock_market_liquidities(Pubkey::default());
+        mls.add_liquidity_for(0, Price::new(2_000), 100_000)
+            .unwrap();
+        mls.add_liquidity_for(0, Price::new(2_100), 100_000)
+            .unwrap();
+        mls.add_liquidity_for(1, Price::new(4_000), 100_000)
+            .unwrap();
+
+        // no liquidity on outcome 2 to cross with
+        mls.update_best_cross_liquidity_against(3, 4, &DEFAULT_PRICES);
+        assert!(mls.liquidities_against.is_empty());
+
+        mls.add_liquidity_for(2, Price::new(10_000), 100_000)
+            .unwrap();
+        mls.update_best_cross_liquidity_against(3, 4, &DEFAULT_PRICES);
+
+        // 1 / (1 - (1/2 + 1/4 + 1/10)) = 6.666... rounded down to 6.6 on the ladder
+        let sources = [
+            LiquiditySource::new(0, Price::new(2_000)),
+            LiquiditySource::new(1, Price::new(4_000)),
+            LiquiditySource::new(2, Price::new(10_000)),
+        ];
+        assert_eq!(
+            vec![mock_liquidity_with_sources(
+                3,
+                Price::new(6_600),
+                &sources,
+                30_000
+            )],
+            mls.liquidities_against
+        );
+    }
+
+    #[test]
+    fn test_update_best_cross_liquidity_for() {
+        let mut mls: MarketLiquidities = mock_market_liquidities(Pubkey::default());
+        mls.add_liquidity_against(1, Price::new(2_200), 100_000)
+            .unwrap();
+        mls.add_liquidity_against(2, Price::new(3_000), 100_000)
+            .unwrap();
+        mls.add_liquidity_against(2, Price::new(2_900), 100_000)
+            .unwrap();
+
+        mls.update_best_cross_liquidity_for(0, 3, &DEFAULT_PRICES);
+
+        // 1 / (1 - (1/2.2 + 1/3)) = 4.714... rounded up to 4.8 on the ladder
+        let sources = [
+            LiquiditySource::new(1, Price::new(2_200)),
+            LiquiditySource::new(2, Price::new(3_000)),
+        ];
+        assert_eq!(
+            vec![mock_liquidity_with_sources(
+                0,
+                Price::new(4_800),
+                &sources,
+                45_000
+            )],
+            mls.liquidities_for
+        );
+    }
+
+    #[test]
+    fn test_update_best_cross_liquidity_on_outcome_ladder() {
+        let ladder = [
+            Price::new(2_000),
+            Price::new(5_000),
+            Price::new(4_000),
+            Price::new(7_000),
+            Price::new(6_000),
+        ];
+        let mut mls: MarketLiquidities = mock_market_liquidities(Pubkey::default());
+        mls.add_liquidity_against(1, Price::new(2_200), 100_000)
+            .unwrap();
+        mls.add_liquidity_against(2, Price::new(3_000), 100_000)
+            .unwrap();
+        mls.add_liquidity_for(0, Price::new(2_000), 100_000)
+            .unwrap();
+        mls.add_liquidity_for(1, Price::new(4_000), 100_000)
+            .unwrap();
+        mls.add_liquidity_for(2, Price::new(10_000), 100_000)
+            .unwrap();
+
+        // 4.714... rounded up to 5 rather than 4.8 of the default ladder
+        mls.update_best_cross_liquidity_for(0, 3, &ladder);
+        let cross_liquidity = mls.liquidities_for.iter().find(|l| !l.sources.is_empty());
+        assert_eq!(Some(Price::new(5_000)), cross_liquidity.map(|l| l.price));
+
+        // 6.666... rounded down to 6 rather than 6.6 of the default ladder
+        mls.update_best_cross_liquidity_against(3, 4, &ladder);
+        let cross_liquidity = mls
+            .liquidities_against
+            .iter()
+            .find(|l| !l.sources.is_empty());
+        assert_eq!(Some(Price::new(6_000)), cross_liquidity.map(|l| l.price));
+    }
+
     #[test]
     fn test_remove_liquidity() {
         let mut mls: MarketLiquidities = MarketLiquidities {
             market: Pubkey::default(),
             enable_cross_matching: true,
             liquidities_for: vec![
-                mock_liquidity(0, 2.111, 1001),
-                mock_liquidity(1, 2.111, 2001),
-                mock_liquidity(2, 2.111, 3001),
+                mock_liquidity(0, Price::new(2_111), 1001),
+                mock_liquidity(1, Price::new(2_111), 2001),
+                mock_liquidity(2, Price::new(2_111), 3001),
             ],
             liquidities_against: vec![
-                mock_liquidity(2, 2.111, 3001),
-                mock_liquidity(1, 2.111, 2001),
-                mock_liquidity(0, 2.111, 1001),
+                mock_liquidity(2, Price::new(2_111), 3001),
+                mock_liquidity(1, Price::new(2_111), 2001),
+                mock_liquidity(0, Price::new(2_111), 1001),
             ],
             stake_matched_total: 0_u64,
         };
 
-        mls.remove_liquidity_for(0, 2.111, 200).unwrap();
-        mls.remove_liquidity_for(1, 2.111, 200).unwrap();
-        mls.remove_liquidity_for(2, 2.111, 200).unwrap();
+        mls.remove_liquidity_for(0, Price::new(2_111), 200).unwrap();
+        mls.remove_liquidity_for(1, Price::new(2_111), 200).unwrap();
+        mls.remove_liquidity_for(2, Price::new(2_111), 200).unwrap();
 
-        mls.remove_liquidity_against(0, 2.111, 200).unwrap();
-        mls.remove_liquidity_against(1, 2.111, 200).unwrap();
-        mls.remove_liquidity_against(2, 2.111, 200).unwrap();
+        mls.remove_liquidity_against(0, Price::new(2_111), 200)
+            .unwrap();
+        mls.remove_liquidity_against(1, Price::new(2_111), 200)
+            .unwrap();
+        mls.remove_liquidity_against(2, Price::new(2_111), 200)
+            .unwrap();
 
         assert_eq!(
             vec![
-                mock_liquidity(0, 2.111, 801),
-                mock_liquidity(1, 2.111, 1801),
-                mock_liquidity(2, 2.111, 2801),
+                mock_liquidity(0, Price::new(2_111), 801),
+                mock_liquidity(1, Price::new(2_111), 1801),
+                mock_liquidity(2, Price::new(2_111), 2801),
             ],
             mls.liquidities_for
         );
         assert_eq!(
             vec![
-                mock_liquidity(2, 2.111, 2801),
-                mock_liquidity(1, 2.111, 1801),
-                mock_liquidity(0, 2.111, 801),
+                mock_liquidity(2, Price::new(2_111), 2801),
+                mock_liquidity(1, Price::new(2_111), 1801),
+                mock_liquidity(0, Price::new(2_111), 801),
             ],
             mls.liquidities_against
         );
@@ -677,10 +1023,10 @@ mod tests {
             market: Pubkey::default(),
             enable_cross_matching: true,
             liquidities_for: vec![
-                mock_liquidity(0, 2.30, 1001),
-                mock_liquidity(0, 2.31, 1002),
-                mock_liquidity(0, 2.32, 1003),
-                mock_liquidity(0, 2.33, 1004),
+                mock_liquidity(0, Price::new(2_300), 1001),
+                mock_liquidity(0, Price::new(2_310), 1002),
+                mock_liquidity(0, Price::new(2_320), 1003),
+                mock_liquidity(0, Price::new(2_330), 1004),
             ],
             liquidities_against: vec![],
             stake_matched_total: 0_u64,
@@ -689,11 +1035,14 @@ mod tests {
         assert_eq!(
             1002,
             market_liquidities
-                .get_liquidity_for(0, 2.31)
+                .get_liquidity_for(0, Price::new(2_310))
                 .unwrap()
                 .liquidity
         );
-        assert_eq!(None, market_liquidities.get_liquidity_for(0, 2.315));
+        assert_eq!(
+            None,
+            market_liquidities.get_liquidity_for(0, Price::new(2_315))
+        );
     }
 
     #[test]
@@ -703,10 +1052,10 @@ mod tests {
             enable_cross_matching: true,
             liquidities_for: vec![],
             liquidities_against: vec![
-                mock_liquidity(0, 2.33, 1004),
-                mock_liquidity(0, 2.32, 1003),
-                mock_liquidity(0, 2.31, 1002),
-                mock_liquidity(0, 2.30, 1001),
+                mock_liquidity(0, Price::new(2_330), 1004),
+                mock_liquidity(0, Price::new(2_320), 1003),
+                mock_liquidity(0, Price::new(2_310), 1002),
+                mock_liquidity(0, Price::new(2_300), 1001),
             ],
             stake_matched_total: 0_u64,
         };
@@ -714,11 +1063,14 @@ mod tests {
         assert_eq!(
             1002,
             market_liquidities
-                .get_liquidity_against(0, 2.31)
+                .get_liquidity_against(0, Price::new(2_310))
                 .unwrap()
                 .liquidity
         );
-        assert_eq!(None, market_liquidities.get_liquidity_against(0, 2.315));
+        assert_eq!(
+            None,
+            market_liquidities.get_liquidity_against(0, Price::new(2_315))
+        );
     }
 }
 
@@ -730,40 +1082,51 @@ mod update_all_cross_liquidity {
     fn test_for() {
         let market_pk = Pubkey::new_unique();
         let mut mls = mock_market_liquidities(market_pk);
-        mls.add_liquidity_for(0, 3.0, 100_000).unwrap();
-        for price in [3.5, 4.0, 4.5] {
+        mls.add_liquidity_for(0, Price::new(3_000), 100_000)
+            .unwrap();
+        for price in [Price::new(3_500), Price::new(4_000), Price::new(4_500)] {
             mls.add_liquidity_for(1, price, 100_000).unwrap();
             mls.update_cross_liquidity_against(&[
-                LiquiditySource::new(0, 3.0),
+                LiquiditySource::new(0, Price::new(3_000)),
                 LiquiditySource::new(1, price),
             ]);
         }
 
         assert_eq!(
             vec![
-                mock_liquidity(0, 3.0, 100_000),
-                mock_liquidity(1, 3.5, 100_000),
-                mock_liquidity(1, 4.0, 100_000),
-                mock_liquidity(1, 4.5, 100_000),
+                mock_liquidity(0, Price::new(3_000), 100_000),
+                mock_liquidity(1, Price::new(3_500), 100_000),
+                mock_liquidity(1, Price::new(4_000), 100_000),
+                mock_liquidity(1, Price::new(4_500), 100_000),
             ],
             mls.liquidities_for
         );
 
-        let sources1 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 3.5)];
-        let sources2 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 4.0)];
-        let sources3 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 4.5)];
+        let sources1 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(3_500)),
+        ];
+        let sources2 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(4_000)),
+        ];
+        let sources3 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(4_500)),
+        ];
 
         assert_eq!(
             vec![
-                mock_liquidity_with_sources(2, 2.625, &sources1, 114_000),
-                mock_liquidity_with_sources(2, 2.4, &sources2, 125_000),
-                mock_liquidity_with_sources(2, 2.25, &sources3, 133_000),
+                mock_liquidity_with_sources(2, Price::new(2_625), &sources1, 114_000),
+                mock_liquidity_with_sources(2, Price::new(2_400), &sources2, 125_000),
+                mock_liquidity_with_sources(2, Price::new(2_250), &sources3, 133_000),
             ],
             mls.liquidities_against
         );
 
-        mls.remove_liquidity_for(0, 3.0, 100_000).unwrap();
-        mls.update_all_cross_liquidity_against(&LiquiditySource::new(0, 3.0));
+        mls.remove_liquidity_for(0, Price::new(3_000), 100_000)
+            .unwrap();
+        mls.update_all_cross_liquidity_against(&LiquiditySource::new(0, Price::new(3_000)));
 
         assert_eq!(
             Vec::<MarketOutcomePriceLiquidity>::new(),
@@ -775,40 +1138,51 @@ mod update_all_cross_liquidity {
     fn test_against() {
         let market_pk = Pubkey::new_unique();
         let mut mls = mock_market_liquidities(market_pk);
-        mls.add_liquidity_against(0, 3.0, 100_000).unwrap();
-        for price in [3.5, 4.0, 4.5] {
+        mls.add_liquidity_against(0, Price::new(3_000), 100_000)
+            .unwrap();
+        for price in [Price::new(3_500), Price::new(4_000), Price::new(4_500)] {
             mls.add_liquidity_against(1, price, 100_000).unwrap();
             mls.update_cross_liquidity_for(&[
-                LiquiditySource::new(0, 3.0),
+                LiquiditySource::new(0, Price::new(3_000)),
                 LiquiditySource::new(1, price),
             ]);
         }
 
         assert_eq!(
             vec![
-                mock_liquidity(1, 4.5, 100_000),
-                mock_liquidity(1, 4.0, 100_000),
-                mock_liquidity(1, 3.5, 100_000),
-                mock_liquidity(0, 3.0, 100_000),
+                mock_liquidity(1, Price::new(4_500), 100_000),
+                mock_liquidity(1, Price::new(4_000), 100_000),
+                mock_liquidity(1, Price::new(3_500), 100_000),
+                mock_liquidity(0, Price::new(3_000), 100_000),
             ],
             mls.liquidities_against
         );
 
-        let sources1 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 3.5)];
-        let sources2 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 4.0)];
-        let sources3 = [LiquiditySource::new(0, 3.0), LiquiditySource::new(1, 4.5)];
+        let sources1 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(3_500)),
+        ];
+        let sources2 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(4_000)),
+        ];
+        let sources3 = [
+            LiquiditySource::new(0, Price::new(3_000)),
+            LiquiditySource::new(1, Price::new(4_500)),
+        ];
 
         assert_eq!(
             vec![
-                mock_liquidity_with_sources(2, 2.25, &sources3, 133_000),
-                mock_liquidity_with_sources(2, 2.4, &sources2, 125_000),
-                mock_liquidity_with_sources(2, 2.625, &sources1, 114_000),
+                mock_liquidity_with_sources(2, Price::new(2_250), &sources3, 133_000),
+                mock_liquidity_with_sources(2, Price::new(2_400), &sources2, 125_000),
+                mock_liquidity_with_sources(2, Price::new(2_625), &sources1, 114_000),
             ],
             mls.liquidities_for
         );
 
-        mls.remove_liquidity_against(0, 3.0, 100_000).unwrap();
-        mls.update_all_cross_liquidity_for(&LiquiditySource::new(0, 3.0));
+        mls.remove_liquidity_against(0, Price::new(3_000), 100_000)
+            .unwrap();
+        mls.update_all_cross_liquidity_for(&LiquiditySource::new(0, Price::new(3_000)));
 
         assert_eq!(
             Vec::<MarketOutcomePriceLiquidity>::new(),
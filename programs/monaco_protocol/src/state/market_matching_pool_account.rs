@@ -3,13 +3,14 @@ use anchor_lang::prelude::*;
 use std::string::ToString;
 
 use super::market_account::MarketOrderBehaviour;
+use crate::state::price::Price;
 
 #[account]
 pub struct MarketMatchingPool {
     pub market: Pubkey,
     pub market_outcome_index: u16,
     pub for_outcome: bool,
-    pub price: f64,
+    pub price: Price,
     pub payer: Pubkey,
     pub liquidity_amount: u64,
     pub matched_amount: u64,
@@ -24,7 +25,7 @@ impl MarketMatchingPool {
         PUB_KEY_SIZE + // market
         U16_SIZE + // market_outcome_index
         BOOL_SIZE + // for_outcome
-        F64_SIZE + // price
+        Price::SIZE + // price
         PUB_KEY_SIZE + // payer
         U64_SIZE + // liquidity_amount
         U64_SIZE + // matched_amount
@@ -35,10 +36,14 @@ impl MarketMatchingPool {
         self.inplay = true;
 
         if market_event_start_order_behaviour.eq(&MarketOrderBehaviour::CancelUnmatched) {
-            self.orders.set_length_to_zero();
-            self.liquidity_amount = 0_u64;
+            self.clear_liquidity();
         }
     }
+
+    pub fn clear_liquidity(&mut self) {
+        self.orders.set_length_to_zero();
+        self.liquidity_amount = 0_u64;
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
@@ -198,7 +203,7 @@ pub fn mock_market_matching_pool(
     market_pk: Pubkey,
     market_outcome_index: u16,
     for_outcome: bool,
-    price: f64,
+    price: Price,
 ) -> MarketMatchingPool {
     MarketMatchingPool {
         market: market_pk,
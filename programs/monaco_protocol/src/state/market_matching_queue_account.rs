@@ -1,6 +1,7 @@
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 
+use crate::state::price::Price;
 use std::string::ToString;
 
 #[account]
@@ -100,6 +101,13 @@ impl MatchingQueue {
         }
     }
 
+    // maker matches are always queued ahead of the taker match they fill
+    pub fn peek_taker(&self) -> Option<&OrderMatch> {
+        (0..self.len)
+            .map(|offset| &self.items[((self.front + offset) % self.size()) as usize])
+            .find(|order_match| order_match.pk.is_some())
+    }
+
     pub fn to_vec(&self) -> Vec<OrderMatch> {
         let mut clone = Vec::with_capacity(self.len() as usize);
         for i in 0..self.len() as usize {
@@ -124,7 +132,7 @@ pub struct OrderMatch {
 
     pub for_outcome: bool,
     pub outcome_index: u16,
-    pub price: f64,
+    pub price: Price,
     pub stake: u64,
 }
 
@@ -132,14 +140,14 @@ impl OrderMatch {
     pub const SIZE: usize = option_size(PUB_KEY_SIZE) +  // pk
         BOOL_SIZE + // for_outcome
         U16_SIZE + // outcome_index
-        F64_SIZE + // price
+        Price::SIZE + // price
         U64_SIZE; // stake
 
     pub fn taker(
         pk: Pubkey,
         for_outcome: bool,
         outcome_index: u16,
-        price: f64,
+        price: Price,
         stake: u64,
     ) -> Self {
         OrderMatch {
@@ -151,7 +159,7 @@ impl OrderMatch {
         }
     }
 
-    pub fn maker(for_outcome: bool, outcome_index: u16, price: f64, stake: u64) -> Self {
+    pub fn maker(for_outcome: bool, outcome_index: u16, price: Price, stake: u64) -> Self {
         OrderMatch {
             pk: Option::None,
             for_outcome,
@@ -329,6 +337,31 @@ mod tests_matching_queue {
         assert_eq!(10, queue.peek().unwrap().stake);
     }
 
+    #[test]
+    fn test_peek_taker() {
+        let mut queue = MatchingQueue::new(3);
+        assert!(queue.peek_taker().is_none());
+
+        let taker_pk = Pubkey::new_unique();
+        queue.enqueue(OrderMatch::maker(false, 0, Price::new(2_000), 10));
+        queue.enqueue(OrderMatch::taker(taker_pk, true, 0, Price::new(2_000), 10));
+        assert_eq!(Some(taker_pk), queue.peek_taker().unwrap().pk);
+
+        // wraps around the end of the queue
+        queue.dequeue();
+        queue.dequeue();
+        let taker_pk = Pubkey::new_unique();
+        queue.enqueue(OrderMatch::maker(false, 0, Price::new(2_000), 10));
+        queue.enqueue(OrderMatch::maker(false, 0, Price::new(2_000), 10));
+        queue.enqueue(OrderMatch::taker(taker_pk, true, 0, Price::new(2_000), 10));
+        assert_eq!(Some(taker_pk), queue.peek_taker().unwrap().pk);
+
+        queue.dequeue();
+        queue.dequeue();
+        queue.dequeue();
+        assert!(queue.peek_taker().is_none());
+    }
+
     #[test]
     fn test_clear() {
         let mut queue = MatchingQueue::new(3);
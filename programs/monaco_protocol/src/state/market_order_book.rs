@@ -0,0 +1,249 @@
+use crate::error::CoreError;
+use crate::state::market_account::MarketOrderBehaviour;
+use crate::state::market_outcome_account::MarketOutcome;
+use crate::state::price::Price;
+use crate::state::price_ladder::PriceLadder;
+use crate::state::type_size::*;
+use anchor_lang::prelude::*;
+
+/// Aggregated unmatched stake resting at each price of a page of an outcome's price ladder, so
+/// clients can read the depth of the book without fetching every matching pool
+#[account]
+pub struct MarketOrderBook {
+    pub market: Pubkey,
+    pub market_outcome_index: u16,
+    pub page_index: u16,
+    pub levels: Vec<OrderBookLevel>,
+    pub inplay: bool, // set when the page is first updated after the market moved to inplay
+    pub suspension_timestamp: i64, // last suspension cancelling unmatched orders applied to the page
+}
+
+impl MarketOrderBook {
+    pub const PAGE_LENGTH: usize = 100;
+
+    pub fn size_for(number_of_levels: usize) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUB_KEY_SIZE // market
+            + U16_SIZE // market_outcome_index
+            + U16_SIZE // page_index
+            + vec_size(OrderBookLevel::SIZE, number_of_levels) // levels
+            + BOOL_SIZE // inplay
+            + I64_SIZE // suspension_timestamp
+    }
+
+    pub fn move_to_inplay(&mut self, market_event_start_order_behaviour: &MarketOrderBehaviour) {
+        // unmatched preplay orders no longer rest on the book if cancelled at event start
+        if MarketOrderBehaviour::CancelUnmatched.eq(market_event_start_order_behaviour) {
+            self.clear_liquidity();
+        }
+        self.inplay = true;
+    }
+
+    // unmatched orders cancelled by a suspension since the page was last updated no longer rest on
+    // the book
+    pub fn apply_suspension(&mut self, market_suspension_timestamp: i64) {
+        if self.suspension_timestamp < market_suspension_timestamp {
+            self.clear_liquidity();
+            self.suspension_timestamp = market_suspension_timestamp;
+        }
+    }
+
+    pub fn clear_liquidity(&mut self) {
+        for level in self.levels.iter_mut() {
+            level.liquidity_for = 0;
+            level.liquidity_against = 0;
+        }
+    }
+
+    // prices of the given page of the ladder orders for the outcome are validated against
+    pub fn outcome_page_prices(
+        market_outcome: &MarketOutcome,
+        price_ladder: Option<&PriceLadder>,
+        page_index: u16,
+    ) -> Vec<Price> {
+        MarketOrderBook::page_prices(market_outcome.ladder_prices(price_ladder), page_index)
+    }
+
+    // prices of the given page of the ladder, in ascending order
+    pub fn page_prices(price_ladder: &[Price], page_index: u16) -> Vec<Price> {
+        let mut prices = price_ladder.to_vec();
+        prices.sort();
+        prices.dedup();
+        prices
+            .chunks(MarketOrderBook::PAGE_LENGTH)
+            .nth(page_index as usize)
+            .map(|page| page.to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn contains(&self, market_outcome_index: u16, price: Price) -> bool {
+        self.market_outcome_index == market_outcome_index && self.level_index(price).is_some()
+    }
+
+    pub fn add_liquidity(&mut self, for_outcome: bool, price: Price, stake: u64) -> Result<()> {
+        let index = self
+            .level_index(price)
+            .ok_or(CoreError::OrderBookPriceNotOnPage)?;
+        let level = &mut self.levels[index];
+        let liquidity = match for_outcome {
+            true => &mut level.liquidity_for,
+            false => &mut level.liquidity_against,
+        };
+        *liquidity = liquidity
+            .checked_add(stake)
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(())
+    }
+
+    // never fails on insufficient liquidity, as the book is informational and must not block
+    // order flow
+    pub fn remove_liquidity(&mut self, for_outcome: bool, price: Price, stake: u64) -> Result<()> {
+        let index = self
+            .level_index(price)
+            .ok_or(CoreError::OrderBookPriceNotOnPage)?;
+        let level = &mut self.levels[index];
+        let liquidity = match for_outcome {
+            true => &mut level.liquidity_for,
+            false => &mut level.liquidity_against,
+        };
+        *liquidity = liquidity.saturating_sub(stake);
+        Ok(())
+    }
+
+    fn level_index(&self, price: Price) -> Option<usize> {
+        self.levels
+            .binary_search_by(|level| level.price.cmp(&price))
+            .ok()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OrderBookLevel {
+    pub price: Price,
+    pub liquidity_for: u64,
+    pub liquidity_against: u64,
+}
+
+impl OrderBookLevel {
+    pub const SIZE: usize = Price::SIZE + U64_SIZE * 2;
+
+    pub fn new(price: Price) -> Self {
+        OrderBookLevel {
+            price,
+            ..OrderBookLevel::default()
+        }
+    }
+}
+
+#[cfg(test)]
+pub fn mock_market_order_book(
+    market: Pubkey,
+    market_outcome_index: u16,
+    prices: &[Price],
+) -> MarketOrderBook {
+    MarketOrderBook {
+        market,
+        market_outcome_index,
+        page_index: 0,
+        levels: prices
+            .iter()
+            .map(|price| OrderBookLevel::new(*price))
+            .collect(),
+        inplay: false,
+        suspension_timestamp: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::price_ladder::DEFAULT_PRICES;
+
+    #[test]
+    fn test_page_prices() {
+        let first_page = MarketOrderBook::page_prices(&DEFAULT_PRICES, 0);
+        let last_page = MarketOrderBook::page_prices(&DEFAULT_PRICES, 3);
+
+        assert_eq!(MarketOrderBook::PAGE_LENGTH, first_page.len());
+        assert_eq!(Price::new(1_001), first_page[0]);
+        assert_eq!(17, last_page.len());
+        assert_eq!(Price::new(1_000_000), last_page[16]);
+        assert!(MarketOrderBook::page_prices(&DEFAULT_PRICES, 4).is_empty());
+    }
+
+    #[test]
+    fn test_page_prices_unsorted_ladder() {
+        let prices = MarketOrderBook::page_prices(
+            &[Price::new(3_000), Price::new(2_000), Price::new(3_000)],
+            0,
+        );
+
+        assert_eq!(vec![Price::new(2_000), Price::new(3_000)], prices);
+    }
+
+    #[test]
+    fn test_add_and_remove_liquidity() {
+        let mut order_book = mock_market_order_book(
+            Pubkey::new_unique(),
+            1,
+            &[Price::new(2_000), Price::new(3_000)],
+        );
+
+        order_book
+            .add_liquidity(true, Price::new(3_000), 100)
+            .unwrap();
+        order_book
+            .add_liquidity(false, Price::new(3_000), 50)
+            .unwrap();
+        order_book
+            .remove_liquidity(true, Price::new(3_000), 40)
+            .unwrap();
+        order_book
+            .remove_liquidity(false, Price::new(3_000), 80)
+            .unwrap();
+
+        assert_eq!(OrderBookLevel::new(Price::new(2_000)), order_book.levels[0]);
+        assert_eq!(60, order_book.levels[1].liquidity_for);
+        assert_eq!(0, order_book.levels[1].liquidity_against);
+        assert!(order_book.contains(1, Price::new(2_000)));
+        assert!(!order_book.contains(0, Price::new(2_000)));
+    }
+
+    #[test]
+    fn test_price_not_on_page() {
+        let mut order_book = mock_market_order_book(Pubkey::new_unique(), 0, &[Price::new(2_000)]);
+
+        assert_eq!(
+            Err(error!(CoreError::OrderBookPriceNotOnPage)),
+            order_book.add_liquidity(true, Price::new(2_500), 100)
+        );
+        assert_eq!(
+            Err(error!(CoreError::OrderBookPriceNotOnPage)),
+            order_book.remove_liquidity(false, Price::new(2_500), 100)
+        );
+    }
+
+    #[test]
+    fn test_move_to_inplay() {
+        let prices = [Price::new(2_000), Price::new(3_000)];
+        let mut order_book = mock_market_order_book(Pubkey::new_unique(), 1, &prices);
+        order_book
+            .add_liquidity(true, Price::new(3_000), 100)
+            .unwrap();
+
+        order_book.move_to_inplay(&MarketOrderBehaviour::None);
+
+        assert!(order_book.inplay);
+        assert_eq!(100, order_book.levels[1].liquidity_for);
+
+        let mut order_book = mock_market_order_book(Pubkey::new_unique(), 1, &prices);
+        order_book
+            .add_liquidity(false, Price::new(2_000), 100)
+            .unwrap();
+
+        order_book.move_to_inplay(&MarketOrderBehaviour::CancelUnmatched);
+
+        assert!(order_book.inplay);
+        assert_eq!(OrderBookLevel::new(Price::new(2_000)), order_book.levels[0]);
+    }
+}
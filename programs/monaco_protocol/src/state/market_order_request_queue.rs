@@ -1,3 +1,4 @@
+use crate::state::price::Price;
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 use std::string::ToString;
@@ -23,12 +24,14 @@ pub struct OrderRequest {
     pub for_outcome: bool, // is order for or against the outcome
     pub product: Option<Pubkey>, // product this order was placed on
     pub stake: u64,        // total stake amount provided by purchaser
-    pub expected_price: f64, // expected price provided by purchaser
+    pub expected_price: Price, // expected price provided by purchaser
     pub delay_expiration_timestamp: i64,
     pub product_commission_rate: f64, // product commission rate at time of order creation
     pub distinct_seed: [u8; 16],      // used as a seed for generating a unique order pda
     pub creation_timestamp: i64,      // timestamp when request was created
     pub expires_on: Option<i64>,      // timestamp when request is supposed to expire if set
+    pub time_in_force: TimeInForce,   // what happens to the stake not matched on creation
+    pub post_only: bool,              // order is rejected if it would match on creation
 }
 
 impl OrderRequest {
@@ -37,11 +40,14 @@ impl OrderRequest {
     + BOOL_SIZE // for outcome
     + option_size(PUB_KEY_SIZE) // product
     + U64_SIZE // stake
-    + (F64_SIZE * 2) // expected_price & product_commission_rate
+    + Price::SIZE // expected_price
+    + F64_SIZE // product_commission_rate
     + I64_SIZE // delay_expiration_timestamp
     + U128_SIZE // distinct_seed
     + I64_SIZE // creation_timestamp
-    + option_size(I64_SIZE); // expire_on
+    + option_size(I64_SIZE) // expire_on
+    + ENUM_SIZE // time_in_force
+    + BOOL_SIZE; // post_only
 
     pub fn new_unique() -> Self {
         OrderRequest {
@@ -51,11 +57,13 @@ impl OrderRequest {
             delay_expiration_timestamp: 0,
             stake: 0,
             product: None,
-            expected_price: 0.0,
+            expected_price: Price::default(),
             product_commission_rate: 0.0,
             distinct_seed: [0; 16],
             creation_timestamp: 0,
             expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
         }
     }
 }
@@ -65,9 +73,19 @@ pub struct OrderRequestData {
     pub market_outcome_index: u16,
     pub for_outcome: bool,
     pub stake: u64,
-    pub price: f64,
+    pub price: Price,
     pub distinct_seed: [u8; 16],
     pub expires_on: Option<i64>,
+    pub time_in_force: TimeInForce,
+    pub post_only: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeInForce {
+    #[default]
+    GoodTillCancelled, // unmatched stake stays on the book
+    ImmediateOrCancel, // unmatched stake is voided after the initial match
+    FillOrKill,        // request is rejected unless the full stake can be matched on creation
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
@@ -199,7 +217,7 @@ pub fn mock_order_request(
     for_outcome: bool,
     outcome: u16,
     stake: u64,
-    price: f64,
+    price: Price,
 ) -> OrderRequest {
     OrderRequest {
         purchaser,
@@ -213,12 +231,15 @@ pub fn mock_order_request(
         distinct_seed: [0; 16],
         creation_timestamp: 0,
         expires_on: None,
+        time_in_force: TimeInForce::GoodTillCancelled,
+        post_only: false,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::state::market_order_request_queue::{OrderRequest, OrderRequestQueue};
+    use crate::state::market_order_request_queue::{OrderRequest, OrderRequestQueue, TimeInForce};
+    use crate::state::price::Price;
     use solana_program::pubkey::Pubkey;
 
     //
@@ -377,10 +398,12 @@ mod tests {
             delay_expiration_timestamp: 0,
             stake: 0,
             product: None,
-            expected_price: 0.0,
+            expected_price: Price::new(0),
             product_commission_rate: 0.0,
             creation_timestamp: 0,
             expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
         };
 
         let request_2 = OrderRequest {
@@ -393,10 +416,12 @@ mod tests {
             delay_expiration_timestamp: 0,
             stake: 0,
             product: None,
-            expected_price: 0.0,
+            expected_price: Price::new(0),
             product_commission_rate: 0.0,
             creation_timestamp: 0,
             expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
         };
         assert_eq!(request_1, request_2);
 
@@ -410,10 +435,12 @@ mod tests {
             delay_expiration_timestamp: 0,
             stake: 0,
             product: None,
-            expected_price: 0.0,
+            expected_price: Price::new(0),
             product_commission_rate: 0.0,
             creation_timestamp: 0,
             expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
         };
         assert_ne!(request_1, request_3);
     }
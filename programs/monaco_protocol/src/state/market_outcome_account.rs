@@ -1,3 +1,5 @@
+use crate::state::price::Price;
+use crate::state::price_ladder::{PriceLadder, DEFAULT_PRICES};
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 use std::string::ToString;
@@ -8,7 +10,7 @@ pub struct MarketOutcome {
     pub index: u16,
     pub title: String,
     pub prices: Option<Pubkey>,
-    pub price_ladder: Vec<f64>,
+    pub price_ladder: Vec<Price>,
 }
 
 impl MarketOutcome {
@@ -20,7 +22,17 @@ impl MarketOutcome {
         + U16_SIZE // index
         + vec_size(CHAR_SIZE, MarketOutcome::TITLE_MAX_LENGTH) // title
         + option_size(PUB_KEY_SIZE) // price ladder account
-        + vec_size(F64_SIZE, MarketOutcome::PRICE_LADDER_LENGTH); // price_ladder
+        + vec_size(Price::SIZE, MarketOutcome::PRICE_LADDER_LENGTH); // price_ladder
+
+    // prices orders for the outcome are validated against, the outcome's own prices take
+    // precedence over its price ladder account, an empty price ladder account accepts any price
+    pub fn ladder_prices<'a>(&'a self, price_ladder: Option<&'a PriceLadder>) -> &'a [Price] {
+        match (self.price_ladder.is_empty(), price_ladder) {
+            (false, _) => &self.price_ladder,
+            (true, Some(price_ladder)) => &price_ladder.prices,
+            (true, None) => &DEFAULT_PRICES,
+        }
+    }
 }
 
 #[cfg(test)]
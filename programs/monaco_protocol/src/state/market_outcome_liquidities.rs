@@ -0,0 +1,146 @@
+use crate::state::market_account::MarketOrderBehaviour;
+use crate::state::market_liquidities::MarketOutcomePriceLiquidity;
+use crate::state::type_size::*;
+use anchor_lang::prelude::*;
+
+/// Liquidities of a single outcome, kept apart from the market's `MarketLiquidities` so the number
+/// of prices a market can hold liquidity at grows with its number of outcomes
+#[account]
+pub struct MarketOutcomeLiquidities {
+    pub market: Pubkey,
+    pub market_outcome_index: u16,
+    pub payer: Pubkey,
+    pub inplay: bool,
+    pub liquidities_for: Vec<MarketOutcomePriceLiquidity>,
+    pub liquidities_against: Vec<MarketOutcomePriceLiquidity>,
+}
+
+impl MarketOutcomeLiquidities {
+    // space allocated beyond what is needed, so the account is not resized on every new price
+    pub const SIZE_INCREMENT: usize = 10 * MarketOutcomePriceLiquidity::SIZE;
+
+    pub fn new(market: Pubkey, market_outcome_index: u16, payer: Pubkey, inplay: bool) -> Self {
+        MarketOutcomeLiquidities {
+            market,
+            market_outcome_index,
+            payer,
+            inplay,
+            liquidities_for: Vec::new(),
+            liquidities_against: Vec::new(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUB_KEY_SIZE // market
+            + U16_SIZE // market_outcome_index
+            + PUB_KEY_SIZE // payer
+            + BOOL_SIZE // inplay
+            + MarketOutcomePriceLiquidity::liquidities_size(&self.liquidities_for)
+            + MarketOutcomePriceLiquidity::liquidities_size(&self.liquidities_against)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.liquidities_for.is_empty() && self.liquidities_against.is_empty()
+    }
+
+    pub fn move_to_inplay(&mut self, market_event_start_order_behaviour: &MarketOrderBehaviour) {
+        self.inplay = true;
+
+        if market_event_start_order_behaviour.eq(&MarketOrderBehaviour::CancelUnmatched) {
+            self.liquidities_for = Vec::new();
+            self.liquidities_against = Vec::new();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::market_liquidities::{mock_liquidity, mock_market_liquidities};
+    use crate::state::price::Price;
+
+    #[test]
+    fn test_store_and_load_outcome_liquidities() {
+        let market_pk = Pubkey::new_unique();
+        let mut market_liquidities = mock_market_liquidities(market_pk);
+        market_liquidities
+            .add_liquidity_for(0, Price::new(2_000), 100)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_for(1, Price::new(3_000), 200)
+            .unwrap();
+        market_liquidities
+            .add_liquidity_against(1, Price::new(2_500), 300)
+            .unwrap();
+
+        let mut outcome_liquidities =
+            MarketOutcomeLiquidities::new(market_pk, 1, Pubkey::new_unique(), false);
+        market_liquidities.store_outcome_liquidities(&mut outcome_liquidities);
+
+        assert_eq!(
+            vec![mock_liquidity(0, Price::new(2_000), 100)],
+            market_liquidities.liquidities_for
+        );
+        assert!(market_liquidities.liquidities_against.is_empty());
+        assert_eq!(
+            vec![mock_liquidity(1, Price::new(3_000), 200)],
+            outcome_liquidities.liquidities_for
+        );
+        assert_eq!(
+            vec![mock_liquidity(1, Price::new(2_500), 300)],
+            outcome_liquidities.liquidities_against
+        );
+
+        // liquidity added while the outcome account was not loaded is merged on load
+        market_liquidities
+            .add_liquidity_for(1, Price::new(3_000), 50)
+            .unwrap();
+        market_liquidities
+            .load_outcome_liquidities(&mut outcome_liquidities)
+            .unwrap();
+
+        assert!(outcome_liquidities.is_empty());
+        assert_eq!(
+            vec![
+                mock_liquidity(0, Price::new(2_000), 100),
+                mock_liquidity(1, Price::new(3_000), 250),
+            ],
+            market_liquidities.liquidities_for
+        );
+        assert_eq!(
+            vec![mock_liquidity(1, Price::new(2_500), 300)],
+            market_liquidities.liquidities_against
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        let mut outcome_liquidities =
+            MarketOutcomeLiquidities::new(Pubkey::new_unique(), 0, Pubkey::new_unique(), false);
+        outcome_liquidities
+            .liquidities_for
+            .push(mock_liquidity(0, Price::new(2_000), 100));
+
+        let mut data = Vec::new();
+        outcome_liquidities.try_serialize(&mut data).unwrap();
+
+        assert_eq!(data.len(), outcome_liquidities.size());
+    }
+
+    #[test]
+    fn test_move_to_inplay() {
+        let mut outcome_liquidities =
+            MarketOutcomeLiquidities::new(Pubkey::new_unique(), 0, Pubkey::new_unique(), false);
+        outcome_liquidities
+            .liquidities_for
+            .push(mock_liquidity(0, Price::new(2_000), 100));
+
+        outcome_liquidities.move_to_inplay(&MarketOrderBehaviour::None);
+        assert!(outcome_liquidities.inplay);
+        assert!(!outcome_liquidities.is_empty());
+
+        outcome_liquidities.move_to_inplay(&MarketOrderBehaviour::CancelUnmatched);
+        assert!(outcome_liquidities.is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::error::CoreError;
+use crate::state::market_account::WithdrawnOutcome;
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 use std::convert::TryFrom;
@@ -13,6 +15,17 @@ pub struct MarketPosition {
     pub payer: Pubkey, // solana account fee payer
     pub matched_risk: u64,
     pub matched_risk_per_product: Vec<ProductMatchedRiskAndRate>,
+    pub winning_places: u16,
+    // sum and unmatched exposure for the case of none of the outcomes winning, only tracked for
+    // markets with more than one winning place
+    pub no_winner_outcome_sum: i128,
+    pub no_winner_unmatched_exposure: u64,
+    pub schema_version: u8, // layout version, see state::migration
+    // net matched stake per outcome and winnings deductions per outcome scaled by
+    // WithdrawnOutcome::REDUCTION_FACTOR_SCALE, tracked for reductions on outcome withdrawal
+    pub market_outcome_stakes: Vec<i128>,
+    pub market_outcome_deductions: Vec<i128>,
+    pub outcome_withdrawals_applied: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
@@ -28,6 +41,8 @@ impl ProductMatchedRiskAndRate {
 }
 
 impl MarketPosition {
+    pub const SCHEMA_VERSION: u8 = 2;
+
     pub fn size_for(number_of_market_outcomes: usize) -> usize {
         DISCRIMINATOR_SIZE
             + PUB_KEY_SIZE // purchaser
@@ -37,11 +52,94 @@ impl MarketPosition {
             + vec_size(I128_SIZE, number_of_market_outcomes) // market_outcome_sums
             + vec_size(U64_SIZE, number_of_market_outcomes) // unmatched_exposures
             + PUB_KEY_SIZE // payer
-            + vec_size(ProductMatchedRiskAndRate::SIZE, ProductMatchedRiskAndRate::MAX_LENGTH)
-        // number of products to track matched stake contributions for
+            + vec_size(ProductMatchedRiskAndRate::SIZE, ProductMatchedRiskAndRate::MAX_LENGTH) // number of products to track matched stake contributions for
+            + U16_SIZE // winning_places
+            + I128_SIZE // no_winner_outcome_sum
+            + U64_SIZE // no_winner_unmatched_exposure
+            + U8_SIZE // schema_version
+            + vec_size(I128_SIZE, number_of_market_outcomes) // market_outcome_stakes
+            + vec_size(I128_SIZE, number_of_market_outcomes) // market_outcome_deductions
+            + U8_SIZE // outcome_withdrawals_applied
+    }
+
+    // positions migrated with matched stake have no stake tracking, so can't be reduced
+    fn track_outcome_stakes(&mut self) -> bool {
+        if self.market_outcome_stakes.is_empty() {
+            let matched =
+                self.matched_risk > 0 || self.market_outcome_sums.iter().any(|sum| *sum != 0_i128);
+            if matched {
+                return false;
+            }
+            let market_outcomes_len = self.market_outcome_sums.len();
+            self.market_outcome_stakes
+                .resize(market_outcomes_len, 0_i128);
+            self.market_outcome_deductions
+                .resize(market_outcomes_len, 0_i128);
+        }
+        true
+    }
+
+    // snapshot of winnings on each outcome for withdrawals made since the position was last
+    // updated, so reductions only apply to trades matched before the withdrawal
+    pub fn apply_outcome_withdrawals(
+        &mut self,
+        withdrawn_outcomes: &[WithdrawnOutcome],
+    ) -> Result<()> {
+        let applied = usize::from(self.outcome_withdrawals_applied);
+        let pending = withdrawn_outcomes.get(applied..).unwrap_or_default();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        if !self.track_outcome_stakes() {
+            // stakes matched before migration are unknown per outcome, so the position is
+            // settled without reductions
+            self.outcome_withdrawals_applied =
+                u8::try_from(withdrawn_outcomes.len()).map_err(|_| CoreError::ArithmeticError)?;
+            return Ok(());
+        }
+
+        let reduction_factor = pending.iter().try_fold(0_i128, |total, withdrawn_outcome| {
+            total.checked_add(i128::from(withdrawn_outcome.reduction_factor))
+        });
+        let total_stake = self
+            .market_outcome_stakes
+            .iter()
+            .try_fold(0_i128, |total, stake| total.checked_add(*stake));
+        let (reduction_factor, total_stake) = reduction_factor
+            .zip(total_stake)
+            .ok_or(CoreError::ArithmeticError)?;
+
+        for index in 0..self.market_outcome_sums.len() {
+            // winnings if the outcome wins, the sum without the stakes lost on the other outcomes
+            let deduction = total_stake
+                .checked_sub(self.market_outcome_stakes[index])
+                .and_then(|stake_lost| self.market_outcome_sums[index].checked_add(stake_lost))
+                .and_then(|winnings| winnings.checked_mul(reduction_factor))
+                .and_then(|deduction| self.market_outcome_deductions[index].checked_add(deduction))
+                .ok_or(CoreError::ArithmeticError)?;
+            self.market_outcome_deductions[index] = deduction;
+        }
+
+        self.outcome_withdrawals_applied =
+            u8::try_from(withdrawn_outcomes.len()).map_err(|_| CoreError::ArithmeticError)?;
+        Ok(())
+    }
+
+    pub fn update_outcome_stake(&mut self, outcome_index: usize, stake: i128) -> Result<()> {
+        if !self.track_outcome_stakes() {
+            return Ok(());
+        }
+        self.market_outcome_stakes[outcome_index] = self.market_outcome_stakes[outcome_index]
+            .checked_add(stake)
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(())
     }
 
     pub fn total_exposure(&self) -> u64 {
+        if self.winning_places > 1 {
+            return self.total_exposure_for_winning_places();
+        }
+
         self.market_outcome_sums
             .iter()
             .map(|market_outcome_sum| {
@@ -54,6 +152,37 @@ impl MarketPosition {
             .max_by(|x, y| x.cmp(y))
             .unwrap()
     }
+
+    // exposure is linear in the set of winning outcomes, so the worst case is found by taking the
+    // winning_places outcomes increasing exposure the most over the no winner case
+    fn total_exposure_for_winning_places(&self) -> u64 {
+        let unmatched_base = i128::from(self.no_winner_unmatched_exposure);
+        let postmatch_base = self.no_winner_outcome_sum;
+
+        let unmatched_deltas: Vec<i128> = self
+            .unmatched_exposures
+            .iter()
+            .map(|unmatched_exposure| i128::from(*unmatched_exposure) - unmatched_base)
+            .collect();
+        let total_deltas: Vec<i128> = unmatched_deltas
+            .iter()
+            .zip(&self.market_outcome_sums)
+            .map(|(unmatched_delta, market_outcome_sum)| {
+                unmatched_delta - (market_outcome_sum - postmatch_base)
+            })
+            .collect();
+
+        let places = usize::from(self.winning_places);
+        let unmatched_exposure = unmatched_base + sum_of_largest(unmatched_deltas, places);
+        let total_exposure = unmatched_base - postmatch_base + sum_of_largest(total_deltas, places);
+
+        u64::try_from(unmatched_exposure.max(total_exposure).max(0_i128)).unwrap()
+    }
+}
+
+fn sum_of_largest(mut values: Vec<i128>, count: usize) -> i128 {
+    values.sort_unstable_by(|a, b| b.cmp(a));
+    values.iter().take(count).sum()
 }
 
 #[cfg(test)]
@@ -66,6 +195,12 @@ pub fn mock_market_position(number_of_market_outcomes: usize) -> MarketPosition
         .unmatched_exposures
         .resize(number_of_market_outcomes, 0_u64);
     market_position
+        .market_outcome_stakes
+        .resize(number_of_market_outcomes, 0_i128);
+    market_position
+        .market_outcome_deductions
+        .resize(number_of_market_outcomes, 0_i128);
+    market_position
 }
 
 #[cfg(test)]
@@ -123,4 +258,132 @@ mod total_exposure_tests {
 
         market_position.total_exposure();
     }
+
+    #[test]
+    fn test_winning_places_only_unmatched_exposures() {
+        let market_position = MarketPosition {
+            winning_places: 2,
+            unmatched_exposures: vec![0, 10, 10], // for 10 @ 3.0 on outcome 0
+            no_winner_unmatched_exposure: 10,
+            market_outcome_sums: vec![0, 0, 0],
+            ..MarketPosition::default()
+        };
+
+        assert_eq!(10, market_position.total_exposure());
+    }
+
+    #[test]
+    fn test_winning_places_only_postmatch_exposures() {
+        let market_position = MarketPosition {
+            winning_places: 2,
+            unmatched_exposures: vec![0, 0, 0],
+            market_outcome_sums: vec![20, -10, -10], // match of 10 @ 3.0
+            no_winner_outcome_sum: -10,
+            ..MarketPosition::default()
+        };
+
+        assert_eq!(10, market_position.total_exposure());
+    }
+
+    #[test]
+    fn test_winning_places_against_multiple_outcomes() {
+        let mut market_position = MarketPosition {
+            winning_places: 1,
+            unmatched_exposures: vec![0, 0, 0],
+            // against matches of 10 @ 2.0 on outcomes 0 and 1
+            market_outcome_sums: vec![0, 0, 20],
+            no_winner_outcome_sum: 20,
+            ..MarketPosition::default()
+        };
+        assert_eq!(0, market_position.total_exposure());
+
+        market_position.winning_places = 2;
+        assert_eq!(20, market_position.total_exposure());
+    }
+
+    #[test]
+    fn test_winning_places_both_exposures() {
+        let market_position = MarketPosition {
+            winning_places: 2,
+            // against match of 10 @ 2.0 on outcome 0 and unmatched against 10 @ 3.0 on outcome 1
+            unmatched_exposures: vec![0, 20, 0],
+            market_outcome_sums: vec![-10, 10, 10],
+            no_winner_outcome_sum: 10,
+            ..MarketPosition::default()
+        };
+
+        assert_eq!(30, market_position.total_exposure());
+    }
+}
+
+#[cfg(test)]
+mod outcome_withdrawals_tests {
+    use super::*;
+
+    fn withdrawn_outcome(outcome_index: u16, reduction_factor: u16) -> WithdrawnOutcome {
+        WithdrawnOutcome {
+            outcome_index,
+            reduction_factor,
+            withdrawal_timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_apply_outcome_withdrawals() {
+        let mut market_position = mock_market_position(3);
+        // for 10 @ 3.0 on outcome 0 and against 10 @ 2.0 on outcome 1
+        market_position.market_outcome_sums = vec![30, -20, 0];
+        market_position.market_outcome_stakes = vec![10, -10, 0];
+        let withdrawn_outcomes = vec![withdrawn_outcome(2, 1_000), withdrawn_outcome(1, 500)];
+
+        market_position
+            .apply_outcome_withdrawals(&withdrawn_outcomes[..1])
+            .unwrap();
+        assert_eq!(
+            vec![20 * 1_000, -10 * 1_000, 0],
+            market_position.market_outcome_deductions
+        );
+
+        market_position
+            .apply_outcome_withdrawals(&withdrawn_outcomes)
+            .unwrap();
+        assert_eq!(
+            vec![20 * 1_500, -10 * 1_500, 0],
+            market_position.market_outcome_deductions
+        );
+        assert_eq!(2, market_position.outcome_withdrawals_applied);
+
+        // already applied
+        market_position
+            .apply_outcome_withdrawals(&withdrawn_outcomes)
+            .unwrap();
+        assert_eq!(
+            vec![20 * 1_500, -10 * 1_500, 0],
+            market_position.market_outcome_deductions
+        );
+    }
+
+    #[test]
+    fn test_apply_outcome_withdrawals_stakes_not_tracked() {
+        let withdrawn_outcomes = vec![withdrawn_outcome(2, 1_000)];
+
+        let mut market_position = mock_market_position(3);
+        market_position.market_outcome_stakes = vec![];
+        market_position.market_outcome_deductions = vec![];
+        assert!(market_position
+            .apply_outcome_withdrawals(&withdrawn_outcomes)
+            .is_ok());
+        assert_eq!(vec![0, 0, 0], market_position.market_outcome_stakes);
+
+        let mut market_position = mock_market_position(3);
+        market_position.market_outcome_sums = vec![20, -10, -10];
+        market_position.market_outcome_stakes = vec![];
+        market_position.market_outcome_deductions = vec![];
+        assert!(market_position
+            .apply_outcome_withdrawals(&withdrawn_outcomes)
+            .is_ok());
+        assert!(market_position.market_outcome_stakes.is_empty());
+        assert!(market_position.market_outcome_deductions.is_empty());
+        assert_eq!(1, market_position.outcome_withdrawals_applied);
+    }
 }
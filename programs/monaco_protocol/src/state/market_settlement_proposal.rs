@@ -0,0 +1,157 @@
+use crate::error::CoreError;
+use crate::state::market_account::{Market, MarketWinningOutcome};
+use crate::state::operator_account::OperatorPermission;
+use crate::state::type_size::*;
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+/// Settlement or force void of a market proposed by an operator, executable once approved by the
+/// number of operators the market requires and its challenge period has passed
+#[account]
+pub struct MarketSettlementProposal {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub action: SettlementProposalAction,
+    pub winning_outcomes: Vec<MarketWinningOutcome>,
+    pub approvals: Vec<Pubkey>,
+    pub approvals_required: u8,
+    pub challenge_period: i64,
+    pub proposed_timestamp: UnixTimestamp,
+    pub approved_timestamp: Option<UnixTimestamp>,
+}
+
+impl MarketSettlementProposal {
+    pub const APPROVALS_MAX_LENGTH: usize = 16;
+    pub const CHALLENGE_PERIOD_MAX: i64 = 7 * 24 * 60 * 60; // one week
+
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + PUB_KEY_SIZE * 2 // market and proposer
+        + ENUM_SIZE // action
+        + vec_size(MarketWinningOutcome::SIZE, Market::WINNING_OUTCOMES_MAX_LENGTH) // winning_outcomes
+        + vec_size(PUB_KEY_SIZE, MarketSettlementProposal::APPROVALS_MAX_LENGTH) // approvals
+        + U8_SIZE // approvals_required
+        + I64_SIZE * 2 // challenge_period and proposed_timestamp
+        + option_size(I64_SIZE); // approved_timestamp
+
+    pub fn approve(&mut self, operator: Pubkey, now: UnixTimestamp) -> Result<()> {
+        require!(
+            !self.approvals.contains(&operator),
+            CoreError::SettlementProposalAlreadyApproved
+        );
+        require!(
+            self.approvals.len() < MarketSettlementProposal::APPROVALS_MAX_LENGTH,
+            CoreError::SettlementProposalApprovalsFull
+        );
+        self.approvals.push(operator);
+
+        // the challenge period runs from the approval reaching the threshold
+        if self.approved_timestamp.is_none()
+            && self.approvals.len() >= usize::from(self.approvals_required)
+        {
+            self.approved_timestamp = Some(now);
+        }
+        Ok(())
+    }
+
+    pub fn validate_executable(&self, now: UnixTimestamp) -> Result<()> {
+        let approved_timestamp = self
+            .approved_timestamp
+            .ok_or(CoreError::SettlementProposalNotApproved)?;
+        let challenge_period_end = approved_timestamp
+            .checked_add(self.challenge_period)
+            .ok_or(CoreError::ArithmeticError)?;
+        require!(
+            now >= challenge_period_end,
+            CoreError::SettlementProposalChallengePeriodActive
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettlementProposalAction {
+    Settle,
+    ForceVoid,
+}
+
+impl SettlementProposalAction {
+    // permission operators need to propose, approve or challenge the action
+    pub fn permission(&self) -> u16 {
+        match self {
+            SettlementProposalAction::Settle => OperatorPermission::SETTLE,
+            SettlementProposalAction::ForceVoid => OperatorPermission::VOID,
+        }
+    }
+}
+
+#[cfg(test)]
+pub fn mock_market_settlement_proposal(
+    market: Pubkey,
+    action: SettlementProposalAction,
+    approvals_required: u8,
+    challenge_period: i64,
+) -> MarketSettlementProposal {
+    MarketSettlementProposal {
+        market,
+        proposer: Pubkey::new_unique(),
+        action,
+        winning_outcomes: vec![],
+        approvals: vec![],
+        approvals_required,
+        challenge_period,
+        proposed_timestamp: 0,
+        approved_timestamp: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approve() {
+        let operator_1 = Pubkey::new_unique();
+        let operator_2 = Pubkey::new_unique();
+        let mut proposal = mock_market_settlement_proposal(
+            Pubkey::new_unique(),
+            SettlementProposalAction::Settle,
+            2,
+            0,
+        );
+
+        assert!(proposal.approve(operator_1, 10).is_ok());
+        assert_eq!(None, proposal.approved_timestamp);
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalAlreadyApproved)),
+            proposal.approve(operator_1, 20)
+        );
+
+        assert!(proposal.approve(operator_2, 30).is_ok());
+        assert_eq!(Some(30), proposal.approved_timestamp);
+
+        // approvals past the threshold don't restart the challenge period
+        assert!(proposal.approve(Pubkey::new_unique(), 40).is_ok());
+        assert_eq!(Some(30), proposal.approved_timestamp);
+    }
+
+    #[test]
+    fn test_validate_executable() {
+        let mut proposal = mock_market_settlement_proposal(
+            Pubkey::new_unique(),
+            SettlementProposalAction::ForceVoid,
+            1,
+            100,
+        );
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalNotApproved)),
+            proposal.validate_executable(1_000)
+        );
+
+        proposal.approve(Pubkey::new_unique(), 1_000).unwrap();
+        assert_eq!(
+            Err(error!(CoreError::SettlementProposalChallengePeriodActive)),
+            proposal.validate_executable(1_099)
+        );
+        assert!(proposal.validate_executable(1_100).is_ok());
+    }
+}
@@ -1,17 +1,146 @@
-use crate::state::type_size::{string_size, BOOL_SIZE, DISCRIMINATOR_SIZE};
+use crate::error::CoreError;
+use crate::state::market_account::OutcomeResult;
+use crate::state::type_size::{string_size, BOOL_SIZE, DISCRIMINATOR_SIZE, ENUM_SIZE, U8_SIZE};
 use anchor_lang::prelude::*;
+use rust_decimal::prelude::*;
 
 #[account]
 pub struct MarketType {
     pub name: String,
     pub requires_discriminator: bool,
     pub requires_value: bool,
+    pub template: MarketTemplate,
+    pub schema_version: u8, // layout version, see state::migration
 }
 
 impl MarketType {
     pub const NAME_MAX_LENGTH: usize = 32;
+    pub const SCHEMA_VERSION: u8 = 1;
 
     pub fn size_for(str_len: usize) -> usize {
-        DISCRIMINATOR_SIZE + string_size(str_len) + BOOL_SIZE * 2
+        DISCRIMINATOR_SIZE
+            + string_size(str_len) // name
+            + BOOL_SIZE * 2 // requires_discriminator + requires_value
+            + ENUM_SIZE // template
+            + U8_SIZE // schema_version
+    }
+}
+
+/// Settlement template of markets of a type; handicap and total markets read their line from the
+/// market type value and are settled with a result per outcome
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarketTemplate {
+    Standard,
+    Handicap,
+    Total,
+}
+
+/// Handicap or total line in quarter units, e.g., a line of -0.75 is -3 quarters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MarketLine {
+    quarters: i64,
+}
+
+impl MarketLine {
+    // first outcome is the side the line applies to (home or over), second the other side
+    pub const OUTCOMES_COUNT: u16 = 2;
+
+    pub fn parse(template: MarketTemplate, value: &str) -> Result<MarketLine> {
+        require!(
+            template != MarketTemplate::Standard,
+            CoreError::MarketTypeValueInvalidLine
+        );
+        let line = Decimal::from_str(value).map_err(|_| CoreError::MarketTypeValueInvalidLine)?;
+        let quarters = line
+            .checked_mul(Decimal::from(4))
+            .filter(|quarters| quarters.fract().is_zero())
+            .and_then(|quarters| quarters.to_i64())
+            .ok_or(CoreError::MarketTypeValueInvalidLine)?;
+        require!(
+            template != MarketTemplate::Total || quarters >= 0,
+            CoreError::MarketTypeValueInvalidLine
+        );
+        Ok(MarketLine { quarters })
+    }
+
+    // whole lines can push, half lines cannot, and quarter lines split the stake over the two
+    // neighbouring lines so half of it can be won or lost with the other half pushed
+    pub fn allows_result(&self, result: OutcomeResult) -> bool {
+        match self.quarters.rem_euclid(4) {
+            0 => matches!(
+                result,
+                OutcomeResult::Win | OutcomeResult::Push | OutcomeResult::Loss
+            ),
+            2 => matches!(result, OutcomeResult::Win | OutcomeResult::Loss),
+            _ => matches!(
+                result,
+                OutcomeResult::Win
+                    | OutcomeResult::HalfWin
+                    | OutcomeResult::HalfLoss
+                    | OutcomeResult::Loss
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        for (value, quarters) in [
+            ("0", 0),
+            ("-0.75", -3),
+            ("+1.25", 5),
+            ("2.5", 10),
+            ("-1", -4),
+        ] {
+            assert_eq!(
+                MarketLine { quarters },
+                MarketLine::parse(MarketTemplate::Handicap, value).unwrap()
+            );
+        }
+        assert_eq!(
+            MarketLine { quarters: 10 },
+            MarketLine::parse(MarketTemplate::Total, "2.5").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_line_invalid() {
+        for (template, value) in [
+            (MarketTemplate::Handicap, "0.1"),
+            (MarketTemplate::Handicap, "-0.3"),
+            (MarketTemplate::Handicap, "one"),
+            (MarketTemplate::Handicap, ""),
+            (MarketTemplate::Total, "-2.5"),
+            (MarketTemplate::Standard, "2.5"),
+        ] {
+            assert_eq!(
+                Err(error!(CoreError::MarketTypeValueInvalidLine)),
+                MarketLine::parse(template, value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_allows_result() {
+        let whole = MarketLine::parse(MarketTemplate::Handicap, "-1").unwrap();
+        assert!(whole.allows_result(OutcomeResult::Push));
+        assert!(!whole.allows_result(OutcomeResult::HalfWin));
+
+        let half = MarketLine::parse(MarketTemplate::Total, "2.5").unwrap();
+        assert!(half.allows_result(OutcomeResult::Win));
+        assert!(half.allows_result(OutcomeResult::Loss));
+        assert!(!half.allows_result(OutcomeResult::Push));
+        assert!(!half.allows_result(OutcomeResult::HalfLoss));
+
+        for value in ["-0.75", "0.25", "2.75"] {
+            let quarter = MarketLine::parse(MarketTemplate::Handicap, value).unwrap();
+            assert!(quarter.allows_result(OutcomeResult::HalfWin));
+            assert!(quarter.allows_result(OutcomeResult::HalfLoss));
+            assert!(!quarter.allows_result(OutcomeResult::Push));
+        }
     }
 }
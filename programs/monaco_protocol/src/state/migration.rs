@@ -0,0 +1,1353 @@
+use crate::error::CoreError;
+use crate::state::market_account::*;
+use crate::state::market_position_account::*;
+use crate::state::market_type::*;
+use crate::state::operator_account::*;
+use crate::state::order_account::*;
+use crate::state::price::Price;
+use crate::state::trade_account::*;
+use crate::state::type_size::*;
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+// Prior account layouts, kept so that accounts created before a layout change can be rewritten in
+// place by the migrate_account instruction. Layouts are recognised by their schema_version, only
+// the version 0 layouts predating it are recognised by their account size. Accounts are
+// deserialized with the layout of their version only, as String and Vec fields that shrank leave
+// stale bytes behind that must not be read as later fields.
+
+/*
+ * Market
+ */
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketV0 {
+    pub authority: Pubkey,
+    pub event_account: Pubkey,
+    pub mint_account: Pubkey,
+    pub market_status: MarketStatus,
+    pub inplay_enabled: bool,
+    pub inplay: bool,
+    pub market_type: Pubkey,
+    pub market_type_discriminator: Option<String>,
+    pub market_type_value: Option<String>,
+    pub version: u8,
+    pub decimal_limit: u8,
+    pub published: bool,
+    pub suspended: bool,
+    pub market_outcomes_count: u16,
+    pub market_winning_outcome_index: Option<u16>,
+    pub market_lock_timestamp: i64,
+    pub market_settle_timestamp: Option<i64>,
+    pub event_start_order_behaviour: MarketOrderBehaviour,
+    pub market_lock_order_behaviour: MarketOrderBehaviour,
+    pub inplay_order_delay: u8,
+    pub title: String,
+    pub unsettled_accounts_count: u32,
+    pub unclosed_accounts_count: u32,
+    pub escrow_account_bump: u8,
+    pub funding_account_bump: u8,
+    pub event_start_timestamp: i64,
+}
+
+impl MarketV0 {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + (PUB_KEY_SIZE * 3) // authority, event and mint
+        + U8_SIZE // decimal_limit
+        + ENUM_SIZE // market_status
+        + BOOL_SIZE // inplay_enabled
+        + BOOL_SIZE // inplay
+        + PUB_KEY_SIZE // market_type
+        + option_size(string_size(Market::TYPE_FIELD_MAX_LENGTH)) // market_type disc.
+        + option_size(string_size(Market::TYPE_FIELD_MAX_LENGTH)) // market_type value
+        + U8_SIZE // version
+        + BOOL_SIZE * 2 // published + suspended
+        + U16_SIZE // market_outcomes_count
+        + option_size(U16_SIZE) // market_winning_outcome_index
+        + I64_SIZE // market_lock_timestamp
+        + option_size(I64_SIZE) // market_settle_timestamp
+        + ENUM_SIZE * 2 // event_start and market_lock _order_behaviour
+        + U8_SIZE // inplay_order_delay
+        + vec_size(CHAR_SIZE, Market::TITLE_MAX_LENGTH) // title
+        + U8_SIZE * 2// bumps
+        + I64_SIZE // event_start_timestamp
+        + U32_SIZE * 2; // unsettled_accounts + unclosed_accounts
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketV1 {
+    pub authority: Pubkey,
+    pub event_account: Pubkey,
+    pub mint_account: Pubkey,
+    pub market_status: MarketStatus,
+    pub inplay_enabled: bool,
+    pub inplay: bool,
+    pub market_type: Pubkey,
+    pub market_type_discriminator: Option<String>,
+    pub market_type_value: Option<String>,
+    pub version: u8,
+    pub decimal_limit: u8,
+    pub published: bool,
+    pub suspended: bool,
+    pub market_outcomes_count: u16,
+    pub market_winning_places: u16,
+    pub market_winning_outcomes: Vec<MarketWinningOutcome>,
+    pub market_lock_timestamp: i64,
+    pub market_settle_timestamp: Option<i64>,
+    pub event_start_order_behaviour: MarketOrderBehaviour,
+    pub market_lock_order_behaviour: MarketOrderBehaviour,
+    pub inplay_order_delay: u8,
+    pub title: String,
+    pub unsettled_accounts_count: u32,
+    pub unclosed_accounts_count: u32,
+    pub escrow_account_bump: u8,
+    pub funding_account_bump: u8,
+    pub event_start_timestamp: i64,
+    pub schema_version: u8,
+}
+
+impl MarketV1 {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + (PUB_KEY_SIZE * 3) // authority, event and mint
+        + U8_SIZE // decimal_limit
+        + ENUM_SIZE // market_status
+        + BOOL_SIZE // inplay_enabled
+        + BOOL_SIZE // inplay
+        + PUB_KEY_SIZE // market_type
+        + option_size(string_size(Market::TYPE_FIELD_MAX_LENGTH)) // market_type disc.
+        + option_size(string_size(Market::TYPE_FIELD_MAX_LENGTH)) // market_type value
+        + U8_SIZE // version
+        + BOOL_SIZE * 2 // published + suspended
+        + U16_SIZE // market_outcomes_count
+        + U16_SIZE // market_winning_places
+        + vec_size(MarketWinningOutcome::SIZE, Market::WINNING_OUTCOMES_MAX_LENGTH) // market_winning_outcomes
+        + I64_SIZE // market_lock_timestamp
+        + option_size(I64_SIZE) // market_settle_timestamp
+        + ENUM_SIZE * 2 // event_start and market_lock _order_behaviour
+        + U8_SIZE // inplay_order_delay
+        + vec_size(CHAR_SIZE, Market::TITLE_MAX_LENGTH) // title
+        + U8_SIZE * 2// bumps
+        + I64_SIZE // event_start_timestamp
+        + U32_SIZE * 2 // unsettled_accounts + unclosed_accounts
+        + U8_SIZE; // schema_version
+}
+
+impl From<MarketV0> for MarketV1 {
+    fn from(market: MarketV0) -> MarketV1 {
+        MarketV1 {
+            authority: market.authority,
+            event_account: market.event_account,
+            mint_account: market.mint_account,
+            market_status: market.market_status,
+            inplay_enabled: market.inplay_enabled,
+            inplay: market.inplay,
+            market_type: market.market_type,
+            market_type_discriminator: market.market_type_discriminator,
+            market_type_value: market.market_type_value,
+            version: market.version,
+            decimal_limit: market.decimal_limit,
+            published: market.published,
+            suspended: market.suspended,
+            market_outcomes_count: market.market_outcomes_count,
+            // version 0 markets only supported a single winning outcome with no dead heat
+            market_winning_places: 1,
+            market_winning_outcomes: market
+                .market_winning_outcome_index
+                .map(|outcome_index| vec![MarketWinningOutcome::new(outcome_index, 1, 1)])
+                .unwrap_or_default(),
+            market_lock_timestamp: market.market_lock_timestamp,
+            market_settle_timestamp: market.market_settle_timestamp,
+            event_start_order_behaviour: market.event_start_order_behaviour,
+            market_lock_order_behaviour: market.market_lock_order_behaviour,
+            inplay_order_delay: market.inplay_order_delay,
+            title: market.title,
+            unsettled_accounts_count: market.unsettled_accounts_count,
+            unclosed_accounts_count: market.unclosed_accounts_count,
+            escrow_account_bump: market.escrow_account_bump,
+            funding_account_bump: market.funding_account_bump,
+            event_start_timestamp: market.event_start_timestamp,
+            schema_version: 1,
+        }
+    }
+}
+
+// later layouts only append fields, each struct holds the fields appended by its version and
+// they are read in order after the version 1 layout up to the account's schema_version
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV2 {
+    pub market_outcome_results: Vec<OutcomeResult>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV3 {
+    pub trade_void_window: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV4 {
+    pub withdrawn_outcomes: Vec<WithdrawnOutcome>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV5 {
+    pub order_book_enabled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV6 {
+    pub price_improvement_enabled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV7 {
+    pub limits_enabled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV8 {
+    pub access_control_enabled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV9 {
+    pub operator_delegations: Vec<MarketOperatorDelegation>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV10 {
+    pub settlement_approvals_required: u8,
+    pub settlement_challenge_period: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV11 {
+    pub atomic_matching_enabled: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct MarketV12 {
+    pub crank_fee: u64,
+    pub crank_reward: u64,
+    pub crank_fee_balance: u64,
+}
+
+// data is positioned after the version 1 layout
+fn migrate_market_v1(market: MarketV1, data: &mut &[u8]) -> Result<Market> {
+    let schema_version = market.schema_version;
+    let v2: MarketV2 = deserialize_appended_layout(schema_version, 2, data)?;
+    let v3: MarketV3 = deserialize_appended_layout(schema_version, 3, data)?;
+    let v4: MarketV4 = deserialize_appended_layout(schema_version, 4, data)?;
+    let v5: MarketV5 = deserialize_appended_layout(schema_version, 5, data)?;
+    let v6: MarketV6 = deserialize_appended_layout(schema_version, 6, data)?;
+    let v7: MarketV7 = deserialize_appended_layout(schema_version, 7, data)?;
+    let v8: MarketV8 = deserialize_appended_layout(schema_version, 8, data)?;
+    let v9: MarketV9 = deserialize_appended_layout(schema_version, 9, data)?;
+    let v10: MarketV10 = deserialize_appended_layout(schema_version, 10, data)?;
+    let v11: MarketV11 = deserialize_appended_layout(schema_version, 11, data)?;
+    let v12: MarketV12 = deserialize_appended_layout(schema_version, 12, data)?;
+
+    Ok(Market {
+        authority: market.authority,
+        event_account: market.event_account,
+        mint_account: market.mint_account,
+        market_status: market.market_status,
+        inplay_enabled: market.inplay_enabled,
+        inplay: market.inplay,
+        market_type: market.market_type,
+        market_type_discriminator: market.market_type_discriminator,
+        market_type_value: market.market_type_value,
+        version: market.version,
+        decimal_limit: market.decimal_limit,
+        published: market.published,
+        suspended: market.suspended,
+        market_outcomes_count: market.market_outcomes_count,
+        market_winning_places: market.market_winning_places,
+        market_winning_outcomes: market.market_winning_outcomes,
+        market_lock_timestamp: market.market_lock_timestamp,
+        market_settle_timestamp: market.market_settle_timestamp,
+        event_start_order_behaviour: market.event_start_order_behaviour,
+        market_lock_order_behaviour: market.market_lock_order_behaviour,
+        inplay_order_delay: market.inplay_order_delay,
+        title: market.title,
+        unsettled_accounts_count: market.unsettled_accounts_count,
+        unclosed_accounts_count: market.unclosed_accounts_count,
+        escrow_account_bump: market.escrow_account_bump,
+        funding_account_bump: market.funding_account_bump,
+        event_start_timestamp: market.event_start_timestamp,
+        schema_version: Market::SCHEMA_VERSION,
+        // markets before version 2 are settled with winning outcomes only
+        market_outcome_results: v2.market_outcome_results,
+        trade_void_window: v3.trade_void_window,
+        withdrawn_outcomes: v4.withdrawn_outcomes,
+        order_book_enabled: v5.order_book_enabled,
+        price_improvement_enabled: v6.price_improvement_enabled,
+        limits_enabled: v7.limits_enabled,
+        access_control_enabled: v8.access_control_enabled,
+        operator_delegations: v9.operator_delegations,
+        settlement_approvals_required: v10.settlement_approvals_required,
+        settlement_challenge_period: v10.settlement_challenge_period,
+        atomic_matching_enabled: v11.atomic_matching_enabled,
+        crank_fee: v12.crank_fee,
+        crank_reward: v12.crank_reward,
+        crank_fee_balance: v12.crank_fee_balance,
+        suspension_order_behaviour: MarketOrderBehaviour::None,
+        suspension_timestamp: 0,
+    })
+}
+
+pub fn migrate_market(data: &[u8]) -> Result<Market> {
+    if data.len() == MarketV0::SIZE {
+        let market = MarketV1::from(deserialize_layout::<MarketV0>(data)?);
+        return migrate_market_v1(market, &mut &[][..]);
+    }
+
+    // every later layout starts with the version 1 layout, which ends with schema_version
+    let mut layout = data
+        .get(DISCRIMINATOR_SIZE..)
+        .ok_or(CoreError::MigrationUnknownSchemaVersion)?;
+    let market = MarketV1::deserialize(&mut layout)
+        .map_err(|_| error!(CoreError::MigrationUnknownSchemaVersion))?;
+    match market.schema_version {
+        1..=12 => migrate_market_v1(market, &mut layout),
+        Market::SCHEMA_VERSION => Err(error!(CoreError::MigrationAccountUpToDate)),
+        _ => Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+    }
+}
+
+/*
+ * Order
+ */
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct OrderV0 {
+    pub purchaser: Pubkey,
+    pub market: Pubkey,
+    pub market_outcome_index: u16,
+    pub for_outcome: bool,
+    pub order_status: OrderStatus,
+    pub product: Option<Pubkey>,
+    pub stake: u64,
+    pub voided_stake: u64,
+    pub expected_price: f64,
+    pub creation_timestamp: i64,
+    pub stake_unmatched: u64,
+    pub payout: u64,
+    pub payer: Pubkey,
+    pub product_commission_rate: f64,
+}
+
+impl OrderV0 {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + (PUB_KEY_SIZE * 2) // purchaser, market
+        + option_size(PUB_KEY_SIZE) // product
+        + U16_SIZE // market_outcome_index
+        + BOOL_SIZE // for outcome
+        + ENUM_SIZE // order_status
+        + (U64_SIZE * 4) // stake, payout, stake_unmatched, voided_stake
+        + F64_SIZE // expected_price
+        + F64_SIZE // product_commission_rate
+        + I64_SIZE // creation_timestamp
+        + PUB_KEY_SIZE; // payer
+}
+
+impl TryFrom<OrderV0> for Order {
+    type Error = Error;
+
+    fn try_from(order: OrderV0) -> Result<Order> {
+        Ok(Order {
+            purchaser: order.purchaser,
+            market: order.market,
+            market_outcome_index: order.market_outcome_index,
+            for_outcome: order.for_outcome,
+            order_status: order.order_status,
+            product: order.product,
+            stake: order.stake,
+            voided_stake: order.voided_stake,
+            expected_price: Price::try_from(order.expected_price)?,
+            creation_timestamp: order.creation_timestamp,
+            stake_unmatched: order.stake_unmatched,
+            payout: order.payout,
+            payer: order.payer,
+            product_commission_rate: order.product_commission_rate,
+            schema_version: Order::SCHEMA_VERSION,
+        })
+    }
+}
+
+pub fn migrate_order(data: &[u8]) -> Result<Order> {
+    if data.len() == OrderV0::SIZE {
+        return Order::try_from(deserialize_layout::<OrderV0>(data)?);
+    }
+    Err(current_layout_error(
+        Order::try_deserialize(&mut &data[..]).map(|order| order.schema_version),
+        Order::SCHEMA_VERSION,
+    ))
+}
+
+/*
+ * MarketPosition
+ */
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketPositionV0 {
+    pub purchaser: Pubkey,
+    pub market: Pubkey,
+    pub paid: bool,
+    pub market_outcome_sums: Vec<i128>,
+    pub unmatched_exposures: Vec<u64>,
+    pub payer: Pubkey,
+    pub matched_risk: u64,
+    pub matched_risk_per_product: Vec<ProductMatchedRiskAndRate>,
+}
+
+impl MarketPositionV0 {
+    // offset of the market_outcome_sums length prefix, the account size depends on it
+    const MARKET_OUTCOME_SUMS_OFFSET: usize =
+        DISCRIMINATOR_SIZE + PUB_KEY_SIZE + PUB_KEY_SIZE + BOOL_SIZE;
+
+    pub fn size_for(number_of_market_outcomes: usize) -> usize {
+        DISCRIMINATOR_SIZE
+            + PUB_KEY_SIZE // purchaser
+            + PUB_KEY_SIZE // market
+            + BOOL_SIZE // paid
+            + U64_SIZE // total_matched_stake
+            + vec_size(I128_SIZE, number_of_market_outcomes) // market_outcome_sums
+            + vec_size(U64_SIZE, number_of_market_outcomes) // unmatched_exposures
+            + PUB_KEY_SIZE // payer
+            + vec_size(ProductMatchedRiskAndRate::SIZE, ProductMatchedRiskAndRate::MAX_LENGTH)
+    }
+
+    fn number_of_market_outcomes(data: &[u8]) -> Option<usize> {
+        let offset = MarketPositionV0::MARKET_OUTCOME_SUMS_OFFSET;
+        let length = data.get(offset..offset + U32_SIZE)?;
+        u32::try_from_slice(length)
+            .ok()
+            .and_then(|length| usize::try_from(length).ok())
+    }
+}
+
+impl From<MarketPositionV0> for MarketPositionV1 {
+    fn from(market_position: MarketPositionV0) -> MarketPositionV1 {
+        MarketPositionV1 {
+            purchaser: market_position.purchaser,
+            market: market_position.market,
+            paid: market_position.paid,
+            market_outcome_sums: market_position.market_outcome_sums,
+            unmatched_exposures: market_position.unmatched_exposures,
+            payer: market_position.payer,
+            matched_risk: market_position.matched_risk,
+            matched_risk_per_product: market_position.matched_risk_per_product,
+            // version 0 markets only supported a single winning outcome
+            winning_places: 1,
+            no_winner_outcome_sum: 0,
+            no_winner_unmatched_exposure: 0,
+            schema_version: 1,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketPositionV1 {
+    pub purchaser: Pubkey,
+    pub market: Pubkey,
+    pub paid: bool,
+    pub market_outcome_sums: Vec<i128>,
+    pub unmatched_exposures: Vec<u64>,
+    pub payer: Pubkey,
+    pub matched_risk: u64,
+    pub matched_risk_per_product: Vec<ProductMatchedRiskAndRate>,
+    pub winning_places: u16,
+    pub no_winner_outcome_sum: i128,
+    pub no_winner_unmatched_exposure: u64,
+    pub schema_version: u8,
+}
+
+impl MarketPositionV1 {
+    pub fn size_for(number_of_market_outcomes: usize) -> usize {
+        MarketPositionV0::size_for(number_of_market_outcomes)
+            + U16_SIZE // winning_places
+            + I128_SIZE // no_winner_outcome_sum
+            + U64_SIZE // no_winner_unmatched_exposure
+            + U8_SIZE // schema_version
+    }
+}
+
+impl From<MarketPositionV1> for MarketPosition {
+    fn from(market_position: MarketPositionV1) -> MarketPosition {
+        MarketPosition {
+            purchaser: market_position.purchaser,
+            market: market_position.market,
+            paid: market_position.paid,
+            market_outcome_sums: market_position.market_outcome_sums,
+            unmatched_exposures: market_position.unmatched_exposures,
+            payer: market_position.payer,
+            matched_risk: market_position.matched_risk,
+            matched_risk_per_product: market_position.matched_risk_per_product,
+            winning_places: market_position.winning_places,
+            no_winner_outcome_sum: market_position.no_winner_outcome_sum,
+            no_winner_unmatched_exposure: market_position.no_winner_unmatched_exposure,
+            schema_version: MarketPosition::SCHEMA_VERSION,
+            // stakes weren't tracked, so positions with matched stake can't be reduced
+            market_outcome_stakes: vec![],
+            market_outcome_deductions: vec![],
+            outcome_withdrawals_applied: 0,
+        }
+    }
+}
+
+pub fn migrate_market_position(data: &[u8]) -> Result<MarketPosition> {
+    let number_of_market_outcomes = MarketPositionV0::number_of_market_outcomes(data);
+    if number_of_market_outcomes.map(MarketPositionV0::size_for) == Some(data.len()) {
+        let market_position = MarketPositionV1::from(deserialize_layout::<MarketPositionV0>(data)?);
+        return Ok(market_position.into());
+    }
+
+    // the current layout starts with the version 1 layout, which ends with schema_version
+    let market_position = deserialize_layout::<MarketPositionV1>(data)?;
+    match market_position.schema_version {
+        1 => Ok(market_position.into()),
+        MarketPosition::SCHEMA_VERSION => Err(error!(CoreError::MigrationAccountUpToDate)),
+        _ => Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+    }
+}
+
+/*
+ * MarketType
+ */
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarketTypeV0 {
+    pub name: String,
+    pub requires_discriminator: bool,
+    pub requires_value: bool,
+}
+
+impl MarketTypeV0 {
+    pub fn size_for(str_len: usize) -> usize {
+        DISCRIMINATOR_SIZE + string_size(str_len) + BOOL_SIZE * 2
+    }
+
+    fn name_length(data: &[u8]) -> Option<usize> {
+        let length = data.get(DISCRIMINATOR_SIZE..DISCRIMINATOR_SIZE + U32_SIZE)?;
+        u32::try_from_slice(length)
+            .ok()
+            .and_then(|length| usize::try_from(length).ok())
+    }
+}
+
+impl From<MarketTypeV0> for MarketType {
+    fn from(market_type: MarketTypeV0) -> MarketType {
+        MarketType {
+            name: market_type.name,
+            requires_discriminator: market_type.requires_discriminator,
+            requires_value: market_type.requires_value,
+            template: MarketTemplate::Standard,
+            schema_version: MarketType::SCHEMA_VERSION,
+        }
+    }
+}
+
+pub fn migrate_market_type(data: &[u8]) -> Result<MarketType> {
+    let v0_size = MarketTypeV0::name_length(data).map(MarketTypeV0::size_for);
+    if v0_size == Some(data.len()) {
+        return Ok(deserialize_layout::<MarketTypeV0>(data)?.into());
+    }
+    Err(current_layout_error(
+        MarketType::try_deserialize(&mut &data[..]).map(|market_type| market_type.schema_version),
+        MarketType::SCHEMA_VERSION,
+    ))
+}
+
+/*
+ * AuthorisedOperators
+ */
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AuthorisedOperatorsV0 {
+    pub authority: Pubkey,
+    pub operator_list: Vec<Pubkey>,
+}
+
+impl AuthorisedOperatorsV0 {
+    pub const LIST_LENGTH: usize = 31;
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + PUB_KEY_SIZE // authority
+        + vec_size(PUB_KEY_SIZE, AuthorisedOperatorsV0::LIST_LENGTH); // operator_list
+}
+
+impl From<AuthorisedOperatorsV0> for AuthorisedOperators {
+    fn from(authorised_operators: AuthorisedOperatorsV0) -> AuthorisedOperators {
+        // operators were previously permitted everything their role allowed
+        let operator_permissions =
+            vec![OperatorPermission::ALL; authorised_operators.operator_list.len()];
+        AuthorisedOperators {
+            authority: authorised_operators.authority,
+            operator_list: authorised_operators.operator_list,
+            operator_permissions,
+        }
+    }
+}
+
+pub fn migrate_authorised_operators(data: &[u8]) -> Result<AuthorisedOperators> {
+    match data.len() {
+        AuthorisedOperatorsV0::SIZE => {
+            Ok(deserialize_layout::<AuthorisedOperatorsV0>(data)?.into())
+        }
+        AuthorisedOperators::SIZE => Err(error!(CoreError::MigrationAccountUpToDate)),
+        _ => Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+    }
+}
+
+/*
+ * Trade
+ */
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TradeV0 {
+    pub purchaser: Pubkey,
+    pub market: Pubkey,
+    pub order: Pubkey,
+    pub market_outcome_index: u16,
+    pub for_outcome: bool,
+    pub stake: u64,
+    pub price: Price,
+    pub creation_timestamp: i64,
+    pub payer: Pubkey,
+}
+
+impl TradeV0 {
+    pub const SIZE: usize = DISCRIMINATOR_SIZE
+        + (PUB_KEY_SIZE * 3) // purchaser, market, order
+        + U16_SIZE // market_outcome_index
+        + BOOL_SIZE // for outcome
+        + U64_SIZE // stake
+        + Price::SIZE // price
+        + I64_SIZE // creation_timestamp
+        + PUB_KEY_SIZE; // payer
+}
+
+impl From<TradeV0> for Trade {
+    fn from(trade: TradeV0) -> Trade {
+        // the match a trade was created from was not recorded, so it cannot be voided
+        Trade {
+            purchaser: trade.purchaser,
+            market: trade.market,
+            order: trade.order,
+            market_outcome_index: trade.market_outcome_index,
+            for_outcome: trade.for_outcome,
+            stake: trade.stake,
+            price: trade.price,
+            creation_timestamp: trade.creation_timestamp,
+            payer: trade.payer,
+            match_id: Pubkey::default(),
+        }
+    }
+}
+
+pub fn migrate_trade(data: &[u8]) -> Result<Trade> {
+    match data.len() {
+        TradeV0::SIZE => Ok(deserialize_layout::<TradeV0>(data)?.into()),
+        Trade::SIZE => Err(error!(CoreError::MigrationAccountUpToDate)),
+        _ => Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+    }
+}
+
+/*
+ * Helpers
+ */
+
+// data is expected to include the 8 byte discriminator, which is validated by the caller; any
+// bytes after the layout are ignored
+fn deserialize_layout<T: AnchorDeserialize>(data: &[u8]) -> Result<T> {
+    let mut layout = data
+        .get(DISCRIMINATOR_SIZE..)
+        .ok_or(CoreError::MigrationUnknownSchemaVersion)?;
+    T::deserialize(&mut layout).map_err(|_| error!(CoreError::MigrationUnknownSchemaVersion))
+}
+
+// fields appended by a version later than the account's are left to their defaults
+fn deserialize_appended_layout<T: AnchorDeserialize + Default>(
+    schema_version: u8,
+    layout_version: u8,
+    data: &mut &[u8],
+) -> Result<T> {
+    if schema_version < layout_version {
+        return Ok(T::default());
+    }
+    T::deserialize(data).map_err(|_| error!(CoreError::MigrationUnknownSchemaVersion))
+}
+
+fn current_layout_error(schema_version: Result<u8>, current_schema_version: u8) -> Error {
+    match schema_version {
+        Ok(version) if version == current_schema_version => {
+            error!(CoreError::MigrationAccountUpToDate)
+        }
+        _ => error!(CoreError::MigrationUnknownSchemaVersion),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn fixture<T: AnchorSerialize, D: Discriminator>(layout: &T, size: usize) -> Vec<u8> {
+        let mut data = D::discriminator().to_vec();
+        layout.serialize(&mut data).unwrap();
+        data.resize(size, 0);
+        data
+    }
+
+    fn account_data<T: AccountSerialize>(account: &T, size: usize) -> Vec<u8> {
+        let mut data = vec![];
+        account.try_serialize(&mut data).unwrap();
+        data.resize(size, 0);
+        data
+    }
+
+    fn market_v0(market_winning_outcome_index: Option<u16>) -> MarketV0 {
+        MarketV0 {
+            authority: Pubkey::new_unique(),
+            event_account: Pubkey::new_unique(),
+            mint_account: Pubkey::new_unique(),
+            market_status: MarketStatus::Settled,
+            inplay_enabled: true,
+            inplay: false,
+            market_type: Pubkey::new_unique(),
+            market_type_discriminator: Some("1".to_string()),
+            market_type_value: None,
+            version: 2,
+            decimal_limit: 3,
+            published: true,
+            suspended: false,
+            market_outcomes_count: 3,
+            market_winning_outcome_index,
+            market_lock_timestamp: 100,
+            market_settle_timestamp: Some(200),
+            event_start_order_behaviour: MarketOrderBehaviour::CancelUnmatched,
+            market_lock_order_behaviour: MarketOrderBehaviour::None,
+            inplay_order_delay: 5,
+            title: "title".to_string(),
+            unsettled_accounts_count: 7,
+            unclosed_accounts_count: 11,
+            escrow_account_bump: 254,
+            funding_account_bump: 253,
+            event_start_timestamp: 50,
+        }
+    }
+
+    fn order_v0(expected_price: f64) -> OrderV0 {
+        OrderV0 {
+            purchaser: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            market_outcome_index: 1,
+            for_outcome: false,
+            order_status: OrderStatus::Matched,
+            product: Some(Pubkey::new_unique()),
+            stake: 100,
+            voided_stake: 10,
+            expected_price,
+            creation_timestamp: 42,
+            stake_unmatched: 20,
+            payout: 150,
+            payer: Pubkey::new_unique(),
+            product_commission_rate: 1.5,
+        }
+    }
+
+    fn market_position_v0(number_of_market_outcomes: usize) -> MarketPositionV0 {
+        MarketPositionV0 {
+            purchaser: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            paid: false,
+            market_outcome_sums: vec![-10; number_of_market_outcomes],
+            unmatched_exposures: vec![5; number_of_market_outcomes],
+            payer: Pubkey::new_unique(),
+            matched_risk: 10,
+            matched_risk_per_product: vec![ProductMatchedRiskAndRate {
+                product: Pubkey::new_unique(),
+                risk: 10,
+                rate: 2.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_migrate_market_v0() {
+        let market_v0 = market_v0(Some(2));
+        let data = fixture::<_, Market>(&market_v0, MarketV0::SIZE);
+
+        let market = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, market.schema_version);
+        assert_eq!(market_v0.authority, market.authority);
+        assert_eq!(market_v0.event_account, market.event_account);
+        assert_eq!(market_v0.market_type, market.market_type);
+        assert_eq!(Some("1".to_string()), market.market_type_discriminator);
+        assert_eq!(2, market.version);
+        assert_eq!(MarketStatus::Settled, market.market_status);
+        assert_eq!(3, market.market_outcomes_count);
+        assert_eq!(1, market.market_winning_places);
+        assert_eq!(
+            vec![MarketWinningOutcome::new(2, 1, 1)],
+            market.market_winning_outcomes
+        );
+        assert_eq!(Some(200), market.market_settle_timestamp);
+        assert_eq!(
+            MarketOrderBehaviour::CancelUnmatched,
+            market.event_start_order_behaviour
+        );
+        assert_eq!("title", market.title);
+        assert_eq!(7, market.unsettled_accounts_count);
+        assert_eq!(11, market.unclosed_accounts_count);
+        assert_eq!(253, market.funding_account_bump);
+        assert_eq!(50, market.event_start_timestamp);
+
+        // migrated market fits the current layout and reads back the same
+        let migrated_data = account_data(&market, Market::SIZE);
+        assert_eq!(Market::SIZE, migrated_data.len());
+        let result = Market::try_deserialize(&mut &migrated_data[..]).unwrap();
+        assert_eq!(
+            market.market_winning_outcomes,
+            result.market_winning_outcomes
+        );
+        assert_eq!(market.event_start_timestamp, result.event_start_timestamp);
+    }
+
+    #[test]
+    fn test_migrate_market_v0_no_winning_outcome() {
+        let data = fixture::<_, Market>(&market_v0(None), MarketV0::SIZE);
+
+        let market = migrate_market(&data).unwrap();
+
+        assert!(market.market_winning_outcomes.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_market_v1() {
+        let mut market_v1 = MarketV1::from(market_v0(None));
+        market_v1.market_winning_places = 2;
+        market_v1.market_winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 1),
+            MarketWinningOutcome::new(1, 1, 1),
+        ];
+        let data = fixture::<_, Market>(&market_v1, MarketV1::SIZE);
+
+        let market = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, market.schema_version);
+        assert_eq!(market_v1.authority, market.authority);
+        assert_eq!(2, market.market_winning_places);
+        assert_eq!(
+            market_v1.market_winning_outcomes,
+            market.market_winning_outcomes
+        );
+        assert_eq!("title", market.title);
+        assert!(market.market_outcome_results.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_market_v2() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 2;
+        market.market_outcome_results = vec![OutcomeResult::Win, OutcomeResult::Loss];
+        // later fields of the account data are not part of its layout
+        market.trade_void_window = 60;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert_eq!(market.authority, migrated.authority);
+        assert_eq!(
+            market.market_outcome_results,
+            migrated.market_outcome_results
+        );
+        assert_eq!(0, migrated.trade_void_window);
+        assert!(migrated.withdrawn_outcomes.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_market_v3() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 3;
+        market.trade_void_window = 60;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert_eq!(60, migrated.trade_void_window);
+        assert!(migrated.withdrawn_outcomes.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_market_v4() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 4;
+        market.withdrawn_outcomes = vec![WithdrawnOutcome {
+            outcome_index: 1,
+            reduction_factor: 2_000,
+            withdrawal_timestamp: 100,
+        }];
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert_eq!(market.withdrawn_outcomes, migrated.withdrawn_outcomes);
+        assert!(!migrated.order_book_enabled);
+    }
+
+    #[test]
+    fn test_migrate_market_v5() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 5;
+        market.order_book_enabled = true;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert!(migrated.order_book_enabled);
+        assert!(!migrated.price_improvement_enabled);
+    }
+
+    #[test]
+    fn test_migrate_market_v6() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 6;
+        market.price_improvement_enabled = true;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert!(migrated.price_improvement_enabled);
+        assert!(!migrated.limits_enabled);
+    }
+
+    #[test]
+    fn test_migrate_market_v7() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 7;
+        market.limits_enabled = true;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert!(migrated.limits_enabled);
+        assert!(!migrated.access_control_enabled);
+    }
+
+    #[test]
+    fn test_migrate_market_v8() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 8;
+        market.access_control_enabled = true;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert!(migrated.access_control_enabled);
+        assert!(migrated.operator_delegations.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_market_v9() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 9;
+        market.operator_delegations = vec![MarketOperatorDelegation {
+            operator: Pubkey::new_unique(),
+            permissions: 1,
+        }];
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert_eq!(market.operator_delegations, migrated.operator_delegations);
+        assert_eq!(0, migrated.settlement_approvals_required);
+        assert_eq!(0, migrated.settlement_challenge_period);
+    }
+
+    #[test]
+    fn test_migrate_market_v10() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 10;
+        market.settlement_approvals_required = 2;
+        market.settlement_challenge_period = 3600;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert_eq!(2, migrated.settlement_approvals_required);
+        assert_eq!(3600, migrated.settlement_challenge_period);
+        assert!(!migrated.atomic_matching_enabled);
+    }
+
+    #[test]
+    fn test_migrate_market_v11() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 11;
+        market.atomic_matching_enabled = true;
+        market.crank_fee = 10;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert!(migrated.atomic_matching_enabled);
+        assert_eq!(0, migrated.crank_fee);
+        assert_eq!(0, migrated.crank_reward);
+        assert_eq!(0, migrated.crank_fee_balance);
+    }
+
+    #[test]
+    fn test_migrate_market_v12() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 12;
+        market.crank_fee = 10;
+        market.crank_reward = 2;
+        market.crank_fee_balance = 6;
+        let data = account_data(&market, Market::SIZE);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!(Market::SCHEMA_VERSION, migrated.schema_version);
+        assert_eq!(10, migrated.crank_fee);
+        assert_eq!(2, migrated.crank_reward);
+        assert_eq!(6, migrated.crank_fee_balance);
+        assert_eq!(
+            MarketOrderBehaviour::None,
+            migrated.suspension_order_behaviour
+        );
+        assert_eq!(0, migrated.suspension_timestamp);
+    }
+
+    #[test]
+    fn test_migrate_market_stale_data() {
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = 2;
+        market.title = "a title long enough to leave stale data".to_string();
+        market.market_outcome_results = vec![OutcomeResult::Win, OutcomeResult::Loss];
+        market.trade_void_window = 60;
+        let mut data = account_data(&market, Market::SIZE);
+
+        // title shortened in place, as by update_market_title
+        market.title = "title".to_string();
+        let mut shortened = vec![];
+        market.try_serialize(&mut shortened).unwrap();
+        data[..shortened.len()].copy_from_slice(&shortened);
+
+        let migrated = migrate_market(&data).unwrap();
+
+        assert_eq!("title", migrated.title);
+        assert_eq!(
+            market.market_outcome_results,
+            migrated.market_outcome_results
+        );
+        assert_eq!(0, migrated.trade_void_window);
+    }
+
+    #[test]
+    fn test_migrate_market_up_to_date() {
+        let market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        let data = account_data(&market, Market::SIZE);
+
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_market(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_market_unknown_layout() {
+        let mut data = fixture::<_, Market>(&market_v0(None), MarketV0::SIZE);
+        data.truncate(MarketV0::SIZE - 1);
+
+        assert_eq!(
+            Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+            migrate_market(&data).map(|_| ())
+        );
+
+        let mut market =
+            migrate_market(&fixture::<_, Market>(&market_v0(None), MarketV0::SIZE)).unwrap();
+        market.schema_version = Market::SCHEMA_VERSION + 1;
+        let data = account_data(&market, Market::SIZE);
+        assert_eq!(
+            Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+            migrate_market(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_order_v0() {
+        let order_v0 = order_v0(2.1);
+        let data = fixture::<_, Order>(&order_v0, OrderV0::SIZE);
+
+        let order = migrate_order(&data).unwrap();
+
+        assert_eq!(Order::SCHEMA_VERSION, order.schema_version);
+        assert_eq!(order_v0.purchaser, order.purchaser);
+        assert_eq!(order_v0.market, order.market);
+        assert_eq!(1, order.market_outcome_index);
+        assert!(!order.for_outcome);
+        assert_eq!(OrderStatus::Matched, order.order_status);
+        assert_eq!(order_v0.product, order.product);
+        assert_eq!(100, order.stake);
+        assert_eq!(10, order.voided_stake);
+        assert_eq!(Price::new(2_100), order.expected_price);
+        assert_eq!(42, order.creation_timestamp);
+        assert_eq!(20, order.stake_unmatched);
+        assert_eq!(150, order.payout);
+        assert_eq!(order_v0.payer, order.payer);
+        assert_eq!(1.5, order.product_commission_rate);
+
+        let migrated_data = account_data(&order, Order::SIZE);
+        let result = Order::try_deserialize(&mut &migrated_data[..]).unwrap();
+        assert_eq!(Price::new(2_100), result.expected_price);
+        assert_eq!(Order::SCHEMA_VERSION, result.schema_version);
+    }
+
+    #[test]
+    fn test_migrate_order_v0_prices() {
+        for (legacy_price, price) in [(1.001, 1_001), (3.35, 3_350), (1000.0, 1_000_000)] {
+            let data = fixture::<_, Order>(&order_v0(legacy_price), OrderV0::SIZE);
+
+            let order = migrate_order(&data).unwrap();
+
+            assert_eq!(Price::new(price), order.expected_price);
+        }
+    }
+
+    #[test]
+    fn test_migrate_order_up_to_date() {
+        let order = migrate_order(&fixture::<_, Order>(&order_v0(2.1), OrderV0::SIZE)).unwrap();
+        let data = account_data(&order, Order::SIZE);
+
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_order(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_market_position_v0() {
+        for number_of_market_outcomes in [2, 3, 10] {
+            let market_position_v0 = market_position_v0(number_of_market_outcomes);
+            let data = fixture::<_, MarketPosition>(
+                &market_position_v0,
+                MarketPositionV0::size_for(number_of_market_outcomes),
+            );
+
+            let market_position = migrate_market_position(&data).unwrap();
+
+            assert_eq!(
+                MarketPosition::SCHEMA_VERSION,
+                market_position.schema_version
+            );
+            assert_eq!(market_position_v0.purchaser, market_position.purchaser);
+            assert_eq!(market_position_v0.market, market_position.market);
+            assert_eq!(
+                vec![-10; number_of_market_outcomes],
+                market_position.market_outcome_sums
+            );
+            assert_eq!(
+                vec![5; number_of_market_outcomes],
+                market_position.unmatched_exposures
+            );
+            assert_eq!(market_position_v0.payer, market_position.payer);
+            assert_eq!(10, market_position.matched_risk);
+            assert_eq!(
+                market_position_v0.matched_risk_per_product,
+                market_position.matched_risk_per_product
+            );
+            assert_eq!(1, market_position.winning_places);
+            assert_eq!(0, market_position.no_winner_outcome_sum);
+            assert_eq!(0, market_position.no_winner_unmatched_exposure);
+            assert_eq!(15, market_position.total_exposure());
+        }
+    }
+
+    #[test]
+    fn test_migrate_market_position_v1() {
+        let mut market_position = migrate_market_position(&fixture::<_, MarketPosition>(
+            &market_position_v0(3),
+            MarketPositionV0::size_for(3),
+        ))
+        .unwrap();
+        market_position.schema_version = 1;
+        market_position.winning_places = 2;
+        let mut data = account_data(&market_position, MarketPosition::size_for(3));
+        data.truncate(MarketPositionV1::size_for(3));
+
+        let migrated = migrate_market_position(&data).unwrap();
+
+        assert_eq!(MarketPosition::SCHEMA_VERSION, migrated.schema_version);
+        assert_eq!(vec![-10; 3], migrated.market_outcome_sums);
+        assert_eq!(2, migrated.winning_places);
+        assert!(migrated.market_outcome_stakes.is_empty());
+        assert_eq!(0, migrated.outcome_withdrawals_applied);
+    }
+
+    #[test]
+    fn test_migrate_market_position_v1_stale_data() {
+        let mut market_position = migrate_market_position(&fixture::<_, MarketPosition>(
+            &market_position_v0(3),
+            MarketPositionV0::size_for(3),
+        ))
+        .unwrap();
+        market_position.schema_version = 1;
+        market_position.market_outcome_stakes = vec![1, 2, 3];
+        market_position.outcome_withdrawals_applied = 1;
+        let mut data = account_data(&market_position, MarketPosition::size_for(3));
+        data.truncate(MarketPositionV1::size_for(3));
+
+        // fewer products tracked than the account was sized for leaves stale data after the layout
+        market_position.matched_risk_per_product = vec![];
+        let mut shortened = vec![];
+        market_position.try_serialize(&mut shortened).unwrap();
+        data[..shortened.len()].copy_from_slice(&shortened);
+
+        let migrated = migrate_market_position(&data).unwrap();
+
+        assert!(migrated.matched_risk_per_product.is_empty());
+        assert!(migrated.market_outcome_stakes.is_empty());
+        assert_eq!(0, migrated.outcome_withdrawals_applied);
+    }
+
+    #[test]
+    fn test_migrate_market_position_up_to_date() {
+        let data =
+            fixture::<_, MarketPosition>(&market_position_v0(3), MarketPositionV0::size_for(3));
+        let market_position = migrate_market_position(&data).unwrap();
+        let data = account_data(&market_position, MarketPosition::size_for(3));
+
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_market_position(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_market_position_unknown_layout() {
+        assert_eq!(
+            Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+            migrate_market_position(&MarketPosition::discriminator()).map(|_| ())
+        );
+
+        let market_position = MarketPosition {
+            schema_version: MarketPosition::SCHEMA_VERSION + 1,
+            ..MarketPosition::default()
+        };
+        let data = account_data(&market_position, MarketPosition::size_for(0));
+        assert_eq!(
+            Err(error!(CoreError::MigrationUnknownSchemaVersion)),
+            migrate_market_position(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_authorised_operators_v0() {
+        let authorised_operators_v0 = AuthorisedOperatorsV0 {
+            authority: Pubkey::new_unique(),
+            operator_list: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        };
+        let data = fixture::<_, AuthorisedOperators>(
+            &authorised_operators_v0,
+            AuthorisedOperatorsV0::SIZE,
+        );
+
+        let authorised_operators = migrate_authorised_operators(&data).unwrap();
+
+        assert_eq!(
+            authorised_operators_v0.operator_list,
+            authorised_operators.operator_list
+        );
+        assert_eq!(
+            vec![OperatorPermission::ALL; 2],
+            authorised_operators.operator_permissions
+        );
+
+        let data = account_data(&authorised_operators, AuthorisedOperators::SIZE);
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_authorised_operators(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_trade_v0() {
+        let trade_v0 = TradeV0 {
+            purchaser: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            order: Pubkey::new_unique(),
+            market_outcome_index: 2,
+            for_outcome: true,
+            stake: 100,
+            price: Price::new(3_000),
+            creation_timestamp: 1_700_000_000,
+            payer: Pubkey::new_unique(),
+        };
+        let data = fixture::<_, Trade>(&trade_v0, TradeV0::SIZE);
+
+        let trade = migrate_trade(&data).unwrap();
+
+        assert_eq!(trade_v0.order, trade.order);
+        assert_eq!(2, trade.market_outcome_index);
+        assert_eq!(100, trade.stake);
+        assert_eq!(Price::new(3_000), trade.price);
+        assert_eq!(trade_v0.payer, trade.payer);
+        assert_eq!(Pubkey::default(), trade.match_id);
+
+        let data = account_data(&trade, Trade::SIZE);
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_trade(&data).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_migrate_market_type_v0() {
+        let market_type_v0 = MarketTypeV0 {
+            name: "EventResultWinner".to_string(),
+            requires_discriminator: false,
+            requires_value: true,
+        };
+        let data = fixture::<_, MarketType>(
+            &market_type_v0,
+            MarketTypeV0::size_for(market_type_v0.name.len()),
+        );
+
+        let market_type = migrate_market_type(&data).unwrap();
+
+        assert_eq!("EventResultWinner", market_type.name);
+        assert!(!market_type.requires_discriminator);
+        assert!(market_type.requires_value);
+        assert_eq!(MarketTemplate::Standard, market_type.template);
+        assert_eq!(MarketType::SCHEMA_VERSION, market_type.schema_version);
+
+        let data = account_data(&market_type, MarketType::size_for(market_type.name.len()));
+        assert_eq!(
+            Err(error!(CoreError::MigrationAccountUpToDate)),
+            migrate_market_type(&data).map(|_| ())
+        );
+    }
+}
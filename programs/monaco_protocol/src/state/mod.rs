@@ -1,15 +1,22 @@
-pub(crate) mod market_type;
 pub(crate) mod operator_account;
-pub(crate) mod payments_queue;
-pub(crate) mod trade_account;
 pub(crate) mod type_size;
 
+pub mod market_access_control;
 pub mod market_account;
+pub mod market_limits;
 pub mod market_liquidities;
 pub mod market_matching_pool_account;
 pub mod market_matching_queue_account;
+pub mod market_order_book;
 pub mod market_order_request_queue;
 pub mod market_outcome_account;
+pub mod market_outcome_liquidities;
 pub mod market_position_account;
+pub mod market_settlement_proposal;
+pub mod market_type;
+pub mod migration;
 pub mod order_account;
+pub mod payments_queue;
+pub mod price;
 pub mod price_ladder;
+pub mod trade_account;
@@ -3,24 +3,45 @@ use anchor_lang::prelude::*;
 use std::result::Result;
 use std::str::FromStr;
 
+/// Operators of a single role, each with the permissions they hold for that role
 #[account]
 #[derive(Default)]
 pub struct AuthorisedOperators {
     pub authority: Pubkey,
     pub operator_list: Vec<Pubkey>,
+    pub operator_permissions: Vec<u16>, // permissions of the operator at the same index of operator_list
 }
 
 impl AuthorisedOperators {
     pub fn contains(&self, operator: &Pubkey) -> bool {
         self.operator_list.contains(operator)
     }
-    pub fn insert(&mut self, operator: Pubkey) -> bool {
-        if !self.contains(&operator) {
-            let list_len = self.operator_list.len();
-            if list_len >= AuthorisedOperators::LIST_LENGTH {
-                return false;
+
+    pub fn permissions(&self, operator: &Pubkey) -> u16 {
+        self.operator_list
+            .iter()
+            .position(|item| item == operator)
+            .and_then(|index| self.operator_permissions.get(index))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn has_permission(&self, operator: &Pubkey, permission: u16) -> bool {
+        OperatorPermission::includes(self.permissions(operator), permission)
+    }
+
+    // inserting an operator already present replaces its permissions
+    pub fn insert(&mut self, operator: Pubkey, permissions: u16) -> bool {
+        match self.operator_list.iter().position(|item| *item == operator) {
+            Some(index) => self.operator_permissions[index] = permissions,
+            None => {
+                let list_len = self.operator_list.len();
+                if list_len >= AuthorisedOperators::LIST_LENGTH {
+                    return false;
+                }
+                self.operator_list.push(operator);
+                self.operator_permissions.push(permissions);
             }
-            self.operator_list.push(operator);
         }
         true
     }
@@ -32,17 +53,47 @@ impl AuthorisedOperators {
             .position(|item| (*item).eq(&operator))
         {
             self.operator_list.remove(index);
+            self.operator_permissions.remove(index);
         }
     }
 
-    const LIST_LENGTH: usize = 31;
+    pub const LIST_LENGTH: usize = 256;
     pub const SIZE: usize = DISCRIMINATOR_SIZE
         + PUB_KEY_SIZE // authority
-        + vec_size(PUB_KEY_SIZE, AuthorisedOperators::LIST_LENGTH); // operator_list
+        + vec_size(PUB_KEY_SIZE, AuthorisedOperators::LIST_LENGTH) // operator_list
+        + vec_size(U16_SIZE, AuthorisedOperators::LIST_LENGTH); // operator_permissions
+}
+
+/// Permission flags held by operators, checked by each operator instruction against the role
+/// registry and, for instructions on a market, against the market authority or its delegations
+pub struct OperatorPermission;
+
+impl OperatorPermission {
+    pub const SETTLE: u16 = 1 << 0;
+    pub const VOID: u16 = 1 << 1;
+    pub const SUSPEND: u16 = 1 << 2;
+    pub const UPDATE_TIMES: u16 = 1 << 3;
+    pub const CREATE_MARKETS: u16 = 1 << 4; // create markets and manage their setup and lifecycle
+    pub const CRANK: u16 = 1 << 5;
+    pub const ALL: u16 = OperatorPermission::SETTLE
+        | OperatorPermission::VOID
+        | OperatorPermission::SUSPEND
+        | OperatorPermission::UPDATE_TIMES
+        | OperatorPermission::CREATE_MARKETS
+        | OperatorPermission::CRANK;
+
+    pub fn includes(permissions: u16, permission: u16) -> bool {
+        permission != 0 && permissions & permission == permission
+    }
+
+    pub fn is_valid(permissions: u16) -> bool {
+        permissions & !OperatorPermission::ALL == 0
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::state::operator_account::OperatorPermission;
     use crate::AuthorisedOperators;
     use solana_program::pubkey::Pubkey;
 
@@ -74,7 +125,7 @@ mod tests {
             operator_list: Vec::new(),
             ..Default::default()
         };
-        assert!(account.insert(operator));
+        assert!(account.insert(operator, OperatorPermission::ALL));
         assert_eq!(account.operator_list, vec![operator]);
     }
 
@@ -85,8 +136,8 @@ mod tests {
             operator_list: Vec::new(),
             ..Default::default()
         };
-        assert!(account.insert(operator));
-        assert!(account.insert(operator));
+        assert!(account.insert(operator, OperatorPermission::ALL));
+        assert!(account.insert(operator, OperatorPermission::ALL));
         assert_eq!(account.operator_list, vec![operator]);
     }
 
@@ -97,13 +148,13 @@ mod tests {
             ..Default::default()
         };
         for _ in 0..AuthorisedOperators::LIST_LENGTH {
-            account.insert(Pubkey::new_unique());
+            account.insert(Pubkey::new_unique(), OperatorPermission::ALL);
         }
         assert_eq!(
             account.operator_list.len(),
             AuthorisedOperators::LIST_LENGTH
         );
-        assert!(!account.insert(Pubkey::new_unique()));
+        assert!(!account.insert(Pubkey::new_unique(), OperatorPermission::ALL));
     }
 
     #[test]
@@ -111,10 +162,12 @@ mod tests {
         let operator = Pubkey::new_unique();
         let mut account = AuthorisedOperators {
             operator_list: vec![operator],
+            operator_permissions: vec![OperatorPermission::ALL],
             ..Default::default()
         };
         account.remove(operator);
         assert_eq!(account.operator_list, vec![]);
+        assert!(account.operator_permissions.is_empty());
     }
 
     #[test]
@@ -127,10 +180,41 @@ mod tests {
 
         let mut account = AuthorisedOperators {
             operator_list: vec![operator0, operator1, operator2, operator3, operator4],
+            operator_permissions: vec![0, 1, 2, 3, 4],
             ..Default::default()
         };
         account.remove(operator0);
         assert_eq!(account.operator_list, vec![operator2, operator4]);
+        assert_eq!(account.operator_permissions, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_permissions() {
+        let operator = Pubkey::new_unique();
+        let mut account = AuthorisedOperators::default();
+        account.insert(
+            operator,
+            OperatorPermission::SETTLE | OperatorPermission::VOID,
+        );
+
+        assert!(account.has_permission(&operator, OperatorPermission::SETTLE));
+        assert!(account.has_permission(&operator, OperatorPermission::VOID));
+        assert!(!account.has_permission(&operator, OperatorPermission::SUSPEND));
+        assert!(!account.has_permission(&Pubkey::new_unique(), OperatorPermission::SETTLE));
+
+        // inserting again replaces the permissions held
+        account.insert(operator, OperatorPermission::SUSPEND);
+        assert_eq!(account.operator_list, vec![operator]);
+        assert!(!account.has_permission(&operator, OperatorPermission::SETTLE));
+        assert!(account.has_permission(&operator, OperatorPermission::SUSPEND));
+    }
+
+    #[test]
+    fn test_permission_validity() {
+        assert!(OperatorPermission::is_valid(OperatorPermission::ALL));
+        assert!(OperatorPermission::is_valid(0));
+        assert!(!OperatorPermission::is_valid(1 << 15));
+        assert!(!OperatorPermission::includes(OperatorPermission::ALL, 0));
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::error::CoreError;
 use crate::instructions::calculate_for_payout;
+use crate::state::price::Price;
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 
@@ -20,16 +21,19 @@ pub struct Order {
     pub product: Option<Pubkey>, // product this order was placed on
     pub stake: u64,        // total stake amount provided by purchaser
     pub voided_stake: u64, // stake amount returned to purchaser due to cancelation or settlement for partially matched orders
-    pub expected_price: f64, // expected price provided by purchaser
+    pub expected_price: Price, // expected price provided by purchaser
     pub creation_timestamp: i64,
     // matching data
     pub stake_unmatched: u64,         // stake amount available for matching
     pub payout: u64, // amount paid to purchaser during settlement for winning orders
     pub payer: Pubkey, // solana account fee payer
     pub product_commission_rate: f64, // product commission rate at time of order creation
+    pub schema_version: u8, // layout version, see state::migration
 }
 
 impl Order {
+    pub const SCHEMA_VERSION: u8 = 1;
+
     pub const SIZE: usize = DISCRIMINATOR_SIZE
         + (PUB_KEY_SIZE * 2) // purchaser, market
         + option_size(PUB_KEY_SIZE) // product
@@ -37,9 +41,11 @@ impl Order {
         + BOOL_SIZE // for outcome
         + ENUM_SIZE // order_status
         + (U64_SIZE * 4) // stake, payout, stake_unmatched, voided_stake
-        + (F64_SIZE  * 2)// expected_price & product_commission_rate
+        + Price::SIZE // expected_price
+        + F64_SIZE // product_commission_rate
         + I64_SIZE // creation_timestamp
-        + PUB_KEY_SIZE; // payer
+        + PUB_KEY_SIZE // payer
+        + U8_SIZE; // schema_version
 
     pub fn is_completed(&self) -> bool {
         self.order_status == OrderStatus::SettledWin
@@ -48,7 +54,11 @@ impl Order {
             || self.order_status == OrderStatus::Voided
     }
 
-    pub fn match_stake_unmatched(&mut self, stake_matched: u64, price_matched: f64) -> Result<()> {
+    pub fn match_stake_unmatched(
+        &mut self,
+        stake_matched: u64,
+        price_matched: Price,
+    ) -> Result<()> {
         self.stake_unmatched = self
             .stake_unmatched
             .checked_sub(stake_matched)
@@ -81,6 +91,18 @@ impl Order {
 
         Ok(())
     }
+
+    pub fn void_stake_matched(&mut self, stake_to_void: u64, price_matched: Price) -> Result<()> {
+        self.voided_stake = self
+            .voided_stake
+            .checked_add(stake_to_void)
+            .ok_or(CoreError::ArithmeticError)?;
+        self.payout = self
+            .payout
+            .checked_sub(calculate_for_payout(stake_to_void, price_matched))
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, PartialEq, Eq)]
@@ -98,7 +120,14 @@ use crate::state::market_order_request_queue::OrderRequest;
 
 #[cfg(test)]
 pub fn mock_order_default() -> Order {
-    mock_order(Pubkey::new_unique(), 0, true, 0.0, 0, Pubkey::new_unique())
+    mock_order(
+        Pubkey::new_unique(),
+        0,
+        true,
+        Price::new(0),
+        0,
+        Pubkey::new_unique(),
+    )
 }
 
 #[cfg(test)]
@@ -122,6 +151,7 @@ pub fn mock_order_from_order_request(
         product_commission_rate: order_request.product_commission_rate,
         creation_timestamp: 0,
         payer,
+        schema_version: Order::SCHEMA_VERSION,
     }
 }
 
@@ -130,7 +160,7 @@ pub fn mock_order(
     market: Pubkey,
     market_outcome_index: u16,
     for_outcome: bool,
-    expected_price: f64,
+    expected_price: Price,
     stake: u64,
     payer: Pubkey,
 ) -> Order {
@@ -149,6 +179,7 @@ pub fn mock_order(
         stake_unmatched: stake,
         payout: 0,
         payer,
+        schema_version: Order::SCHEMA_VERSION,
     }
 }
 
@@ -165,13 +196,13 @@ mod tests {
             Pubkey::new_unique(),
             1,
             true,
-            2.10,
+            Price::new(2_100),
             1000,
             Pubkey::new_unique(),
         );
 
         // when
-        let result = order.match_stake_unmatched(1001, 2.10);
+        let result = order.match_stake_unmatched(1001, Price::new(2_100));
 
         // then
         assert!(result.is_err());
@@ -187,14 +218,14 @@ mod tests {
             Pubkey::new_unique(),
             1,
             true,
-            2.10,
+            Price::new(2_100),
             1000,
             Pubkey::new_unique(),
         );
         let stake_matched = order.stake_unmatched - 10;
 
         // when
-        let result = order.match_stake_unmatched(stake_matched, 2.10);
+        let result = order.match_stake_unmatched(stake_matched, Price::new(2_100));
 
         // then
         assert!(result.is_ok());
@@ -210,14 +241,14 @@ mod tests {
             Pubkey::new_unique(),
             1,
             true,
-            2.10,
+            Price::new(2_100),
             1000,
             Pubkey::new_unique(),
         );
         let stake_matched = order.stake_unmatched;
 
         // when
-        let result = order.match_stake_unmatched(stake_matched, 2.10);
+        let result = order.match_stake_unmatched(stake_matched, Price::new(2_100));
 
         // then
         assert!(result.is_ok());
@@ -233,7 +264,7 @@ mod tests {
             Pubkey::new_unique(),
             1,
             true,
-            2.10,
+            Price::new(2_100),
             1000,
             Pubkey::new_unique(),
         );
@@ -252,7 +283,7 @@ mod tests {
             Pubkey::new_unique(),
             1,
             true,
-            2.10,
+            Price::new(2_100),
             1000,
             Pubkey::new_unique(),
         );
@@ -0,0 +1,145 @@
+use crate::error::CoreError;
+use crate::state::type_size::U64_SIZE;
+use anchor_lang::prelude::*;
+use rust_decimal::prelude::*;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Read, Write};
+
+/// Price with 3 implied decimals, e.g. a value of 2_500 is a price of 2.5
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Price {
+    value: u64,
+}
+
+impl Price {
+    pub const DECIMALS: u32 = 3;
+    pub const SCALE: u64 = 1_000;
+    pub const ONE: Price = Price::new(Price::SCALE);
+
+    pub const SIZE: usize = U64_SIZE;
+
+    // prices used to be stored as f64 - the bits of any f64 price >= 1.0 are far above any
+    // fixed-point value, so accounts written before the change can still be read
+    const LEGACY_F64_MIN_BITS: u64 = 0x3FF0_0000_0000_0000; // 1.0_f64.to_bits()
+
+    pub const fn new(value: u64) -> Price {
+        Price { value }
+    }
+
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn to_decimal(self) -> Decimal {
+        Decimal::from(self.value) / Decimal::from(Price::SCALE)
+    }
+
+    fn from_legacy_f64(price: f64) -> Price {
+        // legacy prices were validated to have at most 3 decimals, rounding only removes float error
+        Price::new((price * Price::SCALE as f64).round() as u64)
+    }
+}
+
+impl TryFrom<f64> for Price {
+    type Error = Error;
+
+    fn try_from(price: f64) -> Result<Price> {
+        let decimal = Decimal::from_f64(price).ok_or(CoreError::ArithmeticError)?;
+        require!(
+            decimal.eq(&decimal.trunc_with_scale(Price::DECIMALS)),
+            CoreError::PricePrecisionTooLarge
+        );
+        let value = (decimal * Decimal::from(Price::SCALE))
+            .to_u64()
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(Price::new(value))
+    }
+}
+
+// matches the `{:.3}` formatting of the f64 prices used in matching pool seeds
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{:03}",
+            self.value / Price::SCALE,
+            self.value % Price::SCALE
+        )
+    }
+}
+
+impl AnchorSerialize for Price {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.value.serialize(writer)
+    }
+}
+
+impl AnchorDeserialize for Price {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let value = u64::deserialize_reader(reader)?;
+        match value < Price::LEGACY_F64_MIN_BITS {
+            true => Ok(Price::new(value)),
+            false => Ok(Price::from_legacy_f64(f64::from_bits(value))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_f64() {
+        assert_eq!(Ok(Price::new(2_100)), Price::try_from(2.1));
+        assert_eq!(Ok(Price::new(1_001)), Price::try_from(1.001));
+        assert_eq!(Ok(Price::new(1_000_000)), Price::try_from(1000.0));
+        assert_eq!(
+            Err(error!(CoreError::PricePrecisionTooLarge)),
+            Price::try_from(2.1111)
+        );
+        assert_eq!(
+            Err(error!(CoreError::ArithmeticError)),
+            Price::try_from(f64::NAN)
+        );
+    }
+
+    #[test]
+    fn test_display_matches_f64_seed_format() {
+        for price in [1.001, 1.01, 2.1, 2.0, 10.5, 1000.0] {
+            assert_eq!(
+                format!("{:.3}", price),
+                Price::try_from(price).unwrap().to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let price = Price::new(3_250);
+
+        let data = price.try_to_vec().unwrap();
+        assert_eq!(3_250_u64.to_le_bytes().to_vec(), data);
+        assert_eq!(price, Price::try_from_slice(&data).unwrap());
+    }
+
+    #[test]
+    fn test_deserialize_legacy_f64() {
+        for (legacy_price, price) in [
+            (1.001, 1_001),
+            (2.1, 2_100),
+            (3.35, 3_350),
+            (1000.0, 1_000_000),
+        ] {
+            let data = f64::to_le_bytes(legacy_price);
+            assert_eq!(Price::new(price), Price::try_from_slice(&data).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Price::new(1_990) < Price::new(2_000));
+        assert!(Price::ONE < Price::new(1_001));
+        assert_eq!(Price::new(2_000), Price::try_from(2.0).unwrap());
+    }
+}
@@ -1,28 +1,325 @@
-use crate::state::type_size::{vec_size, DISCRIMINATOR_SIZE, F64_SIZE, PUB_KEY_SIZE, U16_SIZE};
+use crate::state::price::Price;
+use crate::state::type_size::{vec_size, DISCRIMINATOR_SIZE, PUB_KEY_SIZE, U16_SIZE};
 use anchor_lang::prelude::*;
 
-pub static DEFAULT_PRICES: [f64; 317] = [
-    1.001, 1.002, 1.003, 1.004, 1.005, 1.006, 1.007, 1.008, 1.009, 1.01, 1.02, 1.03, 1.04, 1.05,
-    1.06, 1.07, 1.08, 1.09, 1.1, 1.11, 1.12, 1.13, 1.14, 1.15, 1.16, 1.17, 1.18, 1.19, 1.2, 1.21,
-    1.22, 1.23, 1.24, 1.25, 1.26, 1.27, 1.28, 1.29, 1.3, 1.31, 1.32, 1.33, 1.34, 1.35, 1.36, 1.37,
-    1.38, 1.39, 1.4, 1.41, 1.42, 1.43, 1.44, 1.45, 1.46, 1.47, 1.48, 1.49, 1.5, 1.51, 1.52, 1.53,
-    1.54, 1.55, 1.56, 1.57, 1.58, 1.59, 1.6, 1.61, 1.62, 1.63, 1.64, 1.65, 1.66, 1.67, 1.68, 1.69,
-    1.7, 1.71, 1.72, 1.73, 1.74, 1.75, 1.76, 1.77, 1.78, 1.79, 1.8, 1.81, 1.82, 1.83, 1.84, 1.85,
-    1.86, 1.87, 1.88, 1.89, 1.9, 1.91, 1.92, 1.93, 1.94, 1.95, 1.96, 1.97, 1.98, 1.99, 2.0, 2.01,
-    2.02, 2.03, 2.04, 2.05, 2.06, 2.07, 2.08, 2.09, 2.1, 2.11, 2.12, 2.13, 2.14, 2.15, 2.16, 2.17,
-    2.18, 2.19, 2.2, 2.22, 2.24, 2.26, 2.28, 2.3, 2.32, 2.34, 2.36, 2.38, 2.4, 2.42, 2.44, 2.46,
-    2.48, 2.5, 2.52, 2.54, 2.56, 2.58, 2.6, 2.62, 2.64, 2.66, 2.68, 2.7, 2.72, 2.74, 2.76, 2.78,
-    2.8, 2.82, 2.84, 2.86, 2.88, 2.9, 2.92, 2.94, 2.96, 2.98, 3.0, 3.05, 3.1, 3.15, 3.2, 3.25, 3.3,
-    3.35, 3.4, 3.45, 3.5, 3.55, 3.6, 3.65, 3.7, 3.75, 3.8, 3.85, 3.9, 3.95, 4.0, 4.1, 4.2, 4.3,
-    4.4, 4.5, 4.6, 4.7, 4.8, 4.9, 5.0, 5.1, 5.2, 5.3, 5.4, 5.5, 5.6, 5.7, 5.8, 5.9, 6.0, 6.2, 6.4,
-    6.6, 6.8, 7.0, 7.2, 7.4, 7.6, 7.8, 8.0, 8.2, 8.4, 8.6, 8.8, 9.0, 9.2, 9.4, 9.6, 9.8, 10.0,
-    10.5, 11.0, 11.5, 12.0, 12.5, 13.0, 13.5, 14.0, 14.5, 15.0, 15.5, 16.0, 16.5, 17.0, 17.5, 18.0,
-    18.5, 19.0, 19.5, 20.0, 21.0, 22.0, 23.0, 24.0, 25.0, 26.0, 27.0, 28.0, 29.0, 30.0, 32.0, 34.0,
-    36.0, 38.0, 40.0, 42.0, 44.0, 46.0, 48.0, 50.0, 55.0, 60.0, 65.0, 70.0, 75.0, 80.0, 85.0, 90.0,
-    95.0, 100.0, 110.0, 120.0, 130.0, 140.0, 150.0, 160.0, 170.0, 180.0, 190.0, 200.0, 210.0,
-    220.0, 230.0, 240.0, 250.0, 260.0, 270.0, 280.0, 290.0, 300.0, 320.0, 340.0, 360.0, 380.0,
-    400.0, 420.0, 440.0, 460.0, 480.0, 500.0, 550.0, 600.0, 650.0, 700.0, 750.0, 800.0, 900.0,
-    1000.0,
+pub static DEFAULT_PRICES: [Price; 317] = [
+    Price::new(1_001),
+    Price::new(1_002),
+    Price::new(1_003),
+    Price::new(1_004),
+    Price::new(1_005),
+    Price::new(1_006),
+    Price::new(1_007),
+    Price::new(1_008),
+    Price::new(1_009),
+    Price::new(1_010),
+    Price::new(1_020),
+    Price::new(1_030),
+    Price::new(1_040),
+    Price::new(1_050),
+    Price::new(1_060),
+    Price::new(1_070),
+    Price::new(1_080),
+    Price::new(1_090),
+    Price::new(1_100),
+    Price::new(1_110),
+    Price::new(1_120),
+    Price::new(1_130),
+    Price::new(1_140),
+    Price::new(1_150),
+    Price::new(1_160),
+    Price::new(1_170),
+    Price::new(1_180),
+    Price::new(1_190),
+    Price::new(1_200),
+    Price::new(1_210),
+    Price::new(1_220),
+    Price::new(1_230),
+    Price::new(1_240),
+    Price::new(1_250),
+    Price::new(1_260),
+    Price::new(1_270),
+    Price::new(1_280),
+    Price::new(1_290),
+    Price::new(1_300),
+    Price::new(1_310),
+    Price::new(1_320),
+    Price::new(1_330),
+    Price::new(1_340),
+    Price::new(1_350),
+    Price::new(1_360),
+    Price::new(1_370),
+    Price::new(1_380),
+    Price::new(1_390),
+    Price::new(1_400),
+    Price::new(1_410),
+    Price::new(1_420),
+    Price::new(1_430),
+    Price::new(1_440),
+    Price::new(1_450),
+    Price::new(1_460),
+    Price::new(1_470),
+    Price::new(1_480),
+    Price::new(1_490),
+    Price::new(1_500),
+    Price::new(1_510),
+    Price::new(1_520),
+    Price::new(1_530),
+    Price::new(1_540),
+    Price::new(1_550),
+    Price::new(1_560),
+    Price::new(1_570),
+    Price::new(1_580),
+    Price::new(1_590),
+    Price::new(1_600),
+    Price::new(1_610),
+    Price::new(1_620),
+    Price::new(1_630),
+    Price::new(1_640),
+    Price::new(1_650),
+    Price::new(1_660),
+    Price::new(1_670),
+    Price::new(1_680),
+    Price::new(1_690),
+    Price::new(1_700),
+    Price::new(1_710),
+    Price::new(1_720),
+    Price::new(1_730),
+    Price::new(1_740),
+    Price::new(1_750),
+    Price::new(1_760),
+    Price::new(1_770),
+    Price::new(1_780),
+    Price::new(1_790),
+    Price::new(1_800),
+    Price::new(1_810),
+    Price::new(1_820),
+    Price::new(1_830),
+    Price::new(1_840),
+    Price::new(1_850),
+    Price::new(1_860),
+    Price::new(1_870),
+    Price::new(1_880),
+    Price::new(1_890),
+    Price::new(1_900),
+    Price::new(1_910),
+    Price::new(1_920),
+    Price::new(1_930),
+    Price::new(1_940),
+    Price::new(1_950),
+    Price::new(1_960),
+    Price::new(1_970),
+    Price::new(1_980),
+    Price::new(1_990),
+    Price::new(2_000),
+    Price::new(2_010),
+    Price::new(2_020),
+    Price::new(2_030),
+    Price::new(2_040),
+    Price::new(2_050),
+    Price::new(2_060),
+    Price::new(2_070),
+    Price::new(2_080),
+    Price::new(2_090),
+    Price::new(2_100),
+    Price::new(2_110),
+    Price::new(2_120),
+    Price::new(2_130),
+    Price::new(2_140),
+    Price::new(2_150),
+    Price::new(2_160),
+    Price::new(2_170),
+    Price::new(2_180),
+    Price::new(2_190),
+    Price::new(2_200),
+    Price::new(2_220),
+    Price::new(2_240),
+    Price::new(2_260),
+    Price::new(2_280),
+    Price::new(2_300),
+    Price::new(2_320),
+    Price::new(2_340),
+    Price::new(2_360),
+    Price::new(2_380),
+    Price::new(2_400),
+    Price::new(2_420),
+    Price::new(2_440),
+    Price::new(2_460),
+    Price::new(2_480),
+    Price::new(2_500),
+    Price::new(2_520),
+    Price::new(2_540),
+    Price::new(2_560),
+    Price::new(2_580),
+    Price::new(2_600),
+    Price::new(2_620),
+    Price::new(2_640),
+    Price::new(2_660),
+    Price::new(2_680),
+    Price::new(2_700),
+    Price::new(2_720),
+    Price::new(2_740),
+    Price::new(2_760),
+    Price::new(2_780),
+    Price::new(2_800),
+    Price::new(2_820),
+    Price::new(2_840),
+    Price::new(2_860),
+    Price::new(2_880),
+    Price::new(2_900),
+    Price::new(2_920),
+    Price::new(2_940),
+    Price::new(2_960),
+    Price::new(2_980),
+    Price::new(3_000),
+    Price::new(3_050),
+    Price::new(3_100),
+    Price::new(3_150),
+    Price::new(3_200),
+    Price::new(3_250),
+    Price::new(3_300),
+    Price::new(3_350),
+    Price::new(3_400),
+    Price::new(3_450),
+    Price::new(3_500),
+    Price::new(3_550),
+    Price::new(3_600),
+    Price::new(3_650),
+    Price::new(3_700),
+    Price::new(3_750),
+    Price::new(3_800),
+    Price::new(3_850),
+    Price::new(3_900),
+    Price::new(3_950),
+    Price::new(4_000),
+    Price::new(4_100),
+    Price::new(4_200),
+    Price::new(4_300),
+    Price::new(4_400),
+    Price::new(4_500),
+    Price::new(4_600),
+    Price::new(4_700),
+    Price::new(4_800),
+    Price::new(4_900),
+    Price::new(5_000),
+    Price::new(5_100),
+    Price::new(5_200),
+    Price::new(5_300),
+    Price::new(5_400),
+    Price::new(5_500),
+    Price::new(5_600),
+    Price::new(5_700),
+    Price::new(5_800),
+    Price::new(5_900),
+    Price::new(6_000),
+    Price::new(6_200),
+    Price::new(6_400),
+    Price::new(6_600),
+    Price::new(6_800),
+    Price::new(7_000),
+    Price::new(7_200),
+    Price::new(7_400),
+    Price::new(7_600),
+    Price::new(7_800),
+    Price::new(8_000),
+    Price::new(8_200),
+    Price::new(8_400),
+    Price::new(8_600),
+    Price::new(8_800),
+    Price::new(9_000),
+    Price::new(9_200),
+    Price::new(9_400),
+    Price::new(9_600),
+    Price::new(9_800),
+    Price::new(10_000),
+    Price::new(10_500),
+    Price::new(11_000),
+    Price::new(11_500),
+    Price::new(12_000),
+    Price::new(12_500),
+    Price::new(13_000),
+    Price::new(13_500),
+    Price::new(14_000),
+    Price::new(14_500),
+    Price::new(15_000),
+    Price::new(15_500),
+    Price::new(16_000),
+    Price::new(16_500),
+    Price::new(17_000),
+    Price::new(17_500),
+    Price::new(18_000),
+    Price::new(18_500),
+    Price::new(19_000),
+    Price::new(19_500),
+    Price::new(20_000),
+    Price::new(21_000),
+    Price::new(22_000),
+    Price::new(23_000),
+    Price::new(24_000),
+    Price::new(25_000),
+    Price::new(26_000),
+    Price::new(27_000),
+    Price::new(28_000),
+    Price::new(29_000),
+    Price::new(30_000),
+    Price::new(32_000),
+    Price::new(34_000),
+    Price::new(36_000),
+    Price::new(38_000),
+    Price::new(40_000),
+    Price::new(42_000),
+    Price::new(44_000),
+    Price::new(46_000),
+    Price::new(48_000),
+    Price::new(50_000),
+    Price::new(55_000),
+    Price::new(60_000),
+    Price::new(65_000),
+    Price::new(70_000),
+    Price::new(75_000),
+    Price::new(80_000),
+    Price::new(85_000),
+    Price::new(90_000),
+    Price::new(95_000),
+    Price::new(100_000),
+    Price::new(110_000),
+    Price::new(120_000),
+    Price::new(130_000),
+    Price::new(140_000),
+    Price::new(150_000),
+    Price::new(160_000),
+    Price::new(170_000),
+    Price::new(180_000),
+    Price::new(190_000),
+    Price::new(200_000),
+    Price::new(210_000),
+    Price::new(220_000),
+    Price::new(230_000),
+    Price::new(240_000),
+    Price::new(250_000),
+    Price::new(260_000),
+    Price::new(270_000),
+    Price::new(280_000),
+    Price::new(290_000),
+    Price::new(300_000),
+    Price::new(320_000),
+    Price::new(340_000),
+    Price::new(360_000),
+    Price::new(380_000),
+    Price::new(400_000),
+    Price::new(420_000),
+    Price::new(440_000),
+    Price::new(460_000),
+    Price::new(480_000),
+    Price::new(500_000),
+    Price::new(550_000),
+    Price::new(600_000),
+    Price::new(650_000),
+    Price::new(700_000),
+    Price::new(750_000),
+    Price::new(800_000),
+    Price::new(900_000),
+    Price::new(1_000_000),
 ];
 
 #[account]
@@ -30,11 +327,14 @@ pub static DEFAULT_PRICES: [f64; 317] = [
 pub struct PriceLadder {
     pub authority: Pubkey,
     pub max_number_of_prices: u16,
-    pub prices: Vec<f64>,
+    pub prices: Vec<Price>,
 }
 
 impl PriceLadder {
     pub fn size_for(number_of_prices: u16) -> usize {
-        DISCRIMINATOR_SIZE + PUB_KEY_SIZE + U16_SIZE + vec_size(F64_SIZE, number_of_prices as usize)
+        DISCRIMINATOR_SIZE
+            + PUB_KEY_SIZE
+            + U16_SIZE
+            + vec_size(Price::SIZE, number_of_prices as usize)
     }
 }
@@ -1,3 +1,4 @@
+use crate::state::price::Price;
 use crate::state::type_size::*;
 use anchor_lang::prelude::*;
 
@@ -10,10 +11,12 @@ pub struct Trade {
     pub market_outcome_index: u16,
     pub for_outcome: bool,
     pub stake: u64,
-    pub price: f64,
+    pub price: Price,
     pub creation_timestamp: i64,
 
     pub payer: Pubkey,
+    // shared by the trades created from the same match, the taker order for queued matches
+    pub match_id: Pubkey,
 }
 
 impl Trade {
@@ -22,7 +25,8 @@ impl Trade {
         + U16_SIZE // market_outcome_index
         + BOOL_SIZE // for outcome
         + U64_SIZE // stake
-        + F64_SIZE // price
+        + Price::SIZE // price
         + I64_SIZE // creation_timestamp
-        + PUB_KEY_SIZE; // payer
+        + PUB_KEY_SIZE // payer
+        + PUB_KEY_SIZE; // match_id
 }
@@ -0,0 +1,177 @@
+use anchor_lang::AccountDeserialize;
+use anchor_lang::Result;
+
+use monaco_protocol::state::market_access_control::MarketAccessControl;
+use monaco_protocol::state::market_account::Market;
+use monaco_protocol::state::market_limits::MarketLimits;
+use monaco_protocol::state::market_liquidities::MarketLiquidities;
+use monaco_protocol::state::market_matching_queue_account::MarketMatchingQueue;
+use monaco_protocol::state::market_order_book::MarketOrderBook;
+use monaco_protocol::state::market_order_request_queue::MarketOrderRequestQueue;
+use monaco_protocol::state::market_outcome_liquidities::MarketOutcomeLiquidities;
+use monaco_protocol::state::market_position_account::MarketPosition;
+use monaco_protocol::state::market_settlement_proposal::MarketSettlementProposal;
+use monaco_protocol::state::order_account::Order;
+use monaco_protocol::state::payments_queue::MarketPaymentsQueue;
+
+// account data is expected to include the 8 byte anchor discriminator which is validated
+
+pub fn decode_market(data: &[u8]) -> Result<Market> {
+    decode(data)
+}
+
+pub fn decode_order(data: &[u8]) -> Result<Order> {
+    decode(data)
+}
+
+pub fn decode_market_position(data: &[u8]) -> Result<MarketPosition> {
+    decode(data)
+}
+
+pub fn decode_market_access_control(data: &[u8]) -> Result<MarketAccessControl> {
+    decode(data)
+}
+
+pub fn decode_market_settlement_proposal(data: &[u8]) -> Result<MarketSettlementProposal> {
+    decode(data)
+}
+
+pub fn decode_market_limits(data: &[u8]) -> Result<MarketLimits> {
+    decode(data)
+}
+
+pub fn decode_market_liquidities(data: &[u8]) -> Result<MarketLiquidities> {
+    decode(data)
+}
+
+pub fn decode_market_outcome_liquidities(data: &[u8]) -> Result<MarketOutcomeLiquidities> {
+    decode(data)
+}
+
+pub fn decode_market_order_book(data: &[u8]) -> Result<MarketOrderBook> {
+    decode(data)
+}
+
+pub fn decode_market_order_request_queue(data: &[u8]) -> Result<MarketOrderRequestQueue> {
+    decode(data)
+}
+
+pub fn decode_market_matching_queue(data: &[u8]) -> Result<MarketMatchingQueue> {
+    decode(data)
+}
+
+pub fn decode_market_payments_queue(data: &[u8]) -> Result<MarketPaymentsQueue> {
+    decode(data)
+}
+
+fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data = data;
+    T::try_deserialize(&mut data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::error::ErrorCode;
+    use anchor_lang::AccountSerialize;
+    use monaco_protocol::state::market_position_account::ProductMatchedRiskAndRate;
+    use monaco_protocol::state::order_account::OrderStatus;
+    use monaco_protocol::state::price::Price;
+    use solana_program::pubkey::Pubkey;
+
+    fn serialize<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn test_order() -> Order {
+        Order {
+            purchaser: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            market_outcome_index: 1,
+            for_outcome: true,
+            order_status: OrderStatus::Open,
+            product: None,
+            stake: 100,
+            voided_stake: 0,
+            expected_price: Price::new(2_100),
+            creation_timestamp: 0,
+            stake_unmatched: 100,
+            payout: 0,
+            payer: Pubkey::new_unique(),
+            product_commission_rate: 0.0,
+            schema_version: Order::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_decode_market_position() {
+        let market_position = MarketPosition {
+            purchaser: Pubkey::new_unique(),
+            market: Pubkey::new_unique(),
+            paid: false,
+            market_outcome_sums: vec![10, -10],
+            unmatched_exposures: vec![0, 5],
+            payer: Pubkey::new_unique(),
+            matched_risk: 10,
+            matched_risk_per_product: vec![ProductMatchedRiskAndRate {
+                product: Pubkey::new_unique(),
+                rate: 1.0,
+                risk: 10,
+            }],
+            ..MarketPosition::default()
+        };
+
+        let result = decode_market_position(&serialize(&market_position)).unwrap();
+
+        assert_eq!(market_position.purchaser, result.purchaser);
+        assert_eq!(market_position.market, result.market);
+        assert_eq!(
+            market_position.market_outcome_sums,
+            result.market_outcome_sums
+        );
+        assert_eq!(
+            market_position.unmatched_exposures,
+            result.unmatched_exposures
+        );
+        assert_eq!(
+            market_position.matched_risk_per_product,
+            result.matched_risk_per_product
+        );
+    }
+
+    #[test]
+    fn test_decode_order() {
+        let order = test_order();
+
+        let result = decode_order(&serialize(&order)).unwrap();
+
+        assert_eq!(order.purchaser, result.purchaser);
+        assert_eq!(order.market_outcome_index, result.market_outcome_index);
+        assert_eq!(order.expected_price, result.expected_price);
+        assert_eq!(order.stake_unmatched, result.stake_unmatched);
+    }
+
+    #[test]
+    fn test_decode_wrong_account_type() {
+        let order = test_order();
+
+        let result = decode_market_position(&serialize(&order));
+
+        assert_eq!(
+            Err(ErrorCode::AccountDiscriminatorMismatch.into()),
+            result.map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_decode_empty_data() {
+        let result = decode_market(&[]);
+
+        assert_eq!(
+            Err(ErrorCode::AccountDiscriminatorNotFound.into()),
+            result.map(|_| ())
+        );
+    }
+}
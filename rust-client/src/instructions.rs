@@ -0,0 +1,1153 @@
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+
+use monaco_protocol::accounts;
+use monaco_protocol::instruction;
+use monaco_protocol::state::market_access_control::PurchaserListMode;
+use monaco_protocol::state::market_account::{
+    MarketOrderBehaviour, MarketWinningOutcome, OutcomeResult,
+};
+use monaco_protocol::state::market_liquidities::LiquiditySource;
+use monaco_protocol::state::market_order_request_queue::OrderRequestData;
+use monaco_protocol::state::market_settlement_proposal::SettlementProposalAction;
+use monaco_protocol::state::market_type::MarketTemplate;
+use monaco_protocol::state::order_account::{Order, OrderStatus};
+use monaco_protocol::state::price::Price;
+use monaco_protocol::ID;
+
+use crate::pda;
+
+// one builder per instruction in monaco_protocol::monaco_protocol, accounts are passed as the
+// anchor generated account structs so that account ordering always matches the program
+
+pub fn create_order_request(
+    accounts: accounts::CreateOrderRequest,
+    data: OrderRequestData,
+) -> Instruction {
+    build_instruction(accounts, instruction::CreateOrderRequest { data })
+}
+
+// order and market outcome accounts expected by the program are derived and appended as remaining
+// accounts
+pub fn create_order_requests(
+    accounts: accounts::CreateOrderRequests,
+    data: Vec<OrderRequestData>,
+) -> Instruction {
+    let mut remaining_accounts: Vec<AccountMeta> = data
+        .iter()
+        .map(|order_request| {
+            let (order, _) = pda::find_order_address(
+                &accounts.market,
+                &accounts.purchaser,
+                &order_request.distinct_seed,
+            );
+            AccountMeta::new_readonly(order, false)
+        })
+        .collect();
+
+    let mut market_outcome_indexes: Vec<u16> = data
+        .iter()
+        .map(|order_request| order_request.market_outcome_index)
+        .collect();
+    market_outcome_indexes.sort_unstable();
+    market_outcome_indexes.dedup();
+    remaining_accounts.extend(market_outcome_indexes.into_iter().map(|index| {
+        let (market_outcome, _) = pda::find_market_outcome_address(&accounts.market, index);
+        AccountMeta::new_readonly(market_outcome, false)
+    }));
+
+    let mut instruction = build_instruction(accounts, instruction::CreateOrderRequests { data });
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn create_market_position(accounts: accounts::CreateMarketPosition) -> Instruction {
+    build_instruction(accounts, instruction::CreateMarketPosition {})
+}
+
+// instructions updating market liquidities are passed the outcome liquidities accounts of the given
+// outcomes as remaining accounts
+pub fn process_order_request(
+    accounts: accounts::ProcessOrderRequest,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction = build_instruction(accounts, instruction::ProcessOrderRequest {});
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn dequeue_order_request(accounts: accounts::DequeueOrderRequest) -> Instruction {
+    build_instruction(accounts, instruction::DequeueOrderRequest {})
+}
+
+pub fn move_market_matching_pool_to_inplay(
+    accounts: accounts::UpdateMarketMatchingPool,
+) -> Instruction {
+    build_instruction(accounts, instruction::MoveMarketMatchingPoolToInplay {})
+}
+
+pub fn cancel_order(
+    accounts: accounts::CancelOrder,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction = build_instruction(accounts, instruction::CancelOrder {});
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+// remaining accounts are the (order, market matching pool) pairs of the orders to cancel followed by
+// the outcome liquidities accounts of their outcomes, any order book pages of their prices other
+// than accounts.market_order_book and the payers of orders that were never matched, whose order
+// accounts are closed to them
+pub fn cancel_all_orders(
+    accounts: accounts::CancelAllOrders,
+    orders: &[(Pubkey, Order)],
+    market_order_books: &[Pubkey],
+) -> Instruction {
+    let mut market_outcome_indexes: Vec<u16> = orders
+        .iter()
+        .map(|(_, order)| order.market_outcome_index)
+        .collect();
+    market_outcome_indexes.sort_unstable();
+    market_outcome_indexes.dedup();
+    let outcome_liquidities_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, &market_outcome_indexes);
+
+    let mut payers: Vec<Pubkey> = orders
+        .iter()
+        .filter(|(_, order)| order.order_status == OrderStatus::Open)
+        .map(|(_, order)| order.payer)
+        .collect();
+    payers.sort_unstable();
+    payers.dedup();
+    let payer_accounts = payers.iter().map(|payer| AccountMeta::new(*payer, false));
+
+    let remaining_accounts = orders.iter().flat_map(|(order_pk, order)| {
+        let (market_matching_pool, _) = pda::find_market_matching_pool_address(
+            &order.market,
+            order.market_outcome_index,
+            order.expected_price,
+            order.for_outcome,
+        );
+        vec![
+            AccountMeta::new(*order_pk, false),
+            AccountMeta::new(market_matching_pool, false),
+        ]
+    });
+
+    let mut instruction = build_instruction(accounts, instruction::CancelAllOrders {});
+    instruction.accounts.extend(remaining_accounts);
+    instruction.accounts.extend(outcome_liquidities_accounts);
+    instruction.accounts.extend(
+        market_order_books
+            .iter()
+            .map(|market_order_book| AccountMeta::new(*market_order_book, false)),
+    );
+    instruction.accounts.extend(payer_accounts);
+    instruction
+}
+
+pub fn amend_order(
+    accounts: accounts::AmendOrder,
+    data: OrderRequestData,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction = build_instruction(accounts, instruction::AmendOrder { data });
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn cancel_order_post_market_lock(
+    accounts: accounts::CancelOrderPostMarketLock,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction = build_instruction(accounts, instruction::CancelOrderPostMarketLock {});
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn cancel_preplay_order_post_event_start(
+    accounts: accounts::CancelPreplayOrderPostEventStart,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction =
+        build_instruction(accounts, instruction::CancelPreplayOrderPostEventStart {});
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn cancel_order_post_suspension(
+    accounts: accounts::CancelOrderPostSuspension,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction = build_instruction(accounts, instruction::CancelOrderPostSuspension {});
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn settle_order(accounts: accounts::SettleOrder) -> Instruction {
+    build_instruction(accounts, instruction::SettleOrder {})
+}
+
+pub fn settle_market_position(accounts: accounts::SettleMarketPosition) -> Instruction {
+    build_instruction(accounts, instruction::SettleMarketPosition {})
+}
+
+pub fn void_market_position(accounts: accounts::VoidMarketPosition) -> Instruction {
+    build_instruction(accounts, instruction::VoidMarketPosition {})
+}
+
+pub fn void_order(accounts: accounts::VoidOrder) -> Instruction {
+    build_instruction(accounts, instruction::VoidOrder {})
+}
+
+pub fn void_trades(accounts: accounts::VoidTrades) -> Instruction {
+    build_instruction(accounts, instruction::VoidTrades {})
+}
+
+pub fn authorise_admin_operator(
+    accounts: accounts::AuthoriseAdminOperator,
+    operator: Pubkey,
+) -> Instruction {
+    build_instruction(accounts, instruction::AuthoriseAdminOperator { operator })
+}
+
+pub fn authorise_operator(
+    accounts: accounts::AuthoriseOperator,
+    operator_type: String,
+    operator: Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::AuthoriseOperator {
+            operator_type,
+            operator,
+        },
+    )
+}
+
+pub fn update_operator_permissions(
+    accounts: accounts::AuthoriseOperator,
+    operator_type: String,
+    operator: Pubkey,
+    permissions: u16,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateOperatorPermissions {
+            operator_type,
+            operator,
+            permissions,
+        },
+    )
+}
+
+pub fn remove_authorised_operator(
+    accounts: accounts::AuthoriseOperator,
+    operator_type: String,
+    operator: Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::RemoveAuthorisedOperator {
+            operator_type,
+            operator,
+        },
+    )
+}
+
+pub fn migrate_account(accounts: accounts::MigrateAccount) -> Instruction {
+    build_instruction(accounts, instruction::MigrateAccount {})
+}
+
+pub fn process_order_match_taker(
+    accounts: accounts::ProcessOrderMatchTaker,
+    order_trade_seed: [u8; 16],
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::ProcessOrderMatchTaker { order_trade_seed },
+    )
+}
+
+pub fn process_order_match_maker(
+    accounts: accounts::ProcessOrderMatchMaker,
+    order_trade_seed: [u8; 16],
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::ProcessOrderMatchMaker { order_trade_seed },
+    )
+}
+
+pub fn update_market_liquidities_with_cross_liquidity(
+    accounts: accounts::UpdateMarketLiquidities,
+    source_for_outcome: bool,
+    source_liquidities: Vec<LiquiditySource>,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction = build_instruction(
+        accounts,
+        instruction::UpdateMarketLiquiditiesWithCrossLiquidity {
+            source_for_outcome,
+            source_liquidities,
+        },
+    );
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+// outcome liquidities accounts are only read when matching orders
+pub fn match_orders(
+    accounts: accounts::MatchOrders,
+    trade_for_seed: [u8; 16],
+    trade_against_seed: [u8; 16],
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes)
+            .into_iter()
+            .map(|meta| AccountMeta::new_readonly(meta.pubkey, false));
+    let mut instruction = build_instruction(
+        accounts,
+        instruction::MatchOrders {
+            trade_for_seed,
+            trade_against_seed,
+        },
+    );
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn create_market_type(
+    accounts: accounts::CreateMarketType,
+    name: String,
+    requires_discriminator: bool,
+    requires_value: bool,
+    template: MarketTemplate,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::CreateMarketType {
+            name,
+            requires_discriminator,
+            requires_value,
+            template,
+        },
+    )
+}
+
+pub fn create_price_ladder(
+    accounts: accounts::CreatePriceLadder,
+    distinct_seed: String,
+    max_number_of_prices: u16,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::CreatePriceLadder {
+            _distinct_seed: distinct_seed,
+            max_number_of_prices,
+        },
+    )
+}
+
+pub fn add_prices_to_price_ladder(
+    accounts: accounts::UpdatePriceLadder,
+    prices_to_add: Vec<Price>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::AddPricesToPriceLadder { prices_to_add },
+    )
+}
+
+pub fn remove_prices_from_price_ladder(
+    accounts: accounts::UpdatePriceLadder,
+    prices_to_remove: Vec<Price>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::RemovePricesFromPriceLadder { prices_to_remove },
+    )
+}
+
+pub fn increase_price_ladder_size(
+    accounts: accounts::UpdatePriceLadderSize,
+    max_number_of_prices: u16,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::IncreasePriceLadderSize {
+            max_number_of_prices,
+        },
+    )
+}
+
+pub fn close_price_ladder(accounts: accounts::ClosePriceLadder) -> Instruction {
+    build_instruction(accounts, instruction::ClosePriceLadder {})
+}
+
+pub fn create_market(
+    accounts: accounts::CreateMarket,
+    event_account: Pubkey,
+    market_type_discriminator: Option<String>,
+    market_type_value: Option<String>,
+    title: String,
+    max_decimals: u8,
+    market_lock_timestamp: i64,
+    event_start_timestamp: i64,
+    inplay_enabled: bool,
+    inplay_order_delay: u8,
+    event_start_order_behaviour: MarketOrderBehaviour,
+    market_lock_order_behaviour: MarketOrderBehaviour,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::CreateMarket {
+            event_account,
+            market_type_discriminator,
+            market_type_value,
+            title,
+            max_decimals,
+            market_lock_timestamp,
+            event_start_timestamp,
+            inplay_enabled,
+            inplay_order_delay,
+            event_start_order_behaviour,
+            market_lock_order_behaviour,
+        },
+    )
+}
+
+pub fn initialize_market_outcome(
+    accounts: accounts::InitializeMarketOutcome,
+    title: String,
+) -> Instruction {
+    build_instruction(accounts, instruction::InitializeMarketOutcome { title })
+}
+
+pub fn add_prices_to_market_outcome(
+    accounts: accounts::UpdateMarketOutcome,
+    outcome_index: u16,
+    new_prices: Vec<Price>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::AddPricesToMarketOutcome {
+            _outcome_index: outcome_index,
+            new_prices,
+        },
+    )
+}
+
+pub fn create_market_access_control(
+    accounts: accounts::CreateMarketAccessControl,
+    purchaser_list_mode: PurchaserListMode,
+    token_gate_mint: Option<Pubkey>,
+    purchasers_capacity: u16,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::CreateMarketAccessControl {
+            purchaser_list_mode,
+            token_gate_mint,
+            purchasers_capacity,
+        },
+    )
+}
+
+pub fn create_market_limits(
+    accounts: accounts::CreateMarketLimits,
+    max_order_stake: u64,
+    max_position_exposure: u64,
+    max_outcome_stake_matched: u64,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::CreateMarketLimits {
+            max_order_stake,
+            max_position_exposure,
+            max_outcome_stake_matched,
+        },
+    )
+}
+
+pub fn create_market_order_book_page(
+    accounts: accounts::CreateMarketOrderBookPage,
+    outcome_index: u16,
+    page_index: u16,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::CreateMarketOrderBookPage {
+            _outcome_index: outcome_index,
+            page_index,
+        },
+    )
+}
+
+pub fn update_market_access_control(
+    accounts: accounts::UpdateMarketAccessControl,
+    purchaser_list_mode: PurchaserListMode,
+    token_gate_mint: Option<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketAccessControl {
+            purchaser_list_mode,
+            token_gate_mint,
+        },
+    )
+}
+
+pub fn add_market_access_purchasers(
+    accounts: accounts::UpdateMarketAccessControl,
+    purchasers: Vec<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::AddMarketAccessPurchasers { purchasers },
+    )
+}
+
+pub fn remove_market_access_purchasers(
+    accounts: accounts::UpdateMarketAccessControl,
+    purchasers: Vec<Pubkey>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::RemoveMarketAccessPurchasers { purchasers },
+    )
+}
+
+pub fn update_market_limits(
+    accounts: accounts::UpdateMarketLimits,
+    max_order_stake: u64,
+    max_position_exposure: u64,
+    max_outcome_stake_matched: u64,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketLimits {
+            max_order_stake,
+            max_position_exposure,
+            max_outcome_stake_matched,
+        },
+    )
+}
+
+pub fn update_market_price_improvement(
+    accounts: accounts::UpdateMarket,
+    price_improvement_enabled: bool,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketPriceImprovement {
+            price_improvement_enabled,
+        },
+    )
+}
+
+pub fn update_market_atomic_matching(
+    accounts: accounts::UpdateMarket,
+    atomic_matching_enabled: bool,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketAtomicMatching {
+            atomic_matching_enabled,
+        },
+    )
+}
+
+pub fn update_market_crank_fee(
+    accounts: accounts::UpdateMarket,
+    crank_fee: u64,
+    crank_reward: u64,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketCrankFee {
+            crank_fee,
+            crank_reward,
+        },
+    )
+}
+
+pub fn update_market_suspension_order_behaviour(
+    accounts: accounts::UpdateMarket,
+    suspension_order_behaviour: MarketOrderBehaviour,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketSuspensionOrderBehaviour {
+            suspension_order_behaviour,
+        },
+    )
+}
+
+pub fn delegate_market_operator(
+    accounts: accounts::UpdateMarketOperatorDelegation,
+    operator: Pubkey,
+    permissions: u16,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::DelegateMarketOperator {
+            operator,
+            permissions,
+        },
+    )
+}
+
+pub fn revoke_market_operator_delegation(
+    accounts: accounts::UpdateMarketOperatorDelegation,
+    operator: Pubkey,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::RevokeMarketOperatorDelegation { operator },
+    )
+}
+
+pub fn update_market_title(accounts: accounts::UpdateMarket, title: String) -> Instruction {
+    build_instruction(accounts, instruction::UpdateMarketTitle { title })
+}
+
+pub fn update_market_trade_void_window(
+    accounts: accounts::UpdateMarket,
+    trade_void_window: i64,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketTradeVoidWindow { trade_void_window },
+    )
+}
+
+// remaining accounts are the outcome liquidities account of the withdrawn outcome
+pub fn withdraw_market_outcome(
+    accounts: accounts::WithdrawMarketOutcome,
+    outcome_index: u16,
+    reduction_factor: u16,
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, &[outcome_index]);
+    let mut instruction = build_instruction(
+        accounts,
+        instruction::WithdrawMarketOutcome {
+            outcome_index,
+            reduction_factor,
+        },
+    );
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn update_market_locktime(
+    accounts: accounts::UpdateMarketTimes,
+    lock_time: i64,
+) -> Instruction {
+    build_instruction(accounts, instruction::UpdateMarketLocktime { lock_time })
+}
+
+pub fn update_market_locktime_to_now(accounts: accounts::UpdateMarketTimes) -> Instruction {
+    build_instruction(accounts, instruction::UpdateMarketLocktimeToNow {})
+}
+
+pub fn update_market_event_start_time(
+    accounts: accounts::UpdateMarketTimes,
+    event_start_time: i64,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketEventStartTime { event_start_time },
+    )
+}
+
+pub fn update_market_event_start_time_to_now(accounts: accounts::UpdateMarketTimes) -> Instruction {
+    build_instruction(accounts, instruction::UpdateMarketEventStartTimeToNow {})
+}
+
+pub fn update_market_winning_places(
+    accounts: accounts::UpdateMarket,
+    winning_places: u16,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketWinningPlaces { winning_places },
+    )
+}
+
+pub fn move_market_to_inplay(accounts: accounts::MoveMarketToInplay) -> Instruction {
+    build_instruction(accounts, instruction::MoveMarketToInplay {})
+}
+
+pub fn open_market(accounts: accounts::OpenMarket, enable_cross_matching: bool) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::OpenMarket {
+            enable_cross_matching,
+        },
+    )
+}
+
+pub fn settle_market(
+    accounts: accounts::SettleMarket,
+    winning_outcomes: Vec<MarketWinningOutcome>,
+) -> Instruction {
+    build_instruction(accounts, instruction::SettleMarket { winning_outcomes })
+}
+
+pub fn settle_market_with_results(
+    accounts: accounts::SettleMarketWithResults,
+    outcome_results: Vec<OutcomeResult>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::SettleMarketWithResults { outcome_results },
+    )
+}
+
+pub fn complete_market_settlement(accounts: accounts::CompleteMarketSettlement) -> Instruction {
+    build_instruction(accounts, instruction::CompleteMarketSettlement {})
+}
+
+pub fn void_market(accounts: accounts::VoidMarket) -> Instruction {
+    build_instruction(accounts, instruction::VoidMarket {})
+}
+
+pub fn force_void_market(accounts: accounts::ForceVoidMarket) -> Instruction {
+    build_instruction(accounts, instruction::ForceVoidMarket {})
+}
+
+pub fn update_market_settlement_approvals(
+    accounts: accounts::UpdateMarketSettlementApprovals,
+    approvals_required: u8,
+    challenge_period: i64,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::UpdateMarketSettlementApprovals {
+            approvals_required,
+            challenge_period,
+        },
+    )
+}
+
+pub fn propose_market_settlement(
+    accounts: accounts::ProposeMarketSettlement,
+    action: SettlementProposalAction,
+    winning_outcomes: Vec<MarketWinningOutcome>,
+) -> Instruction {
+    build_instruction(
+        accounts,
+        instruction::ProposeMarketSettlement {
+            action,
+            winning_outcomes,
+        },
+    )
+}
+
+pub fn approve_market_settlement_proposal(
+    accounts: accounts::ApproveMarketSettlementProposal,
+) -> Instruction {
+    build_instruction(accounts, instruction::ApproveMarketSettlementProposal {})
+}
+
+pub fn execute_market_settlement_proposal(
+    accounts: accounts::ExecuteMarketSettlementProposal,
+) -> Instruction {
+    build_instruction(accounts, instruction::ExecuteMarketSettlementProposal {})
+}
+
+pub fn cancel_market_settlement_proposal(
+    accounts: accounts::CancelMarketSettlementProposal,
+) -> Instruction {
+    build_instruction(accounts, instruction::CancelMarketSettlementProposal {})
+}
+
+pub fn force_unsettled_count(
+    accounts: accounts::ForceUnsettledCount,
+    new_count: u32,
+) -> Instruction {
+    build_instruction(accounts, instruction::ForceUnsettledCount { new_count })
+}
+
+pub fn complete_market_void(accounts: accounts::CompleteMarketVoid) -> Instruction {
+    build_instruction(accounts, instruction::CompleteMarketVoid {})
+}
+
+pub fn publish_market(accounts: accounts::UpdateMarket) -> Instruction {
+    build_instruction(accounts, instruction::PublishMarket {})
+}
+
+pub fn unpublish_market(accounts: accounts::UpdateMarket) -> Instruction {
+    build_instruction(accounts, instruction::UnpublishMarket {})
+}
+
+pub fn suspend_market(
+    accounts: accounts::SuspendMarket,
+    market_outcome_indexes: &[u16],
+) -> Instruction {
+    let remaining_accounts =
+        market_outcome_liquidities_accounts(&accounts.market, market_outcome_indexes);
+    let mut instruction = build_instruction(accounts, instruction::SuspendMarket {});
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+pub fn unsuspend_market(accounts: accounts::SuspendMarket) -> Instruction {
+    build_instruction(accounts, instruction::UnsuspendMarket {})
+}
+
+pub fn set_market_ready_to_close(accounts: accounts::SetMarketReadyToClose) -> Instruction {
+    build_instruction(accounts, instruction::SetMarketReadyToClose {})
+}
+
+pub fn transfer_market_token_surplus(
+    accounts: accounts::TransferMarketTokenSurplus,
+) -> Instruction {
+    build_instruction(accounts, instruction::TransferMarketTokenSurplus {})
+}
+
+pub fn process_commission_payment(
+    accounts: accounts::ProcessMarketCommissionPayment,
+) -> Instruction {
+    build_instruction(accounts, instruction::ProcessCommissionPayment {})
+}
+
+pub fn close_order(accounts: accounts::CloseOrder) -> Instruction {
+    build_instruction(accounts, instruction::CloseOrder {})
+}
+
+pub fn close_trade(accounts: accounts::CloseTrade) -> Instruction {
+    build_instruction(accounts, instruction::CloseTrade {})
+}
+
+pub fn close_market_position(accounts: accounts::CloseMarketPosition) -> Instruction {
+    build_instruction(accounts, instruction::CloseMarketPosition {})
+}
+
+pub fn close_market_matching_pool(accounts: accounts::CloseMarketMatchingPool) -> Instruction {
+    build_instruction(accounts, instruction::CloseMarketMatchingPool {})
+}
+
+pub fn close_market_outcome_liquidities(
+    accounts: accounts::CloseMarketOutcomeLiquidities,
+) -> Instruction {
+    build_instruction(accounts, instruction::CloseMarketOutcomeLiquidities {})
+}
+
+pub fn close_market_outcome(accounts: accounts::CloseMarketOutcome) -> Instruction {
+    build_instruction(accounts, instruction::CloseMarketOutcome {})
+}
+
+pub fn close_market_queues(accounts: accounts::CloseMarketQueues) -> Instruction {
+    build_instruction(accounts, instruction::CloseMarketQueues {})
+}
+
+pub fn close_market(accounts: accounts::CloseMarket) -> Instruction {
+    build_instruction(accounts, instruction::CloseMarket {})
+}
+
+fn build_instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn market_outcome_liquidities_accounts(
+    market: &Pubkey,
+    market_outcome_indexes: &[u16],
+) -> Vec<AccountMeta> {
+    market_outcome_indexes
+        .iter()
+        .map(|index| {
+            let (market_outcome_liquidities, _) =
+                pda::find_market_outcome_liquidities_address(market, *index);
+            AccountMeta::new(market_outcome_liquidities, false)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{AnchorDeserialize, Discriminator};
+    use monaco_protocol::state::market_order_request_queue::TimeInForce;
+
+    #[test]
+    fn test_create_order_request() {
+        let market = Pubkey::new_unique();
+        let purchaser = Pubkey::new_unique();
+        let data = OrderRequestData {
+            market_outcome_index: 1,
+            for_outcome: true,
+            stake: 100,
+            price: Price::new(2_100),
+            distinct_seed: [1; 16],
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+        };
+
+        let result = create_order_request(
+            accounts::CreateOrderRequest {
+                reserved_order: Pubkey::new_unique(),
+                order_request_queue: Pubkey::new_unique(),
+                market_position: Pubkey::new_unique(),
+                payer: purchaser,
+                purchaser,
+                purchaser_token: Pubkey::new_unique(),
+                market,
+                market_outcome: Pubkey::new_unique(),
+                price_ladder: None,
+                market_escrow: Pubkey::new_unique(),
+                product: None,
+                system_program: solana_program::system_program::ID,
+                token_program: anchor_spl::token::ID,
+                market_limits: None,
+                market_access_control: None,
+                purchaser_gate_token: None,
+            },
+            data.clone(),
+        );
+
+        assert_eq!(ID, result.program_id);
+        assert_eq!(
+            instruction::CreateOrderRequest::DISCRIMINATOR,
+            result.data[..8]
+        );
+        assert_eq!(
+            data,
+            OrderRequestData::deserialize(&mut &result.data[8..]).unwrap()
+        );
+
+        assert_eq!(16, result.accounts.len());
+        assert_eq!(purchaser, result.accounts[4].pubkey);
+        assert!(result.accounts[4].is_signer);
+        assert_eq!(market, result.accounts[6].pubkey);
+        assert!(result.accounts[6].is_writable);
+        // optional accounts which are not provided are replaced by the program id
+        assert_eq!(ID, result.accounts[8].pubkey);
+        assert_eq!(ID, result.accounts[10].pubkey);
+        assert_eq!(ID, result.accounts[13].pubkey);
+        assert_eq!(ID, result.accounts[14].pubkey);
+        assert_eq!(ID, result.accounts[15].pubkey);
+    }
+
+    #[test]
+    fn test_create_order_requests() {
+        let market = Pubkey::new_unique();
+        let purchaser = Pubkey::new_unique();
+        let order_request = |market_outcome_index: u16, distinct_seed: [u8; 16]| OrderRequestData {
+            market_outcome_index,
+            for_outcome: true,
+            stake: 100,
+            price: Price::new(2_100),
+            distinct_seed,
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+        };
+        let data = vec![
+            order_request(1, [1; 16]),
+            order_request(0, [2; 16]),
+            order_request(1, [3; 16]),
+        ];
+
+        let result = create_order_requests(
+            accounts::CreateOrderRequests {
+                order_request_queue: Pubkey::new_unique(),
+                market_position: Pubkey::new_unique(),
+                payer: purchaser,
+                purchaser,
+                purchaser_token: Pubkey::new_unique(),
+                market,
+                price_ladder: None,
+                market_escrow: Pubkey::new_unique(),
+                product: None,
+                system_program: solana_program::system_program::ID,
+                token_program: anchor_spl::token::ID,
+                market_limits: None,
+                market_access_control: None,
+                purchaser_gate_token: None,
+            },
+            data.clone(),
+        );
+
+        assert_eq!(
+            instruction::CreateOrderRequests::DISCRIMINATOR,
+            result.data[..8]
+        );
+        assert_eq!(
+            data,
+            Vec::<OrderRequestData>::deserialize(&mut &result.data[8..]).unwrap()
+        );
+
+        // 14 accounts, 3 orders and 2 distinct market outcomes
+        assert_eq!(19, result.accounts.len());
+        let remaining_accounts: Vec<Pubkey> = result.accounts[14..]
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect();
+        assert_eq!(
+            vec![
+                pda::find_order_address(&market, &purchaser, &[1; 16]).0,
+                pda::find_order_address(&market, &purchaser, &[2; 16]).0,
+                pda::find_order_address(&market, &purchaser, &[3; 16]).0,
+                pda::find_market_outcome_address(&market, 0).0,
+                pda::find_market_outcome_address(&market, 1).0,
+            ],
+            remaining_accounts
+        );
+    }
+
+    #[test]
+    fn test_cancel_all_orders() {
+        let market = Pubkey::new_unique();
+        let order = |market_outcome_index: u16| Order {
+            purchaser: Pubkey::new_unique(),
+            market,
+            market_outcome_index,
+            for_outcome: true,
+            order_status: OrderStatus::Open,
+            product: None,
+            stake: 100,
+            voided_stake: 0,
+            expected_price: Price::new(2_100),
+            creation_timestamp: 0,
+            stake_unmatched: 100,
+            payout: 0,
+            payer: Pubkey::new_unique(),
+            product_commission_rate: 0.0,
+            schema_version: Order::SCHEMA_VERSION,
+        };
+        let mut orders = vec![
+            (Pubkey::new_unique(), order(1)),
+            (Pubkey::new_unique(), order(0)),
+            (Pubkey::new_unique(), order(1)),
+        ];
+        orders[1].1.order_status = OrderStatus::Matched;
+
+        let accounts = accounts::CancelAllOrders {
+            purchaser: Pubkey::new_unique(),
+            purchaser_token_account: Pubkey::new_unique(),
+            market,
+            market_liquidities: Pubkey::new_unique(),
+            market_matching_queue: Pubkey::new_unique(),
+            market_escrow: Pubkey::new_unique(),
+            market_position: Pubkey::new_unique(),
+            token_program: anchor_spl::token::ID,
+            market_order_book: None,
+        };
+        let accounts_len = accounts.to_account_metas(None).len();
+        let market_order_book = pda::find_market_order_book_address(&market, 1, 0).0;
+        let result = cancel_all_orders(accounts, &orders, &[market_order_book]);
+
+        // (order, matching pool) pairs followed by 2 distinct outcome liquidities accounts, the
+        // order book page and the payers of the 2 open orders
+        assert_eq!(accounts_len + 11, result.accounts.len());
+        assert_eq!(orders[0].0, result.accounts[accounts_len].pubkey);
+        let outcome_liquidities: Vec<Pubkey> = result.accounts[accounts_len + 6..accounts_len + 8]
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect();
+        assert_eq!(
+            vec![
+                pda::find_market_outcome_liquidities_address(&market, 0).0,
+                pda::find_market_outcome_liquidities_address(&market, 1).0,
+            ],
+            outcome_liquidities
+        );
+        assert!(result.accounts[accounts_len + 6].is_writable);
+        assert_eq!(market_order_book, result.accounts[accounts_len + 8].pubkey);
+        assert!(result.accounts[accounts_len + 8].is_writable);
+        let mut payers = vec![orders[0].1.payer, orders[2].1.payer];
+        payers.sort_unstable();
+        let payer_accounts: Vec<Pubkey> = result.accounts[accounts_len + 9..]
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect();
+        assert_eq!(payers, payer_accounts);
+        assert!(result.accounts[accounts_len + 9].is_writable);
+    }
+
+    #[test]
+    fn test_settle_market() {
+        let winning_outcomes = vec![
+            MarketWinningOutcome::new(0, 1, 2),
+            MarketWinningOutcome::new(1, 1, 2),
+        ];
+
+        let result = settle_market(
+            accounts::SettleMarket {
+                market: Pubkey::new_unique(),
+                market_matching_queue: Pubkey::new_unique(),
+                order_request_queue: Pubkey::new_unique(),
+                market_operator: Pubkey::new_unique(),
+                authorised_operators: Pubkey::new_unique(),
+            },
+            winning_outcomes.clone(),
+        );
+
+        assert_eq!(instruction::SettleMarket::DISCRIMINATOR, result.data[..8]);
+        assert_eq!(
+            winning_outcomes,
+            Vec::<MarketWinningOutcome>::deserialize(&mut &result.data[8..]).unwrap()
+        );
+        assert_eq!(5, result.accounts.len());
+        assert!(result.accounts[3].is_signer);
+    }
+
+    #[test]
+    fn test_propose_market_settlement() {
+        let market = Pubkey::new_unique();
+        let winning_outcomes = vec![MarketWinningOutcome::new(1, 1, 1)];
+
+        let result = propose_market_settlement(
+            accounts::ProposeMarketSettlement {
+                market_settlement_proposal: pda::find_market_settlement_proposal_address(&market).0,
+                market,
+                market_operator: Pubkey::new_unique(),
+                authorised_operators: Pubkey::new_unique(),
+                system_program: solana_program::system_program::ID,
+            },
+            SettlementProposalAction::Settle,
+            winning_outcomes.clone(),
+        );
+
+        assert_eq!(
+            instruction::ProposeMarketSettlement::DISCRIMINATOR,
+            result.data[..8]
+        );
+        assert_eq!(
+            SettlementProposalAction::Settle,
+            SettlementProposalAction::deserialize(&mut &result.data[8..9]).unwrap()
+        );
+        assert_eq!(
+            winning_outcomes,
+            Vec::<MarketWinningOutcome>::deserialize(&mut &result.data[9..]).unwrap()
+        );
+        assert_eq!(5, result.accounts.len());
+        assert!(result.accounts[0].is_writable);
+        assert!(result.accounts[2].is_signer);
+    }
+}
@@ -0,0 +1,14 @@
+//! Rust client for the Monaco Protocol program.
+//!
+//! - `instructions` builds an `Instruction` for every instruction exposed by the program
+//! - `pda` derives the program addresses used by the program's account contexts
+//! - `decode` deserializes the program's accounts from raw account data
+//! - `events` re-exports the events emitted by the program
+
+pub mod decode;
+pub mod instructions;
+pub mod pda;
+
+pub use monaco_protocol::accounts;
+pub use monaco_protocol::events;
+pub use monaco_protocol::ID as PROGRAM_ID;
@@ -0,0 +1,303 @@
+use solana_program::pubkey::Pubkey;
+
+use monaco_protocol::monaco_protocol::SEED_SEPARATOR;
+use monaco_protocol::state::price::Price;
+use monaco_protocol::ID;
+
+// seeds must be kept in sync with monaco_protocol::context
+
+pub const ADMIN_OPERATOR_TYPE: &str = "ADMIN";
+pub const CRANK_OPERATOR_TYPE: &str = "CRANK";
+pub const MARKET_OPERATOR_TYPE: &str = "MARKET";
+
+/*
+ * Market
+ */
+
+pub fn find_market_address(
+    event_account: &Pubkey,
+    market_type: &Pubkey,
+    market_type_discriminator: Option<&str>,
+    market_type_value: Option<&str>,
+    version: u8,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            event_account.as_ref(),
+            market_type.as_ref(),
+            market_type_discriminator.unwrap_or("").as_ref(),
+            SEED_SEPARATOR,
+            market_type_value.unwrap_or("").as_ref(),
+            SEED_SEPARATOR,
+            version.to_string().as_ref(),
+            mint.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_market_escrow_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_funding_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"funding".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_outcome_address(market: &Pubkey, outcome_index: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[market.as_ref(), outcome_index.to_string().as_ref()], &ID)
+}
+
+pub fn find_market_access_control_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"access_control".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_settlement_proposal_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"settlement_proposal".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_limits_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"limits".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_type_address(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"market_type".as_ref(), name.as_ref()], &ID)
+}
+
+pub fn find_price_ladder_address(authority: &Pubkey, distinct_seed: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"price_ladder".as_ref(),
+            authority.as_ref(),
+            distinct_seed.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+/*
+ * Market queues
+ */
+
+pub fn find_market_liquidities_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"liquidities".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_outcome_liquidities_address(
+    market: &Pubkey,
+    market_outcome_index: u16,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"liquidities".as_ref(),
+            market.as_ref(),
+            market_outcome_index.to_string().as_ref(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_market_matching_queue_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"matching".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_order_request_queue_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"order_request".as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_commission_payments_queue_address(market: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"commission_payments".as_ref(), market.as_ref()], &ID)
+}
+
+/*
+ * Orders and positions
+ */
+
+pub fn find_order_address(
+    market: &Pubkey,
+    purchaser: &Pubkey,
+    distinct_seed: &[u8; 16],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[market.as_ref(), purchaser.as_ref(), distinct_seed.as_ref()],
+        &ID,
+    )
+}
+
+// reserved order and order share the same address
+pub fn find_reserved_order_address(
+    market: &Pubkey,
+    purchaser: &Pubkey,
+    distinct_seed: &[u8; 16],
+) -> (Pubkey, u8) {
+    find_order_address(market, purchaser, distinct_seed)
+}
+
+pub fn find_trade_address(order: &Pubkey, trade_seed: &[u8; 16]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[order.as_ref(), trade_seed.as_ref()], &ID)
+}
+
+pub fn find_market_position_address(market: &Pubkey, purchaser: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[purchaser.as_ref(), market.as_ref()], &ID)
+}
+
+pub fn find_market_matching_pool_address(
+    market: &Pubkey,
+    market_outcome_index: u16,
+    price: Price,
+    for_outcome: bool,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            market.as_ref(),
+            market_outcome_index.to_string().as_ref(),
+            b"-".as_ref(),
+            price.to_string().as_ref(),
+            for_outcome.to_string().as_ref(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_market_order_book_address(
+    market: &Pubkey,
+    market_outcome_index: u16,
+    page_index: u16,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"order_book".as_ref(),
+            market.as_ref(),
+            market_outcome_index.to_string().as_ref(),
+            b"-".as_ref(),
+            page_index.to_string().as_ref(),
+        ],
+        &ID,
+    )
+}
+
+/*
+ * Operators and product
+ */
+
+pub fn find_authorised_operators_address(operator_type: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"authorised_operators".as_ref(), operator_type.as_ref()],
+        &ID,
+    )
+}
+
+pub fn find_protocol_product_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"product".as_ref(), b"MONACO_PROTOCOL".as_ref()],
+        &protocol_product::ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_market_matching_pool_address_price_seed() {
+        let market = Pubkey::new_unique();
+        let price = Price::new(2_100);
+
+        let expected = Pubkey::find_program_address(
+            &[
+                market.as_ref(),
+                b"1".as_ref(),
+                b"-".as_ref(),
+                b"2.100".as_ref(),
+                b"true".as_ref(),
+            ],
+            &ID,
+        );
+        assert_eq!(
+            expected,
+            find_market_matching_pool_address(&market, 1, price, true)
+        );
+        assert_ne!(
+            find_market_matching_pool_address(&market, 1, price, true),
+            find_market_matching_pool_address(&market, 1, price, false),
+        );
+        assert_ne!(
+            find_market_matching_pool_address(&market, 1, price, true),
+            find_market_matching_pool_address(&market, 1, Price::new(2_110), true),
+        );
+    }
+
+    #[test]
+    fn test_market_outcome_liquidities_address() {
+        let market = Pubkey::new_unique();
+
+        let expected =
+            Pubkey::find_program_address(&[b"liquidities".as_ref(), market.as_ref(), b"12"], &ID);
+        assert_eq!(
+            expected,
+            find_market_outcome_liquidities_address(&market, 12)
+        );
+        assert_ne!(
+            find_market_liquidities_address(&market),
+            find_market_outcome_liquidities_address(&market, 0)
+        );
+    }
+
+    #[test]
+    fn test_market_address_optional_seeds() {
+        let event = Pubkey::new_unique();
+        let market_type = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let (market, _) = find_market_address(&event, &market_type, None, None, 0, &mint);
+        let expected = Pubkey::find_program_address(
+            &[
+                event.as_ref(),
+                market_type.as_ref(),
+                b"".as_ref(),
+                SEED_SEPARATOR,
+                b"".as_ref(),
+                SEED_SEPARATOR,
+                b"0".as_ref(),
+                mint.as_ref(),
+            ],
+            &ID,
+        )
+        .0;
+        assert_eq!(expected, market);
+
+        let (market_v1, _) = find_market_address(&event, &market_type, None, None, 1, &mint);
+        assert_ne!(market, market_v1);
+
+        let (market_discriminated, _) =
+            find_market_address(&event, &market_type, Some("1"), None, 0, &mint);
+        let (market_valued, _) =
+            find_market_address(&event, &market_type, None, Some("1"), 0, &mint);
+        assert_ne!(market_discriminated, market_valued);
+    }
+
+    #[test]
+    fn test_order_and_market_position_seed_order() {
+        let market = Pubkey::new_unique();
+        let purchaser = Pubkey::new_unique();
+
+        let expected_position =
+            Pubkey::find_program_address(&[purchaser.as_ref(), market.as_ref()], &ID).0;
+        assert_eq!(
+            expected_position,
+            find_market_position_address(&market, &purchaser).0
+        );
+
+        let distinct_seed = [1; 16];
+        let expected_order = Pubkey::find_program_address(
+            &[market.as_ref(), purchaser.as_ref(), distinct_seed.as_ref()],
+            &ID,
+        )
+        .0;
+        assert_eq!(
+            expected_order,
+            find_order_address(&market, &purchaser, &distinct_seed).0
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::cell::Cell;
+use std::sync::Once;
+
+use solana_program::clock::{Clock, UnixTimestamp};
+use solana_program::entrypoint::SUCCESS;
+use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+// the program reads time through the clock sysvar, off-chain the syscall is served from a
+// per-thread timestamp so simulations on different threads don't interfere
+thread_local! {
+    static TIMESTAMP: Cell<UnixTimestamp> = const { Cell::new(0) };
+}
+
+static INSTALL_STUBS: Once = Once::new();
+
+struct SimulatorSyscallStubs;
+
+impl SyscallStubs for SimulatorSyscallStubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: timestamp(),
+            ..Clock::default()
+        };
+        unsafe {
+            *(var_addr as *mut Clock) = clock;
+        }
+        SUCCESS
+    }
+}
+
+pub fn set_timestamp(timestamp: UnixTimestamp) {
+    INSTALL_STUBS.call_once(|| {
+        set_syscall_stubs(Box::new(SimulatorSyscallStubs));
+    });
+    TIMESTAMP.with(|cell| cell.set(timestamp));
+}
+
+pub fn timestamp() -> UnixTimestamp {
+    TIMESTAMP.with(|cell| cell.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::sysvar::Sysvar;
+
+    #[test]
+    fn test_clock_sysvar() {
+        set_timestamp(1_700_000_000);
+
+        assert_eq!(1_700_000_000, Clock::get().unwrap().unix_timestamp);
+
+        set_timestamp(1_700_000_060);
+
+        assert_eq!(1_700_000_060, Clock::get().unwrap().unix_timestamp);
+    }
+}
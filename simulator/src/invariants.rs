@@ -0,0 +1,469 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use anchor_lang::prelude::*;
+use monaco_protocol::state::market_account::MarketStatus;
+use monaco_protocol::state::order_account::OrderStatus;
+use monaco_protocol::state::price::Price;
+
+use crate::Simulator;
+
+/// An invariant of the simulated market which no sequence of instructions should break
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// balances and escrow don't add up to what was deposited
+    FundsNotConserved {
+        deposits: u64,
+        funds: u128,
+    },
+    /// exposure of a market position can't be represented as an unsigned amount
+    ExposureInvalid {
+        purchaser: Pubkey,
+        exposure: i128,
+    },
+    /// escrow doesn't hold the exposure of unpaid market positions, queued commission payments
+    /// and crank fees not yet paid out
+    EscrowShortfall {
+        escrow_balance: u64,
+        required: u128,
+    },
+    /// market positions would together win more if the outcome won than they would lose
+    MarketOutcomeSumsUnbalanced {
+        market_outcome_index: u16,
+        total: i128,
+    },
+    UnsettledAccountsCount {
+        market: u32,
+        expected: u32,
+    },
+    UnclosedAccountsCount {
+        market: u32,
+        expected: u32,
+    },
+    /// pool liquidity doesn't equal the unmatched stake of the orders in it
+    PoolLiquidityMismatch {
+        market_outcome_index: u16,
+        for_outcome: bool,
+        price: Price,
+        liquidity_amount: u64,
+        stake_unmatched: u64,
+    },
+    /// pool liquidity, less stake matched but not yet cranked, doesn't equal the market liquidity
+    MarketLiquidityMismatch {
+        market_outcome_index: u16,
+        for_outcome: bool,
+        price: Price,
+        pool_liquidity: u64,
+        market_liquidity: u64,
+    },
+}
+
+/// Checks the invariants over the simulator's current accounts, returning the first broken.
+///
+/// Escrow must exactly cover exposure until the market is settled, after which position profits
+/// are rounded down and the escrow may keep the remainder.
+pub fn check_invariants(simulator: &Simulator) -> std::result::Result<(), InvariantViolation> {
+    check_funds_conserved(simulator)?;
+    check_escrow_covers_exposure(simulator)?;
+    // takers' positions are updated on order creation but makers' only when the match is cranked
+    if simulator.market_matching_queue().matches.is_empty() {
+        check_market_outcome_sums(simulator)?;
+    }
+    check_account_counts(simulator)?;
+    if simulator.market().market_status == MarketStatus::Open {
+        check_pool_liquidities(simulator)?;
+        check_market_liquidities(simulator)?;
+    }
+    Ok(())
+}
+
+fn check_funds_conserved(simulator: &Simulator) -> std::result::Result<(), InvariantViolation> {
+    let funds = simulator
+        .balances()
+        .map(|(_, balance)| u128::from(*balance))
+        .sum::<u128>()
+        + u128::from(simulator.escrow_balance());
+
+    if funds != u128::from(simulator.deposits()) {
+        return Err(InvariantViolation::FundsNotConserved {
+            deposits: simulator.deposits(),
+            funds,
+        });
+    }
+    Ok(())
+}
+
+fn check_escrow_covers_exposure(
+    simulator: &Simulator,
+) -> std::result::Result<(), InvariantViolation> {
+    let mut required = 0_u128;
+    for (purchaser, market_position) in simulator.market_positions() {
+        if market_position.paid {
+            continue;
+        }
+        // worst case over a single winning outcome, checked before the position computes it
+        if market_position.winning_places <= 1 {
+            let exposure = market_position
+                .market_outcome_sums
+                .iter()
+                .zip(&market_position.unmatched_exposures)
+                .map(|(sum, unmatched)| i128::from(*unmatched) - sum.min(&0_i128))
+                .max()
+                .unwrap_or(0);
+            if u64::try_from(exposure).is_err() {
+                return Err(InvariantViolation::ExposureInvalid {
+                    purchaser: *purchaser,
+                    exposure,
+                });
+            }
+        }
+        required += u128::from(market_position.total_exposure());
+    }
+
+    let payment_queue = &simulator.commission_payments_queue().payment_queue;
+    let mut queued_payments = payment_queue.clone();
+    while let Some(payment) = queued_payments.dequeue() {
+        required += u128::from(payment.amount);
+    }
+
+    required += u128::from(simulator.market().crank_fee_balance);
+
+    let escrow_balance = u128::from(simulator.escrow_balance());
+    let covered = match simulator.market().market_status {
+        MarketStatus::Initializing | MarketStatus::Open | MarketStatus::Locked => {
+            escrow_balance == required
+        }
+        _ => escrow_balance >= required,
+    };
+    if !covered {
+        return Err(InvariantViolation::EscrowShortfall {
+            escrow_balance: simulator.escrow_balance(),
+            required,
+        });
+    }
+    Ok(())
+}
+
+fn check_market_outcome_sums(simulator: &Simulator) -> std::result::Result<(), InvariantViolation> {
+    for market_outcome_index in 0..simulator.market().market_outcomes_count {
+        let total = simulator
+            .market_positions()
+            .filter_map(|(_, market_position)| {
+                market_position
+                    .market_outcome_sums
+                    .get(usize::from(market_outcome_index))
+            })
+            .sum::<i128>();
+        // rounding may leave the market ahead, never behind
+        if total > 0 {
+            return Err(InvariantViolation::MarketOutcomeSumsUnbalanced {
+                market_outcome_index,
+                total,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_account_counts(simulator: &Simulator) -> std::result::Result<(), InvariantViolation> {
+    let market_positions = simulator.market_positions().count();
+    let unpaid_market_positions = simulator
+        .market_positions()
+        .filter(|(_, market_position)| !market_position.paid)
+        .count();
+    let orders = simulator.orders().count();
+    let unsettled_orders = simulator
+        .orders()
+        .filter(|(_, order)| matches!(order.order_status, OrderStatus::Open | OrderStatus::Matched))
+        .count();
+    let market_matching_pools = simulator.market_matching_pools().count();
+    let trades = simulator.trades().len();
+
+    let market = simulator.market();
+    let unsettled_expected = count(unpaid_market_positions + unsettled_orders);
+    if market.unsettled_accounts_count != unsettled_expected {
+        return Err(InvariantViolation::UnsettledAccountsCount {
+            market: market.unsettled_accounts_count,
+            expected: unsettled_expected,
+        });
+    }
+    let unclosed_expected = count(market_positions + orders + market_matching_pools + trades);
+    if market.unclosed_accounts_count != unclosed_expected {
+        return Err(InvariantViolation::UnclosedAccountsCount {
+            market: market.unclosed_accounts_count,
+            expected: unclosed_expected,
+        });
+    }
+    Ok(())
+}
+
+fn check_pool_liquidities(simulator: &Simulator) -> std::result::Result<(), InvariantViolation> {
+    for pool in simulator.market_matching_pools() {
+        let stake_unmatched = (0..pool.orders.len())
+            .filter_map(|index| pool.orders.peek(index))
+            .filter_map(|order_pk| simulator.order(order_pk))
+            .map(|order| order.stake_unmatched)
+            .sum::<u64>();
+
+        if pool.liquidity_amount != stake_unmatched {
+            return Err(InvariantViolation::PoolLiquidityMismatch {
+                market_outcome_index: pool.market_outcome_index,
+                for_outcome: pool.for_outcome,
+                price: pool.price,
+                liquidity_amount: pool.liquidity_amount,
+                stake_unmatched,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_market_liquidities(simulator: &Simulator) -> std::result::Result<(), InvariantViolation> {
+    // maker stake matched on order creation only leaves the pool when the match is cranked
+    let mut stake_uncranked: BTreeMap<(u16, bool, Price), u64> = BTreeMap::new();
+    for order_match in simulator.market_matching_queue().matches.to_vec() {
+        if order_match.pk.is_none() {
+            *stake_uncranked
+                .entry((
+                    order_match.outcome_index,
+                    order_match.for_outcome,
+                    order_match.price,
+                ))
+                .or_default() += order_match.stake;
+        }
+    }
+
+    let market_liquidities = simulator.market_liquidities();
+    let mut pool_keys = Vec::new();
+    for pool in simulator.market_matching_pools() {
+        let key = (pool.market_outcome_index, pool.for_outcome, pool.price);
+        let pool_liquidity = pool
+            .liquidity_amount
+            .saturating_sub(stake_uncranked.get(&key).copied().unwrap_or(0));
+        let market_liquidity = match pool.for_outcome {
+            true => market_liquidities.get_liquidity_for(pool.market_outcome_index, pool.price),
+            false => {
+                market_liquidities.get_liquidity_against(pool.market_outcome_index, pool.price)
+            }
+        }
+        .map(|liquidity| liquidity.liquidity)
+        .unwrap_or(0);
+
+        if pool_liquidity != market_liquidity {
+            return Err(InvariantViolation::MarketLiquidityMismatch {
+                market_outcome_index: pool.market_outcome_index,
+                for_outcome: pool.for_outcome,
+                price: pool.price,
+                pool_liquidity,
+                market_liquidity,
+            });
+        }
+        pool_keys.push(key);
+    }
+
+    // direct liquidity can only come from orders resting in a pool
+    let liquidities = market_liquidities
+        .liquidities_for
+        .iter()
+        .map(|liquidity| (true, liquidity))
+        .chain(
+            market_liquidities
+                .liquidities_against
+                .iter()
+                .map(|liquidity| (false, liquidity)),
+        );
+    for (for_outcome, liquidity) in liquidities {
+        let key = (liquidity.outcome, for_outcome, liquidity.price);
+        if liquidity.sources.is_empty() && liquidity.liquidity > 0 && !pool_keys.contains(&key) {
+            return Err(InvariantViolation::MarketLiquidityMismatch {
+                market_outcome_index: liquidity.outcome,
+                for_outcome,
+                price: liquidity.price,
+                pool_liquidity: 0,
+                market_liquidity: liquidity.liquidity,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn count(accounts: usize) -> u32 {
+    u32::try_from(accounts).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use monaco_protocol::state::market_account::MarketWinningOutcome;
+    use monaco_protocol::state::market_order_request_queue::{OrderRequestData, TimeInForce};
+    use proptest::prelude::*;
+
+    // few prices and outcomes so generated orders often match
+    const PRICES: [u64; 3] = [2_000, 2_500, 3_000];
+
+    #[derive(Clone, Debug)]
+    enum Action {
+        CreateOrderRequest {
+            purchaser: usize,
+            market_outcome_index: u16,
+            for_outcome: bool,
+            stake: u64,
+            price: usize,
+            time_in_force: TimeInForce,
+        },
+        ProcessOrderRequest,
+        DequeueOrderRequest,
+        CancelOrder(usize),
+        Crank,
+        AdvanceClock(i64),
+    }
+
+    fn action(purchasers: usize, market_outcomes_count: u16) -> impl Strategy<Value = Action> {
+        prop_oneof![
+            4 => (
+                0..purchasers,
+                0..market_outcomes_count,
+                any::<bool>(),
+                // risk is rounded down per match, so a taker matching several makers at a
+                // fractional price can win more than they lose; even stakes keep risk whole
+                (1_u64..500).prop_map(|stake| stake * 2),
+                0..PRICES.len(),
+                prop_oneof![
+                    3 => Just(TimeInForce::GoodTillCancelled),
+                    1 => Just(TimeInForce::ImmediateOrCancel)
+                ],
+            )
+                .prop_map(
+                    |(purchaser, market_outcome_index, for_outcome, stake, price, time_in_force)| {
+                        Action::CreateOrderRequest {
+                            purchaser,
+                            market_outcome_index,
+                            for_outcome,
+                            stake,
+                            price,
+                            time_in_force,
+                        }
+                    }
+                ),
+            4 => Just(Action::ProcessOrderRequest),
+            1 => Just(Action::DequeueOrderRequest),
+            1 => any::<usize>().prop_map(Action::CancelOrder),
+            4 => Just(Action::Crank),
+            1 => (1_i64..120).prop_map(Action::AdvanceClock),
+        ]
+    }
+
+    fn scenario() -> impl Strategy<Value = (u16, usize, u16, Vec<Action>, u8, (u64, u64))> {
+        (2_u16..=3, 2_usize..=4).prop_flat_map(|(market_outcomes_count, purchasers)| {
+            (
+                Just(market_outcomes_count),
+                Just(purchasers),
+                0..market_outcomes_count,
+                prop::collection::vec(action(purchasers, market_outcomes_count), 1..120),
+                0_u8..=10,
+                // rewards are bounded by the fee, as enforced by update_market_crank_fee
+                (0_u64..=3).prop_flat_map(|crank_fee| (Just(crank_fee), 0..=crank_fee)),
+            )
+        })
+    }
+
+    fn apply(
+        simulator: &mut Simulator,
+        purchasers: &[Pubkey],
+        order_pks: &mut Vec<Pubkey>,
+        seed: &mut u8,
+        action: &Action,
+    ) {
+        // instructions failing is expected, e.g., insufficient funds, and leaves the accounts as
+        // they were, so only the invariants are asserted
+        match action {
+            Action::CreateOrderRequest {
+                purchaser,
+                market_outcome_index,
+                for_outcome,
+                stake,
+                price,
+                time_in_force,
+            } => {
+                *seed = seed.wrapping_add(1);
+                let data = OrderRequestData {
+                    market_outcome_index: *market_outcome_index,
+                    for_outcome: *for_outcome,
+                    stake: *stake,
+                    price: Price::new(PRICES[*price]),
+                    distinct_seed: [*seed; 16],
+                    expires_on: None,
+                    time_in_force: *time_in_force,
+                    post_only: false,
+                };
+                let _ = simulator.create_order_request(purchasers[*purchaser], data);
+            }
+            Action::ProcessOrderRequest => {
+                if let Ok(order_pk) = simulator.process_order_request() {
+                    order_pks.push(order_pk);
+                }
+            }
+            Action::DequeueOrderRequest => {
+                let _ = simulator.dequeue_order_request();
+            }
+            Action::CancelOrder(index) => {
+                if !order_pks.is_empty() {
+                    let _ = simulator.cancel_order(&order_pks[index % order_pks.len()]);
+                }
+            }
+            Action::Crank => {
+                let _ = simulator.crank();
+            }
+            Action::AdvanceClock(seconds) => simulator.advance_clock(*seconds),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_invariants_hold_through_order_flow_and_settlement(
+            (market_outcomes_count, purchasers_count, winning_outcome, actions, commission, (crank_fee, crank_reward)) in scenario()
+        ) {
+            let mut simulator = Simulator::new(Pubkey::new_unique(), market_outcomes_count, 0);
+            simulator.set_protocol_commission_rate(f64::from(commission));
+            simulator.market_mut().crank_fee = crank_fee;
+            simulator.market_mut().crank_reward = crank_reward;
+            let purchasers: Vec<Pubkey> = (0..purchasers_count).map(|_| Pubkey::new_unique()).collect();
+            for purchaser in &purchasers {
+                simulator.deposit(*purchaser, 10_000).unwrap();
+            }
+
+            let mut order_pks = vec![];
+            let mut seed = 0_u8;
+            for action in &actions {
+                apply(&mut simulator, &purchasers, &mut order_pks, &mut seed, action);
+                prop_assert_eq!(Ok(()), check_invariants(&simulator), "after {:?}", action);
+            }
+
+            // drain the queues as settlement requires
+            while simulator.dequeue_order_request().is_ok() {
+                prop_assert_eq!(Ok(()), check_invariants(&simulator));
+            }
+            simulator.crank_all().unwrap();
+            prop_assert_eq!(Ok(()), check_invariants(&simulator));
+
+            simulator
+                .settle(vec![MarketWinningOutcome {
+                    outcome_index: winning_outcome,
+                    dead_heat_numerator: 1,
+                    dead_heat_denominator: 1,
+                }])
+                .unwrap();
+            prop_assert_eq!(Ok(()), check_invariants(&simulator));
+
+            // every account settled and all commission paid, leaving only rounding and crank fees
+            // not paid out in escrow
+            prop_assert_eq!(0, simulator.market().unsettled_accounts_count);
+            prop_assert!(simulator.commission_payments_queue().payment_queue.is_empty());
+            prop_assert!(simulator.market_positions().all(|(_, position)| position.paid));
+            prop_assert!(
+                simulator.escrow_balance()
+                    <= u64::try_from(purchasers_count).unwrap() + simulator.market().crank_fee_balance
+            );
+        }
+    }
+}
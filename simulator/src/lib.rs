@@ -0,0 +1,13 @@
+//! Off-chain deterministic simulator of the Monaco Protocol matching engine.
+//!
+//! - `simulator` holds a market's accounts in memory and runs the program's order request,
+//!   cancellation, matching and settlement logic against them
+//! - `invariants` checks conservation of funds, account counts and liquidity agreement over the
+//!   simulator's accounts, e.g., after every step of a replay
+//! - `clock` provides the simulated clock read by the program in place of the clock sysvar
+
+pub mod clock;
+pub mod invariants;
+pub mod simulator;
+
+pub use simulator::Simulator;
@@ -0,0 +1,1021 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use protocol_product::state::product::Product;
+use solana_program::clock::UnixTimestamp;
+use solana_program::program_error::ProgramError;
+
+use monaco_protocol::error::CoreError;
+use monaco_protocol::instructions::{
+    market, market_liquidities, market_position, matching, order, order_request,
+};
+use monaco_protocol::state::market_account::{
+    Market, MarketOrderBehaviour, MarketStatus, MarketWinningOutcome,
+};
+use monaco_protocol::state::market_liquidities::{LiquiditySource, MarketLiquidities};
+use monaco_protocol::state::market_matching_pool_account::{Cirque, MarketMatchingPool};
+use monaco_protocol::state::market_matching_queue_account::{MarketMatchingQueue, MatchingQueue};
+use monaco_protocol::state::market_order_request_queue::{
+    MarketOrderRequestQueue, OrderRequestData, OrderRequestQueue,
+};
+use monaco_protocol::state::market_outcome_account::MarketOutcome;
+use monaco_protocol::state::market_position_account::MarketPosition;
+use monaco_protocol::state::order_account::{Order, OrderStatus};
+use monaco_protocol::state::payments_queue::{MarketPaymentsQueue, PaymentQueue};
+use monaco_protocol::state::price::Price;
+use monaco_protocol::state::price_ladder::PriceLadder;
+use monaco_protocol::state::trade_account::Trade;
+use monaco_protocol::ID;
+
+use crate::clock;
+
+/// A single market's accounts held in memory and driven through the program's instruction logic.
+///
+/// Every operation runs against a copy of the accounts which only replaces them if the operation
+/// succeeds, so a failed operation leaves the simulation untouched as a failed transaction would.
+pub struct Simulator {
+    now: UnixTimestamp,
+    market_pk: Pubkey,
+    crank_operator: Pubkey,
+    market_outcomes: Vec<MarketOutcome>,
+    price_ladder: Option<PriceLadder>,
+    protocol_commission_rate: f64,
+    protocol_config_pk: Pubkey,
+    market_escrow_pk: Pubkey,
+    deposits: u64,
+    state: SimulatorState,
+}
+
+#[derive(Clone)]
+struct SimulatorState {
+    market: Market,
+    market_liquidities: MarketLiquidities,
+    market_matching_queue: MarketMatchingQueue,
+    order_request_queue: MarketOrderRequestQueue,
+    // keyed by outcome index, for outcome and price as the pool accounts are
+    market_matching_pools: BTreeMap<(u16, bool, Price), MarketMatchingPool>,
+    orders: BTreeMap<Pubkey, Order>,
+    market_positions: BTreeMap<Pubkey, MarketPosition>,
+    trades: Vec<Trade>,
+    // token balances of purchasers and commission recipients, and of the market escrow
+    balances: BTreeMap<Pubkey, u64>,
+    escrow_balance: u64,
+    commission_payments_queue: MarketPaymentsQueue,
+}
+
+/// A step of order flow, e.g., read from production transactions, applied with `Simulator::apply`
+#[derive(Clone, Debug)]
+pub enum SimulatorStep {
+    Deposit {
+        purchaser: Pubkey,
+        amount: u64,
+    },
+    AdvanceClock {
+        seconds: i64,
+    },
+    CreateOrderRequest {
+        purchaser: Pubkey,
+        data: OrderRequestData,
+    },
+    ProcessOrderRequest,
+    DequeueOrderRequest,
+    CancelOrder {
+        order: Pubkey,
+    },
+    Crank,
+    Settle {
+        winning_outcomes: Vec<MarketWinningOutcome>,
+    },
+}
+
+impl Simulator {
+    /// Opens a market with the given number of outcomes at the given time, with cross matching
+    /// disabled and orders accepted at the default price ladder until it is settled
+    pub fn new(market_pk: Pubkey, market_outcomes_count: u16, now: UnixTimestamp) -> Simulator {
+        let market = Market {
+            authority: Pubkey::default(),
+            event_account: Pubkey::default(),
+            mint_account: Pubkey::default(),
+            market_status: MarketStatus::Open,
+            inplay_enabled: false,
+            inplay: false,
+            market_type: Pubkey::default(),
+            market_type_discriminator: None,
+            market_type_value: None,
+            version: 0,
+            decimal_limit: 0,
+            published: true,
+            suspended: false,
+            market_outcomes_count,
+            market_winning_places: 1,
+            market_winning_outcomes: vec![],
+            market_lock_timestamp: UnixTimestamp::MAX,
+            market_settle_timestamp: None,
+            event_start_order_behaviour: MarketOrderBehaviour::None,
+            market_lock_order_behaviour: MarketOrderBehaviour::None,
+            inplay_order_delay: 0,
+            title: String::new(),
+            unsettled_accounts_count: 0,
+            unclosed_accounts_count: 0,
+            escrow_account_bump: 0,
+            funding_account_bump: 0,
+            event_start_timestamp: UnixTimestamp::MAX,
+            schema_version: Market::SCHEMA_VERSION,
+            market_outcome_results: vec![],
+            trade_void_window: 0,
+            withdrawn_outcomes: vec![],
+            order_book_enabled: false,
+            price_improvement_enabled: false,
+            limits_enabled: false,
+            access_control_enabled: false,
+            operator_delegations: vec![],
+            settlement_approvals_required: 0,
+            settlement_challenge_period: 0,
+            atomic_matching_enabled: false,
+            crank_fee: 0,
+            crank_reward: 0,
+            crank_fee_balance: 0,
+            suspension_order_behaviour: MarketOrderBehaviour::None,
+            suspension_timestamp: 0,
+        };
+        let market_outcomes = (0..market_outcomes_count)
+            .map(|index| MarketOutcome {
+                market: market_pk,
+                index,
+                title: index.to_string(),
+                prices: None,
+                price_ladder: vec![],
+            })
+            .collect();
+
+        Simulator {
+            now,
+            market_pk,
+            crank_operator: Pubkey::default(),
+            market_outcomes,
+            price_ladder: None,
+            protocol_commission_rate: 0.0,
+            protocol_config_pk: Pubkey::find_program_address(
+                &[b"product".as_ref(), b"MONACO_PROTOCOL".as_ref()],
+                &protocol_product::ID,
+            )
+            .0,
+            market_escrow_pk: Pubkey::find_program_address(
+                &[b"escrow".as_ref(), market_pk.as_ref()],
+                &ID,
+            )
+            .0,
+            deposits: 0,
+            state: SimulatorState {
+                market,
+                market_liquidities: MarketLiquidities {
+                    market: market_pk,
+                    enable_cross_matching: false,
+                    stake_matched_total: 0,
+                    liquidities_for: vec![],
+                    liquidities_against: vec![],
+                },
+                market_matching_queue: MarketMatchingQueue {
+                    market: market_pk,
+                    matches: MatchingQueue::new(MarketMatchingQueue::QUEUE_LENGTH),
+                },
+                order_request_queue: MarketOrderRequestQueue {
+                    market: market_pk,
+                    order_requests: OrderRequestQueue::new(MarketOrderRequestQueue::QUEUE_LENGTH),
+                },
+                market_matching_pools: BTreeMap::new(),
+                orders: BTreeMap::new(),
+                market_positions: BTreeMap::new(),
+                trades: vec![],
+                balances: BTreeMap::new(),
+                escrow_balance: 0,
+                commission_payments_queue: MarketPaymentsQueue {
+                    market: market_pk,
+                    payment_queue: PaymentQueue::new(MarketPaymentsQueue::QUEUE_LENGTH),
+                },
+            },
+        }
+    }
+
+    /*
+    Configuration
+     */
+
+    /// Market settings, e.g., price improvement or inplay timings, can be changed directly
+    pub fn market_mut(&mut self) -> &mut Market {
+        &mut self.state.market
+    }
+
+    pub fn set_cross_matching_enabled(&mut self, enable_cross_matching: bool) {
+        self.state.market_liquidities.enable_cross_matching = enable_cross_matching;
+    }
+
+    /// Prices accepted for order requests in place of the default price ladder, empty for any price
+    pub fn set_price_ladder(&mut self, prices: Vec<Price>) {
+        self.price_ladder = Some(PriceLadder {
+            authority: Pubkey::default(),
+            max_number_of_prices: u16::try_from(prices.len()).unwrap_or(u16::MAX),
+            prices,
+        });
+    }
+
+    pub fn set_protocol_commission_rate(&mut self, protocol_commission_rate: f64) {
+        self.protocol_commission_rate = protocol_commission_rate;
+    }
+
+    pub fn set_clock(&mut self, now: UnixTimestamp) {
+        self.now = now;
+    }
+
+    pub fn advance_clock(&mut self, seconds: i64) {
+        self.now = self.now.saturating_add(seconds);
+    }
+
+    pub fn deposit(&mut self, purchaser: Pubkey, amount: u64) -> Result<()> {
+        let deposits = self
+            .deposits
+            .checked_add(amount)
+            .ok_or(CoreError::ArithmeticError)?;
+        let balance = self.state.balances.entry(purchaser).or_default();
+        *balance = balance
+            .checked_add(amount)
+            .ok_or(CoreError::ArithmeticError)?;
+        self.deposits = deposits;
+        Ok(())
+    }
+
+    /*
+    Instructions
+     */
+
+    pub fn apply(&mut self, step: &SimulatorStep) -> Result<()> {
+        match step {
+            SimulatorStep::Deposit { purchaser, amount } => self.deposit(*purchaser, *amount),
+            SimulatorStep::AdvanceClock { seconds } => {
+                self.advance_clock(*seconds);
+                Ok(())
+            }
+            SimulatorStep::CreateOrderRequest { purchaser, data } => {
+                self.create_order_request(*purchaser, data.clone())
+            }
+            SimulatorStep::ProcessOrderRequest => self.process_order_request().map(|_| ()),
+            SimulatorStep::DequeueOrderRequest => self.dequeue_order_request(),
+            SimulatorStep::CancelOrder { order } => self.cancel_order(order),
+            SimulatorStep::Crank => self.crank().map(|_| ()),
+            SimulatorStep::Settle { winning_outcomes } => self.settle(winning_outcomes.clone()),
+        }
+    }
+
+    pub fn create_order_request(
+        &mut self,
+        purchaser: Pubkey,
+        data: OrderRequestData,
+    ) -> Result<()> {
+        let mut state = self.begin();
+        let market_outcome = self
+            .market_outcomes
+            .get(usize::from(data.market_outcome_index))
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+        let market_position = state.market_positions.entry(purchaser).or_default();
+
+        let payment = order_request::create_order_request(
+            self.market_pk,
+            &mut state.market,
+            &purchaser,
+            &purchaser,
+            &None::<Account<Product>>,
+            market_position,
+            market_outcome,
+            self.price_ladder.as_ref(),
+            None,
+            &mut state.order_request_queue,
+            data,
+        )?;
+        state.transfer_to_escrow(&purchaser, payment)?;
+
+        self.state = state;
+        Ok(())
+    }
+
+    /// Processes the order request at the front of the queue, returning the address of the order
+    /// which no longer exists if the request was rejected or its order fully cancelled
+    pub fn process_order_request(&mut self) -> Result<Pubkey> {
+        let atomic_matching_enabled = self.state.market.atomic_matching_enabled;
+        if atomic_matching_enabled {
+            require!(
+                self.state.market_matching_queue.matches.is_empty(),
+                CoreError::MatchingAtomicQueueNotEmpty
+            );
+        }
+        let mut state = self.begin();
+        let order_request = *state
+            .order_request_queue
+            .order_requests
+            .peek_front()
+            .ok_or(CoreError::OrderRequestQueueIsEmpty)?;
+        let order_pk = self.order_address(&order_request.purchaser, &order_request.distinct_seed);
+        let pool_key = (
+            order_request.market_outcome_index,
+            order_request.for_outcome,
+            order_request.expected_price,
+        );
+
+        let mut order = empty_order();
+        let mut matching_pool = state
+            .market_matching_pools
+            .remove(&pool_key)
+            .unwrap_or_else(empty_matching_pool);
+        let mut market_position = state
+            .market_positions
+            .remove(&order_request.purchaser)
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+        let ladder_prices = self
+            .market_outcomes
+            .get(usize::from(order_request.market_outcome_index))
+            .map(|market_outcome| market_outcome.ladder_prices(self.price_ladder.as_ref()));
+
+        let (refund, order_closed) = order_request::process_order_request_common(
+            &order_pk,
+            &mut order,
+            &mut market_position,
+            &self.market_pk,
+            &mut state.market,
+            &mut state.market_liquidities,
+            &mut state.market_matching_queue,
+            &self.crank_operator,
+            &mut matching_pool,
+            &mut state.order_request_queue,
+            None,
+            None,
+            ladder_prices,
+        )?;
+
+        if matching_pool.orders.capacity() > 0 {
+            state.market_matching_pools.insert(pool_key, matching_pool);
+        }
+        state
+            .market_positions
+            .insert(order_request.purchaser, market_position);
+        if !order_closed {
+            state.orders.insert(order_pk, order);
+        }
+        state.transfer_from_escrow(&order_request.purchaser, refund)?;
+        let reward = state.market.take_crank_reward();
+        state.transfer_from_escrow(&self.crank_operator, reward)?;
+
+        // with atomic matching the matches are completed along with the order, all or nothing
+        let previous_state = std::mem::replace(&mut self.state, state);
+        if atomic_matching_enabled {
+            if let Err(error) = self.crank_all() {
+                self.state = previous_state;
+                return Err(error);
+            }
+        }
+        Ok(order_pk)
+    }
+
+    pub fn dequeue_order_request(&mut self) -> Result<()> {
+        let mut state = self.begin();
+        let purchaser = state
+            .order_request_queue
+            .order_requests
+            .peek_front()
+            .ok_or(CoreError::OrderRequestQueueIsEmpty)?
+            .purchaser;
+        let market_position = state
+            .market_positions
+            .get_mut(&purchaser)
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+
+        let refund =
+            order_request::dequeue_order_request(&mut state.order_request_queue, market_position)?;
+        state.transfer_from_escrow(&purchaser, refund)?;
+
+        self.state = state;
+        Ok(())
+    }
+
+    pub fn cancel_order(&mut self, order_pk: &Pubkey) -> Result<()> {
+        let mut state = self.begin();
+        let mut order = state
+            .orders
+            .remove(order_pk)
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+        let pool_key = (
+            order.market_outcome_index,
+            order.for_outcome,
+            order.expected_price,
+        );
+        let mut matching_pool = state
+            .market_matching_pools
+            .remove(&pool_key)
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+        let mut market_position = state
+            .market_positions
+            .remove(&order.purchaser)
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+
+        let refund = order::cancel_order(
+            &mut state.market,
+            order_pk,
+            &mut order,
+            &mut market_position,
+            &mut state.market_liquidities,
+            &state.market_matching_queue,
+            &mut matching_pool,
+        )?;
+
+        let purchaser = order.purchaser;
+        state.market_matching_pools.insert(pool_key, matching_pool);
+        state.market_positions.insert(purchaser, market_position);
+        // if never matched, close
+        if order.order_status == OrderStatus::Cancelled {
+            state.market.decrement_account_counts()?;
+        } else {
+            state.orders.insert(*order_pk, order);
+        }
+        state.transfer_from_escrow(&purchaser, refund)?;
+
+        self.state = state;
+        Ok(())
+    }
+
+    /// Processes the match at the front of the matching queue, returning false if it is empty
+    pub fn crank(&mut self) -> Result<bool> {
+        let mut state = self.begin();
+        let order_match = match state.market_matching_queue.matches.peek() {
+            Some(order_match) => *order_match,
+            None => return Ok(false),
+        };
+        let mut trade = Trade::default();
+
+        match order_match.pk {
+            Some(taker_order_pk) => {
+                let order = state
+                    .orders
+                    .get_mut(&taker_order_pk)
+                    .ok_or(ErrorCode::AccountNotInitialized)?;
+
+                matching::on_order_match_taker(
+                    &self.market_pk,
+                    &mut state.market,
+                    &mut state.market_matching_queue,
+                    &taker_order_pk,
+                    order,
+                    &mut trade,
+                    &self.crank_operator,
+                )?;
+            }
+            None => {
+                // maker orders are matched from the front of their pool
+                let pool_key = (
+                    order_match.outcome_index,
+                    order_match.for_outcome,
+                    order_match.price,
+                );
+                let matching_pool = state
+                    .market_matching_pools
+                    .get_mut(&pool_key)
+                    .ok_or(CoreError::MatchingMarketMatchingPoolMismatch)?;
+                let maker_order_pk = *matching_pool
+                    .orders
+                    .peek(0)
+                    .ok_or(CoreError::MatchingPoolHeadMismatch)?;
+                let order = state
+                    .orders
+                    .get_mut(&maker_order_pk)
+                    .ok_or(ErrorCode::AccountNotInitialized)?;
+                let market_position = state
+                    .market_positions
+                    .get_mut(&order.purchaser)
+                    .ok_or(ErrorCode::AccountNotInitialized)?;
+
+                let refund = matching::on_order_match_maker(
+                    &mut state.market,
+                    &mut state.market_matching_queue,
+                    matching_pool,
+                    &maker_order_pk,
+                    order,
+                    market_position,
+                    &mut trade,
+                    &self.crank_operator,
+                )?;
+                let purchaser = order.purchaser;
+                state.transfer_from_escrow(&purchaser, refund)?;
+                let reward = state.market.take_crank_reward();
+                state.transfer_from_escrow(&self.crank_operator, reward)?;
+            }
+        }
+        state.trades.push(trade);
+
+        self.state = state;
+        Ok(true)
+    }
+
+    /// Cranks the matching queue until it is empty, returning the number of matches processed
+    pub fn crank_all(&mut self) -> Result<u32> {
+        let mut count = 0_u32;
+        while self.crank()? {
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    pub fn update_market_liquidities_with_cross_liquidity(
+        &mut self,
+        source_for_outcome: bool,
+        source_liquidities: Vec<LiquiditySource>,
+    ) -> Result<()> {
+        let mut state = self.begin();
+
+        market_liquidities::update_market_liquidities_with_cross_liquidity(
+            &state.market,
+            &mut state.market_liquidities,
+            source_for_outcome,
+            source_liquidities,
+        )?;
+
+        self.state = state;
+        Ok(())
+    }
+
+    /// Settles the market then, as the settlement crank would, settles every order and market
+    /// position and pays out the commission payments they enqueue
+    pub fn settle(&mut self, winning_outcomes: Vec<MarketWinningOutcome>) -> Result<()> {
+        self.settle_market(winning_outcomes)?;
+
+        let order_pks: Vec<Pubkey> = self.state.orders.keys().copied().collect();
+        for order_pk in order_pks {
+            self.settle_order(&order_pk)?;
+        }
+        let purchasers: Vec<Pubkey> = self.state.market_positions.keys().copied().collect();
+        for purchaser in purchasers {
+            self.settle_market_position(&purchaser)?;
+            while self.process_commission_payment()? {}
+        }
+        Ok(())
+    }
+
+    pub fn settle_market(&mut self, winning_outcomes: Vec<MarketWinningOutcome>) -> Result<()> {
+        let mut state = self.begin();
+
+        market::settle(
+            &self.market_pk,
+            &mut state.market,
+            &state.market_matching_queue,
+            &state.order_request_queue,
+            winning_outcomes,
+            self.now,
+        )?;
+
+        self.state = state;
+        Ok(())
+    }
+
+    pub fn settle_order(&mut self, order_pk: &Pubkey) -> Result<()> {
+        let mut state = self.begin();
+        let order = state
+            .orders
+            .get_mut(order_pk)
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+
+        let close_order =
+            order::settle_order_common(&self.market_pk, &mut state.market, order_pk, order)?;
+        if close_order {
+            state.orders.remove(order_pk);
+        }
+
+        self.state = state;
+        Ok(())
+    }
+
+    pub fn settle_market_position(&mut self, purchaser: &Pubkey) -> Result<()> {
+        let mut state = self.begin();
+        let market_position = state
+            .market_positions
+            .get_mut(purchaser)
+            .ok_or(ErrorCode::AccountNotInitialized)?;
+
+        let payout = market_position::settle_market_position_common(
+            &self.market_pk,
+            &mut state.market,
+            purchaser,
+            market_position,
+            self.protocol_commission_rate,
+            &self.protocol_config_pk,
+            &self.market_escrow_pk,
+            &mut state.commission_payments_queue.payment_queue,
+        )?;
+        if let Some(payout) = payout {
+            state.transfer_from_escrow(purchaser, payout)?;
+        }
+
+        self.state = state;
+        Ok(())
+    }
+
+    /// Pays the commission payment at the front of the queue, returning false if it is empty
+    pub fn process_commission_payment(&mut self) -> Result<bool> {
+        let mut state = self.begin();
+        let payment = match state.commission_payments_queue.payment_queue.dequeue() {
+            Some(payment) => payment,
+            None => return Ok(false),
+        };
+        require!(
+            payment.from == self.market_escrow_pk,
+            CoreError::SettlementPaymentAddressMismatch
+        );
+        state.transfer_from_escrow(&payment.to, payment.amount)?;
+
+        self.state = state;
+        Ok(true)
+    }
+
+    /*
+    Accounts
+     */
+
+    pub fn now(&self) -> UnixTimestamp {
+        self.now
+    }
+
+    pub fn market_pk(&self) -> Pubkey {
+        self.market_pk
+    }
+
+    pub fn market(&self) -> &Market {
+        &self.state.market
+    }
+
+    pub fn market_liquidities(&self) -> &MarketLiquidities {
+        &self.state.market_liquidities
+    }
+
+    pub fn market_matching_queue(&self) -> &MarketMatchingQueue {
+        &self.state.market_matching_queue
+    }
+
+    pub fn order_request_queue(&self) -> &MarketOrderRequestQueue {
+        &self.state.order_request_queue
+    }
+
+    pub fn market_matching_pool(
+        &self,
+        market_outcome_index: u16,
+        for_outcome: bool,
+        price: Price,
+    ) -> Option<&MarketMatchingPool> {
+        self.state
+            .market_matching_pools
+            .get(&(market_outcome_index, for_outcome, price))
+    }
+
+    pub fn order(&self, order_pk: &Pubkey) -> Option<&Order> {
+        self.state.orders.get(order_pk)
+    }
+
+    pub fn market_position(&self, purchaser: &Pubkey) -> Option<&MarketPosition> {
+        self.state.market_positions.get(purchaser)
+    }
+
+    pub fn trades(&self) -> &[Trade] {
+        &self.state.trades
+    }
+
+    pub fn balance(&self, purchaser: &Pubkey) -> u64 {
+        self.state.balances.get(purchaser).copied().unwrap_or(0)
+    }
+
+    /// Crank rewards are paid to the balance of this account
+    pub fn crank_operator(&self) -> Pubkey {
+        self.crank_operator
+    }
+
+    pub fn escrow_balance(&self) -> u64 {
+        self.state.escrow_balance
+    }
+
+    pub fn protocol_commission(&self) -> u64 {
+        self.balance(&self.protocol_config_pk)
+    }
+
+    pub fn commission_payments_queue(&self) -> &MarketPaymentsQueue {
+        &self.state.commission_payments_queue
+    }
+
+    /// Total of all deposits, which the balances and escrow always add up to
+    pub fn deposits(&self) -> u64 {
+        self.deposits
+    }
+
+    pub fn balances(&self) -> impl Iterator<Item = (&Pubkey, &u64)> {
+        self.state.balances.iter()
+    }
+
+    pub fn orders(&self) -> impl Iterator<Item = (&Pubkey, &Order)> {
+        self.state.orders.iter()
+    }
+
+    pub fn market_positions(&self) -> impl Iterator<Item = (&Pubkey, &MarketPosition)> {
+        self.state.market_positions.iter()
+    }
+
+    pub fn market_matching_pools(&self) -> impl Iterator<Item = &MarketMatchingPool> {
+        self.state.market_matching_pools.values()
+    }
+
+    /// Address the program creates the order for a request at
+    pub fn order_address(&self, purchaser: &Pubkey, distinct_seed: &[u8; 16]) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                self.market_pk.as_ref(),
+                purchaser.as_ref(),
+                distinct_seed.as_ref(),
+            ],
+            &ID,
+        )
+        .0
+    }
+
+    // the program reads the clock set here, operations work on a copy of the accounts
+    fn begin(&self) -> SimulatorState {
+        clock::set_timestamp(self.now);
+        self.state.clone()
+    }
+}
+
+impl SimulatorState {
+    fn transfer_to_escrow(&mut self, account: &Pubkey, amount: u64) -> Result<()> {
+        let balance = self.balances.entry(*account).or_default();
+        *balance = balance
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        self.escrow_balance = self
+            .escrow_balance
+            .checked_add(amount)
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(())
+    }
+
+    fn transfer_from_escrow(&mut self, account: &Pubkey, amount: u64) -> Result<()> {
+        self.escrow_balance = self
+            .escrow_balance
+            .checked_sub(amount)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        let balance = self.balances.entry(*account).or_default();
+        *balance = balance
+            .checked_add(amount)
+            .ok_or(CoreError::ArithmeticError)?;
+        Ok(())
+    }
+}
+
+// accounts the program initializes on processing an order request
+fn empty_order() -> Order {
+    Order {
+        purchaser: Pubkey::default(),
+        market: Pubkey::default(),
+        market_outcome_index: 0,
+        for_outcome: false,
+        order_status: OrderStatus::Open,
+        product: None,
+        stake: 0,
+        voided_stake: 0,
+        expected_price: Price::default(),
+        creation_timestamp: 0,
+        stake_unmatched: 0,
+        payout: 0,
+        payer: Pubkey::default(),
+        product_commission_rate: 0.0,
+        schema_version: 0,
+    }
+}
+
+fn empty_matching_pool() -> MarketMatchingPool {
+    MarketMatchingPool {
+        market: Pubkey::default(),
+        market_outcome_index: 0,
+        for_outcome: false,
+        price: Price::default(),
+        payer: Pubkey::default(),
+        liquidity_amount: 0,
+        matched_amount: 0,
+        inplay: false,
+        orders: Cirque::new(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use monaco_protocol::state::market_order_request_queue::TimeInForce;
+
+    const NOW: UnixTimestamp = 1_700_000_000;
+
+    fn order_request_data(
+        market_outcome_index: u16,
+        for_outcome: bool,
+        stake: u64,
+        price: u64,
+        seed: u8,
+    ) -> OrderRequestData {
+        OrderRequestData {
+            market_outcome_index,
+            for_outcome,
+            stake,
+            price: Price::new(price),
+            distinct_seed: [seed; 16],
+            expires_on: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            post_only: false,
+        }
+    }
+
+    fn winning_outcome(outcome_index: u16) -> MarketWinningOutcome {
+        MarketWinningOutcome {
+            outcome_index,
+            dead_heat_numerator: 1,
+            dead_heat_denominator: 1,
+        }
+    }
+
+    #[test]
+    fn test_match_and_settle() {
+        let backer = Pubkey::new_unique();
+        let layer = Pubkey::new_unique();
+        let mut simulator = Simulator::new(Pubkey::new_unique(), 3, NOW);
+        simulator.deposit(backer, 100).unwrap();
+        simulator.deposit(layer, 100).unwrap();
+
+        simulator
+            .create_order_request(layer, order_request_data(0, false, 10, 3_000, 1))
+            .unwrap();
+        let maker_order_pk = simulator.process_order_request().unwrap();
+        simulator
+            .create_order_request(backer, order_request_data(0, true, 10, 3_000, 2))
+            .unwrap();
+        let taker_order_pk = simulator.process_order_request().unwrap();
+
+        assert_eq!(80, simulator.balance(&layer));
+        assert_eq!(90, simulator.balance(&backer));
+        assert_eq!(30, simulator.escrow_balance());
+        assert_eq!(2, simulator.market_matching_queue().matches.len());
+
+        assert_eq!(2, simulator.crank_all().unwrap());
+
+        assert_eq!(2, simulator.trades().len());
+        assert_eq!(0, simulator.order(&maker_order_pk).unwrap().stake_unmatched);
+        assert_eq!(0, simulator.order(&taker_order_pk).unwrap().stake_unmatched);
+        assert_eq!(
+            vec![20, -10, -10],
+            simulator
+                .market_position(&backer)
+                .unwrap()
+                .market_outcome_sums
+        );
+
+        simulator.settle(vec![winning_outcome(0)]).unwrap();
+
+        assert_eq!(120, simulator.balance(&backer));
+        assert_eq!(80, simulator.balance(&layer));
+        assert_eq!(0, simulator.escrow_balance());
+        assert_eq!(
+            MarketStatus::ReadyForSettlement,
+            simulator.market().market_status
+        );
+    }
+
+    #[test]
+    fn test_atomic_matching() {
+        let backer = Pubkey::new_unique();
+        let layer = Pubkey::new_unique();
+        let mut simulator = Simulator::new(Pubkey::new_unique(), 3, NOW);
+        simulator.market_mut().atomic_matching_enabled = true;
+        simulator.deposit(backer, 100).unwrap();
+        simulator.deposit(layer, 100).unwrap();
+
+        simulator
+            .create_order_request(layer, order_request_data(0, false, 10, 3_000, 1))
+            .unwrap();
+        let maker_order_pk = simulator.process_order_request().unwrap();
+        simulator
+            .create_order_request(backer, order_request_data(0, true, 10, 3_000, 2))
+            .unwrap();
+        let taker_order_pk = simulator.process_order_request().unwrap();
+
+        assert_eq!(0, simulator.market_matching_queue().matches.len());
+        assert_eq!(2, simulator.trades().len());
+        assert_eq!(0, simulator.order(&maker_order_pk).unwrap().stake_unmatched);
+        assert_eq!(0, simulator.order(&taker_order_pk).unwrap().stake_unmatched);
+        assert_eq!(
+            vec![-20, 10, 10],
+            simulator
+                .market_position(&layer)
+                .unwrap()
+                .market_outcome_sums
+        );
+    }
+
+    #[test]
+    fn test_crank_rewards() {
+        let backer = Pubkey::new_unique();
+        let layer = Pubkey::new_unique();
+        let mut simulator = Simulator::new(Pubkey::new_unique(), 3, NOW);
+        simulator.market_mut().crank_fee = 3;
+        simulator.market_mut().crank_reward = 2;
+        simulator.deposit(backer, 100).unwrap();
+        simulator.deposit(layer, 100).unwrap();
+
+        simulator
+            .create_order_request(layer, order_request_data(0, false, 10, 3_000, 1))
+            .unwrap();
+        simulator.process_order_request().unwrap();
+        simulator
+            .create_order_request(backer, order_request_data(0, true, 10, 3_000, 2))
+            .unwrap();
+        simulator.process_order_request().unwrap();
+
+        assert_eq!(77, simulator.balance(&layer));
+        assert_eq!(87, simulator.balance(&backer));
+        assert_eq!(4, simulator.balance(&simulator.crank_operator()));
+
+        // the taker match is not rewarded, the maker match is paid what is left
+        assert_eq!(2, simulator.crank_all().unwrap());
+
+        assert_eq!(6, simulator.balance(&simulator.crank_operator()));
+        assert_eq!(0, simulator.market().crank_fee_balance);
+        assert_eq!(30, simulator.escrow_balance());
+    }
+
+    #[test]
+    fn test_partial_match_and_cancel() {
+        let backer = Pubkey::new_unique();
+        let layer = Pubkey::new_unique();
+        let mut simulator = Simulator::new(Pubkey::new_unique(), 2, NOW);
+        simulator.deposit(backer, 100).unwrap();
+        simulator.deposit(layer, 100).unwrap();
+
+        simulator
+            .create_order_request(backer, order_request_data(1, true, 50, 2_000, 1))
+            .unwrap();
+        let maker_order_pk = simulator.process_order_request().unwrap();
+        simulator
+            .create_order_request(layer, order_request_data(1, false, 20, 2_000, 1))
+            .unwrap();
+        simulator.process_order_request().unwrap();
+        simulator.crank_all().unwrap();
+
+        simulator.cancel_order(&maker_order_pk).unwrap();
+
+        let maker_order = simulator.order(&maker_order_pk).unwrap();
+        assert_eq!(OrderStatus::Matched, maker_order.order_status);
+        assert_eq!(30, maker_order.voided_stake);
+        assert_eq!(80, simulator.balance(&backer));
+        assert_eq!(80, simulator.balance(&layer));
+        assert_eq!(40, simulator.escrow_balance());
+
+        simulator.settle(vec![winning_outcome(0)]).unwrap();
+
+        assert_eq!(80, simulator.balance(&backer));
+        assert_eq!(120, simulator.balance(&layer));
+        assert_eq!(0, simulator.escrow_balance());
+    }
+
+    #[test]
+    fn test_failed_operation_leaves_state_untouched() {
+        let purchaser = Pubkey::new_unique();
+        let mut simulator = Simulator::new(Pubkey::new_unique(), 2, NOW);
+        simulator.deposit(purchaser, 5).unwrap();
+
+        let result =
+            simulator.create_order_request(purchaser, order_request_data(0, true, 10, 2_000, 1));
+
+        assert_eq!(Err(Error::from(ProgramError::InsufficientFunds)), result);
+        assert!(simulator.market_position(&purchaser).is_none());
+        assert!(simulator.order_request_queue().order_requests.is_empty());
+        assert_eq!(5, simulator.balance(&purchaser));
+    }
+
+    #[test]
+    fn test_expired_order_request_refunded() {
+        let purchaser = Pubkey::new_unique();
+        let mut simulator = Simulator::new(Pubkey::new_unique(), 2, NOW);
+        simulator.deposit(purchaser, 10).unwrap();
+
+        let mut data = order_request_data(0, true, 10, 2_000, 1);
+        data.expires_on = Some(NOW + 60);
+        simulator
+            .apply(&SimulatorStep::CreateOrderRequest { purchaser, data })
+            .unwrap();
+        simulator
+            .apply(&SimulatorStep::AdvanceClock { seconds: 60 })
+            .unwrap();
+        let order_pk = simulator.process_order_request().unwrap();
+
+        assert!(simulator.order(&order_pk).is_none());
+        assert_eq!(10, simulator.balance(&purchaser));
+        assert_eq!(0, simulator.escrow_balance());
+    }
+}
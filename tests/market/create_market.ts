@@ -4,7 +4,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import assert from "assert";
-import { findEscrowPda, findMarketPda } from "../../npm-client/src/";
+import { findEscrowPda, findMarketPda, toPrice } from "../../npm-client/src/";
 import { createNewMint, createWalletWithBalance } from "../util/test_util";
 import { Monaco, monaco } from "../util/wrappers";
 import { findMarketFundingPda } from "../../npm-admin-client";
@@ -672,7 +672,7 @@ describe("Market: creation", () => {
     const pricesToAdd = [9.999, 9.99, 9.9];
 
     await monaco.program.methods
-      .addPricesToMarketOutcome(0, pricesToAdd)
+      .addPricesToMarketOutcome(0, pricesToAdd.map(toPrice))
       .accounts({
         outcome: market.outcomePks[0],
         market: market.pk,
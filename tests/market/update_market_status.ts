@@ -13,7 +13,9 @@ describe("Market: update status", () => {
     const market = await monaco.create3WayMarket([4.2]);
 
     await monaco.program.methods
-      .settleMarket(1)
+      .settleMarket([
+        { outcomeIndex: 1, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+      ])
       .accounts({
         market: market.pk,
         marketMatchingQueue: market.matchingQueuePk,
@@ -30,7 +32,9 @@ describe("Market: update status", () => {
       });
 
     const marketAccount = await monaco.fetchMarket(market.pk);
-    assert.equal(marketAccount.marketWinningOutcomeIndex, 1);
+    assert.deepEqual(marketAccount.marketWinningOutcomes, [
+      { outcomeIndex: 1, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+    ]);
     assert.deepEqual(marketAccount.marketStatus, { readyForSettlement: {} });
   });
 
@@ -41,7 +45,13 @@ describe("Market: update status", () => {
 
     try {
       await monaco.program.methods
-        .settleMarket(winningIndex)
+        .settleMarket([
+          {
+            outcomeIndex: winningIndex,
+            deadHeatNumerator: 1,
+            deadHeatDenominator: 1,
+          },
+        ])
         .accounts({
           market: market.pk,
           marketMatchingQueue: market.matchingQueuePk,
@@ -60,7 +70,7 @@ describe("Market: update status", () => {
       );
     }
     const marketAccount = await monaco.fetchMarket(market.pk);
-    assert.equal(marketAccount.marketWinningOutcomeIndex, null);
+    assert.deepEqual(marketAccount.marketWinningOutcomes, []);
     assert.deepEqual(marketAccount.marketStatus, { open: {} });
   });
 
@@ -69,7 +79,9 @@ describe("Market: update status", () => {
     const market = await monaco.create3WayMarket([4.2]);
 
     await monaco.program.methods
-      .settleMarket(1)
+      .settleMarket([
+        { outcomeIndex: 1, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+      ])
       .accounts({
         market: market.pk,
         marketMatchingQueue: market.matchingQueuePk,
@@ -113,7 +125,9 @@ describe("Market: update status", () => {
     await market.forOrder(0, 1, 4.2, purchaser);
 
     await monaco.program.methods
-      .settleMarket(1)
+      .settleMarket([
+        { outcomeIndex: 1, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+      ])
       .accounts({
         market: market.pk,
         marketMatchingQueue: market.matchingQueuePk,
@@ -180,7 +194,9 @@ describe("Market: update status", () => {
     const orderPk = await market.forOrder(0, 1, 4.2, purchaser);
 
     await monaco.program.methods
-      .settleMarket(1)
+      .settleMarket([
+        { outcomeIndex: 1, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+      ])
       .accounts({
         market: market.pk,
         marketMatchingQueue: market.matchingQueuePk,
@@ -249,7 +265,9 @@ describe("Market: update status", () => {
     await market.airdropTokenAccount(market.escrowPk, 1);
 
     await monaco.program.methods
-      .settleMarket(1)
+      .settleMarket([
+        { outcomeIndex: 1, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+      ])
       .accounts({
         market: market.pk,
         marketMatchingQueue: market.matchingQueuePk,
@@ -13,7 +13,7 @@ describe("Attempt to create market types with", () => {
 
     try {
       await monaco.program.methods
-        .createMarketType(name, false, false)
+        .createMarketType(name, false, false, { standard: {} })
         .accounts({
           marketType: marketTypePda,
           authority: monaco.provider.publicKey,
@@ -31,7 +31,7 @@ describe("Attempt to create market types with", () => {
 
     try {
       await monaco.program.methods
-        .createMarketType(name, false, false)
+        .createMarketType(name, false, false, { standard: {} })
         .accounts({
           marketType: marketTypePda,
           authority: monaco.provider.publicKey,
@@ -35,7 +35,9 @@ describe("Settle market", () => {
     assert(settleMarketResponse.success);
     assert(settleMarketResponse.data.tnxId);
     assert.deepEqual(settleMarketResponse.errors, []);
-    assert.equal(updatedMarket.marketWinningOutcomeIndex, 0);
+    assert.deepEqual(updatedMarket.marketWinningOutcomes, [
+      { outcomeIndex: 0, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+    ]);
   });
 
   it("Fails settle with invalid index", async () => {
@@ -51,7 +53,7 @@ describe("Settle market", () => {
     assert.equal(settleMarketResponse.success, false);
     assert.equal(settleMarketResponse.data, undefined);
     assert(settleMarketResponse.errors);
-    assert.equal(updatedMarket.marketWinningOutcomeIndex, undefined);
+    assert.deepEqual(updatedMarket.marketWinningOutcomes, []);
   });
 });
 
@@ -1,6 +1,7 @@
 import assert from "assert";
 import {
   findMarketLiquiditiesPda,
+  fromPrice,
   GetAccount,
   getCrossMatchEnabledMarketLiquidities,
   getCrossMatchEnabledMarketLiquiditiesPks,
@@ -90,7 +91,7 @@ describe("Market Liquidities", () => {
 function mapMarketLiquidity(marketLiquidity: MarketLiquidity) {
   return {
     outcome: marketLiquidity.outcome,
-    price: marketLiquidity.price,
+    price: fromPrice(marketLiquidity.price),
     liquidity: marketLiquidity.liquidity.toNumber(),
   };
 }
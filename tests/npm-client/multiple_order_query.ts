@@ -2,6 +2,7 @@ import assert from "assert";
 import {
   confirmTransaction,
   createOrderUiStake as createOrderNpm,
+  fromPrice,
   getOrders,
   getOrdersByMarketForProviderWallet,
 } from "../../npm-client";
@@ -74,13 +75,13 @@ describe("Multi Order Query", () => {
     //verify order1 key matches order 1
     assert.equal(responseByOrderPk.data.orderAccounts[0].publicKey, orderPk1);
     assert.equal(
-      responseByOrderPk.data.orderAccounts[0].account.expectedPrice,
+      fromPrice(responseByOrderPk.data.orderAccounts[0].account.expectedPrice),
       price1,
     );
     //verify order2 key matches order 2
     assert.equal(responseByOrderPk.data.orderAccounts[1].publicKey, orderPk2);
     assert.equal(
-      responseByOrderPk.data.orderAccounts[1].account.expectedPrice,
+      fromPrice(responseByOrderPk.data.orderAccounts[1].account.expectedPrice),
       price2,
     );
   });
@@ -1,6 +1,7 @@
 import assert from "assert";
 import { createWalletWithBalance } from "../util/test_util";
 import { monaco } from "../util/wrappers";
+import { fromPrice } from "../../npm-client";
 import { AnchorError } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 
@@ -382,7 +383,7 @@ async function getOrderLocal(orderPk: PublicKey) {
     stake: order.stake.toNumber() / decimalsMultiplier,
     stakeUnmatched: order.stakeUnmatched.toNumber() / decimalsMultiplier,
     stakeVoided: order.voidedStake.toNumber() / decimalsMultiplier,
-    price: order.expectedPrice,
+    price: fromPrice(order.expectedPrice),
     payout: order.payout.toNumber() / decimalsMultiplier,
   };
 }
@@ -8,7 +8,9 @@ import { Keypair, SystemProgram } from "@solana/web3.js";
 import { findMarketPdas, findUserPdas } from "../util/pdas";
 import {
   findMarketOrderRequestQueuePda,
+  fromPrice,
   MarketOrderRequestQueue,
+  toPrice,
 } from "../../npm-client";
 
 describe("Order Request Creation", () => {
@@ -40,7 +42,7 @@ describe("Order Request Creation", () => {
     assert.ok(forOrderRequest.forOutcome);
     assert.equal(forOrderRequest.product, null);
     assert.equal(forOrderRequest.stake.toNumber() / 10 ** 6, 10);
-    assert.equal(forOrderRequest.expectedPrice, prices[0]);
+    assert.equal(fromPrice(forOrderRequest.expectedPrice), prices[0]);
     assert.equal(
       forOrderRequest.purchaser.toBase58(),
       purchaser.publicKey.toBase58(),
@@ -52,7 +54,7 @@ describe("Order Request Creation", () => {
     assert.ok(!againstOrderRequest.forOutcome);
     assert.equal(againstOrderRequest.product, null);
     assert.equal(againstOrderRequest.stake.toNumber() / 10 ** 6, 10);
-    assert.equal(againstOrderRequest.expectedPrice, prices[1]);
+    assert.equal(fromPrice(againstOrderRequest.expectedPrice), prices[1]);
     assert.equal(
       againstOrderRequest.purchaser.toBase58(),
       purchaser.publicKey.toBase58(),
@@ -183,9 +185,11 @@ describe("Order Request Creation", () => {
           marketOutcomeIndex: outcomeIndex,
           forOutcome: forOutcome,
           stake: new BN(uiAmountToAmount(stake + 1)),
-          price: price + 1,
+          price: toPrice(price + 1),
           distinctSeed: duplicateDistinctSeed,
           expiresOn: null,
+          timeInForce: { goodTillCancelled: {} },
+          postOnly: false,
         })
         .accounts({
           reservedOrder: orderPk.data.orderPk,
@@ -269,9 +273,11 @@ describe("Order Request Creation", () => {
           marketOutcomeIndex: outcomeIndex,
           forOutcome: forOutcome,
           stake: new BN(uiAmountToAmount(stake)),
-          price: price,
+          price: toPrice(price),
           distinctSeed: duplicateDistinctSeed,
           expiresOn: null,
+          timeInForce: { goodTillCancelled: {} },
+          postOnly: false,
         })
         .accounts({
           reservedOrder: orderPk.data.orderPk,
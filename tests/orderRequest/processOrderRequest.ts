@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { createWalletWithBalance } from "../util/test_util";
 import { monaco } from "../util/wrappers";
 import assert from "assert";
-import { MarketOrderRequestQueue } from "../../npm-client";
+import { fromPrice, MarketOrderRequestQueue } from "../../npm-client";
 
 describe("Order Request Processing", () => {
   const provider = anchor.AnchorProvider.local();
@@ -52,7 +52,7 @@ describe("Order Request Processing", () => {
     assert.equal(order.market.toBase58(), market.pk.toBase58());
     assert.equal(order.marketOutcomeIndex, orderRequestOutcomeIndex);
     assert.equal(order.stake.toNumber() / 10 ** 6, orderRequestStake);
-    assert.equal(order.expectedPrice, orderRequestPrice);
+    assert.equal(fromPrice(order.expectedPrice), orderRequestPrice);
     assert.equal(order.forOutcome, orderRequestForOutcome);
 
     const matchingPool = await market.getForMatchingPool(
@@ -180,7 +180,7 @@ describe("Order Request Processing", () => {
     assert.equal(order.market.toBase58(), market.pk.toBase58());
     assert.equal(order.marketOutcomeIndex, orderRequestOutcomeIndex);
     assert.equal(order.stake.toNumber() / 10 ** 6, orderRequestStake);
-    assert.equal(order.expectedPrice, orderRequestPrice);
+    assert.equal(fromPrice(order.expectedPrice), orderRequestPrice);
     assert.equal(order.forOutcome, orderRequestForOutcome);
 
     const matchingPool = await market.getForMatchingPool(
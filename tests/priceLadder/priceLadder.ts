@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { MonacoProtocol } from "../../target/types/monaco_protocol";
 import assert from "assert";
+import { fromPrice, toPrice } from "../../npm-client";
 
 describe("PriceLadder support includes", () => {
   const provider = anchor.AnchorProvider.local();
@@ -36,13 +37,13 @@ describe("PriceLadder support includes", () => {
       priceLadder.authority.toBase58(),
       program.provider.publicKey.toBase58(),
     );
-    assert.deepEqual(priceLadder.prices, []);
-    assert.equal((priceLadder.prices as number[]).length, 0);
+    assert.deepEqual(priceLadder.prices.map(fromPrice), []);
+    assert.equal(priceLadder.prices.length, 0);
     assert.equal(priceLadder.maxNumberOfPrices, 3);
 
     try {
       await program.methods
-        .addPricesToPriceLadder([1.1, 1.2, 1.3])
+        .addPricesToPriceLadder([1.1, 1.2, 1.3].map(toPrice))
         .accounts({
           priceLadder: priceLadderPk,
           authority: program.provider.publicKey,
@@ -58,13 +59,13 @@ describe("PriceLadder support includes", () => {
       priceLadder.authority.toBase58(),
       program.provider.publicKey.toBase58(),
     );
-    assert.deepEqual(priceLadder.prices, [1.1, 1.2, 1.3]);
-    assert.equal((priceLadder.prices as number[]).length, 3);
+    assert.deepEqual(priceLadder.prices.map(fromPrice), [1.1, 1.2, 1.3]);
+    assert.equal(priceLadder.prices.length, 3);
     assert.equal(priceLadder.maxNumberOfPrices, 3);
 
     try {
       await program.methods
-        .addPricesToPriceLadder([1.4])
+        .addPricesToPriceLadder([1.4].map(toPrice))
         .accounts({
           priceLadder: priceLadderPk,
           authority: program.provider.publicKey,
@@ -93,7 +94,7 @@ describe("PriceLadder support includes", () => {
 
     try {
       await program.methods
-        .addPricesToPriceLadder([1.4])
+        .addPricesToPriceLadder([1.4].map(toPrice))
         .accounts({
           priceLadder: priceLadderPk,
           authority: program.provider.publicKey,
@@ -105,12 +106,12 @@ describe("PriceLadder support includes", () => {
     }
 
     priceLadder = await program.account.priceLadder.fetch(priceLadderPk);
-    assert.deepEqual(priceLadder.prices, [1.1, 1.2, 1.3, 1.4]);
-    assert.equal((priceLadder.prices as number[]).length, 4);
+    assert.deepEqual(priceLadder.prices.map(fromPrice), [1.1, 1.2, 1.3, 1.4]);
+    assert.equal(priceLadder.prices.length, 4);
 
     try {
       await program.methods
-        .removePricesFromPriceLadder([1.4])
+        .removePricesFromPriceLadder([1.4].map(toPrice))
         .accounts({
           priceLadder: priceLadderPk,
           authority: program.provider.publicKey,
@@ -122,8 +123,8 @@ describe("PriceLadder support includes", () => {
     }
 
     priceLadder = await program.account.priceLadder.fetch(priceLadderPk);
-    assert.deepEqual(priceLadder.prices, [1.1, 1.2, 1.3]);
-    assert.equal((priceLadder.prices as number[]).length, 3);
+    assert.deepEqual(priceLadder.prices.map(fromPrice), [1.1, 1.2, 1.3]);
+    assert.equal(priceLadder.prices.length, 3);
     assert.equal(priceLadder.maxNumberOfPrices, 4);
   });
 });
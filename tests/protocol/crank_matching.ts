@@ -8,7 +8,11 @@ import {
   OperatorType,
 } from "../util/test_util";
 import { Monaco, monaco } from "../util/wrappers";
-import { findTradePda, uiStakeToInteger } from "../../npm-client/src";
+import {
+  findTradePda,
+  fromPrice,
+  uiStakeToInteger,
+} from "../../npm-client/src";
 
 describe("Matching Crank", () => {
   it("Unauthorised crank should error", async () => {
@@ -69,6 +73,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .signers([operatorAccountUnauthorised])
       .instruction();
 
@@ -144,7 +149,7 @@ describe("Matching Crank", () => {
         againstTrade.marketOutcomeIndex,
         againstTrade.forOutcome,
         againstTrade.stake.toNumber(),
-        againstTrade.price,
+        fromPrice(againstTrade.price),
       ],
       [
         purchaser.publicKey,
@@ -164,7 +169,7 @@ describe("Matching Crank", () => {
         forTrade.marketOutcomeIndex,
         forTrade.forOutcome,
         forTrade.stake.toNumber(),
-        forTrade.price,
+        fromPrice(forTrade.price),
       ],
       [
         purchaser.publicKey,
@@ -272,7 +277,7 @@ describe("Matching Crank", () => {
         againstTrade.marketOutcomeIndex,
         againstTrade.forOutcome,
         againstTrade.stake.toNumber(),
-        againstTrade.price,
+        fromPrice(againstTrade.price),
         againstTrade.payer,
       ],
       [
@@ -294,7 +299,7 @@ describe("Matching Crank", () => {
         forTrade.marketOutcomeIndex,
         forTrade.forOutcome,
         forTrade.stake.toNumber(),
-        forTrade.price,
+        fromPrice(forTrade.price),
         forTrade.payer,
       ],
       [
@@ -362,6 +367,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(ix, "already in use");
@@ -442,6 +448,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(ix, "already in use");
@@ -530,6 +537,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(
@@ -619,6 +627,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(ix, "MatchingExpectedAForOrder");
@@ -710,6 +719,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(
@@ -801,6 +811,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(ix, "MatchingExpectedAForOrder");
@@ -880,6 +891,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(ix, "MatchingMarketMismatch");
@@ -960,6 +972,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(ix, "MatchingMarketOutcomeMismatch");
@@ -1040,6 +1053,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperators,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await assertTransactionThrowsErrorCode(ix, "MatchingMarketMismatch");
@@ -16,6 +16,7 @@ import {
   findMarketMatchingPoolPda,
   findMarketOrderRequestQueuePda,
   confirmTransaction,
+  fromPrice,
 } from "../../npm-client/";
 import { getMint } from "@solana/spl-token";
 import { monaco } from "../util/wrappers";
@@ -69,7 +70,7 @@ describe("Protocol - Create Order", () => {
       market.toAmountInteger(testData.stake),
     );
     assert.equal(orderAccount.payout, 0);
-    assert.equal(orderAccount.expectedPrice, price);
+    assert.equal(fromPrice(orderAccount.expectedPrice), price);
     assert.equal(
       orderAccount.stakeUnmatched.toNumber(),
       market.toAmountInteger(testData.stake),
@@ -271,7 +272,9 @@ describe("Protocol - Create Order", () => {
 
     // set an outcome on this market
     await protocolProgram.methods
-      .settleMarket(outcomeIndex)
+      .settleMarket([
+        { outcomeIndex, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+      ])
       .accounts({
         market: marketPda,
         marketMatchingQueue: matchingQueuePda,
@@ -285,7 +288,9 @@ describe("Protocol - Create Order", () => {
       .rpc();
 
     const account = await protocolProgram.account.market.fetch(marketPda);
-    assert.equal(account.marketWinningOutcomeIndex, 1);
+    assert.deepEqual(account.marketWinningOutcomes, [
+      { outcomeIndex: 1, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+    ]);
 
     const stakeInteger = new BN(
       stake * 10 ** (await getMint(provider.connection, mintPk)).decimals,
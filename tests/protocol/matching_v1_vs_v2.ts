@@ -93,6 +93,7 @@ describe("Matching Crank", () => {
         authorisedOperators: authorisedOperatorsPk,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(market.outcomeLiquiditiesAccounts())
       .instruction();
 
     await executeTransactionMaxCompute([ix]).then(
@@ -1,6 +1,7 @@
 import assert from "assert";
 import { createWalletWithBalance } from "../util/test_util";
 import { monaco } from "../util/wrappers";
+import { fromPrice } from "../../npm-client";
 
 describe("Security: Settle Market", () => {
   it("failed: matching queue not empty", async () => {
@@ -31,7 +32,7 @@ describe("Security: Settle Market", () => {
           return {
             forOutcome: head.forOutcome,
             outcomeIndex: head.outcomeIndex,
-            price: head.price,
+            price: fromPrice(head.price),
             stake: head.stake,
           };
         }),
@@ -30,13 +30,16 @@ import {
   findEscrowPda,
   findMarketMatchingPoolPda,
   findMarketOrderRequestQueuePda,
+  findMarketOutcomeLiquiditiesPda,
   findMarketOutcomePda,
   findMarketPda,
   findMarketPositionPda,
   findOrderPda,
+  fromPrice,
   getMarketCommissionPaymentQueue,
   MarketAccount,
   toCommissionPayments,
+  toPrice,
 } from "../../npm-client";
 import { findMarketPdas, findProductPda } from "./pdas";
 import * as assert from "assert";
@@ -233,7 +236,7 @@ export async function createMarket(
         const batch = priceLadder.slice(i, i + priceLadderBatchSize);
         await getAnchorProvider().connection.confirmTransaction(
           await protocolProgram.methods
-            .addPricesToMarketOutcome(index, batch)
+            .addPricesToMarketOutcome(index, batch.map(toPrice))
             .accounts({
               systemProgram: SystemProgram.programId,
               outcome: outcomePdas[index],
@@ -257,7 +260,7 @@ export async function createMarket(
     const priceLadderAccount = await protocolProgram.account.priceLadder.fetch(
       priceLadder,
     );
-    prices = priceLadderAccount.prices;
+    prices = priceLadderAccount.prices.map(fromPrice);
   }
   let matchingPools: { against: PublicKey; forOutcome: PublicKey }[][] = [];
   if (initialisePools) {
@@ -480,7 +483,7 @@ export async function processNextOrderRequest(
     marketPk,
     firstOrderRequest.forOutcome,
     firstOrderRequest.marketOutcomeIndex,
-    firstOrderRequest.expectedPrice,
+    fromPrice(firstOrderRequest.expectedPrice),
     protocolProgram as Program<anchor.Idl>,
   );
 
@@ -502,6 +505,18 @@ export async function processNextOrderRequest(
     marketPk,
     firstOrderRequest.purchaser,
   );
+  const marketOutcomeLiquiditiesPks = await Promise.all(
+    [...Array(market.marketOutcomesCount).keys()].map(
+      async (marketOutcomeIndex) =>
+        (
+          await findMarketOutcomeLiquiditiesPda(
+            protocolProgram as Program,
+            marketPk,
+            marketOutcomeIndex,
+          )
+        ).data.pda,
+    ),
+  );
 
   await protocolProgram.methods
     .processOrderRequest()
@@ -522,6 +537,13 @@ export async function processNextOrderRequest(
       systemProgram: SystemProgram.programId,
       tokenProgram: TOKEN_PROGRAM_ID,
     })
+    .remainingAccounts(
+      marketOutcomeLiquiditiesPks.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+    )
     .signers(crankOperator instanceof Keypair ? [crankOperator] : [])
     .rpc()
     .catch((e) => {
@@ -1,4 +1,4 @@
-import { Keypair, PublicKey } from "@solana/web3.js";
+import { AccountMeta, Keypair, PublicKey } from "@solana/web3.js";
 import {
   getMint,
   getOrCreateAssociatedTokenAccount,
@@ -17,6 +17,8 @@ import {
   findMarketPositionPda,
   findOrderPda,
   findTradePda,
+  fromPrice,
+  toPrice,
 } from "../../npm-client/src/";
 import {
   authoriseOperator,
@@ -134,6 +136,13 @@ export class Monaco {
     return await this.program.account.marketLiquidities.fetch(pk);
   }
 
+  async fetchMarketOutcomeLiquidities(marketPk: PublicKey) {
+    const accounts = await this.program.account.marketOutcomeLiquidities.all([
+      { memcmp: { offset: 8, bytes: marketPk.toBase58() } },
+    ]);
+    return accounts.map((account) => account.account);
+  }
+
   async fetchMarketMatchingQueue(pk: PublicKey) {
     return await this.program.account.marketMatchingQueue.fetch(pk);
   }
@@ -183,7 +192,7 @@ export class Monaco {
     const marketOutcome = await this.fetchMarketOutcome(marketOutcomePk);
     return {
       title: marketOutcome.title,
-      price: marketOutcome.priceLadder,
+      price: marketOutcome.priceLadder.map(fromPrice),
     };
   }
 
@@ -204,7 +213,7 @@ export class Monaco {
       distinctSeed: orderRequest.distinctSeed,
       forOutcome: orderRequest.forOutcome,
       marketOutcomeIndex: orderRequest.marketOutcomeIndex,
-      expectedPrice: orderRequest.expectedPrice,
+      expectedPrice: fromPrice(orderRequest.expectedPrice),
       delayExpirationTimestamp: orderRequest.delayExpirationTimestamp,
     };
   }
@@ -217,21 +226,39 @@ export class Monaco {
     const marketLiquidities = await this.fetchMarketLiquidities(
       marketLiquiditiesPk,
     );
+    const marketOutcomeLiquidities = await this.fetchMarketOutcomeLiquidities(
+      marketLiquidities.market,
+    );
+
+    // each outcome's liquidities are held by either the market liquidities or the outcome's own
+    // account, so merging them in the program's outcome order gives the full view
+    const allLiquiditiesFor = marketLiquidities.liquiditiesFor
+      .concat(...marketOutcomeLiquidities.map((l) => l.liquiditiesFor))
+      .sort((a, b) => a.outcome - b.outcome);
+    const allLiquiditiesAgainst = marketLiquidities.liquiditiesAgainst
+      .concat(...marketOutcomeLiquidities.map((l) => l.liquiditiesAgainst))
+      .sort((a, b) => b.outcome - a.outcome);
 
-    const liquiditiesFor = marketLiquidities.liquiditiesFor.map((l) => {
+    const liquiditiesFor = allLiquiditiesFor.map((l) => {
       return {
         outcome: l.outcome,
-        price: l.price,
+        price: fromPrice(l.price),
         liquidity: l.liquidity.toNumber() / decimalsMultiplier,
-        sources: l.sources,
+        sources: l.sources.map((s) => ({
+          outcome: s.outcome,
+          price: fromPrice(s.price),
+        })),
       };
     });
-    const liquiditiesAgainst = marketLiquidities.liquiditiesAgainst.map((l) => {
+    const liquiditiesAgainst = allLiquiditiesAgainst.map((l) => {
       return {
         outcome: l.outcome,
-        price: l.price,
+        price: fromPrice(l.price),
         liquidity: l.liquidity.toNumber() / decimalsMultiplier,
-        sources: l.sources,
+        sources: l.sources.map((s) => ({
+          outcome: s.outcome,
+          price: fromPrice(s.price),
+        })),
       };
     });
 
@@ -261,7 +288,7 @@ export class Monaco {
       pk: matchesHead.pk,
       forOutcome: matchesHead.forOutcome,
       outcomeIndex: matchesHead.outcomeIndex,
-      price: matchesHead.price,
+      price: fromPrice(matchesHead.price),
       stake: matchesHead.stake.toNumber() / decimalsMultiplier,
     };
   }
@@ -542,7 +569,10 @@ export class Monaco {
         const batch = options.priceLadder.slice(i, i + priceLadderBatchSize);
         await this.provider.connection.confirmTransaction(
           await this.program.methods
-            .addPricesToMarketOutcome(parseInt(outcomeIndex), batch)
+            .addPricesToMarketOutcome(
+              parseInt(outcomeIndex),
+              batch.map(toPrice),
+            )
             .accounts({
               outcome: outcomePks[outcomeIndex],
               market: marketPk,
@@ -845,6 +875,21 @@ export class MonacoMarket {
     return await this.monaco.getMarketLiquidities(this.liquiditiesPk);
   }
 
+  outcomeLiquiditiesAccounts(): AccountMeta[] {
+    return this.outcomePks.map((_, outcomeIndex) => ({
+      pubkey: PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("liquidities"),
+          this.pk.toBuffer(),
+          Buffer.from(outcomeIndex.toString()),
+        ],
+        this.monaco.program.programId,
+      )[0],
+      isSigner: false,
+      isWritable: true,
+    }));
+  }
+
   async getMarketMatchingQueueHead() {
     return await this.monaco.getMarketMatchingQueueHead(this.matchingQueuePk);
   }
@@ -960,6 +1005,8 @@ export class MonacoMarket {
       productPk?: PublicKey;
       purchaserToken?: PublicKey;
       expiresOn?: number;
+      timeInForce?: object;
+      postOnly?: boolean;
     },
   ) {
     const orderPk = await findOrderPda(
@@ -972,9 +1019,11 @@ export class MonacoMarket {
         marketOutcomeIndex: outcome,
         forOutcome: forOutcome,
         stake: new BN(this.toAmountInteger(stake)),
-        price: price,
+        price: toPrice(price),
         distinctSeed: Array.from(orderPk.data.distinctSeed),
         expiresOn: overrides.expiresOn ? new BN(overrides.expiresOn) : null,
+        timeInForce: overrides.timeInForce ?? { goodTillCancelled: {} },
+        postOnly: overrides.postOnly ?? false,
       })
       .accounts({
         reservedOrder: orderPk.data.orderPk,
@@ -1026,10 +1075,10 @@ export class MonacoMarket {
 
     const marketMatchingPoolPk = firstOrderRequest.forOutcome
       ? this.matchingPools[firstOrderRequest.marketOutcomeIndex][
-          firstOrderRequest.expectedPrice
+          fromPrice(firstOrderRequest.expectedPrice)
         ].forOutcome
       : this.matchingPools[firstOrderRequest.marketOutcomeIndex][
-          firstOrderRequest.expectedPrice
+          fromPrice(firstOrderRequest.expectedPrice)
         ].against;
 
     await this.monaco.program.methods
@@ -1054,6 +1103,7 @@ export class MonacoMarket {
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(this.outcomeLiquiditiesAccounts())
       .signers(crankKeypair ? [crankKeypair] : [])
       .rpc()
       .catch((e) => {
@@ -1088,12 +1138,18 @@ export class MonacoMarket {
     await this.monaco.program.methods
       .updateMarketLiquiditiesWithCrossLiquidity(
         sourceForOutcome,
-        sourceLiquidities,
+        sourceLiquidities.map((source) => ({
+          outcome: source.outcome,
+          price: toPrice(source.price),
+        })),
       )
       .accounts({
         market: this.pk,
         marketLiquidities: this.liquiditiesPk,
+        crankOperator: this.monaco.operatorPk,
+        systemProgram: SystemProgram.programId,
       })
+      .remainingAccounts(this.outcomeLiquiditiesAccounts())
       .rpc()
       .catch((e) => {
         console.error(e);
@@ -1141,11 +1197,13 @@ export class MonacoMarket {
       purchaser.publicKey,
     );
     const outcomePk = this.outcomePks[order.marketOutcomeIndex];
+    const matchingPools =
+      this.matchingPools[order.marketOutcomeIndex][
+        fromPrice(order.expectedPrice)
+      ];
     const matchingPoolPk = order.forOutcome
-      ? this.matchingPools[order.marketOutcomeIndex][order.expectedPrice]
-          .forOutcome
-      : this.matchingPools[order.marketOutcomeIndex][order.expectedPrice]
-          .against;
+      ? matchingPools.forOutcome
+      : matchingPools.against;
     await this.monaco.program.methods
       .cancelOrder()
       .accounts({
@@ -1162,6 +1220,7 @@ export class MonacoMarket {
         marketMatchingPool: matchingPoolPk,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(this.outcomeLiquiditiesAccounts())
       .signers([purchaser])
       .rpc()
       .catch((e) => {
@@ -1173,11 +1232,13 @@ export class MonacoMarket {
   async cancelPreplayOrderPostEventStart(orderPk: PublicKey) {
     const [order] = await Promise.all([this.monaco.fetchOrder(orderPk)]);
     const purchaserTokenPk = await this.cachePurchaserTokenPk(order.purchaser);
+    const matchingPools =
+      this.matchingPools[order.marketOutcomeIndex][
+        fromPrice(order.expectedPrice)
+      ];
     const matchingPoolPk = order.forOutcome
-      ? this.matchingPools[order.marketOutcomeIndex][order.expectedPrice]
-          .forOutcome
-      : this.matchingPools[order.marketOutcomeIndex][order.expectedPrice]
-          .against;
+      ? matchingPools.forOutcome
+      : matchingPools.against;
     await this.monaco.program.methods
       .cancelPreplayOrderPostEventStart()
       .accounts({
@@ -1193,6 +1254,7 @@ export class MonacoMarket {
         orderRequestQueue: this.orderRequestQueuePk,
         matchingQueue: this.matchingQueuePk,
       })
+      .remainingAccounts(this.outcomeLiquiditiesAccounts())
       .rpc()
       .catch((e) => {
         console.error(e);
@@ -1203,11 +1265,13 @@ export class MonacoMarket {
   async cancelOrderPostMarketLock(orderPk: PublicKey) {
     const [order] = await Promise.all([this.monaco.fetchOrder(orderPk)]);
     const purchaserTokenPk = await this.cachePurchaserTokenPk(order.purchaser);
+    const matchingPools =
+      this.matchingPools[order.marketOutcomeIndex][
+        fromPrice(order.expectedPrice)
+      ];
     const matchingPoolPk = order.forOutcome
-      ? this.matchingPools[order.marketOutcomeIndex][order.expectedPrice]
-          .forOutcome
-      : this.matchingPools[order.marketOutcomeIndex][order.expectedPrice]
-          .against;
+      ? matchingPools.forOutcome
+      : matchingPools.against;
     await this.monaco.program.methods
       .cancelOrderPostMarketLock()
       .accounts({
@@ -1223,6 +1287,7 @@ export class MonacoMarket {
         marketLiquidities: this.liquiditiesPk,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(this.outcomeLiquiditiesAccounts())
       .rpc()
       .catch((e) => {
         console.error(e);
@@ -1263,7 +1328,7 @@ export class MonacoMarket {
       const result = await this.processMatchingQueueMakerOnce(
         orderMatch.forOutcome,
         orderMatch.outcomeIndex,
-        orderMatch.price,
+        fromPrice(orderMatch.price),
         crankOperatorKeypair,
       );
 
@@ -1382,7 +1447,9 @@ export class MonacoMarket {
     ).data.pda;
 
     await this.monaco.program.methods
-      .settleMarket(outcome)
+      .settleMarket([
+        { outcomeIndex: outcome, deadHeatNumerator: 1, deadHeatDenominator: 1 },
+      ])
       .accounts({
         market: this.pk,
         marketMatchingQueue: this.matchingQueuePk,